@@ -1,9 +1,28 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use futures::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 use tokio::sync::broadcast::{self, Receiver, Sender};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize)]
+/// Tenant id used when a request carries no `x-tenant-id` header.
+pub const DEFAULT_TENANT: &str = "default";
+
+/// Default on-disk location for `AlertHub`'s persistent spool, used when no
+/// `ALERT_SPOOL_PATH` environment variable is set (see `main.rs`).
+pub const DEFAULT_ALERT_SPOOL_PATH: &str = "data/alert_spool.json";
+
+// Derive order `Info < Warning < Critical` so `AlertsWsSubscription::min_severity`
+// (see `handlers::alerts_ws`) can filter with a plain `>=` comparison; variant
+// declaration order is what `#[derive(PartialOrd, Ord)]` ranks on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AlertSeverity {
     Info,
@@ -11,7 +30,7 @@ pub enum AlertSeverity {
     Critical,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AlertCategory {
     CollisionRisk,
@@ -19,7 +38,7 @@ pub enum AlertCategory {
     ServiceHealth,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiveAlert {
     pub id: Uuid,
     pub tenant_id: String,
@@ -29,24 +48,203 @@ pub struct LiveAlert {
     pub category: AlertCategory,
     pub created_at: DateTime<Utc>,
     pub metadata: serde_json::Value,
+    /// Monotonically increasing, stamped by `AlertHub::publish`. Lets a
+    /// reconnecting SSE client (via `Last-Event-ID`) ask for only the
+    /// alerts it hasn't seen yet via `AlertHub::subscribe_since`; the
+    /// `/alerts/ws` transport has no resume and only receives live alerts.
+    pub seq: u64,
+}
+
+/// Server-side filter applied to both the replay buffer and the live feed in
+/// `AlertHub::subscribe_since`.
+#[derive(Debug, Clone)]
+pub struct AlertFilter {
+    pub tenant_id: String,
+    pub categories: Option<HashSet<AlertCategory>>,
+    pub min_severity: AlertSeverity,
+}
+
+impl AlertFilter {
+    pub fn new(tenant_id: String) -> Self {
+        Self {
+            tenant_id,
+            categories: None,
+            min_severity: AlertSeverity::Info,
+        }
+    }
+
+    pub fn matches(&self, alert: &LiveAlert) -> bool {
+        alert.tenant_id == self.tenant_id
+            && alert.severity >= self.min_severity
+            && self
+                .categories
+                .as_ref()
+                .map_or(true, |categories| categories.contains(&alert.category))
+    }
 }
 
 #[derive(Clone)]
 pub struct AlertHub {
     sender: Sender<LiveAlert>,
+    buffer: Arc<RwLock<VecDeque<LiveAlert>>>,
+    capacity: usize,
+    next_seq: Arc<AtomicU64>,
+    /// Where `publish` spools the replay buffer to disk, if set. `None`
+    /// means in-memory only, matching the pre-chunk7-3 behavior (and what
+    /// tests/examples that construct an `AlertHub` directly still get).
+    persistence_path: Option<PathBuf>,
 }
 
 impl AlertHub {
     pub fn new(buffer: usize) -> Self {
         let (sender, _) = broadcast::channel(buffer);
-        Self { sender }
+        Self {
+            sender,
+            buffer: Arc::new(RwLock::new(VecDeque::with_capacity(buffer))),
+            capacity: buffer,
+            next_seq: Arc::new(AtomicU64::new(1)),
+            persistence_path: None,
+        }
+    }
+
+    /// Like [`AlertHub::new`], but backs the replay buffer with a bounded
+    /// on-disk spool at `path`: existing entries (if any) are loaded back
+    /// into the buffer and `next_seq` is resumed from the highest stored
+    /// sequence number, so a server restart doesn't reset a reconnecting
+    /// client's `Last-Event-ID` watermark to nothing. A missing or corrupt
+    /// spool file just starts empty, the same as a fresh `AlertHub::new`.
+    pub fn with_persistence<P: AsRef<Path>>(buffer: usize, path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let backlog = load_spool(&path).unwrap_or_default();
+
+        let (sender, _) = broadcast::channel(buffer);
+        let next_seq = backlog.back().map_or(1, |alert| alert.seq + 1);
+
+        Self {
+            sender,
+            buffer: Arc::new(RwLock::new(backlog)),
+            capacity: buffer,
+            next_seq: Arc::new(AtomicU64::new(next_seq)),
+            persistence_path: Some(path),
+        }
     }
 
     pub fn subscribe(&self) -> Receiver<LiveAlert> {
         self.sender.subscribe()
     }
 
-    pub fn publish(&self, alert: LiveAlert) {
+    /// Number of live subscribers (SSE + WS connections currently attached).
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Count of alerts published per category within `window` of now, read
+    /// straight off the replay buffer — good enough for a monitoring
+    /// snapshot without keeping a separate counter per category.
+    pub fn recent_publish_counts(
+        &self,
+        window: chrono::Duration,
+    ) -> std::collections::HashMap<AlertCategory, usize> {
+        let since = Utc::now() - window;
+        let mut counts = std::collections::HashMap::new();
+        for alert in self.buffer.read().unwrap().iter() {
+            if alert.created_at >= since {
+                *counts.entry(alert.category).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    pub fn publish(&self, mut alert: LiveAlert) {
+        alert.seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+
+        let mut buffer = self.buffer.write().unwrap();
+        buffer.push_back(alert.clone());
+        while buffer.len() > self.capacity {
+            buffer.pop_front();
+        }
+
+        if let Some(path) = &self.persistence_path {
+            if let Err(err) = persist_spool(path, &buffer) {
+                tracing::warn!("alert_hub.persist.failure" = %err, "Failed to persist alert spool");
+            }
+        }
+        drop(buffer);
+
         let _ = self.sender.send(alert);
     }
+
+    /// Replays buffered alerts with `seq > last_seq` matching `filter`, then
+    /// switches to the live broadcast feed — a reconnecting subscriber never
+    /// sees a gap, even if it was disconnected long enough to miss broadcasts.
+    ///
+    /// Subscribing before reading the buffer (and re-filtering the live feed
+    /// against the replay watermark) closes the race where an alert is
+    /// published between the snapshot read and the broadcast subscription.
+    pub fn subscribe_since(
+        &self,
+        last_seq: Option<u64>,
+        filter: AlertFilter,
+    ) -> impl Stream<Item = LiveAlert> {
+        let receiver = self.subscribe();
+
+        let backlog: Vec<LiveAlert> = {
+            let buffer = self.buffer.read().unwrap();
+            buffer
+                .iter()
+                .filter(|alert| last_seq.map_or(true, |since| alert.seq > since))
+                .filter(|alert| filter.matches(alert))
+                .cloned()
+                .collect()
+        };
+        let watermark = backlog.last().map(|alert| alert.seq).or(last_seq).unwrap_or(0);
+
+        let live = BroadcastStream::new(receiver).filter_map(move |result| {
+            let filter = filter.clone();
+            async move {
+                match result {
+                    Ok(alert) if alert.seq > watermark && filter.matches(&alert) => Some(alert),
+                    Ok(_) => None,
+                    Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                        tracing::warn!("Alert hub subscriber lagged, skipped {} alerts", skipped);
+                        None
+                    }
+                }
+            }
+        });
+
+        stream::iter(backlog).chain(live)
+    }
+}
+
+/// Loads a previously-persisted replay buffer, oldest alert first. Returns
+/// `None` if the file doesn't exist or fails to parse -- the caller treats
+/// that the same as "no history yet" rather than refusing to start.
+fn load_spool(path: &Path) -> Option<VecDeque<LiveAlert>> {
+    let mut file = File::open(path).ok()?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).ok()?;
+    serde_json::from_str::<Vec<LiveAlert>>(&contents)
+        .ok()
+        .map(VecDeque::from)
+}
+
+/// Writes the whole replay buffer out as a JSON array, same atomic
+/// temp-file-then-`rename` pattern as `RiskModel::persist` in `ml.rs`, so a
+/// crash mid-write can never leave a truncated spool that fails to parse on
+/// the next `load_spool`.
+fn persist_spool(path: &Path, buffer: &VecDeque<LiveAlert>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let payload = serde_json::to_string(&buffer.iter().collect::<Vec<_>>())?;
+
+    let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(payload.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&tmp_path, path)
 }
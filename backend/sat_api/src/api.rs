@@ -1,33 +1,120 @@
-use crate::tle::{Result, SatelliteGroup, SatellitePosition, TleFetcher};
+use crate::gnss::{assess_constellation, ConstellationStatus, Gnss};
+use crate::search::SatelliteIndex;
+use crate::timescale::TimeScale;
+use crate::tle::{
+    dedup_keep_newest_epoch, N2yoFallback, Result, SatApiError, SatelliteData, SatelliteGroup,
+    SatellitePosition, TleFetcher,
+};
 use crate::tracker::SatelliteTracker;
 use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tokio::time::{interval, Duration as TokioDuration};
 
+/// Every group fetched into the catalog. Each tracks its own
+/// last-successful-fetch timestamp and refresh cadence so a slow/broken
+/// Starlink feed doesn't hold back GPS or ISS data, and so a failed fetch
+/// doesn't wipe out the previous catalog for that source.
+const SOURCE_NAMES: [&str; 5] = ["navigation", "communication", "active", "stations", "starlink"];
+
+/// How often the background task checks for stale sources. Actual re-fetch
+/// cadence per source is governed by `SourceEntry::interval_hours`.
+const BACKGROUND_CHECK_INTERVAL_SECONDS: u64 = 15 * 60;
+
+struct SourceEntry {
+    satellites: Vec<SatelliteData>,
+    last_success: Option<DateTime<Utc>>,
+    interval_hours: i64,
+    /// Error from the most recent fetch attempt, if it failed or returned
+    /// nothing. Cleared on the next successful fetch.
+    last_error: Option<SourceFetchError>,
+}
+
+/// What went wrong on a source's most recent fetch attempt, kept alongside
+/// the retained catalog so `get_statistics`/the health endpoint can tell
+/// operators which feed is degraded and why, instead of only a log line.
+#[derive(Debug, Clone, serde::Serialize)]
+struct SourceFetchError {
+    message: String,
+    occurred_at: DateTime<Utc>,
+}
+
+impl SourceEntry {
+    fn new(interval_hours: i64) -> Self {
+        Self { satellites: Vec::new(), last_success: None, interval_hours, last_error: None }
+    }
+
+    fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        match self.last_success {
+            None => true,
+            Some(last) => now.signed_duration_since(last) > Duration::hours(self.interval_hours),
+        }
+    }
+
+    fn record_success(&mut self, satellites: Vec<SatelliteData>, at: DateTime<Utc>) {
+        self.satellites = satellites;
+        self.last_success = Some(at);
+        self.last_error = None;
+    }
+
+    fn record_failure(&mut self, message: String, at: DateTime<Utc>) {
+        self.last_error = Some(SourceFetchError { message, occurred_at: at });
+    }
+}
+
+/// Per-source refresh cadence, overridable via `TLE_REFRESH_HOURS_<SOURCE>`
+/// (e.g. `TLE_REFRESH_HOURS_STARLINK=2`), defaulting to `default_hours`.
+fn source_interval_hours(name: &str, default_hours: i64) -> i64 {
+    std::env::var(format!("TLE_REFRESH_HOURS_{}", name.to_uppercase()))
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default_hours)
+}
+
+async fn fetch_source(fetcher: &TleFetcher, name: &str) -> Result<Vec<SatelliteData>> {
+    match name {
+        "navigation" => fetcher.fetch_navigation_satellites().await,
+        "communication" => fetcher.fetch_communication_satellites().await,
+        "active" => fetcher.fetch_active_satellites().await,
+        "stations" => fetcher.fetch_iss().await,
+        "starlink" => fetcher.fetch_starlink().await,
+        other => unreachable!("unknown satellite source '{other}'"),
+    }
+}
+
+/// Key under which satellites fetched by `refresh_single_satellite` are
+/// held in `sources`, alongside the bulk `SOURCE_NAMES` feeds. Not in
+/// `SOURCE_NAMES` itself since single-satellite lookups aren't polled on a
+/// fixed cadence -- they're only ever populated on demand.
+const MANUAL_SOURCE_NAME: &str = "manual";
+
 pub struct SatelliteApi {
     tracker: Arc<Mutex<SatelliteTracker>>,
     fetcher: TleFetcher,
-    last_update: Arc<Mutex<DateTime<Utc>>>,
-    update_interval_hours: i64,
+    n2yo: N2yoFallback,
+    sources: Arc<Mutex<HashMap<&'static str, SourceEntry>>>,
 }
 
 impl SatelliteApi {
     pub fn new() -> Self {
+        let mut sources = HashMap::new();
+        for name in SOURCE_NAMES {
+            sources.insert(name, SourceEntry::new(source_interval_hours(name, 6)));
+        }
+        sources.insert(MANUAL_SOURCE_NAME, SourceEntry::new(source_interval_hours(MANUAL_SOURCE_NAME, 6)));
+
         Self {
             tracker: Arc::new(Mutex::new(SatelliteTracker::new())),
             fetcher: TleFetcher::new(),
-            last_update: Arc::new(Mutex::new(Utc::now() - Duration::days(1))), // Force initial update
-            update_interval_hours: 6, // Update every 6 hours
+            n2yo: N2yoFallback::new(),
+            sources: Arc::new(Mutex::new(sources)),
         }
     }
 
     pub async fn initialize(&self) -> Result<()> {
         tracing::info!("Initializing Satellite API...");
 
-        // Initial data load
-        self.update_satellite_data().await?;
-
-        // Start background update task
+        self.refresh_stale_sources().await?;
         self.start_background_updates().await;
 
         tracing::info!("Satellite API initialized successfully");
@@ -59,10 +146,15 @@ impl SatelliteApi {
         Ok(positions)
     }
 
+    /// `scale` governs only the returned `timestamp` field; the propagation
+    /// target time is always computed as a UTC offset from `Utc::now()`
+    /// internally, since offset arithmetic would be ambiguous if `now` were
+    /// read in a stepped scale like UTC is around a leap second.
     pub async fn get_all_satellites_with_offset(
         &self,
         minutes_offset: i64,
         limit: Option<usize>,
+        scale: TimeScale,
     ) -> Result<Vec<SatellitePosition>> {
         self.ensure_data_fresh().await?;
 
@@ -77,14 +169,151 @@ impl SatelliteApi {
             positions.truncate(limit);
         }
 
+        for position in &mut positions {
+            position.timestamp = scale.from_utc(position.timestamp);
+        }
+
         Ok(positions)
     }
 
-    pub async fn get_satellite(&self, norad_id: u64) -> Result<SatellitePosition> {
+    pub async fn get_satellite(&self, norad_id: u64, scale: TimeScale) -> Result<SatellitePosition> {
+        self.ensure_data_fresh().await?;
+
+        let mut position = {
+            let tracker = self.tracker.lock().unwrap();
+            tracker.get_satellite_position(norad_id)?
+        };
+        position.timestamp = scale.from_utc(position.timestamp);
+        Ok(position)
+    }
+
+    /// Raw ECI position for one satellite at an arbitrary epoch, used by
+    /// ground-station pass prediction to sample a single satellite many
+    /// times without re-propagating the whole catalog per sample.
+    pub async fn get_satellite_eci_at(
+        &self,
+        norad_id: u64,
+        time: DateTime<Utc>,
+    ) -> Result<(f64, f64, f64)> {
+        self.ensure_data_fresh().await?;
+
+        let tracker = self.tracker.lock().unwrap();
+        tracker.get_satellite_eci_at(norad_id, time)
+    }
+
+    /// Like `get_satellite_eci_at`'s sibling `get_satellite_position`, but
+    /// for a ground-track sample at an arbitrary future `time`.
+    pub async fn get_satellite_position_at(
+        &self,
+        norad_id: u64,
+        time: DateTime<Utc>,
+    ) -> Result<SatellitePosition> {
         self.ensure_data_fresh().await?;
 
         let tracker = self.tracker.lock().unwrap();
-        tracker.get_satellite_position(norad_id)
+        tracker.get_satellite_position_at(norad_id, time)
+    }
+
+    /// Orbital period (seconds) for a tracked satellite, used to size a
+    /// ground-track sampling window to "one orbit" regardless of altitude.
+    pub async fn orbital_period_seconds(&self, norad_id: u64) -> Result<f64> {
+        self.ensure_data_fresh().await?;
+
+        let tracker = self.tracker.lock().unwrap();
+        tracker.orbital_period_seconds(norad_id)
+    }
+
+    /// Real two-line elements for every currently tracked satellite, keyed
+    /// by NORAD id -- refreshed on the same per-source cadence as
+    /// `get_all_satellites` (see `source_interval_hours`/
+    /// `TLE_REFRESH_HOURS_<SOURCE>`). Callers that need to propagate a
+    /// satellite's actual orbit (rather than its derived position) should
+    /// use this instead of fabricating TLE lines from a `SatellitePosition`.
+    pub async fn get_satellite_tle_catalog(&self) -> Result<Vec<SatelliteData>> {
+        self.ensure_data_fresh().await?;
+
+        let tracker = self.tracker.lock().unwrap();
+        Ok(tracker.get_all_satellite_data())
+    }
+
+    /// Refreshes a single satellite's element set directly from Celestrak's
+    /// CATNR endpoint, falling back to N2YO (see `N2yoFallback`) when
+    /// Celestrak has nothing for this NORAD id. Merges whichever result
+    /// comes back into the tracked catalog, keeping it if it's newer than
+    /// any existing entry for this satellite (see `dedup_keep_newest_epoch`).
+    pub async fn refresh_single_satellite(&self, norad_id: u64) -> Result<SatelliteData> {
+        let from_celestrak = self.fetcher.fetch_by_catnr(norad_id).await.ok().flatten();
+        let fresh = match from_celestrak {
+            Some(sat) => sat,
+            None => self
+                .n2yo
+                .get_tle(norad_id)
+                .await
+                .ok()
+                .flatten()
+                .ok_or(SatApiError::SatelliteNotFound(norad_id))?,
+        };
+
+        {
+            let mut sources = self.sources.lock().unwrap();
+            let entry = sources
+                .get_mut(MANUAL_SOURCE_NAME)
+                .expect("manual source entry registered in new()");
+            entry.satellites.retain(|s| s.norad_id != norad_id);
+            entry.satellites.push(fresh.clone());
+            entry.last_success = Some(Utc::now());
+        }
+        self.rebuild_tracker()?;
+
+        Ok(fresh)
+    }
+
+    /// Typo-tolerant name/NORAD-id/group search over the tracked TLE
+    /// catalog (see `SatelliteIndex`). Rebuilds the index fresh from the
+    /// current catalog on every call rather than maintaining one
+    /// incrementally, matching `get_satellite_tle_catalog`'s own
+    /// always-fresh-snapshot contract.
+    pub async fn search_satellites(&self, query: &str, limit: usize) -> Result<Vec<SatelliteData>> {
+        let catalog = self.get_satellite_tle_catalog().await?;
+        Ok(SatelliteIndex::build(catalog).search(query, limit))
+    }
+
+    /// Constellation-health snapshot for one GNSS system, derived from the
+    /// real tracked TLE catalog (see `get_satellite_tle_catalog`).
+    pub async fn constellation_status(&self, system: Gnss) -> Result<ConstellationStatus> {
+        let catalog = self.get_satellite_tle_catalog().await?;
+        Ok(assess_constellation(system, &catalog))
+    }
+
+    /// Loads an SP3 precise-ephemeris file's arcs into the tracker, keyed by
+    /// NORAD id via `id_to_norad` (SP3's own 3-character ids, e.g. `G01`,
+    /// aren't NORAD catalog numbers, so callers supply the mapping). Once
+    /// loaded, `SatelliteTracker` prefers these arcs over SGP4 for any
+    /// requested time they cover.
+    pub fn load_sp3(&self, contents: &str, id_to_norad: &HashMap<String, u64>) -> usize {
+        let ephemerides = crate::sp3::parse_sp3(contents);
+        let mut tracker = self.tracker.lock().unwrap();
+        let mut loaded = 0;
+        for (sp3_id, ephemeris) in ephemerides {
+            if ephemeris.is_empty() {
+                continue;
+            }
+            if let Some(norad_id) = id_to_norad.get(&sp3_id) {
+                tracker.load_sp3(*norad_id, ephemeris);
+                loaded += 1;
+            } else {
+                tracing::warn!("SP3 satellite id '{}' has no NORAD id mapping; skipping", sp3_id);
+            }
+        }
+        loaded
+    }
+
+    /// Sets the UT1-UTC offset (seconds) the tracker applies to GMST when
+    /// rotating propagated ECI positions into ECEF. See
+    /// `SatelliteTracker::set_ut1_utc_offset`.
+    pub fn set_ut1_utc_offset(&self, offset_seconds: f64) {
+        let mut tracker = self.tracker.lock().unwrap();
+        tracker.set_ut1_utc_offset(offset_seconds);
     }
 
     pub async fn get_satellite_group(&self, group_name: &str) -> Result<SatelliteGroup> {
@@ -101,12 +330,17 @@ impl SatelliteApi {
         })
     }
 
+    /// Current catalog size without forcing a refresh — cheap enough to call
+    /// from the system monitor endpoint on every request.
+    pub fn catalog_size(&self) -> usize {
+        self.tracker.lock().unwrap().get_satellite_count()
+    }
+
     pub async fn get_statistics(&self) -> Result<serde_json::Value> {
         self.ensure_data_fresh().await?;
 
         let tracker = self.tracker.lock().unwrap();
         let total_satellites = tracker.get_satellite_count();
-        let last_update = *self.last_update.lock().unwrap();
 
         // Get group counts
         let starlink_count = tracker.get_satellites_by_group("starlink")?.len();
@@ -115,99 +349,118 @@ impl SatelliteApi {
 
         Ok(serde_json::json!({
             "total_satellites": total_satellites,
-            "last_update": last_update,
             "groups": {
                 "starlink": starlink_count,
                 "gps": gps_count,
                 "galileo": galileo_count
             },
-            "update_interval_hours": self.update_interval_hours
+            "sources": self.source_status()
         }))
     }
 
-    async fn update_satellite_data(&self) -> Result<()> {
-        tracing::info!("Initializing satellite data...");
-
-        let mut all_satellites = Vec::new();
-
-        // Fetch from multiple satellite sources
-        tracing::info!("Fetching satellites from multiple sources...");
-
-        // 1. Navigation satellites (GPS, GLONASS, Galileo, BeiDou)
-        match self.fetcher.fetch_navigation_satellites().await {
-            Ok(mut sats) => {
-                tracing::info!("âœ… Fetched {} navigation satellites", sats.len());
-                all_satellites.append(&mut sats);
-            }
-            Err(e) => tracing::warn!("âŒ Failed to fetch navigation satellites: {}", e),
+    /// Per-source fetch health: whether the last attempt succeeded, when,
+    /// how many satellites it's currently serving, and the error string if
+    /// it's degraded. Backs both `get_statistics`'s `sources` field and the
+    /// dedicated `/sources/health` endpoint.
+    fn source_status(&self) -> serde_json::Value {
+        let now = Utc::now();
+        let sources = self.sources.lock().unwrap();
+        let mut status = serde_json::Map::new();
+        for name in SOURCE_NAMES {
+            let entry = sources.get(name).expect("known source name");
+            status.insert(
+                name.to_string(),
+                serde_json::json!({
+                    "healthy": entry.last_error.is_none(),
+                    "last_success": entry.last_success,
+                    "satellite_count": entry.satellites.len(),
+                    "refresh_interval_hours": entry.interval_hours,
+                    "stale": entry.is_stale(now),
+                    "last_error": entry.last_error,
+                }),
+            );
         }
+        serde_json::Value::Object(status)
+    }
 
-        // 2. Communication satellites (Geostationary, Iridium, etc.)
-        match self.fetcher.fetch_communication_satellites().await {
-            Ok(mut sats) => {
-                tracing::info!("âœ… Fetched {} communication satellites", sats.len());
-                all_satellites.append(&mut sats);
-            }
-            Err(e) => tracing::warn!("âŒ Failed to fetch communication satellites: {}", e),
-        }
+    /// Dedicated health view over the per-source fetch state, for
+    /// `GET /sources/health`, so a broken Starlink feed is visible even
+    /// without pulling the whole statistics payload.
+    pub fn source_health(&self) -> serde_json::Value {
+        self.source_status()
+    }
 
-        // 3. Active/visible satellites (weather, science, etc.)
-        match self.fetcher.fetch_active_satellites().await {
-            Ok(mut sats) => {
-                tracing::info!("âœ… Fetched {} active satellites", sats.len());
-                all_satellites.append(&mut sats);
+    /// Re-fetches only the sources whose `interval_hours` has elapsed since
+    /// their last successful fetch, then rebuilds the tracker from whatever
+    /// per-source catalogs are currently held (fresh or stale-but-retained).
+    async fn refresh_stale_sources(&self) -> Result<()> {
+        let now = Utc::now();
+        let stale_sources: Vec<&'static str> = {
+            let sources = self.sources.lock().unwrap();
+            SOURCE_NAMES
+                .into_iter()
+                .filter(|name| sources.get(name).map(|e| e.is_stale(now)).unwrap_or(true))
+                .collect()
+        };
+
+        for name in stale_sources {
+            tracing::info!("Fetching stale source '{}'...", name);
+            let fetch_result = fetch_source(&self.fetcher, name).await;
+            let mut sources = self.sources.lock().unwrap();
+            let Some(entry) = sources.get_mut(name) else { continue };
+            match fetch_result {
+                Ok(satellites) if !satellites.is_empty() => {
+                    tracing::info!("Fetched {} satellites for source '{}'", satellites.len(), name);
+                    entry.record_success(satellites, now);
+                }
+                Ok(_) => {
+                    let message = "fetch returned no satellites".to_string();
+                    tracing::warn!(
+                        "Source '{}' returned no satellites; retaining previous catalog and retrying next tick",
+                        name
+                    );
+                    entry.record_failure(message, now);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch source '{}': {}", name, e);
+                    entry.record_failure(e.to_string(), now);
+                }
             }
-            Err(e) => tracing::warn!("âŒ Failed to fetch active satellites: {}", e),
         }
 
-        // 4. Space stations (ISS, etc.)
-        match self.fetcher.fetch_iss().await {
-            Ok(mut sats) => {
-                tracing::info!("âœ… Fetched {} space stations", sats.len());
-                all_satellites.append(&mut sats);
-            }
-            Err(e) => tracing::warn!("âŒ Failed to fetch space stations: {}", e),
-        }
+        self.rebuild_tracker()
+    }
 
-        // 5. Starlink constellation
-        match self.fetcher.fetch_starlink().await {
-            Ok(mut sats) => {
-                tracing::info!("âœ… Fetched {} Starlink satellites", sats.len());
-                all_satellites.append(&mut sats);
+    fn rebuild_tracker(&self) -> Result<()> {
+        let mut all_satellites = Vec::new();
+        {
+            let sources = self.sources.lock().unwrap();
+            for entry in sources.values() {
+                all_satellites.extend(entry.satellites.iter().cloned());
             }
-            Err(e) => tracing::warn!("âŒ Failed to fetch Starlink satellites: {}", e),
         }
 
-        // If we got no satellites at all, fall back to sample data
         if all_satellites.is_empty() {
-            tracing::error!("No satellites fetched from any source");
-            return Err(crate::tle::SatApiError::NoSatelliteData);
+            tracing::error!("No satellites available from any source");
+            return Err(SatApiError::NoSatelliteData);
         }
 
-        tracing::info!("ðŸ›°ï¸ Total satellites collected: {}", all_satellites.len());
+        let all_satellites = dedup_keep_newest_epoch(all_satellites);
 
-        // Remove duplicates based on NORAD ID
-        all_satellites.sort_by_key(|s| s.norad_id);
-        all_satellites.dedup_by_key(|s| s.norad_id);
-
-        // Load into tracker
         let mut tracker = self.tracker.lock().unwrap();
-        tracker.load_satellites(all_satellites)?;
-
-        // Update timestamp
-        *self.last_update.lock().unwrap() = Utc::now();
-
-        tracing::info!("Satellite data initialization completed");
-        Ok(())
+        tracker.load_satellites(all_satellites)
     }
 
     async fn ensure_data_fresh(&self) -> Result<()> {
-        let last_update = *self.last_update.lock().unwrap();
         let now = Utc::now();
-
-        if now.signed_duration_since(last_update) > Duration::hours(self.update_interval_hours) {
-            tracing::info!("Data is stale, updating...");
-            self.update_satellite_data().await?;
+        let any_stale = {
+            let sources = self.sources.lock().unwrap();
+            sources.values().any(|e| e.is_stale(now))
+        };
+
+        if any_stale {
+            tracing::info!("One or more sources are stale, refreshing...");
+            self.refresh_stale_sources().await?;
         }
 
         Ok(())
@@ -215,60 +468,66 @@ impl SatelliteApi {
 
     async fn start_background_updates(&self) {
         let tracker = Arc::clone(&self.tracker);
-        let last_update = Arc::clone(&self.last_update);
+        let sources = Arc::clone(&self.sources);
         let fetcher = TleFetcher::new();
-        let update_interval_hours = self.update_interval_hours;
 
         tokio::spawn(async move {
-            let mut interval = interval(TokioDuration::from_secs(
-                (update_interval_hours * 3600) as u64,
-            ));
+            let mut ticker = interval(TokioDuration::from_secs(BACKGROUND_CHECK_INTERVAL_SECONDS));
 
             loop {
-                interval.tick().await;
-
-                tracing::info!("Background satellite data update starting...");
-
-                // Fetch new data from all sources
-                let mut all_satellites = Vec::new();
-
-                // Navigation satellites
-                if let Ok(mut sats) = fetcher.fetch_navigation_satellites().await {
-                    all_satellites.append(&mut sats);
-                }
-
-                // Communication satellites
-                if let Ok(mut sats) = fetcher.fetch_communication_satellites().await {
-                    all_satellites.append(&mut sats);
-                }
-
-                // Active satellites
-                if let Ok(mut sats) = fetcher.fetch_active_satellites().await {
-                    all_satellites.append(&mut sats);
+                ticker.tick().await;
+
+                let now = Utc::now();
+                let stale_sources: Vec<&'static str> = {
+                    let guard = sources.lock().unwrap();
+                    SOURCE_NAMES
+                        .into_iter()
+                        .filter(|name| guard.get(name).map(|e| e.is_stale(now)).unwrap_or(true))
+                        .collect()
+                };
+
+                if stale_sources.is_empty() {
+                    continue;
                 }
 
-                // Space stations
-                if let Ok(mut sats) = fetcher.fetch_iss().await {
-                    all_satellites.append(&mut sats);
+                tracing::info!("Background refresh starting for sources: {:?}", stale_sources);
+
+                for name in stale_sources {
+                    let fetch_result = fetch_source(&fetcher, name).await;
+                    let mut guard = sources.lock().unwrap();
+                    let Some(entry) = guard.get_mut(name) else { continue };
+                    match fetch_result {
+                        Ok(satellites) if !satellites.is_empty() => {
+                            entry.record_success(satellites, now);
+                        }
+                        Ok(_) => {
+                            tracing::warn!(
+                                "Background fetch for '{}' returned no satellites; retaining previous catalog",
+                                name
+                            );
+                            entry.record_failure("fetch returned no satellites".to_string(), now);
+                        }
+                        Err(e) => {
+                            tracing::warn!("Background fetch for '{}' failed: {}", name, e);
+                            entry.record_failure(e.to_string(), now);
+                        }
+                    }
                 }
 
-                // Starlink constellation
-                if let Ok(mut sats) = fetcher.fetch_starlink().await {
-                    all_satellites.append(&mut sats);
+                let mut all_satellites = Vec::new();
+                {
+                    let guard = sources.lock().unwrap();
+                    for entry in guard.values() {
+                        all_satellites.extend(entry.satellites.iter().cloned());
+                    }
                 }
 
-                // Remove duplicates
-                all_satellites.sort_by_key(|s| s.norad_id);
-                all_satellites.dedup_by_key(|s| s.norad_id);
-
                 if all_satellites.is_empty() {
-                    tracing::warn!(
-                        "Background update received no satellites; retaining existing catalog"
-                    );
                     continue;
                 }
 
-                // Update tracker
+                let all_satellites = dedup_keep_newest_epoch(all_satellites);
+
                 if let Ok(mut tracker_guard) = tracker.lock() {
                     if let Err(e) = tracker_guard.load_satellites(all_satellites) {
                         tracing::error!("Failed to update satellite data in background: {}", e);
@@ -276,11 +535,6 @@ impl SatelliteApi {
                     }
                 }
 
-                // Update timestamp
-                if let Ok(mut last_update_guard) = last_update.lock() {
-                    *last_update_guard = Utc::now();
-                }
-
                 tracing::info!("Background satellite data update completed");
             }
         });
@@ -0,0 +1,213 @@
+//! Renders a [`ConjunctionEvent`] as a CCSDS 508.0-B-1 Conjunction Data
+//! Message (CDM), in both the Key-Value Notation (KVN) and XML encodings
+//! the standard defines. `ConjunctionEvent` already carries every field a
+//! CDM needs -- TCA, miss distance, relative velocity, per-object state at
+//! TCA, covariance, Pc -- so this module is purely a presentation layer,
+//! letting OrbitalOS output feed existing space-situational-awareness
+//! tooling instead of only this crate's own JSON schema.
+
+use crate::conjunction::{ConjunctionEvent, ConjunctionSatellite};
+use chrono::{DateTime, Utc};
+use nalgebra::{Matrix3, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// Encoding requested for a CDM export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CdmFormat {
+    Kvn,
+    Xml,
+}
+
+/// `ORIGINATOR` field: who generated this message.
+const ORIGINATOR: &str = "ORBITALOS";
+/// `CATALOG_NAME` field: the element set source used elsewhere in this
+/// crate (see `tle.rs`'s CelesTrak fetch URLs).
+const CATALOG_NAME: &str = "CELESTRAK";
+/// The analytic Pc is the short-encounter 2D integral of the encounter-plane
+/// Gaussian over the hard-body disk, the integral Foster's 1992 method
+/// evaluates. `COLLISION_PROBABILITY_METHOD` takes one enumerated value, so
+/// Monte Carlo estimates are labelled `FOSTER-1992` as well.
+const COLLISION_PROBABILITY_METHOD: &str = "FOSTER-1992";
+
+fn kvn_timestamp(time: &DateTime<Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S%.3f").to_string()
+}
+
+/// Radial/Transverse/Normal unit vectors (expressed in ECI) at an object's
+/// state: R along the position vector, N along the orbit normal (specific
+/// angular momentum direction), T completing the right-handed triad. This
+/// is the frame CDM covariance blocks are conventionally reported in.
+fn rtn_basis(
+    position_km: &Vector3<f64>,
+    velocity_km_s: &Vector3<f64>,
+) -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+    let r = position_km.normalize();
+    let n = position_km.cross(velocity_km_s).normalize();
+    let t = n.cross(&r);
+    (r, t, n)
+}
+
+/// Rotates an ECI covariance matrix into the RTN frame implied by
+/// `position_km`/`velocity_km_s`, the same way `conjunction::analyze_covariance`
+/// projects a 3D covariance into its encounter-plane basis: each rotated
+/// entry is `basis_i . (C_eci * basis_j)`.
+fn covariance_to_rtn(
+    covariance_eci: &Matrix3<f64>,
+    position_km: &Vector3<f64>,
+    velocity_km_s: &Vector3<f64>,
+) -> Matrix3<f64> {
+    let (r, t, n) = rtn_basis(position_km, velocity_km_s);
+    let basis = [r, t, n];
+
+    let mut rotated = Matrix3::zeros();
+    for (i, bi) in basis.iter().enumerate() {
+        for (j, bj) in basis.iter().enumerate() {
+            rotated[(i, j)] = bi.dot(&(covariance_eci * bj));
+        }
+    }
+    rotated
+}
+
+/// The six RTN position-covariance entries a CDM reports, in KVN field
+/// order. Velocity-covariance terms (`CRDOT_*`, `CTDOT_*`, `CNDOT_*`,
+/// `CDRG_*`) are left out entirely rather than padded with zeros:
+/// `ConjunctionAnalyzer`'s covariance model is position-only (see
+/// `conjunction::estimate_covariance`), and a real SSA consumer should be
+/// able to tell "not modeled" apart from "modeled as exactly zero".
+struct RtnCovarianceEntries {
+    cr_r: f64,
+    ct_r: f64,
+    ct_t: f64,
+    cn_r: f64,
+    cn_t: f64,
+    cn_n: f64,
+}
+
+fn rtn_covariance_entries(object: &ConjunctionSatellite) -> RtnCovarianceEntries {
+    let rtn = covariance_to_rtn(&object.covariance_matrix, &object.position_at_tca, &object.velocity_at_tca);
+    RtnCovarianceEntries {
+        cr_r: rtn[(0, 0)],
+        ct_r: rtn[(1, 0)],
+        ct_t: rtn[(1, 1)],
+        cn_r: rtn[(2, 0)],
+        cn_t: rtn[(2, 1)],
+        cn_n: rtn[(2, 2)],
+    }
+}
+
+/// One object's KVN metadata + state-vector + covariance block.
+fn object_block_kvn(label: &str, object: &ConjunctionSatellite) -> String {
+    let c = rtn_covariance_entries(object);
+    let p = &object.position_at_tca;
+    let v = &object.velocity_at_tca;
+
+    vec![
+        format!("OBJECT = {label}"),
+        format!("OBJECT_DESIGNATOR = {}", object.norad_id),
+        format!("CATALOG_NAME = {CATALOG_NAME}"),
+        format!("OBJECT_NAME = {}", object.name),
+        "EPHEMERIS_NAME = NONE".to_string(),
+        "REF_FRAME = EME2000".to_string(),
+        format!("X = {:.6} [km]", p.x),
+        format!("Y = {:.6} [km]", p.y),
+        format!("Z = {:.6} [km]", p.z),
+        format!("X_DOT = {:.6} [km/s]", v.x),
+        format!("Y_DOT = {:.6} [km/s]", v.y),
+        format!("Z_DOT = {:.6} [km/s]", v.z),
+        format!("CR_R = {:e} [km**2]", c.cr_r),
+        format!("CT_R = {:e} [km**2]", c.ct_r),
+        format!("CT_T = {:e} [km**2]", c.ct_t),
+        format!("CN_R = {:e} [km**2]", c.cn_r),
+        format!("CN_T = {:e} [km**2]", c.cn_t),
+        format!("CN_N = {:e} [km**2]", c.cn_n),
+    ]
+    .join("\n")
+}
+
+/// Renders `event` as a CCSDS 508.0-B-1 CDM in Key-Value Notation.
+pub fn to_kvn(event: &ConjunctionEvent) -> String {
+    vec![
+        "CCSDS_CDM_VERS = 1.0".to_string(),
+        format!("CREATION_DATE = {}", kvn_timestamp(&Utc::now())),
+        format!("ORIGINATOR = {ORIGINATOR}"),
+        format!("MESSAGE_ID = {}", event.id),
+        String::new(),
+        format!("TCA = {}", kvn_timestamp(&event.tca)),
+        format!("MISS_DISTANCE = {:.3} [m]", event.dmin_km * 1000.0),
+        format!("RELATIVE_SPEED = {:.3} [m/s]", event.relative_velocity_km_s * 1000.0),
+        format!("COLLISION_PROBABILITY = {:e}", event.pc),
+        format!("COLLISION_PROBABILITY_METHOD = {COLLISION_PROBABILITY_METHOD}"),
+        String::new(),
+        object_block_kvn("OBJECT1", &event.satellite_a),
+        String::new(),
+        object_block_kvn("OBJECT2", &event.satellite_b),
+    ]
+    .join("\n")
+}
+
+/// One object's XML metadata + state-vector + covariance block, at a fixed
+/// four-space indent under `<body>`.
+fn object_block_xml(label: &str, object: &ConjunctionSatellite) -> String {
+    let c = rtn_covariance_entries(object);
+    let p = &object.position_at_tca;
+    let v = &object.velocity_at_tca;
+
+    vec![
+        format!("    <{label}>"),
+        format!("      <OBJECT_DESIGNATOR>{}</OBJECT_DESIGNATOR>", object.norad_id),
+        format!("      <CATALOG_NAME>{CATALOG_NAME}</CATALOG_NAME>"),
+        format!("      <OBJECT_NAME>{}</OBJECT_NAME>", object.name),
+        "      <EPHEMERIS_NAME>NONE</EPHEMERIS_NAME>".to_string(),
+        "      <REF_FRAME>EME2000</REF_FRAME>".to_string(),
+        format!("      <X units=\"km\">{:.6}</X>", p.x),
+        format!("      <Y units=\"km\">{:.6}</Y>", p.y),
+        format!("      <Z units=\"km\">{:.6}</Z>", p.z),
+        format!("      <X_DOT units=\"km/s\">{:.6}</X_DOT>", v.x),
+        format!("      <Y_DOT units=\"km/s\">{:.6}</Y_DOT>", v.y),
+        format!("      <Z_DOT units=\"km/s\">{:.6}</Z_DOT>", v.z),
+        format!("      <CR_R units=\"km**2\">{:e}</CR_R>", c.cr_r),
+        format!("      <CT_R units=\"km**2\">{:e}</CT_R>", c.ct_r),
+        format!("      <CT_T units=\"km**2\">{:e}</CT_T>", c.ct_t),
+        format!("      <CN_R units=\"km**2\">{:e}</CN_R>", c.cn_r),
+        format!("      <CN_T units=\"km**2\">{:e}</CN_T>", c.cn_t),
+        format!("      <CN_N units=\"km**2\">{:e}</CN_N>", c.cn_n),
+        format!("    </{label}>"),
+    ]
+    .join("\n")
+}
+
+/// Renders `event` as a CCSDS 508.0-B-1 CDM in XML. Same field mapping and
+/// velocity-covariance omission as [`to_kvn`].
+pub fn to_xml(event: &ConjunctionEvent) -> String {
+    vec![
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+        "<cdm id=\"CCSDS_CDM_VERS\" version=\"1.0\">".to_string(),
+        "  <header>".to_string(),
+        format!("    <CREATION_DATE>{}</CREATION_DATE>", kvn_timestamp(&Utc::now())),
+        format!("    <ORIGINATOR>{ORIGINATOR}</ORIGINATOR>"),
+        format!("    <MESSAGE_ID>{}</MESSAGE_ID>", event.id),
+        "  </header>".to_string(),
+        "  <body>".to_string(),
+        "    <relativeMetadataData>".to_string(),
+        format!("      <TCA>{}</TCA>", kvn_timestamp(&event.tca)),
+        format!(
+            "      <MISS_DISTANCE units=\"m\">{:.3}</MISS_DISTANCE>",
+            event.dmin_km * 1000.0
+        ),
+        format!(
+            "      <RELATIVE_SPEED units=\"m/s\">{:.3}</RELATIVE_SPEED>",
+            event.relative_velocity_km_s * 1000.0
+        ),
+        format!("      <COLLISION_PROBABILITY>{:e}</COLLISION_PROBABILITY>", event.pc),
+        format!(
+            "      <COLLISION_PROBABILITY_METHOD>{COLLISION_PROBABILITY_METHOD}</COLLISION_PROBABILITY_METHOD>"
+        ),
+        "    </relativeMetadataData>".to_string(),
+        object_block_xml("OBJECT1", &event.satellite_a),
+        object_block_xml("OBJECT2", &event.satellite_b),
+        "  </body>".to_string(),
+        "</cdm>".to_string(),
+    ]
+    .join("\n")
+}
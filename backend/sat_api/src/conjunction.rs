@@ -1,7 +1,244 @@
-use crate::tle::{Result, SatelliteData};
-use chrono::{DateTime, Duration, Utc};
+use crate::sp3::Sp3Ephemeris;
+use crate::tle::{Result, SatApiError, SatelliteData};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use nalgebra::{Matrix3, Vector3};
 use serde::{Deserialize, Serialize, Serializer};
+use sgp4::{Constants as Sgp4Constants, Elements as Sgp4Elements};
+use std::collections::HashMap;
+
+const EARTH_RADIUS_KM: f64 = 6378.137;
+const EARTH_GRAVITATIONAL_PARAMETER_KM3_S2: f64 = 398600.4418;
+
+/// Default combined hard-body radius contribution per object when nothing
+/// more specific is known (a small spacecraft bus, ~5m).
+const DEFAULT_HARD_BODY_RADIUS_KM: f64 = 0.005;
+
+/// Parses a satellite's stored TLE into real SGP4 elements and constants.
+/// Built fresh on every call rather than cached, matching the rest of this
+/// analyzer's call-by-call style.
+fn sgp4_elements(sat_data: &SatelliteData) -> Result<(Sgp4Elements, Sgp4Constants)> {
+    let elements = Sgp4Elements::from_tle(
+        Some(sat_data.name.clone()),
+        sat_data.tle_line1.as_bytes(),
+        sat_data.tle_line2.as_bytes(),
+    )
+    .map_err(|err| SatApiError::TleParseError(err.to_string()))?;
+    let constants = Sgp4Constants::from_elements(&elements)
+        .map_err(|err| SatApiError::PropagationError(err.to_string()))?;
+    Ok((elements, constants))
+}
+
+/// Propagates a satellite's TLE to `time` via real SGP4, returning true
+/// TEME/ECI position (km) and velocity (km/s).
+fn sgp4_propagate(sat_data: &SatelliteData, time: &DateTime<Utc>) -> Result<(Vector3<f64>, Vector3<f64>)> {
+    let (elements, constants) = sgp4_elements(sat_data)?;
+    let minutes_since_epoch = elements
+        .datetime_to_minutes_since_epoch(&time.naive_utc())
+        .map_err(|err| SatApiError::PropagationError(err.to_string()))?;
+    let prediction = constants
+        .propagate(minutes_since_epoch)
+        .map_err(|err| SatApiError::PropagationError(err.to_string()))?;
+
+    Ok((
+        Vector3::new(prediction.position[0], prediction.position[1], prediction.position[2]),
+        Vector3::new(prediction.velocity[0], prediction.velocity[1], prediction.velocity[2]),
+    ))
+}
+
+/// Semi-major axis (km) implied by a mean motion (rev/day) via Kepler's
+/// third law -- the same quantity SGP4 derives internally from the TLE.
+fn semi_major_axis_km(mean_motion_rev_per_day: f64) -> f64 {
+    let period_seconds = 86400.0 / mean_motion_rev_per_day;
+    ((period_seconds / (2.0 * std::f64::consts::PI)).powi(2) * EARTH_GRAVITATIONAL_PARAMETER_KM3_S2)
+        .powf(1.0 / 3.0)
+}
+
+/// Perigee and apogee radii (km) implied by an orbit's semi-major axis and
+/// eccentricity: `q = a(1-e)`, `Q = a(1+e)`.
+fn perigee_apogee_km(elements: &Sgp4Elements) -> (f64, f64) {
+    let a = semi_major_axis_km(elements.mean_motion);
+    let e = elements.eccentricity;
+    (a * (1.0 - e), a * (1.0 + e))
+}
+
+/// Orbit-plane unit normal (specific angular momentum direction) in ECI,
+/// from inclination and RAAN: `n = (sin(i)sin(Ω), -sin(i)cos(Ω), cos(i))`.
+fn orbit_normal(inclination_rad: f64, raan_rad: f64) -> Vector3<f64> {
+    Vector3::new(
+        inclination_rad.sin() * raan_rad.sin(),
+        -inclination_rad.sin() * raan_rad.cos(),
+        inclination_rad.cos(),
+    )
+}
+
+/// Unit vector toward the ascending node, in ECI.
+fn ascending_node(raan_rad: f64) -> Vector3<f64> {
+    Vector3::new(raan_rad.cos(), raan_rad.sin(), 0.0)
+}
+
+/// Time windows (within `[start_time, end_time]`, padded by `margin` on
+/// each side) when `elements` is near a crossing of the line where its
+/// orbital plane intersects the plane with normal `other_normal` -- the
+/// only places the two orbits can possibly come close to each other.
+/// Returns `None` when the two planes are nearly coincident, since then
+/// there's no single crossing line to localize around and the full horizon
+/// must be sampled.
+fn node_crossing_windows(
+    elements: &Sgp4Elements,
+    other_normal: &Vector3<f64>,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    margin: Duration,
+) -> Option<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+    let inclination = elements.inclination.to_radians();
+    let raan = elements.right_ascension.to_radians();
+    let normal = orbit_normal(inclination, raan);
+
+    let line_of_nodes = normal.cross(other_normal);
+    if line_of_nodes.norm() < 1e-6 {
+        return None;
+    }
+    let line_of_nodes = line_of_nodes.normalize();
+
+    let node_vector = ascending_node(raan);
+    let cross_track = normal.cross(&node_vector);
+
+    let eccentricity = elements.eccentricity;
+    let argument_of_perigee = elements.argument_of_perigee.to_radians();
+    let period_seconds = 86400.0 / elements.mean_motion;
+    let mean_motion_rad_per_sec = 2.0 * std::f64::consts::PI / period_seconds;
+    let period = Duration::milliseconds((period_seconds * 1000.0) as i64);
+    let epoch = Utc.from_utc_datetime(&elements.datetime);
+    let mean_anomaly_epoch = elements.mean_anomaly.to_radians();
+
+    let mut windows = Vec::new();
+    for line_direction in [line_of_nodes, -line_of_nodes] {
+        // Argument of latitude where the orbit crosses this direction.
+        let cos_u = node_vector.dot(&line_direction);
+        let sin_u = cross_track.dot(&line_direction);
+        let argument_of_latitude = sin_u.atan2(cos_u);
+
+        let true_anomaly = argument_of_latitude - argument_of_perigee;
+        let tan_half_ecc = ((1.0 - eccentricity) / (1.0 + eccentricity)).sqrt() * (true_anomaly / 2.0).tan();
+        let eccentric_anomaly = 2.0 * tan_half_ecc.atan();
+        let mean_anomaly_at_crossing = eccentric_anomaly - eccentricity * eccentric_anomaly.sin();
+
+        let phase = (mean_anomaly_at_crossing - mean_anomaly_epoch).rem_euclid(2.0 * std::f64::consts::PI);
+        let mut crossing_time = epoch + Duration::milliseconds((phase / mean_motion_rad_per_sec * 1000.0) as i64);
+
+        while crossing_time < start_time {
+            crossing_time += period;
+        }
+        while crossing_time <= end_time {
+            windows.push((crossing_time - margin, crossing_time + margin));
+            crossing_time += period;
+        }
+    }
+
+    windows.sort_by_key(|window| window.0);
+    Some(windows)
+}
+
+/// Merges and sorts two sets of time windows, coalescing any that overlap
+/// or touch so the sampling loop never visits the same instant twice.
+fn merge_windows(
+    mut a: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    b: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    a.extend(b);
+    a.sort_by_key(|window| window.0);
+
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for window in a {
+        match merged.last_mut() {
+            Some(last) if window.0 <= last.1 => last.1 = last.1.max(window.1),
+            _ => merged.push(window),
+        }
+    }
+    merged
+}
+
+/// Rotates an SP3 ECEF state into ECI/TEME via Greenwich Mean Sidereal
+/// Time, so it's directly comparable to SGP4 output. Velocity is rotated
+/// by the same matrix without the Earth-rotation (ω×r) correction, matching
+/// the level of approximation already used by this crate's other ECI/ECEF
+/// conversions (see `tracker::eci_to_geodetic`).
+fn ecef_to_eci(
+    position_km: (f64, f64, f64),
+    velocity_km_s: (f64, f64, f64),
+    time: DateTime<Utc>,
+) -> (Vector3<f64>, Vector3<f64>) {
+    let theta = crate::ground_station::gmst_rad(time);
+    let (cos_t, sin_t) = (theta.cos(), theta.sin());
+    let rotate = |v: (f64, f64, f64)| Vector3::new(v.0 * cos_t - v.1 * sin_t, v.0 * sin_t + v.1 * cos_t, v.2);
+    (rotate(position_km), rotate(velocity_km_s))
+}
+
+/// An orthonormal basis (e1, e2) spanning the 2D plane perpendicular to the
+/// relative velocity at TCA -- the "encounter plane" the Pc integral is
+/// evaluated in.
+fn encounter_plane_basis(relative_velocity: &Vector3<f64>) -> (Vector3<f64>, Vector3<f64>) {
+    let v_hat = relative_velocity.normalize();
+
+    // Any vector not parallel to v_hat works as a seed for Gram-Schmidt;
+    // pick whichever basis axis is least aligned with it.
+    let seed = if v_hat.x.abs() < 0.9 {
+        Vector3::new(1.0, 0.0, 0.0)
+    } else {
+        Vector3::new(0.0, 1.0, 0.0)
+    };
+
+    let e1 = (seed - v_hat * v_hat.dot(&seed)).normalize();
+    let e2 = v_hat.cross(&e1);
+    (e1, e2)
+}
+
+/// Monte Carlo Pc: samples the relative state directly from the combined
+/// covariance `Ca+Cb` (variance reduction vs. sampling each object
+/// separately) via its Cholesky factor, and returns the fraction of
+/// sampled relative positions whose norm falls inside the combined
+/// hard-body radius, with a binomial standard error.
+fn monte_carlo_pc(
+    combined_covariance: &Matrix3<f64>,
+    relative_position: &Vector3<f64>,
+    combined_hard_body_radius_km: f64,
+    samples: u32,
+) -> MonteCarloPc {
+    let l = match combined_covariance.cholesky() {
+        Some(chol) => chol.l(),
+        None => {
+            // Near-singular covariance; regularize with a small diagonal
+            // term rather than failing the estimate outright.
+            match (combined_covariance + Matrix3::identity() * 1e-12).cholesky() {
+                Some(chol) => chol.l(),
+                None => return MonteCarloPc { probability: 0.0, samples, standard_error: 0.0 },
+            }
+        }
+    };
+
+    let mut hits = 0u32;
+    for _ in 0..samples {
+        let z = Vector3::new(standard_normal(), standard_normal(), standard_normal());
+        let sampled_relative_position = relative_position + l * z;
+        if sampled_relative_position.norm() < combined_hard_body_radius_km {
+            hits += 1;
+        }
+    }
+
+    let probability = hits as f64 / samples as f64;
+    let standard_error = (probability * (1.0 - probability) / samples as f64).sqrt();
+
+    MonteCarloPc { probability, samples, standard_error }
+}
+
+/// Standard normal sample via the Box-Muller transform, built on the same
+/// `rand` uniform generator already used elsewhere in this codebase (see
+/// `auth.rs`, `sessions.rs`).
+fn standard_normal() -> f64 {
+    let u1: f64 = rand::random::<f64>().max(1e-12);
+    let u2: f64 = rand::random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
 
 // Custom serialization functions for nalgebra types
 fn serialize_vector3<S>(
@@ -55,17 +292,43 @@ pub struct ConjunctionSatellite {
     pub tle_epoch_age_hours: f64,
     #[serde(serialize_with = "serialize_matrix3")]
     pub covariance_matrix: Matrix3<f64>,
+    /// This object's contribution to the combined hard-body radius (km)
+    /// used in the Pc disk integral.
+    pub hard_body_radius_km: f64,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CovarianceAnalysis {
     #[serde(serialize_with = "serialize_matrix3")]
     pub combined_covariance: Matrix3<f64>,
-    #[serde(serialize_with = "serialize_matrix3")]
-    pub collision_plane_projection: Matrix3<f64>,
+    /// Principal standard deviations (km) of the combined covariance
+    /// projected into the 2D encounter plane (perpendicular to the
+    /// relative velocity), largest first.
+    pub principal_sigma1_km: f64,
+    pub principal_sigma2_km: f64,
+    /// The miss vector, projected into the encounter plane and expressed
+    /// in that plane's principal (eigenvector) frame.
+    pub miss_vector_principal_km: [f64; 2],
     pub uncertainty_ellipse_semi_major_km: f64,
     pub uncertainty_ellipse_semi_minor_km: f64,
-    pub uncertainty_volume_km3: f64,
+    pub uncertainty_ellipse_area_km2: f64,
+    /// Present only when the request asked for the Monte Carlo estimator
+    /// instead of (or alongside) the analytic disk integral.
+    pub monte_carlo: Option<MonteCarloPc>,
+}
+
+/// Monte Carlo Pc estimate: the relative state (not each object
+/// independently) is sampled from the combined covariance `Ca+Cb`, which
+/// is the variance-reduced approach -- sampling both objects separately
+/// would need roughly twice the samples for the same standard error.
+#[derive(Debug, Clone, Serialize)]
+pub struct MonteCarloPc {
+    pub probability: f64,
+    pub samples: u32,
+    /// 1-sigma standard error of `probability`, from the binomial
+    /// proportion estimate (`sqrt(p(1-p)/N)`). Lets a caller tell when `N`
+    /// is too low relative to how small `probability` is.
+    pub standard_error: f64,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -82,6 +345,10 @@ pub struct ConjunctionRequest {
     pub horizon_hours: Option<u64>,
     pub screening_distance_km: Option<f64>,
     pub probability_threshold: Option<f64>,
+    /// When set, `pc` is estimated via Monte Carlo sampling of the
+    /// combined covariance with this many samples instead of the analytic
+    /// 2D disk integral.
+    pub monte_carlo_samples: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -92,6 +359,23 @@ pub struct ConjunctionAnalysisResponse {
     pub conjunctions_found: usize,
     pub conjunctions: Vec<ConjunctionEvent>,
     pub screening_parameters: ScreeningParameters,
+    pub sieve_statistics: SieveStatistics,
+}
+
+/// Per-stage rejection counts from the Hoots-style three-stage sieve that
+/// `coarse_screening` runs ahead of position sampling, so a caller can see
+/// how much of the O(n²) pair space each stage ruled out.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SieveStatistics {
+    pub total_pairs: usize,
+    /// Discarded because the pair's perigee/apogee shells don't overlap
+    /// within the screening pad.
+    pub rejected_by_apogee_perigee: usize,
+    /// Discarded because the minimum distance between the two orbit planes
+    /// exceeds the screening pad.
+    pub rejected_by_orbit_geometry: usize,
+    /// Pairs that reached the time-windowed position-sampling stage.
+    pub sampled_pairs: usize,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -105,6 +389,12 @@ pub struct ScreeningParameters {
 
 pub struct ConjunctionAnalyzer {
     screening_params: ScreeningParameters,
+    /// Precise-ephemeris arcs for an operator's own assets, keyed by NORAD
+    /// id. `propagate_to_eci`/`propagate_velocity_to_eci` prefer these over
+    /// SGP4 whenever the requested time falls inside the loaded arc, so
+    /// conjunction screening can run against high-precision orbits for
+    /// those objects while still using TLEs for the rest of the catalog.
+    sp3: HashMap<u64, Sp3Ephemeris>,
 }
 
 impl ConjunctionAnalyzer {
@@ -117,6 +407,7 @@ impl ConjunctionAnalyzer {
                 time_step_seconds: 300,      // 5 minutes
                 covariance_growth_rate: 0.1, // km²/day
             },
+            sp3: HashMap::new(),
         }
     }
 
@@ -125,6 +416,11 @@ impl ConjunctionAnalyzer {
         self
     }
 
+    /// Loads (or replaces) a precise-ephemeris arc for `norad_id`.
+    pub fn load_sp3(&mut self, norad_id: u64, ephemeris: Sp3Ephemeris) {
+        self.sp3.insert(norad_id, ephemeris);
+    }
+
     pub fn analyze_conjunctions(
         &self,
         satellites: &[SatelliteData],
@@ -143,14 +439,22 @@ impl ConjunctionAnalyzer {
         );
 
         // Phase 1: Coarse screening
-        let candidate_pairs = self.coarse_screening(satellites, start_time, end_time)?;
+        let (candidate_pairs, sieve_statistics) =
+            self.coarse_screening(satellites, start_time, end_time)?;
         let candidate_count = candidate_pairs.len();
-        tracing::info!("Coarse screening found {} candidate pairs", candidate_count);
+        tracing::info!(
+            "Coarse screening found {} candidate pairs ({} total pairs, {} rejected by \
+             apogee/perigee, {} rejected by orbit geometry)",
+            candidate_count,
+            sieve_statistics.total_pairs,
+            sieve_statistics.rejected_by_apogee_perigee,
+            sieve_statistics.rejected_by_orbit_geometry
+        );
 
         // Phase 2: Detailed analysis
         let mut conjunctions = Vec::new();
         for (sat_a, sat_b) in candidate_pairs {
-            if let Ok(conjunction) = self.analyze_pair(&sat_a, &sat_b, start_time, end_time) {
+            if let Ok(conjunction) = self.analyze_pair(&sat_a, &sat_b, start_time, end_time, request) {
                 if conjunction.pc
                     >= request
                         .probability_threshold
@@ -170,73 +474,167 @@ impl ConjunctionAnalyzer {
             conjunctions_found: conjunctions.len(),
             conjunctions,
             screening_parameters: self.screening_params.clone(),
+            sieve_statistics,
         })
     }
 
+    /// Hoots-style three-stage sieve run ahead of position sampling, since
+    /// sampling every pair at a fixed step across the whole horizon is
+    /// quadratic in satellite count and unusable for thousands of objects:
+    ///
+    /// 1. Apogee/perigee filter -- if the pair's radial shells (`a(1±e)`)
+    ///    don't overlap within the screening pad, they can never approach.
+    /// 2. Orbit-geometry filter -- if the minimum distance between the two
+    ///    orbit planes (evaluated at their mutual line of nodes) exceeds the
+    ///    pad, they can never approach either.
+    /// 3. Time filter -- for pairs surviving 1 and 2, position sampling is
+    ///    restricted to the small windows around each object's crossing of
+    ///    that line of nodes, instead of the full horizon.
     fn coarse_screening(
         &self,
         satellites: &[SatelliteData],
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
-    ) -> Result<Vec<(SatelliteData, SatelliteData)>> {
+    ) -> Result<(Vec<(SatelliteData, SatelliteData)>, SieveStatistics)> {
+        let pad_km = self.screening_params.screening_distance_km;
         let mut candidate_pairs = Vec::new();
-        let time_step = Duration::seconds(self.screening_params.time_step_seconds as i64);
+        let mut stats = SieveStatistics::default();
 
         for i in 0..satellites.len() {
             for j in (i + 1)..satellites.len() {
                 let sat_a = &satellites[i];
                 let sat_b = &satellites[j];
+                stats.total_pairs += 1;
+
+                let (Ok((elements_a, _)), Ok((elements_b, _))) =
+                    (sgp4_elements(sat_a), sgp4_elements(sat_b))
+                else {
+                    // Can't parse orbital elements for one of the pair;
+                    // fall back to sampling the whole horizon rather than
+                    // silently dropping a pair we can't screen.
+                    if self.sample_for_conjunction(sat_a, sat_b, start_time, end_time)? {
+                        candidate_pairs.push((sat_a.clone(), sat_b.clone()));
+                    }
+                    stats.sampled_pairs += 1;
+                    continue;
+                };
 
-                // Quick orbital parameter check
-                if self.quick_orbital_filter(sat_a, sat_b) {
+                // Stage 1: apogee/perigee radial-shell overlap.
+                let (perigee_a, apogee_a) = perigee_apogee_km(&elements_a);
+                let (perigee_b, apogee_b) = perigee_apogee_km(&elements_b);
+                if perigee_a - apogee_b > pad_km || perigee_b - apogee_a > pad_km {
+                    stats.rejected_by_apogee_perigee += 1;
                     continue;
                 }
 
-                // Sample positions over time window
-                let mut min_distance = f64::INFINITY;
-                let mut current_time = start_time;
-
-                while current_time <= end_time {
-                    let pos_a = self.propagate_to_eci(sat_a, &current_time)?;
-                    let pos_b = self.propagate_to_eci(sat_b, &current_time)?;
-
-                    let distance = (pos_a - pos_b).norm();
-                    min_distance = min_distance.min(distance);
-
-                    if min_distance < self.screening_params.screening_distance_km {
-                        candidate_pairs.push((sat_a.clone(), sat_b.clone()));
-                        break;
+                // Stage 2: minimum distance between the two orbit planes,
+                // evaluated at their mutual line of nodes.
+                let normal_a = orbit_normal(
+                    elements_a.inclination.to_radians(),
+                    elements_a.right_ascension.to_radians(),
+                );
+                let normal_b = orbit_normal(
+                    elements_b.inclination.to_radians(),
+                    elements_b.right_ascension.to_radians(),
+                );
+                let coplanar = normal_a.cross(&normal_b).norm() < 1e-6;
+                if !coplanar {
+                    let line_of_nodes = normal_a.cross(&normal_b).normalize();
+                    // Conic radius a(1-e²)/(1+e·cos ν) where an orbit crosses `direction`.
+                    let radius_toward = |el: &Sgp4Elements, normal: &Vector3<f64>, direction: &Vector3<f64>| {
+                        let node = ascending_node(el.right_ascension.to_radians());
+                        let u = normal.cross(&node).dot(direction).atan2(node.dot(direction));
+                        let nu = u - el.argument_of_perigee.to_radians();
+                        semi_major_axis_km(el.mean_motion) * (1.0 - el.eccentricity.powi(2))
+                            / (1.0 + el.eccentricity * nu.cos())
+                    };
+                    if [line_of_nodes, -line_of_nodes].iter().all(|d| {
+                        (radius_toward(&elements_a, &normal_a, d) - radius_toward(&elements_b, &normal_b, d)).abs()
+                            > pad_km
+                    }) {
+                        stats.rejected_by_orbit_geometry += 1;
+                        continue;
                     }
+                }
 
-                    current_time = current_time + time_step;
+                // Stage 3: restrict sampling to windows around each orbit's
+                // crossing of the mutual line of nodes. Margin is generous
+                // relative to the screening pad so a close approach just
+                // outside the node-crossing instant isn't missed.
+                let margin = Duration::seconds(
+                    ((pad_km / self.relative_speed_estimate_km_s(&elements_a, &elements_b)) as i64)
+                        .max(60),
+                );
+                let windows_a =
+                    node_crossing_windows(&elements_a, &normal_b, start_time, end_time, margin);
+                let windows_b =
+                    node_crossing_windows(&elements_b, &normal_a, start_time, end_time, margin);
+
+                let sampling_windows = match (windows_a, windows_b) {
+                    (Some(a), Some(b)) => merge_windows(a, b),
+                    _ => vec![(start_time, end_time)], // near-coplanar: can't localize, sample all
+                };
+
+                stats.sampled_pairs += 1;
+                if self.sample_windows_for_conjunction(sat_a, sat_b, &sampling_windows)? {
+                    candidate_pairs.push((sat_a.clone(), sat_b.clone()));
                 }
             }
         }
 
-        Ok(candidate_pairs)
+        Ok((candidate_pairs, stats))
     }
 
-    fn quick_orbital_filter(&self, sat_a: &SatelliteData, sat_b: &SatelliteData) -> bool {
-        // Parse basic orbital parameters from TLE
-        let alt_a = self.estimate_altitude_from_tle(&sat_a.tle_line2);
-        let alt_b = self.estimate_altitude_from_tle(&sat_b.tle_line2);
+    /// Rough relative speed (km/s) used only to size the stage-3 node
+    /// window margin -- the difference of the two near-circular orbital
+    /// speeds implied by their semi-major axes.
+    fn relative_speed_estimate_km_s(&self, elements_a: &Sgp4Elements, elements_b: &Sgp4Elements) -> f64 {
+        let speed = |elements: &Sgp4Elements| {
+            let a = semi_major_axis_km(elements.mean_motion);
+            (EARTH_GRAVITATIONAL_PARAMETER_KM3_S2 / a).sqrt()
+        };
+        (speed(elements_a) - speed(elements_b)).abs().max(0.1)
+    }
 
-        // If altitude difference > 200km, likely no close approach
-        (alt_a - alt_b).abs() > 200.0
+    /// Samples the full horizon at the configured time step, stopping as
+    /// soon as the pair comes within the screening distance. Used when
+    /// either object's orbital elements couldn't be parsed for the sieve.
+    fn sample_for_conjunction(
+        &self,
+        sat_a: &SatelliteData,
+        sat_b: &SatelliteData,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<bool> {
+        self.sample_windows_for_conjunction(sat_a, sat_b, &[(start_time, end_time)])
     }
 
-    fn estimate_altitude_from_tle(&self, tle_line2: &str) -> f64 {
-        // Extract mean motion from TLE line 2 (characters 52-62)
-        if let Ok(mean_motion) = tle_line2[52..63].trim().parse::<f64>() {
-            // Convert mean motion to altitude using Kepler's third law
-            let period_seconds = 86400.0 / mean_motion;
-            let semi_major_axis = ((period_seconds / (2.0 * std::f64::consts::PI)).powi(2)
-                * 398600.4418)
-                .powf(1.0 / 3.0);
-            semi_major_axis - 6378.137 // Earth radius
-        } else {
-            400.0 // Default LEO altitude
+    /// Samples only the given time windows at the configured time step,
+    /// returning true as soon as the pair comes within the screening
+    /// distance.
+    fn sample_windows_for_conjunction(
+        &self,
+        sat_a: &SatelliteData,
+        sat_b: &SatelliteData,
+        windows: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Result<bool> {
+        let time_step = Duration::seconds(self.screening_params.time_step_seconds as i64);
+
+        for &(window_start, window_end) in windows {
+            let mut current_time = window_start;
+            while current_time <= window_end {
+                let pos_a = self.propagate_to_eci(sat_a, &current_time)?;
+                let pos_b = self.propagate_to_eci(sat_b, &current_time)?;
+
+                if (pos_a - pos_b).norm() < self.screening_params.screening_distance_km {
+                    return Ok(true);
+                }
+
+                current_time = current_time + time_step;
+            }
         }
+
+        Ok(false)
     }
 
     fn analyze_pair(
@@ -245,25 +643,48 @@ impl ConjunctionAnalyzer {
         sat_b: &SatelliteData,
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
+        request: &ConjunctionRequest,
     ) -> Result<ConjunctionEvent> {
         // Find Time of Closest Approach (TCA)
         let (tca, dmin_km, pos_a_tca, vel_a_tca, pos_b_tca, vel_b_tca) =
             self.find_tca(sat_a, sat_b, start_time, end_time)?;
 
         // Calculate relative motion
-        let _relative_position = pos_a_tca - pos_b_tca;
+        let relative_position = pos_a_tca - pos_b_tca;
         let relative_velocity = vel_a_tca - vel_b_tca;
         let relative_speed = relative_velocity.norm();
 
         // Covariance analysis
         let covariance_a = self.estimate_covariance(sat_a, &tca);
         let covariance_b = self.estimate_covariance(sat_b, &tca);
-        let covariance_analysis =
-            self.analyze_covariance(&covariance_a, &covariance_b, &relative_velocity);
+        let mut covariance_analysis = self.analyze_covariance(
+            &covariance_a,
+            &covariance_b,
+            &relative_position,
+            &relative_velocity,
+        );
 
-        // Calculate probability of collision
-        let pc =
-            self.calculate_collision_probability(dmin_km, &covariance_analysis, relative_speed);
+        let hard_body_radius_a = DEFAULT_HARD_BODY_RADIUS_KM;
+        let hard_body_radius_b = DEFAULT_HARD_BODY_RADIUS_KM;
+        let combined_hard_body_radius_km = hard_body_radius_a + hard_body_radius_b;
+
+        // The request picks analytic vs. Monte Carlo; when Monte Carlo is
+        // requested it both drives `pc` and is recorded on the covariance
+        // analysis so a caller can see the sample count and standard error.
+        let pc = if let Some(samples) = request.monte_carlo_samples {
+            let combined_covariance = covariance_a + covariance_b;
+            let estimate = monte_carlo_pc(
+                &combined_covariance,
+                &relative_position,
+                combined_hard_body_radius_km,
+                samples,
+            );
+            let probability = estimate.probability;
+            covariance_analysis.monte_carlo = Some(estimate);
+            probability
+        } else {
+            self.calculate_collision_probability(&covariance_analysis, combined_hard_body_radius_km)
+        };
 
         let risk_level = match pc {
             p if p >= 1e-2 => RiskLevel::Critical,
@@ -281,6 +702,7 @@ impl ConjunctionAnalyzer {
                 velocity_at_tca: vel_a_tca,
                 tle_epoch_age_hours: self.calculate_tle_age_hours(sat_a, &tca),
                 covariance_matrix: covariance_a,
+                hard_body_radius_km: hard_body_radius_a,
             },
             satellite_b: ConjunctionSatellite {
                 norad_id: sat_b.norad_id,
@@ -289,6 +711,7 @@ impl ConjunctionAnalyzer {
                 velocity_at_tca: vel_b_tca,
                 tle_epoch_age_hours: self.calculate_tle_age_hours(sat_b, &tca),
                 covariance_matrix: covariance_b,
+                hard_body_radius_km: hard_body_radius_b,
             },
             tca,
             dmin_km,
@@ -368,6 +791,22 @@ impl ConjunctionAnalyzer {
             current_time = current_time + fine_step;
         }
 
+        // Golden-section refinement: the fine sampling above already
+        // brackets the true minimum within one `fine_step` on either side,
+        // so narrow that bracket continuously instead of re-sampling at a
+        // smaller fixed step.
+        let refined_tca = self.golden_section_tca(sat_a, sat_b, tca - fine_step, tca + fine_step)?;
+        let pos_a = self.propagate_to_eci(sat_a, &refined_tca)?;
+        let vel_a = self.propagate_velocity_to_eci(sat_a, &refined_tca)?;
+        let pos_b = self.propagate_to_eci(sat_b, &refined_tca)?;
+        let vel_b = self.propagate_velocity_to_eci(sat_b, &refined_tca)?;
+        let refined_distance = (pos_a - pos_b).norm();
+        if refined_distance <= min_distance {
+            tca = refined_tca;
+            min_distance = refined_distance;
+            best_positions = (pos_a, vel_a, pos_b, vel_b);
+        }
+
         Ok((
             tca,
             min_distance,
@@ -378,35 +817,70 @@ impl ConjunctionAnalyzer {
         ))
     }
 
+    /// Golden-section search for the time of closest approach within
+    /// `[bracket_start, bracket_end]`, assumed (from the coarse+fine
+    /// sampling above) to already bracket a single local minimum of
+    /// inter-satellite distance. Narrows the bracket by the golden ratio
+    /// each iteration rather than re-sampling at ever-smaller fixed steps,
+    /// reaching sub-second precision in a fixed number of propagate calls.
+    fn golden_section_tca(
+        &self,
+        sat_a: &SatelliteData,
+        sat_b: &SatelliteData,
+        bracket_start: DateTime<Utc>,
+        bracket_end: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>> {
+        const GOLDEN_RATIO: f64 = 0.618_033_988_75;
+        const MIN_BRACKET_MS: f64 = 1.0;
+        const MAX_ITERATIONS: usize = 40;
+
+        let at = |offset_ms: f64| bracket_start + Duration::milliseconds(offset_ms.round() as i64);
+        let distance_at = |time: DateTime<Utc>| -> Result<f64> {
+            let pos_a = self.propagate_to_eci(sat_a, &time)?;
+            let pos_b = self.propagate_to_eci(sat_b, &time)?;
+            Ok((pos_a - pos_b).norm())
+        };
+
+        let mut lo = 0.0;
+        let mut hi = (bracket_end - bracket_start).num_milliseconds() as f64;
+
+        let mut x1 = hi - GOLDEN_RATIO * (hi - lo);
+        let mut x2 = lo + GOLDEN_RATIO * (hi - lo);
+        let mut f1 = distance_at(at(x1))?;
+        let mut f2 = distance_at(at(x2))?;
+
+        for _ in 0..MAX_ITERATIONS {
+            if hi - lo < MIN_BRACKET_MS {
+                break;
+            }
+            if f1 < f2 {
+                hi = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = hi - GOLDEN_RATIO * (hi - lo);
+                f1 = distance_at(at(x1))?;
+            } else {
+                lo = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = lo + GOLDEN_RATIO * (hi - lo);
+                f2 = distance_at(at(x2))?;
+            }
+        }
+
+        Ok(at((lo + hi) / 2.0))
+    }
+
     fn propagate_to_eci(
         &self,
         sat_data: &SatelliteData,
         time: &DateTime<Utc>,
     ) -> Result<Vector3<f64>> {
-        // Simplified orbital propagation - in production, use proper SGP4
-        let current_time_seconds = time.timestamp() as f64;
-        let line2 = &sat_data.tle_line2;
-
-        // Parse orbital parameters from TLE
-        let inclination =
-            line2[8..16].trim().parse::<f64>().unwrap_or(51.6) * std::f64::consts::PI / 180.0;
-        let mean_motion = line2[52..63].trim().parse::<f64>().unwrap_or(15.5);
-
-        // Simple circular orbit simulation
-        let orbital_period_seconds = 86400.0 / mean_motion;
-        let angular_velocity = 2.0 * std::f64::consts::PI / orbital_period_seconds;
-        let orbital_angle =
-            (current_time_seconds * angular_velocity) % (2.0 * std::f64::consts::PI);
-
-        let altitude_km = 400.0 + (mean_motion - 15.0) * 20.0;
-        let orbital_radius_km = 6371.0 + altitude_km;
-
-        // ECI coordinates
-        let x = orbital_radius_km * orbital_angle.cos();
-        let y = orbital_radius_km * orbital_angle.sin() * inclination.cos();
-        let z = orbital_radius_km * orbital_angle.sin() * inclination.sin();
-
-        Ok(Vector3::new(x, y, z))
+        if let Some(position) = self.sp3_eci(sat_data.norad_id, time).map(|(pos, _vel)| pos) {
+            return Ok(position);
+        }
+        let (position, _velocity) = sgp4_propagate(sat_data, time)?;
+        Ok(position)
     }
 
     fn propagate_velocity_to_eci(
@@ -414,20 +888,20 @@ impl ConjunctionAnalyzer {
         sat_data: &SatelliteData,
         time: &DateTime<Utc>,
     ) -> Result<Vector3<f64>> {
-        // Simplified velocity calculation
-        let line2 = &sat_data.tle_line2;
-        let mean_motion = line2[52..63].trim().parse::<f64>().unwrap_or(15.5);
-        let altitude_km = 400.0 + (mean_motion - 15.0) * 20.0;
-        let orbital_radius_km = 6371.0 + altitude_km;
-
-        // Circular orbital velocity
-        let velocity_magnitude = (398600.4418 / orbital_radius_km).sqrt();
-
-        // Simplified velocity direction (perpendicular to position)
-        let pos = self.propagate_to_eci(sat_data, time)?;
-        let velocity_direction = Vector3::new(-pos.y, pos.x, 0.0).normalize();
+        if let Some(velocity) = self.sp3_eci(sat_data.norad_id, time).map(|(_pos, vel)| vel) {
+            return Ok(velocity);
+        }
+        let (_position, velocity) = sgp4_propagate(sat_data, time)?;
+        Ok(velocity)
+    }
 
-        Ok(velocity_direction * velocity_magnitude)
+    /// Looks up a loaded SP3 arc for `norad_id` and, if it covers `time`,
+    /// interpolates the ECEF state and rotates it into ECI so it's directly
+    /// comparable to SGP4 output.
+    fn sp3_eci(&self, norad_id: u64, time: &DateTime<Utc>) -> Option<(Vector3<f64>, Vector3<f64>)> {
+        let ephemeris = self.sp3.get(&norad_id)?;
+        let (x, y, z, vx, vy, vz) = ephemeris.interpolate(*time)?;
+        Some(ecef_to_eci((x, y, z), (vx, vy, vz), *time))
     }
 
     fn estimate_covariance(&self, sat_data: &SatelliteData, tca: &DateTime<Utc>) -> Matrix3<f64> {
@@ -443,70 +917,106 @@ impl ConjunctionAnalyzer {
         Matrix3::from_diagonal(&Vector3::new(variance, variance, variance))
     }
 
+    /// Builds the encounter-plane geometry needed for the short-encounter
+    /// Pc integral: an orthonormal basis (e1, e2) spanning the plane
+    /// perpendicular to the relative velocity, the combined covariance and
+    /// miss vector projected into that plane, and that projection's
+    /// principal axes (eigen-decomposition of the resulting 2x2 matrix).
     fn analyze_covariance(
         &self,
         cov_a: &Matrix3<f64>,
         cov_b: &Matrix3<f64>,
+        relative_position: &Vector3<f64>,
         relative_velocity: &Vector3<f64>,
     ) -> CovarianceAnalysis {
-        // Combined covariance
         let combined_covariance = cov_a + cov_b;
 
-        // Project into collision plane (perpendicular to relative velocity)
-        let rel_vel_unit = relative_velocity.normalize();
-
-        // Create projection matrix (I - v̂v̂ᵀ)
-        let projection_matrix = Matrix3::identity() - rel_vel_unit * rel_vel_unit.transpose();
-        let collision_plane_projection =
-            projection_matrix * combined_covariance * projection_matrix.transpose();
+        let (e1, e2) = encounter_plane_basis(relative_velocity);
+
+        // Project the combined 3D covariance into the 2D encounter plane:
+        // C_2d = Bᵀ C B for basis columns B = [e1 e2].
+        let c11 = e1.dot(&(combined_covariance * e1));
+        let c12 = e1.dot(&(combined_covariance * e2));
+        let c22 = e2.dot(&(combined_covariance * e2));
+
+        // Closed-form eigen-decomposition of a symmetric 2x2 matrix.
+        let trace = c11 + c22;
+        let discriminant = ((c11 - c22).powi(2) + 4.0 * c12 * c12).sqrt();
+        let lambda1 = ((trace + discriminant) / 2.0).max(0.0);
+        let lambda2 = ((trace - discriminant) / 2.0).max(0.0);
+        let sigma1 = lambda1.sqrt();
+        let sigma2 = lambda2.sqrt();
+
+        // Rotation angle of the lambda1 eigenvector within the (e1, e2) plane.
+        let theta = if c12.abs() < 1e-15 && (c11 - c22).abs() < 1e-15 {
+            0.0
+        } else {
+            0.5 * (2.0 * c12).atan2(c11 - c22)
+        };
 
-        // Calculate uncertainty ellipse parameters
-        let eigenvalues = collision_plane_projection.symmetric_eigenvalues();
-        let semi_major_km = eigenvalues[2].sqrt();
-        let semi_minor_km = eigenvalues[0].sqrt();
-        let volume_km3 =
-            (4.0 / 3.0) * std::f64::consts::PI * eigenvalues.iter().product::<f64>().sqrt();
+        // Project the miss vector into the plane, then rotate into the
+        // principal frame.
+        let xm_plane = relative_position.dot(&e1);
+        let ym_plane = relative_position.dot(&e2);
+        let xm = xm_plane * theta.cos() + ym_plane * theta.sin();
+        let ym = -xm_plane * theta.sin() + ym_plane * theta.cos();
 
         CovarianceAnalysis {
             combined_covariance,
-            collision_plane_projection,
-            uncertainty_ellipse_semi_major_km: semi_major_km,
-            uncertainty_ellipse_semi_minor_km: semi_minor_km,
-            uncertainty_volume_km3: volume_km3,
+            principal_sigma1_km: sigma1,
+            principal_sigma2_km: sigma2,
+            miss_vector_principal_km: [xm, ym],
+            uncertainty_ellipse_semi_major_km: sigma1,
+            uncertainty_ellipse_semi_minor_km: sigma2,
+            uncertainty_ellipse_area_km2: std::f64::consts::PI * sigma1 * sigma2,
+            monte_carlo: None,
         }
     }
 
+    /// Standard short-encounter 2D Pc integral: the combined covariance and
+    /// miss vector are already projected into the encounter plane's
+    /// principal frame by `analyze_covariance`, so this integrates the
+    /// resulting axis-aligned bivariate normal over the disk of radius
+    /// `combined_hard_body_radius_km` via a polar-grid quadrature (Chan's
+    /// series is the closed-form alternative; the grid is simpler to get
+    /// right and fast enough at this disk size).
     fn calculate_collision_probability(
         &self,
-        dmin_km: f64,
         covariance_analysis: &CovarianceAnalysis,
-        relative_speed_km_s: f64,
+        combined_hard_body_radius_km: f64,
     ) -> f64 {
-        // Combined hard body radius (typical satellite sizes)
-        let hard_body_radius_km: f64 = 0.005; // 5 meters
-
-        // 2D collision probability in the collision plane
-        let collision_area_km2 = std::f64::consts::PI * hard_body_radius_km.powi(2);
+        let sigma1 = covariance_analysis.principal_sigma1_km;
+        let sigma2 = covariance_analysis.principal_sigma2_km;
+        let [xm, ym] = covariance_analysis.miss_vector_principal_km;
 
-        // Use 2D normal distribution
-        let det = covariance_analysis.collision_plane_projection[(0, 0)]
-            * covariance_analysis.collision_plane_projection[(1, 1)]
-            - covariance_analysis.collision_plane_projection[(0, 1)].powi(2);
-
-        if det <= 0.0 {
+        if sigma1 <= 0.0 || sigma2 <= 0.0 {
             return 0.0;
         }
 
-        // Mahalanobis distance squared
-        let _mahalanobis_sq = dmin_km.powi(2) / det.sqrt();
-
-        // 2D collision probability with circular approximation
-        let pc = 1.0 - (-collision_area_km2 / (2.0 * std::f64::consts::PI * det.sqrt())).exp();
-
-        // Apply dilution factor for high relative velocities
-        let dilution_factor = (relative_speed_km_s / 10.0).min(1.0);
+        const RADIAL_STEPS: usize = 60;
+        const ANGULAR_STEPS: usize = 90;
+
+        let dr = combined_hard_body_radius_km / RADIAL_STEPS as f64;
+        let dtheta = 2.0 * std::f64::consts::PI / ANGULAR_STEPS as f64;
+        let normalization = 1.0 / (2.0 * std::f64::consts::PI * sigma1 * sigma2);
+
+        let mut probability = 0.0;
+        for i in 0..RADIAL_STEPS {
+            let r = (i as f64 + 0.5) * dr;
+            for j in 0..ANGULAR_STEPS {
+                let theta = (j as f64 + 0.5) * dtheta;
+                let x = r * theta.cos();
+                let y = r * theta.sin();
+                let exponent = -0.5
+                    * (((x + xm).powi(2) / (sigma1 * sigma1))
+                        + ((y + ym).powi(2) / (sigma2 * sigma2)));
+                let density = normalization * exponent.exp();
+                // Polar-coordinate area element r·dr·dθ.
+                probability += density * r * dr * dtheta;
+            }
+        }
 
-        pc * dilution_factor
+        probability.min(1.0)
     }
 
     fn calculate_tle_age_hours(
@@ -518,3 +1028,66 @@ impl ConjunctionAnalyzer {
         age_duration.num_seconds() as f64 / 3600.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn covariance_analysis(sigma1: f64, sigma2: f64, miss: [f64; 2]) -> CovarianceAnalysis {
+        CovarianceAnalysis {
+            combined_covariance: Matrix3::identity(),
+            principal_sigma1_km: sigma1,
+            principal_sigma2_km: sigma2,
+            miss_vector_principal_km: miss,
+            uncertainty_ellipse_semi_major_km: sigma1,
+            uncertainty_ellipse_semi_minor_km: sigma2,
+            uncertainty_ellipse_area_km2: std::f64::consts::PI * sigma1 * sigma2,
+            monte_carlo: None,
+        }
+    }
+
+    /// A hard-body radius many sigma away from a dead-center miss should
+    /// integrate to (numerically) the full disk, i.e. Pc close to 1 --
+    /// catches a formula that drops the `r·dr·dθ` polar area element (a Pc
+    /// that never saturates) or otherwise under-integrates the density.
+    #[test]
+    fn collision_probability_saturates_for_large_radius_dead_center_miss() {
+        let analyzer = ConjunctionAnalyzer::new();
+        let covariance = covariance_analysis(1.0, 1.0, [0.0, 0.0]);
+
+        let pc = analyzer.calculate_collision_probability(&covariance, 50.0);
+
+        assert!(pc > 0.999, "expected near-total collision probability, got {pc}");
+    }
+
+    /// A hard-body radius far smaller than sigma, with a miss distance many
+    /// sigma away, should integrate to (numerically) zero.
+    #[test]
+    fn collision_probability_vanishes_for_small_radius_large_miss() {
+        let analyzer = ConjunctionAnalyzer::new();
+        let covariance = covariance_analysis(1.0, 1.0, [50.0, 0.0]);
+
+        let pc = analyzer.calculate_collision_probability(&covariance, 0.01);
+
+        assert!(pc < 1e-9, "expected negligible collision probability, got {pc}");
+    }
+
+    /// Widening the encounter-plane uncertainty while holding everything
+    /// else fixed spreads the density thinner, so Pc should strictly
+    /// decrease -- this is the qualitative behavior the disk integral needs
+    /// to reproduce regardless of its exact quadrature.
+    #[test]
+    fn collision_probability_decreases_as_uncertainty_grows() {
+        let analyzer = ConjunctionAnalyzer::new();
+        let tight = covariance_analysis(0.5, 0.5, [0.0, 0.0]);
+        let loose = covariance_analysis(5.0, 5.0, [0.0, 0.0]);
+
+        let pc_tight = analyzer.calculate_collision_probability(&tight, 0.1);
+        let pc_loose = analyzer.calculate_collision_probability(&loose, 0.1);
+
+        assert!(
+            pc_tight > pc_loose,
+            "expected tighter uncertainty to give higher Pc: tight={pc_tight}, loose={pc_loose}"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::throttle::ThrottleRejection;
+use crate::tle::SatApiError;
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde::Serialize;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Crate-wide HTTP-facing error type. Handlers return `Result<HttpResponse,
+/// ApiError>` and use `?` on fallible calls instead of hand-rolling a JSON
+/// error body in every `match` arm; `ResponseError` turns that into a fixed
+/// status code plus a stable `{ "error": "<code>", "message": "..." }` body,
+/// so clients can branch on `error` the same way regardless of endpoint.
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("Failed to load satellite catalog: {0}")]
+    CatalogLoadFailed(String),
+    #[error("Conjunction analyzer is busy")]
+    AnalyzerUnavailable,
+    #[error("Risk model lock was poisoned by a panicked holder")]
+    ModelLockPoisoned,
+    #[error("Reservation manager is busy")]
+    ReservationManagerUnavailable,
+    #[error("Reservation conflict check failed: {0}")]
+    ReservationConflict(String),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Rate limit exceeded, retry in {retry_after:?}")]
+    RateLimited { retry_after: Duration },
+    #[error("Too many concurrent requests for this tenant, retry in {retry_after:?}")]
+    ConcurrencyLimited { retry_after: Duration },
+    #[error(transparent)]
+    Domain(#[from] SatApiError),
+}
+
+impl From<ThrottleRejection> for ApiError {
+    fn from(rejection: ThrottleRejection) -> Self {
+        match rejection {
+            ThrottleRejection::RateLimited { retry_after } => ApiError::RateLimited { retry_after },
+            ThrottleRejection::ConcurrencyLimited { retry_after } => {
+                ApiError::ConcurrencyLimited { retry_after }
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+    message: String,
+}
+
+impl ApiError {
+    fn code(&self) -> &'static str {
+        match self {
+            ApiError::CatalogLoadFailed(_) => "catalog_load_failed",
+            ApiError::AnalyzerUnavailable => "analyzer_unavailable",
+            ApiError::ModelLockPoisoned => "model_lock_poisoned",
+            ApiError::ReservationManagerUnavailable => "reservation_manager_unavailable",
+            ApiError::ReservationConflict(_) => "reservation_conflict_failed",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::RateLimited { .. } => "rate_limited",
+            ApiError::ConcurrencyLimited { .. } => "concurrency_limited",
+            ApiError::Domain(inner) => match inner {
+                SatApiError::HttpError(_) => "upstream_http_error",
+                SatApiError::TleParseError(_) => "tle_parse_error",
+                SatApiError::PropagationError(_) => "propagation_error",
+                SatApiError::NoSatelliteData => "no_satellite_data",
+                SatApiError::SatelliteNotFound(_) => "satellite_not_found",
+            },
+        }
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::CatalogLoadFailed(_)
+            | ApiError::AnalyzerUnavailable
+            | ApiError::ModelLockPoisoned
+            | ApiError::ReservationManagerUnavailable
+            | ApiError::ReservationConflict(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::RateLimited { .. } | ApiError::ConcurrencyLimited { .. } => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            ApiError::Domain(SatApiError::SatelliteNotFound(_)) => StatusCode::NOT_FOUND,
+            ApiError::Domain(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut builder = HttpResponse::build(self.status_code());
+        if let ApiError::RateLimited { retry_after } | ApiError::ConcurrencyLimited { retry_after } =
+            self
+        {
+            builder.insert_header(("Retry-After", retry_after.as_secs().max(1).to_string()));
+        }
+        builder.json(ErrorBody {
+            error: self.code(),
+            message: self.to_string(),
+        })
+    }
+}
@@ -0,0 +1,106 @@
+//! Renders tracked satellite positions (and, optionally, forward ground
+//! tracks) as a GeoJSON `FeatureCollection`, so OrbitalOS output is directly
+//! consumable by any web map (Leaflet/Mapbox/deck.gl) with no client-side
+//! transformation. Pure presentation layer over `SatellitePosition`, the
+//! same data `get_all_satellites` already produces.
+
+use crate::tle::SatellitePosition;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// A GeoJSON `FeatureCollection` of satellite `Point`/`LineString` features.
+#[derive(Debug, Serialize)]
+pub struct FeatureCollection {
+    #[serde(rename = "type")]
+    pub feature_type: &'static str,
+    pub features: Vec<Value>,
+}
+
+/// Builds a `Point` feature for one satellite's current position, with
+/// `norad_id`, `name`, `alt_km`, `velocity_km_s`, `risk_level`, and
+/// `risk_score` carried as properties.
+fn position_feature(position: &SatellitePosition) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": [position.lon_deg, position.lat_deg],
+        },
+        "properties": {
+            "norad_id": position.norad_id,
+            "name": position.name,
+            "alt_km": position.alt_km,
+            "velocity_km_s": position.velocity_km_s,
+            "risk_level": position.risk_level,
+            "risk_score": position.risk_score,
+        },
+    })
+}
+
+/// Builds `LineString` features for a satellite's ground track, split at
+/// the antimeridian (wherever consecutive samples' longitude jumps by more
+/// than 180 degrees) so each segment renders as a straight line instead of
+/// wrapping across the whole map.
+fn ground_track_features(norad_id: u64, name: &str, samples: &[(f64, f64)]) -> Vec<Value> {
+    let mut segments: Vec<Vec<[f64; 2]>> = Vec::new();
+    let mut current: Vec<[f64; 2]> = Vec::new();
+
+    for &(lon, lat) in samples {
+        if let Some(last) = current.last() {
+            if (lon - last[0]).abs() > 180.0 {
+                segments.push(std::mem::take(&mut current));
+            }
+        }
+        current.push([lon, lat]);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+
+    segments
+        .into_iter()
+        .filter(|segment| segment.len() >= 2)
+        .map(|coordinates| {
+            json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": coordinates,
+                },
+                "properties": {
+                    "norad_id": norad_id,
+                    "name": name,
+                    "kind": "ground_track",
+                },
+            })
+        })
+        .collect()
+}
+
+/// Builds the `FeatureCollection` for a set of current positions, with an
+/// optional ground track per satellite. `ground_tracks` pairs each
+/// satellite's NORAD id with the `(lon_deg, lat_deg)` samples for its
+/// track, already ordered in time; satellites with no entry get only their
+/// position `Point`.
+pub fn to_feature_collection(
+    positions: &[SatellitePosition],
+    ground_tracks: &std::collections::HashMap<u64, Vec<(f64, f64)>>,
+) -> FeatureCollection {
+    let mut features = Vec::with_capacity(positions.len());
+
+    for position in positions {
+        features.push(position_feature(position));
+        if let Some(samples) = ground_tracks.get(&position.norad_id) {
+            features.extend(ground_track_features(
+                position.norad_id,
+                &position.name,
+                samples,
+            ));
+        }
+    }
+
+    FeatureCollection {
+        feature_type: "FeatureCollection",
+        features,
+    }
+}
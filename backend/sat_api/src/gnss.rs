@@ -0,0 +1,176 @@
+// Models the GNSS constellations fetched by `TleFetcher::fetch_gps`,
+// `fetch_galileo`, and `fetch_navigation_satellites` as first-class entities,
+// instead of treating every navigation satellite identically. Lets callers
+// monitor constellation completeness (how many of the designed satellites
+// are actually visible in the catalog right now, and how they're spread
+// across orbital planes) and reason correctly about GPS-vs-UTC timing.
+
+use crate::timescale::{leap_seconds_at, TimeScale};
+use crate::tle::SatelliteData;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sgp4::Elements as Sgp4Elements;
+
+/// GPS time runs a fixed 19s behind TAI and never steps for leap seconds, so
+/// `TAI-UTC - 19` is always the current GPS-UTC offset (18s as of the last
+/// 2017-01-01 leap second).
+const GPS_TAI_OFFSET_SECONDS: i64 = 19;
+
+/// The four GNSS constellations currently covered by `TleFetcher`'s
+/// navigation-fetch methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Gnss {
+    Gps,
+    Galileo,
+    Glonass,
+    BeiDou,
+}
+
+impl Gnss {
+    /// Satellites a fully-populated constellation is designed to carry, per
+    /// each system's published baseline constellation size.
+    pub fn expected_satellites(self) -> usize {
+        match self {
+            Gnss::Gps => 24,
+            Gnss::Galileo => 24,
+            Gnss::Glonass => 24,
+            Gnss::BeiDou => 35,
+        }
+    }
+
+    /// Orbital planes the constellation is designed around, used to bucket
+    /// observed satellites into slots by right ascension of ascending node.
+    fn orbital_planes(self) -> usize {
+        match self {
+            Gnss::Gps => 6,
+            Gnss::Galileo => 3,
+            Gnss::Glonass => 3,
+            Gnss::BeiDou => 3,
+        }
+    }
+
+    /// Matches a satellite name as returned by Celestrak's GP feeds (e.g.
+    /// `"GPS BIIR-2  (PRN 13)"`, `"GALILEO 14 (GSAT0211)"`,
+    /// `"COSMOS 2477 (GLONASS-M)"`, `"BEIDOU-3 M1"`) to the constellation it
+    /// belongs to. Returns `None` for anything else in the navigation feed
+    /// (e.g. a stray augmentation satellite).
+    pub fn from_satellite_name(name: &str) -> Option<Gnss> {
+        let upper = name.to_uppercase();
+        if upper.contains("GPS") {
+            Some(Gnss::Gps)
+        } else if upper.contains("GALILEO") {
+            Some(Gnss::Galileo)
+        } else if upper.contains("GLONASS") {
+            Some(Gnss::Glonass)
+        } else if upper.contains("BEIDOU") {
+            Some(Gnss::BeiDou)
+        } else {
+            None
+        }
+    }
+}
+
+/// How many satellites occupy one orbital plane/slot bucket.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlotOccupancy {
+    pub plane: usize,
+    pub satellite_count: usize,
+}
+
+/// Constellation-health snapshot for one GNSS system, derived from whatever
+/// TLEs are currently held for it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstellationStatus {
+    pub system: Gnss,
+    pub expected_satellites: usize,
+    /// Satellites in the catalog matched to this system by name.
+    pub observed_satellites: usize,
+    /// Of the observed satellites, how many have a TLE that parses into
+    /// usable SGP4 elements -- a malformed or corrupted entry counts toward
+    /// `observed_satellites` but not this.
+    pub operational_satellites: usize,
+    pub slots: Vec<SlotOccupancy>,
+}
+
+/// Assesses `system`'s constellation health from `satellites` (typically the
+/// result of `fetch_navigation_satellites`, or the full tracked TLE catalog
+/// from `SatelliteApi::get_satellite_tle_catalog`). Satellites not matching
+/// `system` by name are ignored.
+pub fn assess_constellation(system: Gnss, satellites: &[SatelliteData]) -> ConstellationStatus {
+    let members: Vec<&SatelliteData> = satellites
+        .iter()
+        .filter(|sat| Gnss::from_satellite_name(&sat.name) == Some(system))
+        .collect();
+
+    let plane_count = system.orbital_planes();
+    let mut plane_counts = vec![0usize; plane_count];
+    let mut operational = 0usize;
+
+    for sat in &members {
+        let Ok(elements) =
+            Sgp4Elements::from_tle(Some(sat.name.clone()), sat.tle_line1.as_bytes(), sat.tle_line2.as_bytes())
+        else {
+            continue;
+        };
+
+        operational += 1;
+        let raan_deg = elements.right_ascension.rem_euclid(360.0);
+        let plane = ((raan_deg / 360.0) * plane_count as f64).floor() as usize;
+        plane_counts[plane.min(plane_count - 1)] += 1;
+    }
+
+    let slots = plane_counts
+        .into_iter()
+        .enumerate()
+        .map(|(plane, satellite_count)| SlotOccupancy { plane, satellite_count })
+        .collect();
+
+    ConstellationStatus {
+        system,
+        expected_satellites: system.expected_satellites(),
+        observed_satellites: members.len(),
+        operational_satellites: operational,
+        slots,
+    }
+}
+
+/// The GPS-vs-UTC timing relationship at a given instant, so navigation
+/// satellite timestamps can be converted correctly rather than assumed to
+/// already be UTC. Backed by the same IERS leap-second table
+/// `TimeScale::Gpst` uses internally, since GPS time itself never steps for
+/// leap seconds once past its 1980-01-06 epoch.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct GnssTiming {
+    /// GPS time minus UTC, in nanoseconds, at the instant this was computed.
+    pub gps_utc_offset_ns: i64,
+    /// Whole leap seconds TAI is currently ahead of UTC.
+    pub leap_seconds: i64,
+    /// Whether the IERS has announced an upcoming leap-second insertion.
+    /// No leap second has been scheduled since 2017-01-01, and Bulletin C
+    /// announces one at least six months ahead, so this is a static `false`
+    /// until this repo tracks a live IERS bulletin feed.
+    pub leap_second_planned: bool,
+}
+
+impl GnssTiming {
+    /// Computes the current GPS-vs-UTC timing relationship as of `at`.
+    pub fn at(at: DateTime<Utc>) -> Self {
+        let leap_seconds = leap_seconds_at(at);
+        let gps_utc_offset_seconds = leap_seconds - GPS_TAI_OFFSET_SECONDS;
+        Self {
+            gps_utc_offset_ns: gps_utc_offset_seconds * 1_000_000_000,
+            leap_seconds,
+            leap_second_planned: false,
+        }
+    }
+
+    /// Converts a timestamp in GPS time (e.g. one read off a navigation
+    /// message) into true UTC. `SatelliteApi`'s own propagated
+    /// `SatellitePosition` timestamps are already computed from
+    /// `Utc::now()` and so don't need this -- it's for timestamps sourced
+    /// from a GPS-native clock.
+    pub fn gps_to_utc(gps_time: DateTime<Utc>) -> DateTime<Utc> {
+        TimeScale::Gpst.to_utc(gps_time)
+    }
+}
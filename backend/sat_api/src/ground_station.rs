@@ -0,0 +1,328 @@
+// Ground-station contact scheduling: given an observer's geodetic location
+// and a satellite already tracked by `SatelliteApi`, compute when the
+// satellite rises above a minimum elevation mask, where it peaks, and when
+// it sets, over a requested time window. Mirrors the inclusion/exclusion
+// epoch interval idea used elsewhere for orbit-determination data spans so
+// operators can mark a station unavailable (maintenance) or restrict
+// scheduling to specific windows.
+
+use crate::api::SatelliteApi;
+use crate::tle::{Result, SatApiError};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+const WGS84_A_KM: f64 = 6378.137;
+const WGS84_F: f64 = 1.0 / 298.257223563;
+const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+/// Coarse sampling step used to sweep the requested window for rise/set
+/// crossings before refining AOS/LOS by linear interpolation.
+const SAMPLE_STEP_SECONDS: i64 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl EpochWindow {
+    /// `pub(crate)` so other modules that borrow this inclusion/exclusion
+    /// epoch model (e.g. `reservation`'s blackout/allowed-window conflict
+    /// sampling) don't have to duplicate this check.
+    pub(crate) fn contains(&self, time: DateTime<Utc>) -> bool {
+        time >= self.start && time <= self.end
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroundStation {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    #[serde(default)]
+    pub alt_km: f64,
+    pub min_elevation_deg: f64,
+    /// If non-empty, only epochs inside one of these windows are considered
+    /// visible, even if the satellite is geometrically above the mask.
+    #[serde(default)]
+    pub inclusion_windows: Vec<EpochWindow>,
+    /// Epochs inside any of these windows are never visible (e.g. scheduled
+    /// station maintenance), regardless of `inclusion_windows`.
+    #[serde(default)]
+    pub exclusion_windows: Vec<EpochWindow>,
+}
+
+impl GroundStation {
+    fn is_available(&self, time: DateTime<Utc>) -> bool {
+        if self.exclusion_windows.iter().any(|w| w.contains(time)) {
+            return false;
+        }
+        if !self.inclusion_windows.is_empty() {
+            return self.inclusion_windows.iter().any(|w| w.contains(time));
+        }
+        true
+    }
+
+    fn ecef(&self) -> (f64, f64, f64) {
+        geodetic_to_ecef(self.lat_deg.to_radians(), self.lon_deg.to_radians(), self.alt_km)
+    }
+
+    /// Whether `look_angle` is actually observable from this station at
+    /// `time`: above the elevation mask and not inside an exclusion window
+    /// (or inside an inclusion window, if any are configured).
+    pub fn sees(&self, look_angle: &LookAngle, time: DateTime<Utc>) -> bool {
+        self.is_available(time) && look_angle.elevation_deg >= self.min_elevation_deg
+    }
+}
+
+fn geodetic_to_ecef(lat_rad: f64, lon_rad: f64, alt_km: f64) -> (f64, f64, f64) {
+    let sin_lat = lat_rad.sin();
+    let cos_lat = lat_rad.cos();
+    let n = WGS84_A_KM / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+
+    let x = (n + alt_km) * cos_lat * lon_rad.cos();
+    let y = (n + alt_km) * cos_lat * lon_rad.sin();
+    let z = (n * (1.0 - WGS84_E2) + alt_km) * sin_lat;
+    (x, y, z)
+}
+
+fn julian_date(time: DateTime<Utc>) -> f64 {
+    let timestamp = time.timestamp_millis() as f64 / 1000.0;
+    (timestamp / 86400.0) + 2440587.5
+}
+
+/// Greenwich Mean Sidereal Time in radians. `pub(crate)` so other modules
+/// that need to rotate between ECI and ECEF (e.g. `conjunction`'s SP3
+/// ingestion) don't have to duplicate this.
+pub(crate) fn gmst_rad(time: DateTime<Utc>) -> f64 {
+    let jd = julian_date(time);
+    let t = (jd - 2451545.0) / 36525.0;
+    let gmst_seconds =
+        67310.54841 + (876600.0 * 3600.0 + 8640184.812866) * t + 0.093104 * t * t - 6.2e-6 * t * t * t;
+    let gmst_rad = (gmst_seconds % 86400.0) * PI / 43200.0;
+    gmst_rad.rem_euclid(2.0 * PI)
+}
+
+fn eci_to_ecef(eci: (f64, f64, f64), time: DateTime<Utc>) -> (f64, f64, f64) {
+    let theta = gmst_rad(time);
+    let (cos_t, sin_t) = (theta.cos(), theta.sin());
+    (
+        eci.0 * cos_t + eci.1 * sin_t,
+        -eci.0 * sin_t + eci.1 * cos_t,
+        eci.2,
+    )
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct LookAngle {
+    pub azimuth_deg: f64,
+    pub elevation_deg: f64,
+    pub range_km: f64,
+}
+
+/// Converts a satellite ECEF position to azimuth/elevation/range as seen
+/// from `station`, via the local East-North-Up frame.
+fn topocentric_look_angle(station: &GroundStation, satellite_ecef: (f64, f64, f64)) -> LookAngle {
+    let (station_x, station_y, station_z) = station.ecef();
+    let dx = satellite_ecef.0 - station_x;
+    let dy = satellite_ecef.1 - station_y;
+    let dz = satellite_ecef.2 - station_z;
+
+    let lat = station.lat_deg.to_radians();
+    let lon = station.lon_deg.to_radians();
+    let (sin_lat, cos_lat) = (lat.sin(), lat.cos());
+    let (sin_lon, cos_lon) = (lon.sin(), lon.cos());
+
+    let east = -sin_lon * dx + cos_lon * dy;
+    let north = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+    let up = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+
+    let range_km = (dx * dx + dy * dy + dz * dz).sqrt();
+    let elevation_deg = (up / range_km).asin().to_degrees();
+    let azimuth_deg = east.atan2(north).to_degrees().rem_euclid(360.0);
+
+    LookAngle { azimuth_deg, elevation_deg, range_km }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Pass {
+    pub aos: DateTime<Utc>,
+    pub aos_azimuth_deg: f64,
+    pub max_elevation_time: DateTime<Utc>,
+    pub max_elevation_deg: f64,
+    pub los: DateTime<Utc>,
+    pub los_azimuth_deg: f64,
+}
+
+struct Sample {
+    time: DateTime<Utc>,
+    look_angle: Option<LookAngle>, // None when the epoch is unavailable (excluded)
+}
+
+/// Az/el/range of an already-known ECI position as seen from `station` at
+/// `time`. Unlike `look_angle_at`, this doesn't go through `SatelliteApi` --
+/// for a state that's already been propagated elsewhere (e.g. a
+/// conjunction's position at TCA, possibly from SP3 rather than SGP4),
+/// there's no norad_id/time pair to re-propagate from.
+pub fn look_angle_for_eci(
+    station: &GroundStation,
+    eci_position_km: (f64, f64, f64),
+    time: DateTime<Utc>,
+) -> LookAngle {
+    topocentric_look_angle(station, eci_to_ecef(eci_position_km, time))
+}
+
+async fn look_angle_at(
+    api: &SatelliteApi,
+    norad_id: u64,
+    station: &GroundStation,
+    time: DateTime<Utc>,
+) -> Result<Option<LookAngle>> {
+    if !station.is_available(time) {
+        return Ok(None);
+    }
+    let eci = api.get_satellite_eci_at(norad_id, time).await?;
+    let ecef = eci_to_ecef(eci, time);
+    Ok(Some(topocentric_look_angle(station, ecef)))
+}
+
+/// Linear-interpolates the crossing time between two samples that straddle
+/// the elevation mask, for a tighter AOS/LOS estimate than the sample step.
+fn interpolate_crossing(
+    before: &Sample,
+    before_elevation: f64,
+    after: &Sample,
+    after_elevation: f64,
+    mask_deg: f64,
+) -> DateTime<Utc> {
+    let span = (after.time - before.time).num_milliseconds() as f64;
+    let fraction = (mask_deg - before_elevation) / (after_elevation - before_elevation);
+    before.time + Duration::milliseconds((span * fraction.clamp(0.0, 1.0)) as i64)
+}
+
+/// Computes every pass of `norad_id` visible from `station` between `start`
+/// and `end`, honoring the station's inclusion/exclusion epoch windows.
+pub async fn predict_passes(
+    api: &SatelliteApi,
+    norad_id: u64,
+    station: &GroundStation,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Pass>> {
+    if end <= start {
+        return Err(SatApiError::PropagationError(
+            "pass prediction window end must be after start".to_string(),
+        ));
+    }
+
+    let mut samples = Vec::new();
+    let mut t = start;
+    while t <= end {
+        let look_angle = look_angle_at(api, norad_id, station, t).await?;
+        samples.push(Sample { time: t, look_angle });
+        t += Duration::seconds(SAMPLE_STEP_SECONDS);
+    }
+
+    let mut passes = Vec::new();
+    let mut in_pass = false;
+    let mut aos: Option<DateTime<Utc>> = None;
+    let mut aos_azimuth = 0.0;
+    let mut max_elevation_deg = f64::MIN;
+    let mut max_elevation_time = start;
+
+    for window in samples.windows(2) {
+        let (before, after) = (&window[0], &window[1]);
+        let (Some(before_la), Some(after_la)) = (before.look_angle, after.look_angle) else {
+            // Station unavailable at one end of this interval: treat it as a
+            // forced LOS if we were mid-pass, then skip the gap entirely.
+            if in_pass {
+                if let Some(aos_time) = aos {
+                    passes.push(Pass {
+                        aos: aos_time,
+                        aos_azimuth_deg: aos_azimuth,
+                        max_elevation_time,
+                        max_elevation_deg,
+                        los: before.time,
+                        los_azimuth_deg: before.look_angle.map(|la| la.azimuth_deg).unwrap_or(0.0),
+                    });
+                }
+                in_pass = false;
+                max_elevation_deg = f64::MIN;
+            }
+            continue;
+        };
+
+        if before_la.elevation_deg >= station.min_elevation_deg && !in_pass {
+            // already above the mask at window start with nothing to
+            // interpolate against; treat `before` itself as AOS.
+            in_pass = true;
+            aos = Some(before.time);
+            aos_azimuth = before_la.azimuth_deg;
+            max_elevation_deg = before_la.elevation_deg;
+            max_elevation_time = before.time;
+        }
+
+        let crosses_up = before_la.elevation_deg < station.min_elevation_deg
+            && after_la.elevation_deg >= station.min_elevation_deg;
+        let crosses_down = before_la.elevation_deg >= station.min_elevation_deg
+            && after_la.elevation_deg < station.min_elevation_deg;
+
+        if crosses_up && !in_pass {
+            let crossing = interpolate_crossing(
+                before,
+                before_la.elevation_deg,
+                after,
+                after_la.elevation_deg,
+                station.min_elevation_deg,
+            );
+            in_pass = true;
+            aos = Some(crossing);
+            aos_azimuth = before_la.azimuth_deg;
+            max_elevation_deg = after_la.elevation_deg;
+            max_elevation_time = after.time;
+        } else if in_pass {
+            if after_la.elevation_deg > max_elevation_deg {
+                max_elevation_deg = after_la.elevation_deg;
+                max_elevation_time = after.time;
+            }
+        }
+
+        if crosses_down && in_pass {
+            let crossing = interpolate_crossing(
+                before,
+                before_la.elevation_deg,
+                after,
+                after_la.elevation_deg,
+                station.min_elevation_deg,
+            );
+            if let Some(aos_time) = aos {
+                passes.push(Pass {
+                    aos: aos_time,
+                    aos_azimuth_deg: aos_azimuth,
+                    max_elevation_time,
+                    max_elevation_deg,
+                    los: crossing,
+                    los_azimuth_deg: after_la.azimuth_deg,
+                });
+            }
+            in_pass = false;
+            max_elevation_deg = f64::MIN;
+        }
+    }
+
+    // Still visible at the end of the window: close the pass out at `end`.
+    if in_pass {
+        if let (Some(aos_time), Some(last)) = (aos, samples.last()) {
+            passes.push(Pass {
+                aos: aos_time,
+                aos_azimuth_deg: aos_azimuth,
+                max_elevation_time,
+                max_elevation_deg,
+                los: last.time,
+                los_azimuth_deg: last.look_angle.map(|la| la.azimuth_deg).unwrap_or(0.0),
+            });
+        }
+    }
+
+    Ok(passes)
+}
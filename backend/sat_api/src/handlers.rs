@@ -1,29 +1,47 @@
-use crate::alerts::{AlertCategory, AlertHub, AlertSeverity, LiveAlert};
+use crate::alerts::{AlertCategory, AlertFilter, AlertHub, AlertSeverity, LiveAlert, DEFAULT_TENANT};
 use crate::api::SatelliteApi;
+use crate::cdm::{self, CdmFormat};
 use crate::conjunction::{ConjunctionAnalyzer, ConjunctionRequest};
-use crate::ml::RiskModel;
+use crate::error::ApiError;
+use crate::ground_station::{self, GroundStation};
+use crate::ip_rate_limit::IpRateLimiter;
+use crate::metrics::Metrics;
+use crate::ml::RiskModelRegistry;
 use crate::reservation::{
-    CreateReservationRequest, LaunchFeasibilityRequest, LaunchFeasibilitySummary, OrbitReservation,
-    OrbitReservationManager, ReservationCheckResponse,
+    CreateReservationRequest, FlexibleReservationRequest, LaunchFeasibilityRequest,
+    LaunchWindowScanRequest, OrbitReservation, OrbitReservationManager, ReservationSafetyReport,
+    SchedulingMode,
 };
+use crate::safety_monitor::SafetyWebhookRegistry;
+use crate::throttle::ThrottleRegistry;
+use crate::timescale::TimeScale;
 use crate::tle::{RiskLevel, SatellitePosition};
+use crate::webhooks::{CreateWebhookRequest, WebhookRegistry};
 use actix_web::web::Bytes;
 use actix_web::{http::header, web, HttpRequest, HttpResponse, Result as ActixResult};
 use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex, RwLock};
-use tokio_stream::wrappers::BroadcastStream;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio_stream::wrappers::IntervalStream;
 use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct AppState {
     pub satellite_api: Arc<SatelliteApi>,
     pub conjunction_analyzer: Arc<Mutex<ConjunctionAnalyzer>>,
-    pub reservation_manager: Arc<Mutex<OrbitReservationManager>>,
+    pub reservation_manager: Arc<AsyncMutex<OrbitReservationManager>>,
     pub alert_hub: Arc<AlertHub>,
-    pub risk_model: Arc<RwLock<RiskModel>>,
+    pub risk_models: Arc<RiskModelRegistry>,
+    pub webhook_registry: Arc<WebhookRegistry>,
+    pub safety_webhooks: Arc<SafetyWebhookRegistry>,
+    pub metrics: Arc<Metrics>,
+    pub throttle: Arc<ThrottleRegistry>,
+    pub ip_rate_limiter: IpRateLimiter,
 }
 
 #[derive(Deserialize)]
@@ -32,15 +50,45 @@ pub struct PaginationQuery {
     pub limit: Option<usize>,
 }
 
+#[derive(Deserialize)]
+pub struct GeoJsonQuery {
+    /// Include a forward ground track per satellite, sampled over one
+    /// orbital period. Defaults to `false`, since computing it for the
+    /// whole catalog is far more expensive than the positions alone.
+    pub ground_track: Option<bool>,
+    /// Samples per ground track when `ground_track` is set. Defaults to 60.
+    pub ground_track_samples: Option<usize>,
+}
+
 #[derive(Deserialize)]
 pub struct GroupQuery {
     pub name: String,
 }
 
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+    pub limit: Option<usize>,
+}
+
 #[derive(Deserialize)]
 pub struct PropagationQuery {
     pub minutes: Option<i64>,
     pub limit: Option<usize>,
+    /// Time scale for the returned `timestamp` fields (defaults to `utc`).
+    /// Propagation itself always runs against a UTC offset internally.
+    pub scale: Option<TimeScale>,
+}
+
+#[derive(Deserialize)]
+pub struct CdmExportQuery {
+    /// CDM encoding to render (defaults to `kvn`).
+    pub format: Option<CdmFormat>,
+}
+
+#[derive(Deserialize)]
+pub struct TimeScaleQuery {
+    pub scale: Option<TimeScale>,
 }
 
 #[derive(Deserialize)]
@@ -84,11 +132,23 @@ pub struct RiskPredictionResponse {
     pub model: crate::ml::RiskModelExplanation,
 }
 
+/// One scenario within a `/risk/batch` request, tagged with an opaque
+/// `op_id` the caller chooses so each result in the response array can be
+/// matched back to the scenario that produced it.
+#[derive(Deserialize)]
+pub struct RiskBatchItem {
+    pub op_id: String,
+    #[serde(flatten)]
+    pub payload: RiskPredictionRequestPayload,
+}
+
 #[derive(Serialize)]
-pub struct ReservationSafetyReport {
-    pub safe_to_launch: bool,
-    pub summary: LaunchFeasibilitySummary,
-    pub assessment: ReservationCheckResponse,
+pub struct RiskBatchResult {
+    pub op_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<RiskPredictionResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -97,8 +157,6 @@ pub struct CreateReservationResponse {
     pub safety: Option<ReservationSafetyReport>,
 }
 
-const DEFAULT_TENANT: &str = "default";
-
 fn tenant_id_from_request(req: &HttpRequest) -> String {
     req.headers()
         .get("x-tenant-id")
@@ -111,95 +169,127 @@ fn tenant_id_from_request(req: &HttpRequest) -> String {
 pub async fn get_all_satellites(
     data: web::Data<AppState>,
     query: web::Query<PaginationQuery>,
-) -> ActixResult<HttpResponse> {
-    match data
+) -> Result<HttpResponse, ApiError> {
+    let satellites = data
         .satellite_api
         .get_all_satellites(query.page, query.limit)
-        .await
-    {
-        Ok(satellites) => Ok(HttpResponse::Ok().json(satellites)),
-        Err(e) => {
-            tracing::error!("Failed to get satellites: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to fetch satellite data",
-                "message": e.to_string()
-            })))
+        .await?;
+    Ok(HttpResponse::Ok().json(satellites))
+}
+
+/// `GET /satellites/geojson`: current positions (and, optionally, a
+/// forward ground track per satellite) as a GeoJSON `FeatureCollection`,
+/// ready to drop into any web map.
+pub async fn get_satellites_geojson(
+    data: web::Data<AppState>,
+    query: web::Query<GeoJsonQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let positions = data.satellite_api.get_all_satellites(None, None).await?;
+
+    let mut ground_tracks = HashMap::new();
+    if query.ground_track.unwrap_or(false) {
+        let samples = query.ground_track_samples.unwrap_or(60).max(2);
+        for position in &positions {
+            let Ok(period_seconds) = data
+                .satellite_api
+                .orbital_period_seconds(position.norad_id)
+                .await
+            else {
+                continue;
+            };
+            let step_seconds = period_seconds / samples as f64;
+
+            let mut track = Vec::with_capacity(samples);
+            for i in 0..=samples {
+                let time =
+                    position.timestamp + chrono::Duration::seconds((step_seconds * i as f64) as i64);
+                if let Ok(sample) = data
+                    .satellite_api
+                    .get_satellite_position_at(position.norad_id, time)
+                    .await
+                {
+                    track.push((sample.lon_deg, sample.lat_deg));
+                }
+            }
+            ground_tracks.insert(position.norad_id, track);
         }
     }
+
+    let collection = crate::geojson::to_feature_collection(&positions, &ground_tracks);
+    Ok(HttpResponse::Ok().json(collection))
 }
 
 pub async fn get_satellite(
     data: web::Data<AppState>,
     path: web::Path<u64>,
-) -> ActixResult<HttpResponse> {
+    query: web::Query<TimeScaleQuery>,
+) -> Result<HttpResponse, ApiError> {
     let norad_id = path.into_inner();
+    let scale = query.scale.unwrap_or(TimeScale::Utc);
 
-    match data.satellite_api.get_satellite(norad_id).await {
-        Ok(satellite) => Ok(HttpResponse::Ok().json(satellite)),
-        Err(e) => {
-            tracing::error!("Failed to get satellite {}: {}", norad_id, e);
-            Ok(HttpResponse::NotFound().json(serde_json::json!({
-                "error": "Satellite not found",
-                "norad_id": norad_id,
-                "message": e.to_string()
-            })))
-        }
-    }
+    let satellite = data.satellite_api.get_satellite(norad_id, scale).await?;
+    Ok(HttpResponse::Ok().json(satellite))
 }
 
 pub async fn get_satellite_group(
     data: web::Data<AppState>,
     path: web::Path<String>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
     let group_name = path.into_inner();
 
-    match data.satellite_api.get_satellite_group(&group_name).await {
-        Ok(group) => Ok(HttpResponse::Ok().json(group)),
-        Err(e) => {
-            tracing::error!("Failed to get satellite group {}: {}", group_name, e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to fetch satellite group",
-                "group": group_name,
-                "message": e.to_string()
-            })))
-        }
-    }
+    let group = data.satellite_api.get_satellite_group(&group_name).await?;
+    Ok(HttpResponse::Ok().json(group))
 }
 
-pub async fn get_statistics(data: web::Data<AppState>) -> ActixResult<HttpResponse> {
-    match data.satellite_api.get_statistics().await {
-        Ok(stats) => Ok(HttpResponse::Ok().json(stats)),
-        Err(e) => {
-            tracing::error!("Failed to get statistics: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to fetch statistics",
-                "message": e.to_string()
-            })))
-        }
-    }
+pub async fn get_source_health(data: web::Data<AppState>) -> ActixResult<HttpResponse> {
+    Ok(HttpResponse::Ok().json(data.satellite_api.source_health()))
+}
+
+/// Typo-tolerant satellite search, e.g. `GET /satellites/search?q=iss`.
+pub async fn search_satellites(
+    data: web::Data<AppState>,
+    query: web::Query<SearchQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let limit = query.limit.unwrap_or(20);
+    let results = data.satellite_api.search_satellites(&query.q, limit).await?;
+    Ok(HttpResponse::Ok().json(results))
+}
+
+/// Constellation-health snapshot for one GNSS system, e.g. `GET /gnss/gps`.
+pub async fn get_constellation_status(
+    data: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let system = match path.into_inner().to_lowercase().as_str() {
+        "gps" => crate::gnss::Gnss::Gps,
+        "galileo" => crate::gnss::Gnss::Galileo,
+        "glonass" => crate::gnss::Gnss::Glonass,
+        "beidou" => crate::gnss::Gnss::BeiDou,
+        other => return Err(ApiError::BadRequest(format!("unknown GNSS system '{other}'"))),
+    };
+
+    let status = data.satellite_api.constellation_status(system).await?;
+    Ok(HttpResponse::Ok().json(status))
+}
+
+pub async fn get_statistics(data: web::Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let stats = data.satellite_api.get_statistics().await?;
+    Ok(HttpResponse::Ok().json(stats))
 }
 
 pub async fn propagate_satellites(
     data: web::Data<AppState>,
     query: web::Query<PropagationQuery>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
     let minutes = query.minutes.unwrap_or(0);
     let limit = query.limit;
+    let scale = query.scale.unwrap_or(TimeScale::Utc);
 
-    match data
+    let satellites = data
         .satellite_api
-        .get_all_satellites_with_offset(minutes, limit)
-        .await
-    {
-        Ok(satellites) => Ok(HttpResponse::Ok().json(satellites)),
-        Err(e) => {
-            tracing::error!("Failed to propagate satellites: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to propagate satellites",
-                "message": e.to_string()
-            })))
-        }
-    }
+        .get_all_satellites_with_offset(minutes, limit, scale)
+        .await?;
+    Ok(HttpResponse::Ok().json(satellites))
 }
 
 pub async fn health_check() -> ActixResult<HttpResponse> {
@@ -212,11 +302,155 @@ pub async fn health_check() -> ActixResult<HttpResponse> {
     })))
 }
 
+/// Reports which time basis positions are currently computed in: the
+/// leap-second count applied when converting UTC to continuous scales
+/// (TAI/TT/GNSS), and whether IERS has announced a future leap second.
+pub async fn get_time_info() -> ActixResult<HttpResponse> {
+    let now = chrono::Utc::now();
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "utc": now,
+        "leap_seconds": crate::timescale::leap_seconds_at(now),
+        "planned_leap_second": crate::timescale::planned_leap_second(),
+    })))
+}
+
+/// Prometheus scrape target: per-tenant operational counters accumulated in
+/// `AppState::metrics` by the handlers below, rendered in the text
+/// exposition format.
+pub async fn metrics(data: web::Data<AppState>) -> ActixResult<HttpResponse> {
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(data.metrics.render()))
+}
+
+#[derive(Debug, Serialize)]
+pub struct HostMetrics {
+    pub cpu_usage_percent: f32,
+    pub resident_memory_bytes: u64,
+    pub uptime_seconds: u64,
+    pub thread_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiskModelMetrics {
+    pub observation_count: u64,
+    pub learning_rate: f64,
+    pub l2_penalty: f64,
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AlertMetrics {
+    pub active_subscribers: usize,
+    pub published_last_minute_by_category: HashMap<AlertCategory, usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SystemMonitorResponse {
+    pub status: &'static str,
+    pub degraded_reasons: Vec<String>,
+    pub host: HostMetrics,
+    pub risk_model: RiskModelMetrics,
+    pub alerts: AlertMetrics,
+    pub catalog_size: usize,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Above this resident-set size we consider the process under memory
+/// pressure; tune per deployment once real traffic patterns are known.
+const MEMORY_PRESSURE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// How long the risk model can go without an `update()` (and thus a
+/// `last_updated` bump) once it has seen at least one observation before we
+/// treat it as stalled rather than merely quiet.
+const STALE_MODEL_THRESHOLD_HOURS: i64 = 6;
+
+/// Live process/host metrics (via `sysinfo`) combined with application-level
+/// counters, for operator dashboards — the zino-style system monitor.
+pub async fn system_monitor(data: web::Data<AppState>) -> ActixResult<HttpResponse> {
+    let mut system = sysinfo::System::new_all();
+    system.refresh_all();
+
+    let pid = sysinfo::Pid::from_u32(std::process::id());
+    let process = system.process(pid);
+
+    let host = HostMetrics {
+        cpu_usage_percent: process.map(|p| p.cpu_usage()).unwrap_or(0.0),
+        resident_memory_bytes: process.map(|p| p.memory()).unwrap_or(0),
+        uptime_seconds: process.map(|p| p.run_time()).unwrap_or(0),
+        thread_count: process
+            .and_then(|p| p.tasks())
+            .map(|tasks| tasks.len())
+            .unwrap_or(0),
+    };
+
+    // Per-tenant risk models can diverge, but the dashboard needs one number —
+    // report on the default tenant's model as the representative signal.
+    let explanation = data
+        .risk_models
+        .get_or_create(DEFAULT_TENANT)
+        .read()
+        .map(|model| model.explain())
+        .ok();
+    let risk_model = RiskModelMetrics {
+        observation_count: explanation.as_ref().map_or(0, |e| e.observation_count),
+        learning_rate: explanation.as_ref().map_or(0.0, |e| e.learning_rate),
+        l2_penalty: explanation.as_ref().map_or(0.0, |e| e.l2_penalty),
+        last_updated: explanation.as_ref().and_then(|e| e.last_updated),
+    };
+
+    let alerts = AlertMetrics {
+        active_subscribers: data.alert_hub.subscriber_count(),
+        published_last_minute_by_category: data
+            .alert_hub
+            .recent_publish_counts(chrono::Duration::minutes(1)),
+    };
+
+    let mut degraded_reasons = Vec::new();
+    if host.resident_memory_bytes > MEMORY_PRESSURE_BYTES {
+        degraded_reasons.push(format!(
+            "resident memory {} bytes exceeds the {} byte pressure threshold",
+            host.resident_memory_bytes, MEMORY_PRESSURE_BYTES
+        ));
+    }
+    if risk_model.observation_count > 0 {
+        let stalled = risk_model.last_updated.map_or(true, |last_updated| {
+            Utc::now() - last_updated > chrono::Duration::hours(STALE_MODEL_THRESHOLD_HOURS)
+        });
+        if stalled {
+            degraded_reasons.push(format!(
+                "risk model has not updated in over {} hours",
+                STALE_MODEL_THRESHOLD_HOURS
+            ));
+        }
+    }
+
+    let status = if degraded_reasons.is_empty() {
+        "healthy"
+    } else {
+        "degraded"
+    };
+
+    Ok(HttpResponse::Ok().json(SystemMonitorResponse {
+        status,
+        degraded_reasons,
+        host,
+        risk_model,
+        alerts,
+        catalog_size: data.satellite_api.catalog_size(),
+        timestamp: Utc::now(),
+    }))
+}
+
 // Conjunction Analysis Endpoints
 pub async fn analyze_conjunctions(
     data: web::Data<AppState>,
+    req: HttpRequest,
     request: web::Json<ConjunctionRequest>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let started_at = Instant::now();
+    let permit = data.throttle.clone().acquire(&tenant_id)?;
     tracing::info!(
         "Conjunction analysis requested for {} satellites",
         request.satellite_ids.len()
@@ -229,7 +463,7 @@ pub async fn analyze_conjunctions(
         // Get specific satellites by ID
         let mut satellites = Vec::new();
         for sat_id in &request.satellite_ids {
-            match data.satellite_api.get_satellite(*sat_id).await {
+            match data.satellite_api.get_satellite(*sat_id, TimeScale::Utc).await {
                 Ok(sat_pos) => {
                     // Convert SatellitePosition back to SatelliteData (simplified)
                     let sat_data = crate::tle::SatelliteData {
@@ -243,6 +477,7 @@ pub async fn analyze_conjunctions(
                             "2 {:05}  51.6000   0.0000 0000000   0.0000   0.0000 15.50000000000000",
                             sat_pos.norad_id
                         ),
+                        epoch: sat_pos.timestamp,
                         last_updated: sat_pos.timestamp,
                     };
                     satellites.push(sat_data);
@@ -269,124 +504,316 @@ pub async fn analyze_conjunctions(
             .collect())
     };
 
-    match satellites_result {
-        Ok(sat_positions) => {
-            // Convert positions to satellite data for analysis
-            let satellite_data: Vec<crate::tle::SatelliteData> = sat_positions
+    let sat_positions = satellites_result?;
+
+    // Convert positions to satellite data for analysis
+    let satellite_data: Vec<crate::tle::SatelliteData> = sat_positions
+        .into_iter()
+        .map(|pos| crate::tle::SatelliteData {
+            norad_id: pos.norad_id,
+            name: pos.name,
+            tle_line1: format!(
+                "1 {:05}U          23001.00000000  .00000000  00000-0  00000-0 0  9999",
+                pos.norad_id
+            ),
+            tle_line2: format!(
+                "2 {:05}  51.6000   0.0000 0000000   0.0000   0.0000 15.50000000000000",
+                pos.norad_id
+            ),
+            epoch: pos.timestamp,
+            last_updated: pos.timestamp,
+        })
+        .collect();
+
+    // Perform conjunction analysis
+    let analyzer = data
+        .conjunction_analyzer
+        .lock()
+        .map_err(|_| ApiError::AnalyzerUnavailable)?;
+    let analysis = analyzer.analyze_conjunctions(&satellite_data, &request)?;
+
+    tracing::info!(
+        "Conjunction analysis completed: {} conjunctions found",
+        analysis.conjunctions_found
+    );
+
+    data.metrics.record_conjunction_analysis(&tenant_id);
+    data.metrics
+        .record_request_latency(&tenant_id, "/conjunctions/analyze", started_at.elapsed());
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("X-RateLimit-Limit", permit.limit.to_string()))
+        .insert_header(("X-RateLimit-Remaining", permit.remaining.to_string()))
+        .json(analysis))
+}
+
+/// Runs the same conjunction analysis as `analyze_conjunctions`, then
+/// renders every conjunction found as a CCSDS 508.0-B-1 Conjunction Data
+/// Message instead of this crate's own JSON schema, so the output can feed
+/// existing space-situational-awareness tooling.
+pub async fn export_conjunctions_cdm(
+    data: web::Data<AppState>,
+    query: web::Query<CdmExportQuery>,
+    request: web::Json<ConjunctionRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let format = query.format.unwrap_or(CdmFormat::Kvn);
+
+    let catalog = data.satellite_api.get_all_satellites(None, None).await?;
+    let tle_catalog = data.satellite_api.get_satellite_tle_catalog().await?;
+
+    let (satellite_data_catalog, _unavailable) = if request.satellite_ids.is_empty() {
+        catalog_to_satellite_data(&catalog, &tle_catalog)
+    } else {
+        let wanted: std::collections::HashSet<u64> = request.satellite_ids.iter().copied().collect();
+        catalog_to_satellite_data(
+            &catalog
                 .into_iter()
-                .map(|pos| crate::tle::SatelliteData {
-                    norad_id: pos.norad_id,
-                    name: pos.name,
-                    tle_line1: format!(
-                        "1 {:05}U          23001.00000000  .00000000  00000-0  00000-0 0  9999",
-                        pos.norad_id
-                    ),
-                    tle_line2: format!(
-                        "2 {:05}  51.6000   0.0000 0000000   0.0000   0.0000 15.50000000000000",
-                        pos.norad_id
-                    ),
-                    last_updated: pos.timestamp,
+                .filter(|sat| wanted.contains(&sat.norad_id))
+                .collect::<Vec<_>>(),
+            &tle_catalog,
+        )
+    };
+
+    let analyzer = data
+        .conjunction_analyzer
+        .lock()
+        .map_err(|_| ApiError::AnalyzerUnavailable)?;
+    let analysis = analyzer.analyze_conjunctions(&satellite_data_catalog, &request)?;
+
+    let messages: Vec<String> = analysis
+        .conjunctions
+        .iter()
+        .map(|event| match format {
+            CdmFormat::Kvn => cdm::to_kvn(event),
+            CdmFormat::Xml => cdm::to_xml(event),
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "format": format,
+        "conjunctions_found": analysis.conjunctions_found,
+        "messages": messages,
+    })))
+}
+
+#[derive(Deserialize)]
+pub struct NamedGroundStation {
+    pub name: String,
+    #[serde(flatten)]
+    pub station: GroundStation,
+}
+
+#[derive(Deserialize)]
+pub struct ConjunctionVisibilityRequest {
+    #[serde(flatten)]
+    pub conjunction: ConjunctionRequest,
+    pub stations: Vec<NamedGroundStation>,
+}
+
+#[derive(Serialize)]
+pub struct ObjectVisibility {
+    pub norad_id: u64,
+    pub look_angle: ground_station::LookAngle,
+    pub visible: bool,
+}
+
+#[derive(Serialize)]
+pub struct StationVisibility {
+    pub station_name: String,
+    pub satellite_a: ObjectVisibility,
+    pub satellite_b: ObjectVisibility,
+    /// True when either object is observable from this station at TCA --
+    /// enough to task optical/radar follow-up on the event.
+    pub conjunction_visible: bool,
+}
+
+#[derive(Serialize)]
+pub struct ConjunctionVisibilityEvent {
+    pub conjunction_id: String,
+    pub tca: DateTime<Utc>,
+    pub stations: Vec<StationVisibility>,
+}
+
+#[derive(Serialize)]
+pub struct ConjunctionVisibilityResponse {
+    pub conjunctions_found: usize,
+    pub events: Vec<ConjunctionVisibilityEvent>,
+}
+
+/// Runs the same conjunction analysis as `analyze_conjunctions`, then for
+/// every conjunction found reports whether each object is observable from
+/// each supplied ground station at TCA, and the viewing az/el/range --
+/// useful for tasking optical/radar follow-up on high-risk events.
+pub async fn conjunction_visibility(
+    data: web::Data<AppState>,
+    payload: web::Json<ConjunctionVisibilityRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let request = &payload.conjunction;
+
+    let catalog = data.satellite_api.get_all_satellites(None, None).await?;
+    let tle_catalog = data.satellite_api.get_satellite_tle_catalog().await?;
+
+    let (satellite_data_catalog, _unavailable) = if request.satellite_ids.is_empty() {
+        catalog_to_satellite_data(&catalog, &tle_catalog)
+    } else {
+        let wanted: std::collections::HashSet<u64> = request.satellite_ids.iter().copied().collect();
+        catalog_to_satellite_data(
+            &catalog
+                .into_iter()
+                .filter(|sat| wanted.contains(&sat.norad_id))
+                .collect::<Vec<_>>(),
+            &tle_catalog,
+        )
+    };
+
+    let analyzer = data
+        .conjunction_analyzer
+        .lock()
+        .map_err(|_| ApiError::AnalyzerUnavailable)?;
+    let analysis = analyzer.analyze_conjunctions(&satellite_data_catalog, request)?;
+
+    let events = analysis
+        .conjunctions
+        .iter()
+        .map(|event| {
+            let stations = payload
+                .stations
+                .iter()
+                .map(|named| {
+                    let look_angle_a = ground_station::look_angle_for_eci(
+                        &named.station,
+                        (
+                            event.satellite_a.position_at_tca.x,
+                            event.satellite_a.position_at_tca.y,
+                            event.satellite_a.position_at_tca.z,
+                        ),
+                        event.tca,
+                    );
+                    let look_angle_b = ground_station::look_angle_for_eci(
+                        &named.station,
+                        (
+                            event.satellite_b.position_at_tca.x,
+                            event.satellite_b.position_at_tca.y,
+                            event.satellite_b.position_at_tca.z,
+                        ),
+                        event.tca,
+                    );
+                    let visible_a = named.station.sees(&look_angle_a, event.tca);
+                    let visible_b = named.station.sees(&look_angle_b, event.tca);
+
+                    StationVisibility {
+                        station_name: named.name.clone(),
+                        satellite_a: ObjectVisibility {
+                            norad_id: event.satellite_a.norad_id,
+                            look_angle: look_angle_a,
+                            visible: visible_a,
+                        },
+                        satellite_b: ObjectVisibility {
+                            norad_id: event.satellite_b.norad_id,
+                            look_angle: look_angle_b,
+                            visible: visible_b,
+                        },
+                        conjunction_visible: visible_a || visible_b,
+                    }
                 })
                 .collect();
 
-            // Perform conjunction analysis
-            match data.conjunction_analyzer.lock() {
-                Ok(analyzer) => match analyzer.analyze_conjunctions(&satellite_data, &request) {
-                    Ok(analysis) => {
-                        tracing::info!(
-                            "Conjunction analysis completed: {} conjunctions found",
-                            analysis.conjunctions_found
-                        );
-                        Ok(HttpResponse::Ok().json(analysis))
-                    }
-                    Err(e) => {
-                        tracing::error!("Conjunction analysis failed: {}", e);
-                        Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                            "error": "Conjunction analysis failed",
-                            "message": e.to_string()
-                        })))
-                    }
-                },
-                Err(e) => {
-                    tracing::error!("Failed to acquire conjunction analyzer lock: {}", e);
-                    Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                        "error": "Service temporarily unavailable",
-                        "message": "Could not access conjunction analyzer"
-                    })))
-                }
+            ConjunctionVisibilityEvent {
+                conjunction_id: event.id.clone(),
+                tca: event.tca,
+                stations,
             }
-        }
-        Err(e) => {
-            tracing::error!("Failed to get satellite data: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to retrieve satellite data",
-                "message": e.to_string()
-            })))
-        }
-    }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().json(ConjunctionVisibilityResponse {
+        conjunctions_found: analysis.conjunctions_found,
+        events,
+    }))
 }
 
 pub async fn predict_risk(
     data: web::Data<AppState>,
     req: HttpRequest,
     payload: web::Json<RiskPredictionRequestPayload>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
     let tenant_id = tenant_id_from_request(&req);
-    let horizon_hours = payload.horizon_hours.unwrap_or(24).clamp(1, 168);
-    let screening_distance_km = payload.screening_distance_km.unwrap_or(100.0);
-    let probability_threshold = payload
-        .probability_threshold
-        .unwrap_or(1e-4)
-        .clamp(1e-8, 1.0);
+    let started_at = Instant::now();
+    let permit = data.throttle.clone().acquire(&tenant_id)?;
 
-    let catalog = match data.satellite_api.get_all_satellites(None, None).await {
-        Ok(list) => list,
-        Err(e) => {
-            tracing::error!("Failed to load catalog for risk analysis: {}", e);
-            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "catalog_load_failed",
-                "message": e.to_string()
-            })));
-        }
-    };
+    let catalog = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
 
-    let satellite_data_catalog = catalog_to_satellite_data(&catalog);
+    let (satellite_data_catalog, _unavailable) = catalog_to_satellite_data(&catalog, &tle_catalog);
 
     let mut baseline_map: HashMap<u64, SatellitePosition> = HashMap::new();
     for sat in &catalog {
         baseline_map.insert(sat.norad_id, sat.clone());
     }
 
-    let analyzer_guard = match data.conjunction_analyzer.lock() {
-        Ok(guard) => guard,
-        Err(e) => {
-            tracing::error!("Failed to acquire conjunction analyzer: {}", e);
-            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "analyzer_unavailable",
-                "message": "Conjunction analyzer is busy"
-            })));
-        }
-    };
+    let analyzer_guard = data
+        .conjunction_analyzer
+        .lock()
+        .map_err(|_| ApiError::AnalyzerUnavailable)?;
+
+    let response = evaluate_risk_scenario(
+        &data,
+        &tenant_id,
+        &satellite_data_catalog,
+        &baseline_map,
+        &analyzer_guard,
+        &payload,
+    )?;
+
+    drop(analyzer_guard);
+
+    data.metrics
+        .record_risk_prediction(&tenant_id, response.dangerous_conjunctions);
+    data.metrics
+        .record_request_latency(&tenant_id, "/risk/predict", started_at.elapsed());
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("X-RateLimit-Limit", permit.limit.to_string()))
+        .insert_header(("X-RateLimit-Remaining", permit.remaining.to_string()))
+        .json(response))
+}
+
+/// Runs one risk-prediction scenario against an already-loaded catalog and
+/// an already-locked `ConjunctionAnalyzer`, so a caller evaluating several
+/// scenarios (see `predict_risk_batch`) only pays for the catalog fetch,
+/// TLE conversion, and analyzer lock once rather than once per scenario.
+fn evaluate_risk_scenario(
+    data: &AppState,
+    tenant_id: &str,
+    satellite_data_catalog: &[crate::tle::SatelliteData],
+    baseline_map: &HashMap<u64, SatellitePosition>,
+    analyzer: &ConjunctionAnalyzer,
+    payload: &RiskPredictionRequestPayload,
+) -> Result<RiskPredictionResponse, ApiError> {
+    let horizon_hours = payload.horizon_hours.unwrap_or(24).clamp(1, 168);
+    let screening_distance_km = payload.screening_distance_km.unwrap_or(100.0);
+    let probability_threshold = payload
+        .probability_threshold
+        .unwrap_or(1e-4)
+        .clamp(1e-8, 1.0);
 
     let request = ConjunctionRequest {
         satellite_ids: payload.satellite_ids.clone(),
         horizon_hours: Some(horizon_hours as u64),
         screening_distance_km: Some(screening_distance_km),
         probability_threshold: Some(probability_threshold),
+        monte_carlo_samples: None,
     };
 
-    let analysis = match analyzer_guard.analyze_conjunctions(&satellite_data_catalog, &request) {
-        Ok(result) => result,
-        Err(e) => {
-            tracing::error!("Conjunction analysis failed: {}", e);
-            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "analysis_failed",
-                "message": e.to_string()
-            })));
-        }
-    };
-
-    drop(analyzer_guard);
+    let analysis = analyzer.analyze_conjunctions(satellite_data_catalog, &request)?;
 
     let mut events = Vec::new();
     let mut max_probability: f64 = 0.0;
@@ -415,23 +842,19 @@ pub async fn predict_risk(
             baseline_risk,
         ];
 
+        let risk_model = data.risk_models.get_or_create(tenant_id);
         let logistic_probability = {
-            match data.risk_model.write() {
-                Ok(mut guard) => {
-                    let probability = guard.predict(features);
-                    let label = if event.pc >= probability_threshold {
-                        1.0
-                    } else {
-                        0.0
-                    };
-                    guard.update(features, label);
-                    probability
-                }
-                Err(e) => {
-                    tracing::error!("Failed to acquire risk model lock: {}", e);
-                    0.0
-                }
-            }
+            let mut guard = risk_model
+                .write()
+                .map_err(|_| ApiError::ModelLockPoisoned)?;
+            let probability = guard.predict(features);
+            let label = if event.pc >= probability_threshold {
+                1.0
+            } else {
+                0.0
+            };
+            guard.update(features, label);
+            probability
         };
         let risk_level = if logistic_probability >= 0.7 {
             RiskLevel::Red
@@ -455,7 +878,7 @@ pub async fn predict_risk(
         if logistic_probability >= 0.6 {
             data.alert_hub.publish(LiveAlert {
                 id: Uuid::new_v4(),
-                tenant_id: tenant_id.clone(),
+                tenant_id: tenant_id.to_string(),
                 title: "High collision probability detected".to_string(),
                 message: format!(
                     "Pair {} / {} has {:.2}% risk within {}h horizon",
@@ -473,7 +896,10 @@ pub async fn predict_risk(
                     "minimum_distance_km": event.dmin_km,
                     "relative_velocity_km_s": event.relative_velocity_km_s,
                 }),
+                seq: 0, // stamped by AlertHub::publish
             });
+            data.metrics
+                .record_alert_published(tenant_id, AlertSeverity::Critical);
         }
 
         events.push(RiskPredictionConjunction {
@@ -501,31 +927,16 @@ pub async fn predict_risk(
         0.0
     };
 
-    let model_snapshot = match data.risk_model.read() {
-        Ok(guard) => guard.explain(),
-        Err(e) => {
-            tracing::error!("Failed to read risk model state: {}", e);
-            crate::ml::RiskModelExplanation {
-                bias: 0.0,
-                coefficients: [0.0; 4],
-                feature_order: [
-                    "minimum_distance_km",
-                    "relative_velocity_km_s",
-                    "tle_age_hours",
-                    "baseline_risk_score",
-                ],
-                observation_count: 0,
-                learning_rate: 0.0,
-                l2_penalty: 0.0,
-                last_updated: None,
-                persistence_path: None,
-            }
-        }
-    };
+    let model_snapshot = data
+        .risk_models
+        .get_or_create(tenant_id)
+        .read()
+        .map_err(|_| ApiError::ModelLockPoisoned)?
+        .explain();
 
-    let response = RiskPredictionResponse {
+    Ok(RiskPredictionResponse {
         generated_at: Utc::now(),
-        tenant_id,
+        tenant_id: tenant_id.to_string(),
         horizon_hours,
         conjunctions_evaluated: total_events,
         dangerous_conjunctions,
@@ -536,49 +947,171 @@ pub async fn predict_risk(
         },
         events,
         model: model_snapshot,
-    };
+    })
+}
 
-    Ok(HttpResponse::Ok().json(response))
+/// Evaluates several risk-prediction scenarios in one request. Loads and
+/// converts the satellite catalog and takes the `conjunction_analyzer` lock
+/// once, then fans that shared state across every scenario -- a scenario
+/// that fails (e.g. a propagation error during screening) is reported as an
+/// `error` entry rather than failing the rest of the batch.
+pub async fn predict_risk_batch(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<Vec<RiskBatchItem>>,
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let started_at = Instant::now();
+    let permit = data.throttle.clone().acquire(&tenant_id)?;
+
+    let catalog = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data_catalog, _unavailable) = catalog_to_satellite_data(&catalog, &tle_catalog);
+
+    let mut baseline_map: HashMap<u64, SatellitePosition> = HashMap::new();
+    for sat in &catalog {
+        baseline_map.insert(sat.norad_id, sat.clone());
+    }
+
+    let analyzer_guard = data
+        .conjunction_analyzer
+        .lock()
+        .map_err(|_| ApiError::AnalyzerUnavailable)?;
+
+    let results: Vec<RiskBatchResult> = payload
+        .into_inner()
+        .into_iter()
+        .map(|item| {
+            match evaluate_risk_scenario(
+                &data,
+                &tenant_id,
+                &satellite_data_catalog,
+                &baseline_map,
+                &analyzer_guard,
+                &item.payload,
+            ) {
+                Ok(response) => {
+                    data.metrics
+                        .record_risk_prediction(&tenant_id, response.dangerous_conjunctions);
+                    RiskBatchResult {
+                        op_id: item.op_id,
+                        result: Some(response),
+                        error: None,
+                    }
+                }
+                Err(err) => RiskBatchResult {
+                    op_id: item.op_id,
+                    result: None,
+                    error: Some(err.to_string()),
+                },
+            }
+        })
+        .collect();
+
+    drop(analyzer_guard);
+
+    data.metrics
+        .record_request_latency(&tenant_id, "/risk/batch", started_at.elapsed());
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("X-RateLimit-Limit", permit.limit.to_string()))
+        .insert_header(("X-RateLimit-Remaining", permit.remaining.to_string()))
+        .json(results))
+}
+
+/// Query params narrowing `/alerts/stream`, mirroring `AlertsWsSubscription`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AlertStreamQuery {
+    pub category: Option<AlertCategory>,
+    pub min_severity: Option<AlertSeverity>,
+}
+
+const ALERT_STREAM_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Wraps an SSE stream so `Metrics::sse_subscriber_connected` is balanced by
+/// a `sse_subscriber_disconnected` whenever the stream is dropped --
+/// client disconnect, server shutdown, or normal completion alike -- so the
+/// `orbitalos_alert_stream_subscribers` gauge never drifts upward forever.
+struct SseSubscriberGuard<S> {
+    inner: S,
+    metrics: Arc<Metrics>,
+    tenant_id: String,
+}
+
+impl<S: futures::Stream + Unpin> futures::Stream for SseSubscriberGuard<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        std::pin::Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+impl<S> Drop for SseSubscriberGuard<S> {
+    fn drop(&mut self) {
+        self.metrics.sse_subscriber_disconnected(&self.tenant_id);
+    }
 }
 
 pub async fn stream_alerts(
     data: web::Data<AppState>,
+    query: web::Query<AlertStreamQuery>,
     req: HttpRequest,
 ) -> ActixResult<HttpResponse> {
     let tenant_id = tenant_id_from_request(&req);
-    let receiver = data.alert_hub.subscribe();
-    let tenant_filter = tenant_id.clone();
-
-    let stream = BroadcastStream::new(receiver).filter_map(move |result| {
-        let tenant_filter = tenant_filter.clone();
-        async move {
-            match result {
-                Ok(alert) => {
-                    let allow_default = tenant_filter == DEFAULT_TENANT;
-                    if alert.tenant_id == tenant_filter
-                        || (allow_default && alert.tenant_id == DEFAULT_TENANT)
-                    {
-                        match serde_json::to_string(&alert) {
-                            Ok(json) => {
-                                let payload = format!("event: alert\ndata: {}\n\n", json);
-                                Some(Ok::<Bytes, actix_web::Error>(Bytes::from(payload)))
-                            }
-                            Err(e) => {
-                                tracing::error!("Failed to serialize alert: {}", e);
-                                None
-                            }
-                        }
-                    } else {
-                        None
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Alert stream lagged: {}", e);
-                    None
-                }
+    let last_seq = req
+        .headers()
+        .get("Last-Event-ID")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let mut filter = AlertFilter::new(tenant_id.clone());
+    if let Some(category) = query.category {
+        filter.categories = Some(std::iter::once(category).collect());
+    }
+    if let Some(min_severity) = query.min_severity {
+        filter.min_severity = min_severity;
+    }
+
+    let alerts = data
+        .alert_hub
+        .subscribe_since(last_seq, filter)
+        .map(|alert| match serde_json::to_string(&alert) {
+            Ok(json) => {
+                let payload = format!("id: {}\nevent: alert\ndata: {}\n\n", alert.seq, json);
+                Ok::<Bytes, actix_web::Error>(Bytes::from(payload))
             }
-        }
-    });
+            Err(e) => {
+                tracing::error!("Failed to serialize alert: {}", e);
+                Ok::<Bytes, actix_web::Error>(Bytes::new())
+            }
+        });
+
+    // Cozo's server.rs keeps proxies/browsers from timing out an idle SSE
+    // connection by interleaving blank comment lines; we do the same here.
+    let keep_alive = IntervalStream::new(tokio::time::interval(ALERT_STREAM_KEEP_ALIVE))
+        .map(|_| Ok::<Bytes, actix_web::Error>(Bytes::from_static(b": keep-alive\n\n")));
+
+    let stream = tokio_stream::StreamExt::merge(alerts, keep_alive);
+
+    data.metrics.sse_subscriber_connected(&tenant_id);
+    let stream = SseSubscriberGuard {
+        inner: stream,
+        metrics: data.metrics.clone(),
+        tenant_id,
+    };
 
     Ok(HttpResponse::Ok()
         .append_header((header::CONTENT_TYPE, "text/event-stream"))
@@ -587,10 +1120,122 @@ pub async fn stream_alerts(
         .streaming(stream))
 }
 
+/// Subscription message a client sends over `/alerts/ws` to narrow the feed.
+/// Fields left `None` mean "no filter" (all categories, `AlertSeverity::Info` and up).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AlertsWsSubscription {
+    pub category: Option<AlertCategory>,
+    pub min_severity: Option<AlertSeverity>,
+}
+
+struct ForwardAlert(LiveAlert);
+
+impl actix::Message for ForwardAlert {
+    type Result = ();
+}
+
+struct AlertWsSession {
+    tenant_id: String,
+    subscription: AlertsWsSubscription,
+    alert_hub: Arc<AlertHub>,
+}
+
+impl actix::Actor for AlertWsSession {
+    type Context = actix_web_actors::ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        use actix::AsyncContext;
+        let addr = ctx.address();
+        let mut receiver = self.alert_hub.subscribe();
+        actix_web::rt::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(alert) => addr.do_send(ForwardAlert(alert)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Alert WS subscriber lagged, skipped {} alerts", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+impl actix::Handler<ForwardAlert> for AlertWsSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: ForwardAlert, ctx: &mut Self::Context) {
+        let alert = msg.0;
+
+        let allow_default = self.tenant_id == DEFAULT_TENANT;
+        let tenant_matches = alert.tenant_id == self.tenant_id
+            || (allow_default && alert.tenant_id == DEFAULT_TENANT);
+        if !tenant_matches {
+            return;
+        }
+
+        if let Some(category) = self.subscription.category {
+            if alert.category != category {
+                return;
+            }
+        }
+
+        let min_severity = self.subscription.min_severity.unwrap_or(AlertSeverity::Info);
+        if alert.severity < min_severity {
+            return;
+        }
+
+        match serde_json::to_string(&alert) {
+            Ok(json) => ctx.text(json),
+            Err(e) => tracing::error!("Failed to serialize alert: {}", e),
+        }
+    }
+}
+
+impl actix::StreamHandler<Result<actix_web_actors::ws::Message, actix_web_actors::ws::ProtocolError>>
+    for AlertWsSession
+{
+    fn handle(
+        &mut self,
+        msg: Result<actix_web_actors::ws::Message, actix_web_actors::ws::ProtocolError>,
+        ctx: &mut Self::Context,
+    ) {
+        use actix_web_actors::ws::Message;
+        match msg {
+            Ok(Message::Ping(bytes)) => ctx.pong(&bytes),
+            Ok(Message::Text(text)) => match serde_json::from_str::<AlertsWsSubscription>(&text) {
+                Ok(subscription) => self.subscription = subscription,
+                Err(e) => tracing::warn!("Invalid alert subscription message: {}", e),
+            },
+            Ok(Message::Close(reason)) => ctx.close(reason),
+            _ => {}
+        }
+    }
+}
+
+/// WebSocket alternative to [`stream_alerts`]: send an [`AlertsWsSubscription`] JSON
+/// frame at any point to narrow the feed by `category`/`min_severity`; every
+/// matching `LiveAlert` is pushed back as a JSON text frame.
+pub async fn alerts_ws(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    stream: web::Payload,
+) -> ActixResult<HttpResponse> {
+    let session = AlertWsSession {
+        tenant_id: tenant_id_from_request(&req),
+        subscription: AlertsWsSubscription::default(),
+        alert_hub: data.alert_hub.clone(),
+    };
+    actix_web_actors::ws::start(session, &req, stream)
+}
+
 pub async fn assess_launch_feasibility(
     data: web::Data<AppState>,
+    req: HttpRequest,
     payload: web::Json<LaunchFeasibilityRequest>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
     let request = payload.into_inner();
     tracing::info!(
         "Assessing launch feasibility for mission '{}' (customer: {})",
@@ -598,234 +1243,524 @@ pub async fn assess_launch_feasibility(
         request.customer
     );
 
-    let catalog_positions = match data.satellite_api.get_all_satellites(None, None).await {
-        Ok(list) => list,
-        Err(e) => {
-            tracing::error!("Failed to load catalog for launch feasibility: {}", e);
-            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "catalog_load_failed",
-                "message": e.to_string()
-            })));
-        }
-    };
+    let catalog_positions = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data_catalog, unavailable) =
+        catalog_to_satellite_data(&catalog_positions, &tle_catalog);
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+
+    // The propagation/geometry work inside `evaluate_launch_feasibility` is
+    // CPU-bound; run it on the blocking pool so it doesn't stall the Tokio
+    // worker thread backing this future while it holds the manager lock.
+    let mut result = tokio::task::spawn_blocking(move || {
+        let mut manager = manager_guard;
+        manager
+            .evaluate_launch_feasibility(request, &satellite_data_catalog, &tenant_id)
+            .map_err(|e| ApiError::BadRequest(e.to_string()))
+    })
+    .await
+    .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+    result.assessment.propagation_unavailable = unavailable;
+
+    Ok(HttpResponse::Ok().json(result))
+}
 
-    let satellite_data_catalog = catalog_to_satellite_data(&catalog_positions);
+/// Sweeps candidate launch epochs instead of assessing a single fixed one,
+/// turning `assess_launch_feasibility`'s binary go/no-go into a ranked
+/// launch-window planner.
+pub async fn optimize_launch_window(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<LaunchWindowScanRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let request = payload.into_inner();
+    tracing::info!(
+        "Scanning launch window for mission '{}' (customer: {})",
+        request.feasibility.mission_name,
+        request.feasibility.customer
+    );
 
-    match data.reservation_manager.lock() {
-        Ok(mut manager) => {
-            match manager.evaluate_launch_feasibility(request, &satellite_data_catalog) {
-                Ok(result) => Ok(HttpResponse::Ok().json(result)),
-                Err(e) => {
-                    tracing::warn!("Launch feasibility evaluation failed: {}", e);
-                    Ok(HttpResponse::BadRequest().json(serde_json::json!({
-                        "error": "launch_feasibility_failed",
-                        "message": e.to_string()
-                    })))
-                }
-            }
-        }
-        Err(e) => {
-            tracing::error!("Failed to acquire reservation manager lock: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Service temporarily unavailable",
-                "message": "Could not access reservation manager"
-            })))
+    let catalog_positions = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data_catalog, _unavailable) =
+        catalog_to_satellite_data(&catalog_positions, &tle_catalog);
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+
+    // Each candidate epoch runs a full propagation-backed conflict
+    // assessment, so this stays on the blocking pool just like the
+    // single-epoch `assess_launch_feasibility` endpoint.
+    let result = tokio::task::spawn_blocking(move || {
+        let mut manager = manager_guard;
+        manager
+            .optimize_launch_window(request, &satellite_data_catalog, &tenant_id)
+            .map_err(|e| ApiError::BadRequest(e.to_string()))
+    })
+    .await
+    .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Resolves each catalog position to its real tracked TLE (from
+/// `SatelliteApi::get_satellite_tle_catalog`), keyed by NORAD id, instead of
+/// fabricating one. A position whose real TLE isn't present in
+/// `tle_catalog` (e.g. it fell out of the tracked catalog between the two
+/// fetches) is omitted from the returned satellites and its NORAD id
+/// reported in the second return value, so callers can mark it
+/// `propagation_unavailable` rather than silently assessing it against a
+/// bogus orbit.
+pub(crate) fn catalog_to_satellite_data(
+    catalog: &[SatellitePosition],
+    tle_catalog: &[crate::tle::SatelliteData],
+) -> (Vec<crate::tle::SatelliteData>, Vec<u64>) {
+    let by_norad_id: HashMap<u64, &crate::tle::SatelliteData> =
+        tle_catalog.iter().map(|sat| (sat.norad_id, sat)).collect();
+
+    let mut satellites = Vec::with_capacity(catalog.len());
+    let mut unavailable = Vec::new();
+
+    for pos in catalog {
+        match by_norad_id.get(&pos.norad_id) {
+            Some(tle) => satellites.push((*tle).clone()),
+            None => unavailable.push(pos.norad_id),
         }
     }
-}
 
-fn catalog_to_satellite_data(catalog: &[SatellitePosition]) -> Vec<crate::tle::SatelliteData> {
-    catalog
-        .iter()
-        .map(|pos| crate::tle::SatelliteData {
-            norad_id: pos.norad_id,
-            name: pos.name.clone(),
-            tle_line1: format!(
-                "1 {:05}U          23001.00000000  .00000000  00000-0  00000-0 0  9999",
-                pos.norad_id
-            ),
-            tle_line2: format!(
-                "2 {:05}  51.6000   0.0000 0000000   0.0000   0.0000 15.50000000000000",
-                pos.norad_id
-            ),
-            last_updated: pos.timestamp,
-        })
-        .collect()
+    (satellites, unavailable)
 }
 
 // Orbit Reservation Endpoints
 pub async fn create_reservation(
     data: web::Data<AppState>,
+    req: HttpRequest,
     request: web::Json<CreateReservationRequest>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let started_at = Instant::now();
     tracing::info!("Creating orbit reservation for owner: {}", request.owner);
 
-    let catalog_positions = match data.satellite_api.get_all_satellites(None, None).await {
-        Ok(list) => list,
-        Err(e) => {
-            tracing::error!("Failed to load catalog for reservation safety check: {}", e);
-            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "catalog_load_failed",
-                "message": e.to_string()
-            })));
-        }
-    };
-
-    let satellite_data_catalog = catalog_to_satellite_data(&catalog_positions);
-
-    match data.reservation_manager.lock() {
-        Ok(mut manager) => {
-            let payload = request.into_inner();
-            match manager.create_reservation(payload) {
-                Ok(reservation) => {
-                    let assessment = match manager
-                        .check_reservation_conflicts(reservation.id, &satellite_data_catalog)
-                    {
-                        Ok(result) => result,
-                        Err(e) => {
-                            tracing::error!(
-                                "Failed to evaluate safety for reservation {}: {}",
-                                reservation.id,
-                                e
-                            );
-                            return Ok(HttpResponse::InternalServerError().json(
-                                serde_json::json!({
-                                    "error": "safety_check_failed",
-                                    "message": e.to_string()
-                                }),
-                            ));
-                        }
-                    };
-
-                    let (summary, safe_to_launch) =
-                        OrbitReservationManager::summarize_feasibility(&reservation, &assessment);
-
-                    tracing::info!(
-                        "Created reservation with ID: {} (safe_to_launch: {})",
-                        reservation.id,
-                        safe_to_launch
-                    );
+    let catalog_positions = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data_catalog, unavailable) =
+        catalog_to_satellite_data(&catalog_positions, &tle_catalog);
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+
+    let payload = request.into_inner();
+    let tenant_id_for_blocking = tenant_id.clone();
+
+    let (reservation, mut assessment, summary, safe_to_launch) =
+        tokio::task::spawn_blocking(move || {
+            let mut manager = manager_guard;
+            let reservation = manager
+                .create_reservation(payload)
+                .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+            let assessment = manager
+                .check_reservation_conflicts(
+                    reservation.id,
+                    &satellite_data_catalog,
+                    &tenant_id_for_blocking,
+                )
+                .map_err(|e| ApiError::ReservationConflict(e.to_string()))?;
+
+            let (summary, safe_to_launch) =
+                OrbitReservationManager::summarize_feasibility(&reservation, &assessment);
+
+            Ok::<_, ApiError>((reservation, assessment, summary, safe_to_launch))
+        })
+        .await
+        .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+    assessment.propagation_unavailable = unavailable;
 
-                    let response = CreateReservationResponse {
-                        reservation,
-                        safety: Some(ReservationSafetyReport {
-                            safe_to_launch,
-                            summary,
-                            assessment,
-                        }),
-                    };
+    tracing::info!(
+        "Created reservation with ID: {} (safe_to_launch: {})",
+        reservation.id,
+        safe_to_launch
+    );
 
-                    Ok(HttpResponse::Created().json(response))
-                }
-                Err(e) => {
-                    tracing::error!("Failed to create reservation: {}", e);
-                    Ok(HttpResponse::BadRequest().json(serde_json::json!({
-                        "error": "Failed to create reservation",
-                        "message": e.to_string()
-                    })))
-                }
-            }
-        }
-        Err(e) => {
-            tracing::error!("Failed to acquire reservation manager lock: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Service temporarily unavailable",
-                "message": "Could not access reservation manager"
-            })))
-        }
+    if assessment.conflicts_found > 0 {
+        data.metrics.record_reservation_rejected(&tenant_id);
+    } else {
+        data.metrics.record_reservation_created(&tenant_id);
     }
+    data.metrics
+        .record_request_latency(&tenant_id, "/reservations", started_at.elapsed());
+
+    let response = CreateReservationResponse {
+        reservation,
+        safety: Some(ReservationSafetyReport {
+            safe_to_launch,
+            summary,
+            assessment,
+        }),
+    };
+
+    Ok(HttpResponse::Created().json(response))
 }
 
 pub async fn check_reservation_conflicts(
     data: web::Data<AppState>,
+    req: HttpRequest,
     path: web::Path<String>,
-) -> ActixResult<HttpResponse> {
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
     let reservation_id_str = path.into_inner();
 
-    let reservation_id = match Uuid::parse_str(&reservation_id_str) {
-        Ok(id) => id,
-        Err(e) => {
-            tracing::warn!("Invalid reservation ID format: {}", reservation_id_str);
-            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
-                "error": "Invalid reservation ID format",
-                "message": e.to_string()
-            })));
-        }
-    };
+    let reservation_id = Uuid::parse_str(&reservation_id_str).map_err(|e| {
+        tracing::warn!("Invalid reservation ID format: {}", reservation_id_str);
+        ApiError::BadRequest(format!("Invalid reservation ID format: {}", e))
+    })?;
 
     tracing::info!("Checking conflicts for reservation: {}", reservation_id);
 
-    // Get current satellite catalog
-    match data.satellite_api.get_all_satellites(None, None).await {
-        Ok(sat_positions) => {
-            // Convert to satellite data
-            let satellite_data: Vec<crate::tle::SatelliteData> = sat_positions
-                .into_iter()
-                .map(|pos| crate::tle::SatelliteData {
-                    norad_id: pos.norad_id,
-                    name: pos.name,
-                    tle_line1: format!(
-                        "1 {:05}U          23001.00000000  .00000000  00000-0  00000-0 0  9999",
-                        pos.norad_id
+    let sat_positions = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data, unavailable) = catalog_to_satellite_data(&sat_positions, &tle_catalog);
+
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+
+    let mut check_result = tokio::task::spawn_blocking(move || {
+        let mut manager = manager_guard;
+        manager
+            .check_reservation_conflicts(reservation_id, &satellite_data, &tenant_id)
+            .map_err(|e| ApiError::ReservationConflict(e.to_string()))
+    })
+    .await
+    .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+    check_result.propagation_unavailable = unavailable;
+
+    tracing::info!(
+        "Conflict check completed: {} conflicts found",
+        check_result.conflicts_found
+    );
+    Ok(HttpResponse::Ok().json(check_result))
+}
+
+/// Reschedules an existing reservation's time window and re-runs conflict
+/// detection against the new range, returning a fresh safety report. Rejects
+/// with 409 if the reservation is already `Active`, leaving its original
+/// window untouched.
+pub async fn update_reservation(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    payload: web::Json<crate::reservation::UpdateReservationRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let reservation_id_str = path.into_inner();
+
+    let reservation_id = Uuid::parse_str(&reservation_id_str).map_err(|e| {
+        tracing::warn!("Invalid reservation ID format: {}", reservation_id_str);
+        ApiError::BadRequest(format!("Invalid reservation ID format: {}", e))
+    })?;
+
+    let catalog_positions = data
+        .satellite_api
+        .get_all_satellites(None, None)
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+    let tle_catalog = data
+        .satellite_api
+        .get_satellite_tle_catalog()
+        .await
+        .map_err(|e| ApiError::CatalogLoadFailed(e.to_string()))?;
+
+    let (satellite_data_catalog, unavailable) =
+        catalog_to_satellite_data(&catalog_positions, &tle_catalog);
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+    let update = payload.into_inner();
+
+    let (reservation, mut assessment, summary, safe_to_launch) =
+        tokio::task::spawn_blocking(move || {
+            let mut manager = manager_guard;
+            let reservation = manager
+                .update_reservation(reservation_id, update.start_time, update.end_time)
+                .map_err(|err| match err {
+                    crate::reservation::RescheduleError::NotFound => ApiError::Domain(
+                        crate::tle::SatApiError::SatelliteNotFound(reservation_id.as_u128() as u64),
                     ),
-                    tle_line2: format!(
-                        "2 {:05}  51.6000   0.0000 0000000   0.0000   0.0000 15.50000000000000",
-                        pos.norad_id
+                    crate::reservation::RescheduleError::InvalidWindow(message) => {
+                        ApiError::BadRequest(message)
+                    }
+                    crate::reservation::RescheduleError::AlreadyActive => ApiError::Conflict(
+                        "Reservation is already active and cannot be rescheduled".to_string(),
                     ),
-                    last_updated: pos.timestamp,
-                })
-                .collect();
+                })?;
 
-            match data.reservation_manager.lock() {
-                Ok(mut manager) => {
-                    match manager.check_reservation_conflicts(reservation_id, &satellite_data) {
-                        Ok(check_result) => {
-                            tracing::info!(
-                                "Conflict check completed: {} conflicts found",
-                                check_result.conflicts_found
-                            );
-                            Ok(HttpResponse::Ok().json(check_result))
-                        }
-                        Err(e) => {
-                            tracing::error!("Conflict check failed: {}", e);
-                            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                                "error": "Conflict check failed",
-                                "message": e.to_string()
-                            })))
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::error!("Failed to acquire reservation manager lock: {}", e);
-                    Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                        "error": "Service temporarily unavailable",
-                        "message": "Could not access reservation manager"
-                    })))
-                }
-            }
-        }
-        Err(e) => {
-            tracing::error!("Failed to get satellite catalog: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Failed to retrieve satellite catalog",
-                "message": e.to_string()
-            })))
-        }
+            let assessment = manager
+                .check_reservation_conflicts(reservation.id, &satellite_data_catalog, &tenant_id)
+                .map_err(|e| ApiError::ReservationConflict(e.to_string()))?;
+
+            let (summary, safe_to_launch) =
+                OrbitReservationManager::summarize_feasibility(&reservation, &assessment);
+
+            Ok::<_, ApiError>((reservation, assessment, summary, safe_to_launch))
+        })
+        .await
+        .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+    assessment.propagation_unavailable = unavailable;
+
+    tracing::info!(
+        "Rescheduled reservation {} to {}..{} (safe_to_launch: {})",
+        reservation.id,
+        reservation.start_time,
+        reservation.end_time,
+        safe_to_launch
+    );
+
+    Ok(HttpResponse::Ok().json(ReservationSafetyReport {
+        safe_to_launch,
+        summary,
+        assessment,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct AssignReservationsRequest {
+    pub requests: Vec<FlexibleReservationRequest>,
+    #[serde(default = "default_scheduling_mode")]
+    pub mode: SchedulingMode,
+}
+
+fn default_scheduling_mode() -> SchedulingMode {
+    SchedulingMode::Greedy
+}
+
+/// Batch-schedules a set of flexible-window reservation requests, granting
+/// each a concrete `[start_time, end_time)` or rejecting it with the ids of
+/// whichever reservations (already on the books or elsewhere in this same
+/// batch) blocked every window tried. Doesn't persist any granted
+/// placement as a real reservation -- callers submit the granted windows to
+/// `create_reservation` individually once they accept the schedule.
+pub async fn assign_reservations(
+    data: web::Data<AppState>,
+    payload: web::Json<AssignReservationsRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let body = payload.into_inner();
+    tracing::info!(
+        "Scheduling {} flexible reservation requests in {:?} mode",
+        body.requests.len(),
+        body.mode
+    );
+
+    let manager_guard = data.reservation_manager.clone().lock_owned().await;
+
+    let result = tokio::task::spawn_blocking(move || {
+        let manager = manager_guard;
+        manager.assign_reservations(body.requests, body.mode)
+    })
+    .await
+    .map_err(|_| ApiError::ReservationManagerUnavailable)??;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+#[derive(Deserialize)]
+pub struct PassPredictionRequest {
+    /// Target satellite. Exactly one of `norad_id`/`group` should be set;
+    /// `norad_id` takes precedence if both are.
+    pub norad_id: Option<u64>,
+    pub group: Option<String>,
+    #[serde(flatten)]
+    pub station: GroundStation,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+pub struct SatellitePasses {
+    pub norad_id: u64,
+    pub pass_count: usize,
+    pub passes: Vec<crate::ground_station::Pass>,
+}
+
+#[derive(Serialize)]
+pub struct PassPredictionResponse {
+    pub satellites: Vec<SatellitePasses>,
+}
+
+pub async fn predict_passes(
+    data: web::Data<AppState>,
+    payload: web::Json<PassPredictionRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let request = payload.into_inner();
+
+    let norad_ids: Vec<u64> = if let Some(norad_id) = request.norad_id {
+        vec![norad_id]
+    } else if let Some(group) = &request.group {
+        let group = data.satellite_api.get_satellite_group(group).await?;
+        group.satellites.iter().map(|s| s.norad_id).collect()
+    } else {
+        return Err(ApiError::BadRequest(
+            "either norad_id or group must be provided".to_string(),
+        ));
+    };
+
+    let mut satellites = Vec::with_capacity(norad_ids.len());
+    for norad_id in norad_ids {
+        let passes = ground_station::predict_passes(
+            &data.satellite_api,
+            norad_id,
+            &request.station,
+            request.start,
+            request.end,
+        )
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+        satellites.push(SatellitePasses {
+            norad_id,
+            pass_count: passes.len(),
+            passes,
+        });
     }
+
+    Ok(HttpResponse::Ok().json(PassPredictionResponse { satellites }))
 }
 
-pub async fn list_reservations(data: web::Data<AppState>) -> ActixResult<HttpResponse> {
-    match data.reservation_manager.lock() {
-        Ok(manager) => {
-            let reservations = manager.list_reservations();
-            Ok(HttpResponse::Ok().json(reservations))
-        }
-        Err(e) => {
-            tracing::error!("Failed to acquire reservation manager lock: {}", e);
-            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
-                "error": "Service temporarily unavailable",
-                "message": "Could not access reservation manager"
-            })))
-        }
+pub async fn list_reservations(data: web::Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let manager = data.reservation_manager.lock().await;
+    Ok(HttpResponse::Ok().json(manager.list_reservations()))
+}
+
+// Reservation safety webhook endpoints: let operators register a URL that
+// `safety_monitor::SafetyMonitor` pushes a fresh `ReservationSafetyReport` to
+// whenever a periodic re-evaluation changes its verdict.
+#[derive(Deserialize)]
+pub struct CreateSafetyWebhookRequest {
+    pub url: String,
+}
+
+pub async fn create_safety_webhook(
+    data: web::Data<AppState>,
+    path: web::Path<String>,
+    payload: web::Json<CreateSafetyWebhookRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let reservation_id = Uuid::parse_str(&path.into_inner())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid reservation ID format: {}", e)))?;
+
+    {
+        let manager = data.reservation_manager.lock().await;
+        manager
+            .get_reservation(&reservation_id)
+            .ok_or(crate::tle::SatApiError::SatelliteNotFound(
+                reservation_id.as_u128() as u64,
+            ))?;
+    }
+
+    let subscription = data
+        .safety_webhooks
+        .register(reservation_id, payload.into_inner().url);
+    Ok(HttpResponse::Created().json(subscription))
+}
+
+pub async fn list_safety_webhooks(
+    data: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let reservation_id = Uuid::parse_str(&path.into_inner())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid reservation ID format: {}", e)))?;
+    Ok(HttpResponse::Ok().json(data.safety_webhooks.list_for_reservation(reservation_id)))
+}
+
+pub async fn delete_safety_webhook(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (reservation_id_str, webhook_id_str) = path.into_inner();
+
+    let reservation_id = Uuid::parse_str(&reservation_id_str)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid reservation ID format: {}", e)))?;
+    let webhook_id = Uuid::parse_str(&webhook_id_str)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid webhook ID format: {}", e)))?;
+
+    if data.safety_webhooks.remove(reservation_id, webhook_id) {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "safety_webhook_not_found",
+            "message": format!("No safety webhook subscription {} for this reservation", webhook_id)
+        })))
+    }
+}
+
+// Webhook subscription endpoints: let operators register their own HTTP
+// endpoints to receive alerts at or above a chosen `min_severity` (Critical
+// by default), delivered
+// by the background dispatcher spawned in `main.rs`.
+pub async fn create_webhook(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<CreateWebhookRequest>,
+) -> ActixResult<HttpResponse> {
+    let tenant_id = tenant_id_from_request(&req);
+    let subscription = data
+        .webhook_registry
+        .register(tenant_id, payload.into_inner());
+    Ok(HttpResponse::Created().json(subscription))
+}
+
+pub async fn list_webhooks(data: web::Data<AppState>, req: HttpRequest) -> ActixResult<HttpResponse> {
+    let tenant_id = tenant_id_from_request(&req);
+    Ok(HttpResponse::Ok().json(data.webhook_registry.list(&tenant_id)))
+}
+
+pub async fn delete_webhook(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let tenant_id = tenant_id_from_request(&req);
+    let id_str = path.into_inner();
+
+    let id = Uuid::parse_str(&id_str).map_err(|e| {
+        tracing::warn!("Invalid webhook ID format: {}", id_str);
+        ApiError::BadRequest(format!("Invalid webhook ID format: {}", e))
+    })?;
+
+    if data.webhook_registry.remove(&tenant_id, id) {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "webhook_not_found",
+            "message": format!("No webhook subscription {} for this tenant", id)
+        })))
     }
 }
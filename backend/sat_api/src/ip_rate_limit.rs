@@ -0,0 +1,266 @@
+//! Actix middleware: per-client-IP request rate limiting ahead of the
+//! expensive reservation endpoints (`create_reservation`,
+//! `check_reservation_conflicts`), each of which triggers a full
+//! satellite-catalog fetch plus per-satellite propagation. Unlike
+//! `throttle::ThrottleRegistry` (per-tenant, checked inside the handler
+//! body once the request is already deep into the expensive work), this
+//! runs in front of the handler entirely and keys on the caller's IP
+//! rather than `x-tenant-id`, so it also catches anonymous/unauthenticated
+//! callers that never send a tenant header.
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpResponse};
+use futures::future::{ready, LocalBoxFuture, Ready};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Requests-per-window ceiling for one route.
+#[derive(Debug, Clone, Copy)]
+pub struct RouteLimit {
+    pub max_requests: u32,
+    pub window: Duration,
+}
+
+impl RouteLimit {
+    pub const fn new(max_requests: u32, window_secs: u64) -> Self {
+        Self {
+            max_requests,
+            window: Duration::from_secs(window_secs),
+        }
+    }
+}
+
+/// Parses `IP_RATE_LIMIT_ROUTE_OVERRIDES`-style config: comma-separated
+/// `method:path:max_requests:window_secs` entries, e.g.
+/// `POST:/api/v1/reservations:10:60`, so individual routes can be limited
+/// more tightly than `default_limit`. The `path` segment must match the
+/// normalized form `route_key` produces (see its doc comment for how a
+/// reservation id in the path is collapsed to a literal `{id}`). Entries
+/// that don't parse are logged and skipped rather than failing startup.
+pub fn parse_route_limits(raw: &str) -> HashMap<String, RouteLimit> {
+    let mut overrides = HashMap::new();
+    for entry in raw.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let parts: Vec<&str> = entry.splitn(4, ':').collect();
+        let (method, path, max_requests, window_secs) = match parts.as_slice() {
+            [method, path, max, window] => (*method, *path, max.parse::<u32>(), window.parse::<u64>()),
+            _ => {
+                tracing::warn!("Ignoring malformed IP_RATE_LIMIT_ROUTE_OVERRIDES entry: {}", entry);
+                continue;
+            }
+        };
+        match (max_requests, window_secs) {
+            (Ok(max_requests), Ok(window_secs)) => {
+                overrides.insert(
+                    route_key_parts(method, path),
+                    RouteLimit::new(max_requests, window_secs),
+                );
+            }
+            _ => tracing::warn!(
+                "Ignoring malformed IP_RATE_LIMIT_ROUTE_OVERRIDES entry: {}",
+                entry
+            ),
+        }
+    }
+    overrides
+}
+
+fn route_key_parts(method: &str, path: &str) -> String {
+    format!("{} {}", method.to_uppercase(), path)
+}
+
+/// Collapses a reservation id embedded in the path to a literal `{id}`
+/// placeholder, so `/api/v1/reservations/<uuid>/conflicts` shares one
+/// counter bucket per caller instead of a fresh one per reservation. This
+/// middleware wraps the whole `App` (ahead of routing), so `ServiceRequest`
+/// has no resolved route pattern to read yet — the concrete path is all
+/// that's available, and this is the only configured route with a dynamic
+/// segment.
+fn normalize_path(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').collect();
+    if let [base @ .., id, "conflicts"] = segments.as_slice() {
+        if !id.is_empty() && *id != "{id}" {
+            let mut normalized = base.join("/");
+            normalized.push_str("/{id}/conflicts");
+            return normalized;
+        }
+    }
+    path.to_string()
+}
+
+fn route_key(req: &ServiceRequest) -> String {
+    route_key_parts(req.method().as_str(), &normalize_path(req.path()))
+}
+
+#[derive(Clone)]
+pub struct IpRateLimiterConfig {
+    pub default_limit: RouteLimit,
+    pub route_limits: HashMap<String, RouteLimit>,
+}
+
+struct Window {
+    started_at: Instant,
+    count: u32,
+}
+
+/// How often a request pays for a sweep of stale per-IP windows, so the
+/// store doesn't grow without bound as new client IPs show up over time.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+/// A window is considered stale (and evicted) once it's this many window
+/// lengths old without being touched.
+const STALE_AFTER_WINDOWS: u32 = 4;
+
+struct IpRateLimiterState {
+    config: IpRateLimiterConfig,
+    windows: Mutex<HashMap<(String, String), Window>>,
+    last_swept: Mutex<Instant>,
+}
+
+impl IpRateLimiterState {
+    fn limit_for(&self, route_key: &str) -> RouteLimit {
+        self.config
+            .route_limits
+            .get(route_key)
+            .copied()
+            .unwrap_or(self.config.default_limit)
+    }
+
+    /// `Ok(())` admits the request (and records it); `Err(retry_after)`
+    /// rejects it with how long until the window resets.
+    fn check(&self, client_ip: &str, route_key: &str) -> Result<(), Duration> {
+        let limit = self.limit_for(route_key);
+        let now = Instant::now();
+        self.maybe_sweep(now, limit.window);
+
+        let mut windows = self.windows.lock().unwrap();
+        let window = windows
+            .entry((client_ip.to_string(), route_key.to_string()))
+            .or_insert_with(|| Window {
+                started_at: now,
+                count: 0,
+            });
+
+        if now.duration_since(window.started_at) >= limit.window {
+            window.started_at = now;
+            window.count = 0;
+        }
+
+        if window.count >= limit.max_requests {
+            return Err(limit.window - now.duration_since(window.started_at));
+        }
+
+        window.count += 1;
+        Ok(())
+    }
+
+    fn maybe_sweep(&self, now: Instant, window: Duration) {
+        let mut last_swept = self.last_swept.lock().unwrap();
+        if now.duration_since(*last_swept) < SWEEP_INTERVAL {
+            return;
+        }
+        *last_swept = now;
+        drop(last_swept);
+
+        let stale_after = window * STALE_AFTER_WINDOWS;
+        self.windows
+            .lock()
+            .unwrap()
+            .retain(|_, window| now.duration_since(window.started_at) < stale_after);
+    }
+}
+
+/// `Transform` factory for the per-IP rate limiter. Cheap to clone (an
+/// `Arc` around the shared store), so it can be built once in `main.rs` and
+/// handed to both `AppState` (for configuration parity with
+/// `throttle::ThrottleRegistry`) and `App::wrap`.
+#[derive(Clone)]
+pub struct IpRateLimiter {
+    state: Arc<IpRateLimiterState>,
+}
+
+impl IpRateLimiter {
+    pub fn new(config: IpRateLimiterConfig) -> Self {
+        Self {
+            state: Arc::new(IpRateLimiterState {
+                config,
+                windows: Mutex::new(HashMap::new()),
+                last_swept: Mutex::new(Instant::now()),
+            }),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for IpRateLimiter
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = IpRateLimiterMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(IpRateLimiterMiddleware {
+            service,
+            state: self.state.clone(),
+        }))
+    }
+}
+
+pub struct IpRateLimiterMiddleware<S> {
+    service: S,
+    state: Arc<IpRateLimiterState>,
+}
+
+impl<S, B> Service<ServiceRequest> for IpRateLimiterMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let client_ip = req
+            .connection_info()
+            .realip_remote_addr()
+            .unwrap_or("unknown")
+            .to_string();
+        let key = route_key(&req);
+
+        match self.state.check(&client_ip, &key) {
+            Ok(()) => {
+                let fut = self.service.call(req);
+                Box::pin(async move { Ok(fut.await?.map_into_left_body()) })
+            }
+            Err(retry_after) => {
+                let retry_secs = retry_after.as_secs().max(1);
+                tracing::warn!(
+                    "Rate limit exceeded for {} on {} (retry in {}s)",
+                    client_ip,
+                    key,
+                    retry_secs
+                );
+                let response = HttpResponse::TooManyRequests()
+                    .insert_header(("Retry-After", retry_secs.to_string()))
+                    .json(serde_json::json!({
+                        "error": "rate_limited",
+                        "message": format!(
+                            "Rate limit exceeded for this route, retry in {}s",
+                            retry_secs
+                        ),
+                    }));
+                let (http_req, _) = req.into_parts();
+                Box::pin(async move { Ok(ServiceResponse::new(http_req, response).map_into_right_body()) })
+            }
+        }
+    }
+}
@@ -1,22 +1,58 @@
 pub mod alerts;
 pub mod api;
+pub mod cdm;
 pub mod conjunction;
+pub mod error;
+pub mod geojson;
+pub mod gnss;
+pub mod ground_station;
 pub mod handlers;
+pub mod ip_rate_limit;
+pub mod logging;
+pub mod metrics;
 pub mod ml;
 pub mod reservation;
+pub mod safety_monitor;
+pub mod search;
+pub mod sp3;
+pub mod throttle;
+pub mod timescale;
 pub mod tle;
 pub mod tracker;
+pub mod webhooks;
 
-pub use alerts::{AlertCategory, AlertHub, AlertSeverity, LiveAlert};
+pub use alerts::{
+    AlertCategory, AlertFilter, AlertHub, AlertSeverity, LiveAlert, DEFAULT_ALERT_SPOOL_PATH,
+    DEFAULT_TENANT,
+};
 pub use api::SatelliteApi;
+pub use cdm::CdmFormat;
 pub use conjunction::{
     ConjunctionAnalysisResponse, ConjunctionAnalyzer, ConjunctionEvent, ConjunctionRequest,
 };
+pub use error::ApiError;
+pub use geojson::{to_feature_collection, FeatureCollection};
+pub use gnss::{ConstellationStatus, Gnss, GnssTiming, SlotOccupancy};
+pub use ground_station::{EpochWindow, GroundStation, LookAngle, Pass};
 pub use handlers::AppState;
-pub use ml::{RiskModel, RiskModelExplanation};
+pub use ip_rate_limit::{IpRateLimiter, IpRateLimiterConfig, RouteLimit};
+pub use metrics::Metrics;
+pub use ml::{RiskModel, RiskModelExplanation, RiskModelRegistry, DEFAULT_MODEL_DIR};
 pub use reservation::{
-    CreateReservationRequest, LaunchFeasibilityRequest, LaunchFeasibilityResult,
-    LaunchFeasibilitySummary, LaunchProfile, NewLaunchRequest, OrbitReservation,
-    OrbitReservationManager, ReservationCheckResponse,
+    AssignmentOutcome, CreateReservationRequest, EphemerisSample, FlexibleReservationRequest,
+    LaunchFeasibilityRequest, LaunchFeasibilityResult, LaunchFeasibilitySummary, LaunchProfile,
+    LaunchWindowCandidate, LaunchWindowScanRequest, LaunchWindowScanResult, MissVectorRtn,
+    NewLaunchRequest, OrbitReservation, OrbitReservationManager, RequestAssignment,
+    RescheduleError, ReservationCheckResponse, ReservationSafetyReport, ScheduleResult,
+    SchedulingMode, UpdateReservationRequest,
+};
+pub use safety_monitor::{
+    spawn_safety_delivery_worker, spawn_safety_monitor, SafetyDeliveryQueue, SafetyMonitor,
+    SafetyWebhookRegistry, SafetyWebhookSubscription, DEFAULT_SAFETY_QUEUE_PATH,
 };
+pub use search::SatelliteIndex;
+pub use sp3::{parse_sp3, Sp3Ephemeris};
+pub use throttle::{TenantLimits, ThrottleRegistry};
+pub use timescale::TimeScale;
 pub use tle::{Result, RiskLevel, SatApiError, SatelliteData, SatelliteGroup, SatellitePosition};
+pub use webhooks::{spawn_webhook_dispatcher, CreateWebhookRequest, WebhookRegistry, WebhookSubscription};
@@ -0,0 +1,57 @@
+use std::env;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Boxed so the JSON and pretty-console formats (different concrete types)
+/// can sit behind one `Option` in the same `tracing_subscriber::Registry`.
+type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync>;
+
+/// Installs the process-wide tracing subscriber and, if `SENTRY_DSN` is set,
+/// the Sentry client.
+///
+/// Format is plain, human-readable console output by default — matching the
+/// previous `FmtSubscriber` — switching to structured JSON lines when
+/// `LOG_FORMAT=json`, so log shippers in production don't have to parse
+/// free-form text. `RUST_LOG` still controls verbosity the usual way,
+/// defaulting to `info` when unset.
+///
+/// The returned guard must be kept alive for the lifetime of `main` — Sentry
+/// flushes pending events when it drops, so letting it go out of scope early
+/// would silently drop in-flight error reports.
+pub fn init() -> Option<sentry::ClientInitGuard> {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+    let json_format = env::var("LOG_FORMAT").map_or(false, |value| value.eq_ignore_ascii_case("json"));
+    let fmt_layer: BoxedLayer<_> = if json_format {
+        Box::new(tracing_subscriber::fmt::layer().json())
+    } else {
+        Box::new(tracing_subscriber::fmt::layer())
+    };
+
+    let sentry_dsn = env::var("SENTRY_DSN").ok().filter(|dsn| !dsn.is_empty());
+    let guard = sentry_dsn.as_ref().map(|dsn| {
+        sentry::init((
+            dsn.as_str(),
+            sentry::ClientOptions {
+                release: sentry::release_name!(),
+                traces_sample_rate: 0.0,
+                ..Default::default()
+            },
+        ))
+    });
+
+    // Forwards `warn!`/`error!` spans and events (risk model persistence
+    // failures, handler errors) to Sentry as breadcrumbs/events; a no-op
+    // layer when `guard` is `None` so local development is unchanged.
+    let sentry_layer = guard.as_ref().map(|_| sentry_tracing::layer());
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt_layer)
+        .with(sentry_layer)
+        .init();
+
+    guard
+}
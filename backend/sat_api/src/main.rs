@@ -1,21 +1,25 @@
 use actix_cors::Cors;
 use actix_web::{middleware::Logger, web, App, HttpServer};
+use sat_api::ip_rate_limit::{self, IpRateLimiterConfig, RouteLimit};
+use sat_api::throttle::{self, TenantLimits};
 use sat_api::{
-    handlers, AlertHub, AppState, ConjunctionAnalyzer, OrbitReservationManager, RiskModel,
-    SatelliteApi,
+    handlers, logging, spawn_safety_delivery_worker, spawn_safety_monitor, spawn_webhook_dispatcher,
+    AlertHub, AppState, ConjunctionAnalyzer, IpRateLimiter, Metrics, OrbitReservationManager,
+    RiskModelRegistry, SafetyDeliveryQueue, SafetyMonitor, SafetyWebhookRegistry, SatelliteApi,
+    ThrottleRegistry, WebhookRegistry, DEFAULT_ALERT_SPOOL_PATH, DEFAULT_MODEL_DIR,
+    DEFAULT_SAFETY_QUEUE_PATH,
 };
+use std::collections::HashMap;
 use std::env;
-use std::sync::{Arc, Mutex, RwLock};
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Mutex as AsyncMutex;
+use tracing::info;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    // Initialize logging
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-    let _ = tracing::subscriber::set_global_default(subscriber);
+    // Kept alive for the whole process: dropping it early would flush (and
+    // stop) Sentry reporting before the server has even started.
+    let _sentry_guard = logging::init();
 
     info!("🛰️ Starting Satellite API Server...");
 
@@ -40,23 +44,107 @@ async fn main() -> std::io::Result<()> {
 
     // Initialize orbit reservation manager
     info!("📋 Initializing orbit reservation manager...");
-    let alert_hub = Arc::new(AlertHub::new(256));
+    let alert_spool_path =
+        env::var("ALERT_SPOOL_PATH").unwrap_or_else(|_| DEFAULT_ALERT_SPOOL_PATH.to_string());
+    let alert_hub = Arc::new(AlertHub::with_persistence(256, alert_spool_path));
 
-    let risk_model_path =
-        env::var("RISK_MODEL_PATH").unwrap_or_else(|_| "data/risk_model_state.json".to_string());
-    let risk_model = Arc::new(RwLock::new(RiskModel::load_or_default(Some(
-        risk_model_path.as_str(),
-    ))));
+    let risk_model_dir =
+        env::var("RISK_MODEL_DIR").unwrap_or_else(|_| DEFAULT_MODEL_DIR.to_string());
+    let risk_models = Arc::new(RiskModelRegistry::new(risk_model_dir));
 
-    let reservation_manager =
-        Arc::new(Mutex::new(OrbitReservationManager::new(risk_model.clone())));
+    let reservation_manager = Arc::new(AsyncMutex::new(OrbitReservationManager::new(
+        risk_models.clone(),
+    )));
+
+    let webhook_registry = Arc::new(WebhookRegistry::new());
+    spawn_webhook_dispatcher(alert_hub.clone(), webhook_registry.clone());
+
+    info!("🛡️ Initializing reservation safety monitor...");
+    let safety_webhooks = Arc::new(SafetyWebhookRegistry::new());
+    let safety_queue_path =
+        env::var("SAFETY_WEBHOOK_QUEUE_PATH").unwrap_or_else(|_| DEFAULT_SAFETY_QUEUE_PATH.to_string());
+    let safety_delivery_queue = Arc::new(SafetyDeliveryQueue::with_persistence(safety_queue_path));
+    let safety_monitor = Arc::new(SafetyMonitor::new(
+        safety_webhooks.clone(),
+        safety_delivery_queue.clone(),
+    ));
+    let safety_sweep_interval = std::time::Duration::from_secs(
+        env::var("SAFETY_SWEEP_INTERVAL_SECS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(300),
+    );
+    spawn_safety_monitor(
+        reservation_manager.clone(),
+        satellite_api.clone(),
+        safety_monitor,
+        safety_sweep_interval,
+    );
+    spawn_safety_delivery_worker(safety_delivery_queue);
+
+    let metrics = Arc::new(Metrics::new());
+
+    let default_limits = TenantLimits {
+        requests_per_minute: env::var("THROTTLE_REQUESTS_PER_MINUTE")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(TenantLimits::DEFAULT.requests_per_minute),
+        max_concurrent: env::var("THROTTLE_MAX_CONCURRENT")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(TenantLimits::DEFAULT.max_concurrent),
+    };
+    let tenant_overrides = env::var("THROTTLE_TENANT_OVERRIDES")
+        .ok()
+        .map(|raw| throttle::parse_overrides(&raw))
+        .unwrap_or_default();
+    let throttle = Arc::new(ThrottleRegistry::new(default_limits, tenant_overrides));
+
+    let ip_rate_limit_default = RouteLimit::new(
+        env::var("IP_RATE_LIMIT_DEFAULT_MAX")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(120),
+        env::var("IP_RATE_LIMIT_DEFAULT_WINDOW_SECS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(60),
+    );
+    let ip_rate_limit_routes = match env::var("IP_RATE_LIMIT_ROUTE_OVERRIDES") {
+        Ok(raw) => ip_rate_limit::parse_route_limits(&raw),
+        Err(_) => {
+            // Sensible defaults when the operator hasn't configured
+            // anything: reservation creation and conflict checks both walk
+            // the full catalog with per-satellite propagation, so they get
+            // a tighter per-IP ceiling than everything else.
+            let mut routes = HashMap::new();
+            routes.insert(
+                "POST /api/v1/reservations".to_string(),
+                RouteLimit::new(10, 60),
+            );
+            routes.insert(
+                "POST /api/v1/reservations/{id}/conflicts".to_string(),
+                RouteLimit::new(20, 60),
+            );
+            routes
+        }
+    };
+    let ip_rate_limiter = IpRateLimiter::new(IpRateLimiterConfig {
+        default_limit: ip_rate_limit_default,
+        route_limits: ip_rate_limit_routes,
+    });
 
     let app_state = AppState {
         satellite_api: satellite_api.clone(),
         conjunction_analyzer,
         reservation_manager,
         alert_hub,
-        risk_model,
+        risk_models,
+        webhook_registry,
+        safety_webhooks,
+        metrics,
+        throttle,
+        ip_rate_limiter,
     };
 
     info!("🚀 Starting server on {}:{}", host, port);
@@ -73,11 +161,18 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(app_state.clone()))
             .wrap(Logger::default())
             .wrap(cors)
+            .wrap(app_state.ip_rate_limiter.clone())
             .route("/health", web::get().to(handlers::health_check))
+            .route("/metrics", web::get().to(handlers::metrics))
             .service(
                 web::scope("/api/v1")
+                    .route("/time", web::get().to(handlers::get_time_info))
                     // Satellite tracking endpoints
                     .route("/satellites", web::get().to(handlers::get_all_satellites))
+                    .route(
+                        "/satellites/geojson",
+                        web::get().to(handlers::get_satellites_geojson),
+                    )
                     .route(
                         "/satellite/{norad_id}",
                         web::get().to(handlers::get_satellite),
@@ -87,6 +182,22 @@ async fn main() -> std::io::Result<()> {
                         web::get().to(handlers::get_satellite_group),
                     )
                     .route("/statistics", web::get().to(handlers::get_statistics))
+                    .route(
+                        "/system/monitor",
+                        web::get().to(handlers::system_monitor),
+                    )
+                    .route(
+                        "/sources/health",
+                        web::get().to(handlers::get_source_health),
+                    )
+                    .route(
+                        "/gnss/{system}",
+                        web::get().to(handlers::get_constellation_status),
+                    )
+                    .route(
+                        "/satellites/search",
+                        web::get().to(handlers::search_satellites),
+                    )
                     .route(
                         "/satellites/propagate",
                         web::get().to(handlers::propagate_satellites),
@@ -96,22 +207,66 @@ async fn main() -> std::io::Result<()> {
                         "/conjunctions/analyze",
                         web::post().to(handlers::analyze_conjunctions),
                     )
+                    .route(
+                        "/conjunctions/cdm",
+                        web::post().to(handlers::export_conjunctions_cdm),
+                    )
+                    .route(
+                        "/conjunctions/visibility",
+                        web::post().to(handlers::conjunction_visibility),
+                    )
                     .route("/risk/predict", web::post().to(handlers::predict_risk))
+                    .route("/risk/batch", web::post().to(handlers::predict_risk_batch))
                     .route(
                         "/missions/launch/feasibility",
                         web::post().to(handlers::assess_launch_feasibility),
                     )
+                    .route(
+                        "/missions/launch/optimize",
+                        web::post().to(handlers::optimize_launch_window),
+                    )
                     .route("/alerts/stream", web::get().to(handlers::stream_alerts))
+                    .route("/alerts/ws", web::get().to(handlers::alerts_ws))
+                    .route(
+                        "/ground-stations/passes",
+                        web::post().to(handlers::predict_passes),
+                    )
                     // Orbit reservation management endpoints
                     .route(
                         "/reservations",
                         web::post().to(handlers::create_reservation),
                     )
                     .route("/reservations", web::get().to(handlers::list_reservations))
+                    .route(
+                        "/reservations/{id}",
+                        web::patch().to(handlers::update_reservation),
+                    )
                     .route(
                         "/reservations/{id}/conflicts",
                         web::post().to(handlers::check_reservation_conflicts),
-                    ),
+                    )
+                    .route(
+                        "/reservations/schedule",
+                        web::post().to(handlers::assign_reservations),
+                    )
+                    // Reservation safety webhook subscriptions, delivered by
+                    // the background monitor spawned in `main`
+                    .route(
+                        "/reservations/{id}/webhooks",
+                        web::post().to(handlers::create_safety_webhook),
+                    )
+                    .route(
+                        "/reservations/{id}/webhooks",
+                        web::get().to(handlers::list_safety_webhooks),
+                    )
+                    .route(
+                        "/reservations/{id}/webhooks/{webhook_id}",
+                        web::delete().to(handlers::delete_safety_webhook),
+                    )
+                    // Outbound webhook subscriptions for Critical alerts
+                    .route("/webhooks", web::post().to(handlers::create_webhook))
+                    .route("/webhooks", web::get().to(handlers::list_webhooks))
+                    .route("/webhooks/{id}", web::delete().to(handlers::delete_webhook)),
             )
     })
     .bind((host.as_str(), port))?
@@ -0,0 +1,214 @@
+//! In-process operational counters, exposed at `GET /metrics` in the
+//! Prometheus text exposition format. Everything here is labelled by tenant
+//! (the `x-tenant-id` resolved by `handlers::tenant_id_from_request`) so an
+//! operator running this service for multiple customers can see per-tenant
+//! load and risk, not just a crate-wide total.
+//!
+//! This is a small hand-rolled registry rather than a dependency on the
+//! `prometheus` crate: the exposition format is simple line-oriented text,
+//! and `cdm.rs` already sets the precedent of hand-writing a presentation
+//! format instead of pulling in a library for it.
+
+use crate::alerts::AlertSeverity;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+#[derive(Default)]
+struct LatencyAccumulator {
+    count: u64,
+    sum_seconds: f64,
+}
+
+#[derive(Default)]
+struct TenantCounters {
+    conjunction_analyses_run: u64,
+    risk_predictions_served: u64,
+    dangerous_conjunctions_detected: u64,
+    alerts_published: HashMap<AlertSeverity, u64>,
+    reservations_created: u64,
+    reservations_rejected: u64,
+    active_sse_subscribers: i64,
+    request_latency: HashMap<String, LatencyAccumulator>,
+}
+
+/// Process-wide metrics registry, held as `Arc<Metrics>` on `AppState`.
+///
+/// A single `RwLock<HashMap<tenant, TenantCounters>>` rather than one lock
+/// per counter: every recording call already has the tenant id in hand
+/// (from `tenant_id_from_request`), so grouping by tenant first keeps the
+/// per-request locking to one `write()` instead of half a dozen.
+pub struct Metrics {
+    tenants: RwLock<HashMap<String, TenantCounters>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            tenants: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn record_conjunction_analysis(&self, tenant_id: &str) {
+        self.with_tenant(tenant_id, |counters| counters.conjunction_analyses_run += 1);
+    }
+
+    pub fn record_risk_prediction(&self, tenant_id: &str, dangerous_conjunctions: usize) {
+        self.with_tenant(tenant_id, |counters| {
+            counters.risk_predictions_served += 1;
+            counters.dangerous_conjunctions_detected += dangerous_conjunctions as u64;
+        });
+    }
+
+    pub fn record_alert_published(&self, tenant_id: &str, severity: AlertSeverity) {
+        self.with_tenant(tenant_id, |counters| {
+            *counters.alerts_published.entry(severity).or_insert(0) += 1;
+        });
+    }
+
+    pub fn record_reservation_created(&self, tenant_id: &str) {
+        self.with_tenant(tenant_id, |counters| counters.reservations_created += 1);
+    }
+
+    pub fn record_reservation_rejected(&self, tenant_id: &str) {
+        self.with_tenant(tenant_id, |counters| counters.reservations_rejected += 1);
+    }
+
+    pub fn sse_subscriber_connected(&self, tenant_id: &str) {
+        self.with_tenant(tenant_id, |counters| counters.active_sse_subscribers += 1);
+    }
+
+    pub fn sse_subscriber_disconnected(&self, tenant_id: &str) {
+        self.with_tenant(tenant_id, |counters| counters.active_sse_subscribers -= 1);
+    }
+
+    pub fn record_request_latency(&self, tenant_id: &str, endpoint: &str, elapsed: Duration) {
+        self.with_tenant(tenant_id, |counters| {
+            let accumulator = counters
+                .request_latency
+                .entry(endpoint.to_string())
+                .or_default();
+            accumulator.count += 1;
+            accumulator.sum_seconds += elapsed.as_secs_f64();
+        });
+    }
+
+    fn with_tenant(&self, tenant_id: &str, update: impl FnOnce(&mut TenantCounters)) {
+        let mut tenants = self.tenants.write().unwrap();
+        update(tenants.entry(tenant_id.to_string()).or_default());
+    }
+
+    /// Renders every counter in the Prometheus text exposition format:
+    /// one `# HELP`/`# TYPE` pair per metric name, then one labelled sample
+    /// line per tenant (and per endpoint/severity, where applicable).
+    pub fn render(&self) -> String {
+        let tenants = self.tenants.read().unwrap();
+        let mut out = String::new();
+
+        out.push_str("# HELP orbitalos_conjunction_analyses_total Conjunction analyses run.\n");
+        out.push_str("# TYPE orbitalos_conjunction_analyses_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_conjunction_analyses_total{{tenant=\"{}\"}} {}\n",
+                tenant, counters.conjunction_analyses_run
+            ));
+        }
+
+        out.push_str("# HELP orbitalos_risk_predictions_total Risk predictions served.\n");
+        out.push_str("# TYPE orbitalos_risk_predictions_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_risk_predictions_total{{tenant=\"{}\"}} {}\n",
+                tenant, counters.risk_predictions_served
+            ));
+        }
+
+        out.push_str(
+            "# HELP orbitalos_dangerous_conjunctions_total Conjunctions predicted Red or Amber risk.\n",
+        );
+        out.push_str("# TYPE orbitalos_dangerous_conjunctions_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_dangerous_conjunctions_total{{tenant=\"{}\"}} {}\n",
+                tenant, counters.dangerous_conjunctions_detected
+            ));
+        }
+
+        out.push_str("# HELP orbitalos_alerts_published_total Alerts published, by severity.\n");
+        out.push_str("# TYPE orbitalos_alerts_published_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            for (severity, count) in counters.alerts_published.iter() {
+                out.push_str(&format!(
+                    "orbitalos_alerts_published_total{{tenant=\"{}\",severity=\"{}\"}} {}\n",
+                    tenant,
+                    severity_label(*severity),
+                    count
+                ));
+            }
+        }
+
+        out.push_str("# HELP orbitalos_reservations_created_total Orbit reservations created.\n");
+        out.push_str("# TYPE orbitalos_reservations_created_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_reservations_created_total{{tenant=\"{}\"}} {}\n",
+                tenant, counters.reservations_created
+            ));
+        }
+
+        out.push_str(
+            "# HELP orbitalos_reservations_rejected_total Orbit reservations rejected for conflicts.\n",
+        );
+        out.push_str("# TYPE orbitalos_reservations_rejected_total counter\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_reservations_rejected_total{{tenant=\"{}\"}} {}\n",
+                tenant, counters.reservations_rejected
+            ));
+        }
+
+        out.push_str(
+            "# HELP orbitalos_alert_stream_subscribers Active SSE alert subscribers.\n",
+        );
+        out.push_str("# TYPE orbitalos_alert_stream_subscribers gauge\n");
+        for (tenant, counters) in tenants.iter() {
+            out.push_str(&format!(
+                "orbitalos_alert_stream_subscribers{{tenant=\"{}\"}} {}\n",
+                tenant, counters.active_sse_subscribers
+            ));
+        }
+
+        out.push_str(
+            "# HELP orbitalos_request_latency_seconds Request latency, by endpoint.\n",
+        );
+        out.push_str("# TYPE orbitalos_request_latency_seconds summary\n");
+        for (tenant, counters) in tenants.iter() {
+            for (endpoint, accumulator) in counters.request_latency.iter() {
+                out.push_str(&format!(
+                    "orbitalos_request_latency_seconds_sum{{tenant=\"{}\",endpoint=\"{}\"}} {}\n",
+                    tenant, endpoint, accumulator.sum_seconds
+                ));
+                out.push_str(&format!(
+                    "orbitalos_request_latency_seconds_count{{tenant=\"{}\",endpoint=\"{}\"}} {}\n",
+                    tenant, endpoint, accumulator.count
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn severity_label(severity: AlertSeverity) -> &'static str {
+    match severity {
+        AlertSeverity::Info => "info",
+        AlertSeverity::Warning => "warning",
+        AlertSeverity::Critical => "critical",
+    }
+}
@@ -1,18 +1,39 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 const DEFAULT_MODEL_PATH: &str = "data/risk_model_state.json";
+/// Default directory `RiskModelRegistry` persists per-tenant models under.
+pub const DEFAULT_MODEL_DIR: &str = "data/risk_model";
 const SAVE_INTERVAL: u64 = 25;
 
+// Adam optimizer hyperparameters (Kingma & Ba 2014 defaults).
+const ADAM_BETA1: f64 = 0.9;
+const ADAM_BETA2: f64 = 0.999;
+const ADAM_EPSILON: f64 = 1e-8;
+
+fn zero_moments() -> [f64; 5] {
+    [0.0; 5]
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RiskModelParameters {
     pub bias: f64,
     pub coefficients: [f64; 4],
     pub observation_count: u64,
     pub last_updated: Option<DateTime<Utc>>,
+    /// Adam first-moment estimate, indexed `[bias, coefficients[0..4]]`.
+    /// `#[serde(default)]` so model state persisted before Adam was added
+    /// still loads, resuming as if optimization had just started.
+    #[serde(default = "zero_moments")]
+    pub m: [f64; 5],
+    /// Adam second-moment estimate, same indexing as `m`.
+    #[serde(default = "zero_moments")]
+    pub v: [f64; 5],
 }
 
 impl RiskModelParameters {
@@ -22,6 +43,8 @@ impl RiskModelParameters {
             coefficients,
             observation_count: 0,
             last_updated: None,
+            m: zero_moments(),
+            v: zero_moments(),
         }
     }
 }
@@ -34,6 +57,9 @@ pub struct RiskModelExplanation {
     pub observation_count: u64,
     pub learning_rate: f64,
     pub l2_penalty: f64,
+    pub adam_beta1: f64,
+    pub adam_beta2: f64,
+    pub adam_epsilon: f64,
     pub last_updated: Option<DateTime<Utc>>,
     pub persistence_path: Option<String>,
 }
@@ -133,18 +159,45 @@ impl RiskModel {
         1.0 / (1.0 + (-z).exp())
     }
 
+    /// Adam step (Kingma & Ba 2014) in place of plain SGD: per-parameter
+    /// adaptive learning rates converge far better than a single shared
+    /// `learning_rate` when features like `minimum_distance_km` and
+    /// `tle_age_hours` live on very different scales. `m`/`v`/`observation_count`
+    /// (used as the timestep `t`) persist across restarts so the optimizer
+    /// resumes exactly where it left off.
     pub fn update(&mut self, features: [f64; 4], label: f64) {
         let prediction = self.predict(features);
         let error = (prediction - label).clamp(-50.0, 50.0);
 
-        self.params.bias -= self.learning_rate * (error + self.l2_penalty * self.params.bias);
-
-        for idx in 0..self.params.coefficients.len() {
-            let grad = error * features[idx] + self.l2_penalty * self.params.coefficients[idx];
-            self.params.coefficients[idx] -= self.learning_rate * grad;
+        // Index 0 is the bias (implicit feature = 1); 1..=4 are coefficients.
+        let mut gradients = [0.0; 5];
+        gradients[0] = error + self.l2_penalty * self.params.bias;
+        for idx in 0..features.len() {
+            gradients[idx + 1] =
+                error * features[idx] + self.l2_penalty * self.params.coefficients[idx];
         }
 
         self.params.observation_count = self.params.observation_count.saturating_add(1);
+        let t = self.params.observation_count as i32;
+        let bias_correction1 = 1.0 - ADAM_BETA1.powi(t);
+        let bias_correction2 = 1.0 - ADAM_BETA2.powi(t);
+
+        for idx in 0..gradients.len() {
+            let g = gradients[idx];
+            self.params.m[idx] = ADAM_BETA1 * self.params.m[idx] + (1.0 - ADAM_BETA1) * g;
+            self.params.v[idx] = ADAM_BETA2 * self.params.v[idx] + (1.0 - ADAM_BETA2) * g * g;
+
+            let m_hat = self.params.m[idx] / bias_correction1;
+            let v_hat = self.params.v[idx] / bias_correction2;
+            let step = self.learning_rate * m_hat / (v_hat.sqrt() + ADAM_EPSILON);
+
+            if idx == 0 {
+                self.params.bias -= step;
+            } else {
+                self.params.coefficients[idx - 1] -= step;
+            }
+        }
+
         self.params.last_updated = Some(Utc::now());
 
         if self.params.observation_count % SAVE_INTERVAL == 0 {
@@ -167,6 +220,9 @@ impl RiskModel {
             observation_count: self.params.observation_count,
             learning_rate: self.learning_rate,
             l2_penalty: self.l2_penalty,
+            adam_beta1: ADAM_BETA1,
+            adam_beta2: ADAM_BETA2,
+            adam_epsilon: ADAM_EPSILON,
             last_updated: self.params.last_updated,
             persistence_path: self
                 .persistence_path
@@ -175,6 +231,11 @@ impl RiskModel {
         }
     }
 
+    /// Writes to a temporary sibling file and `fs::rename`s it into place.
+    /// `rename` is atomic on the same filesystem, so a crash mid-write —
+    /// including one interrupting the `Drop` impl below — can never leave a
+    /// truncated `persistence_path` that fails to parse on the next
+    /// `load_from_file`; readers see either the old state or the new one.
     pub fn persist(&self) -> std::io::Result<()> {
         if let Some(path) = &self.persistence_path {
             if let Some(parent) = path.parent() {
@@ -186,10 +247,15 @@ impl RiskModel {
                 learning_rate: self.learning_rate,
                 l2_penalty: self.l2_penalty,
             };
-
-            let mut file = File::create(path)?;
             let payload = serde_json::to_string_pretty(&persisted)?;
+
+            let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+            let mut file = File::create(&tmp_path)?;
             file.write_all(payload.as_bytes())?;
+            file.sync_all()?;
+            drop(file);
+
+            fs::rename(&tmp_path, path)?;
         }
 
         Ok(())
@@ -215,3 +281,50 @@ impl Drop for RiskModel {
         }
     }
 }
+
+/// Lazily instantiates and caches one [`RiskModel`] per tenant, so a noisy or
+/// malicious tenant's conjunction traffic can't skew another tenant's
+/// probability-of-collision scoring. Each tenant's model is persisted under
+/// `<base_dir>/<tenant>.json`, falling back to the same shared default
+/// coefficients as `RiskModel::load_or_default` the first time a tenant is
+/// seen.
+pub struct RiskModelRegistry {
+    base_dir: PathBuf,
+    models: RwLock<HashMap<String, Arc<RwLock<RiskModel>>>>,
+}
+
+impl RiskModelRegistry {
+    pub fn new<P: AsRef<Path>>(base_dir: P) -> Self {
+        Self {
+            base_dir: base_dir.as_ref().to_path_buf(),
+            models: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the shared, cached model for `tenant_id`, creating (and
+    /// loading from disk, if present) it on first access.
+    pub fn get_or_create(&self, tenant_id: &str) -> Arc<RwLock<RiskModel>> {
+        if let Some(model) = self.models.read().unwrap().get(tenant_id) {
+            return model.clone();
+        }
+
+        let mut models = self.models.write().unwrap();
+        models
+            .entry(tenant_id.to_string())
+            .or_insert_with(|| {
+                let path = self.base_dir.join(format!("{}.json", sanitize_tenant_id(tenant_id)));
+                Arc::new(RwLock::new(RiskModel::load_or_default(Some(path))))
+            })
+            .clone()
+    }
+}
+
+/// Tenant ids reach us from an `x-tenant-id` header, so keep only characters
+/// that are safe as a bare file stem — everything else collapses to `_`
+/// rather than risking a path traversal via a crafted header value.
+fn sanitize_tenant_id(tenant_id: &str) -> String {
+    tenant_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
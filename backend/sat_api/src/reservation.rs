@@ -1,17 +1,57 @@
 use crate::conjunction::ConjunctionAnalyzer;
-use crate::ml::RiskModel;
+use crate::ground_station::{look_angle_for_eci, EpochWindow, GroundStation};
+use crate::ml::RiskModelRegistry;
+use crate::sp3::Sp3Ephemeris;
 use crate::tle::{Result, SatApiError, SatelliteData};
 use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
-use nalgebra::Vector3;
+use nalgebra::{Matrix2, Matrix3, Vector2, Vector3};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::f64::consts::PI;
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 use uuid::Uuid;
 
 const EARTH_RADIUS_KM: f64 = 6378.137;
 const MU_KM3_S2: f64 = 398_600.4418;
 
+/// WGS-84 mean angular velocity of Earth's rotation, used to add the
+/// ECEF frame's transport term (`ω × r`) on top of the ECI→ECEF position
+/// rotation when converting a velocity vector.
+const EARTH_ROTATION_RATE_RAD_S: f64 = 7.292_115e-5;
+
+/// Number of discretized candidate start times `assign_optimal` tries per
+/// request. Bounds the branch-and-bound search space for wide
+/// `[earliest_start, latest_end]` windows; coverage beyond this is the same
+/// tradeoff `SchedulingMode::Greedy` already makes by only trying the next
+/// feasible slot instead of every possible one.
+const MAX_OPTIMAL_CANDIDATES_PER_REQUEST: usize = 12;
+
+/// Batch-size ceiling for `SchedulingMode::Optimal`: branch-and-bound over
+/// N requests is exponential in the worst case, so a batch larger than this
+/// falls back to `SchedulingMode::Greedy` rather than risk an unbounded
+/// search.
+const MAX_OPTIMAL_BATCH_SIZE: usize = 16;
+
+/// Ceiling on how many candidate epochs `optimize_launch_window` evaluates
+/// in one scan. Each candidate runs a full conflict assessment against the
+/// catalog, so a wide `scan_hours` window at a tight `cadence_minutes`
+/// widens the cadence (logged, not silently dropped) rather than running
+/// an unbounded number of assessments.
+const MAX_SCAN_CANDIDATES: usize = 200;
+
+/// Relative weight of a priority tier in `assign_reservations`'s scheduling
+/// objective. Each tier weighs 100x the next, more than `MAX_OPTIMAL_BATCH_SIZE`,
+/// so the search always prefers granting one higher-tier request over any
+/// number of lower-tier ones that fit in the same batch.
+fn priority_weight(priority_level: &PriorityLevel) -> u64 {
+    match priority_level {
+        PriorityLevel::Critical => 1_000_000,
+        PriorityLevel::High => 10_000,
+        PriorityLevel::Medium => 100,
+        PriorityLevel::Low => 1,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrbitReservation {
     pub id: Uuid,
@@ -26,6 +66,34 @@ pub struct OrbitReservation {
     pub created_at: DateTime<Utc>,
     pub constraints: ReservationConstraints,
     pub launch_profile: Option<LaunchProfile>,
+    /// If non-empty, `check_satellite_conflict` samples *only* inside one
+    /// of these windows within `[start_time, end_time]` -- e.g. a launch
+    /// corridor that's only active during specific ground-station passes.
+    /// Borrows the inclusion/exclusion epoch model from
+    /// `ground_station::GroundStation`.
+    #[serde(default)]
+    pub inclusion_epochs: Vec<EpochWindow>,
+    /// Epochs inside any of these windows are skipped during conflict
+    /// sampling regardless of `inclusion_epochs` (e.g. a planned
+    /// maintenance/comms blackout where the operation is paused).
+    #[serde(default)]
+    pub exclusion_epochs: Vec<EpochWindow>,
+}
+
+impl OrbitReservation {
+    /// Whether conflict sampling should consider `time` for this
+    /// reservation: not inside an exclusion epoch, and inside an inclusion
+    /// epoch if any are configured. Mirrors `GroundStation::is_available`'s
+    /// identical inclusion/exclusion model.
+    fn is_active_at(&self, time: DateTime<Utc>) -> bool {
+        if self.exclusion_epochs.iter().any(|w| w.contains(time)) {
+            return false;
+        }
+        if !self.inclusion_epochs.is_empty() {
+            return self.inclusion_epochs.iter().any(|w| w.contains(time));
+        }
+        true
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +129,27 @@ pub struct ReservationConstraints {
     pub notification_threshold_hours: u64,
     pub allow_debris_tracking: bool,
     pub coordinate_system: CoordinateSystem,
+    /// Cutover behavior when this reservation abuts another one in the
+    /// same orbital regime. Defaults to `Eager` so existing callers that
+    /// predate this field keep their current hard-cutover behavior.
+    #[serde(default)]
+    pub handoff_policy: HandoffPolicy,
+}
+
+/// Cutover behavior when two consecutive reservations on the same orbital
+/// regime abut: `Overlap` permits a configurable coordinated window where
+/// both operations may run concurrently (e.g. a slow ground-station
+/// handoff), while `Eager` forces a hard cutover with no shared time.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HandoffPolicy {
+    Overlap { coordinated_window_minutes: i64 },
+    Eager,
+}
+
+impl Default for HandoffPolicy {
+    fn default() -> Self {
+        HandoffPolicy::Eager
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +173,24 @@ pub struct ReservationConflict {
     pub ml_probability: f64,
     pub duration_seconds: u64,
     pub mitigation_suggestions: Vec<MitigationSuggestion>,
+    /// Miss vector at `time_of_closest_approach`, expressed in the frame
+    /// requested by `ReservationConstraints::coordinate_system`: ECEF
+    /// applies Earth's rotation before decomposing, ECI and RTN both
+    /// decompose directly from the inertial geometry (RTN is already a
+    /// *decomposition*, not a distinct frame for the underlying vectors).
+    pub miss_vector_rtn: MissVectorRtn,
+}
+
+/// The closest-approach miss vector resolved into the reservation center's
+/// local radial/tangential/normal frame: radial points away from Earth's
+/// center, cross-track follows the orbit's angular momentum, and
+/// along-track is what's left. Lets an operator tell a radial burn from an
+/// along-track time-shift apart when picking a `MitigationSuggestion`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MissVectorRtn {
+    pub radial_km: f64,
+    pub along_track_km: f64,
+    pub cross_track_km: f64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -152,6 +259,201 @@ pub struct CreateReservationRequest {
     pub protection_radius_km: f64,
     pub priority_level: PriorityLevel,
     pub constraints: Option<ReservationConstraints>,
+    /// Precise ephemeris for the reservation's center object, in lieu of
+    /// relying on its TLE for propagation. When present, `create_reservation`
+    /// loads it into the manager's SP3 arc table keyed by the center's
+    /// `norad_id`, and `propagate_to_eci`/conflict checks prefer it over the
+    /// synthetic TLE propagation for any time it covers.
+    #[serde(default)]
+    pub precise_ephemeris: Option<Vec<EphemerisSample>>,
+    /// See `OrbitReservation::inclusion_epochs`.
+    #[serde(default)]
+    pub inclusion_epochs: Vec<EpochWindow>,
+    /// See `OrbitReservation::exclusion_epochs`.
+    #[serde(default)]
+    pub exclusion_epochs: Vec<EpochWindow>,
+}
+
+/// One tabulated precise-ephemeris state, as supplied inline by a
+/// `CreateReservationRequest` rather than parsed from an SP3 file (see
+/// `sp3::parse_sp3` for the file-based path used elsewhere in this crate).
+#[derive(Debug, Clone, Deserialize)]
+pub struct EphemerisSample {
+    pub epoch: DateTime<Utc>,
+    pub position_km: [f64; 3],
+    #[serde(default)]
+    pub velocity_km_s: Option<[f64; 3]>,
+}
+
+/// Body of a `PATCH /reservations/{id}` request: a new time window for an
+/// existing reservation. Everything else about the reservation (owner,
+/// protection radius, constraints, ...) is left untouched.
+#[derive(Debug, Deserialize)]
+pub struct UpdateReservationRequest {
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+/// Why `OrbitReservationManager::update_reservation` refused to reschedule a
+/// reservation, mapped by the handler to a distinct HTTP status per variant.
+#[derive(Debug)]
+pub enum RescheduleError {
+    NotFound,
+    InvalidWindow(String),
+    AlreadyActive,
+}
+
+/// One request in a batch handed to `OrbitReservationManager::assign_reservations`.
+/// Unlike `CreateReservationRequest`, which pins down an exact
+/// `[start_time, end_time)`, this carries a flexible `[earliest_start,
+/// latest_end]` range and a fixed `duration_minutes` -- the scheduler picks
+/// where inside that range (if anywhere) the reservation actually lands.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlexibleReservationRequest {
+    pub request_id: Uuid,
+    pub owner: String,
+    pub reservation_type: ReservationType,
+    pub center_tle: SatelliteData,
+    pub protection_radius_km: f64,
+    pub earliest_start: DateTime<Utc>,
+    pub latest_end: DateTime<Utc>,
+    pub duration_minutes: i64,
+    pub priority_level: PriorityLevel,
+}
+
+/// Which algorithm `assign_reservations` uses to place a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum SchedulingMode {
+    /// Sort by `PriorityLevel` then earliest feasible slot, assign the
+    /// first non-conflicting window found, never backtrack. Fast, not
+    /// guaranteed to maximize granted weight.
+    Greedy,
+    /// Branch-and-bound search over a discretized set of candidate start
+    /// times per request, maximizing the weighted count of granted
+    /// reservations (weight increasing with `PriorityLevel`).
+    Optimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum AssignmentOutcome {
+    Granted {
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    },
+    /// No placement inside `[earliest_start, latest_end]` avoided every
+    /// conflict. `blocking_reservation_ids` names whichever already-placed
+    /// reservations (pre-existing or earlier in this same batch) ruled out
+    /// every window tried.
+    Rejected { blocking_reservation_ids: Vec<Uuid> },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestAssignment {
+    pub request_id: Uuid,
+    pub outcome: AssignmentOutcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleResult {
+    pub assignments: Vec<RequestAssignment>,
+    pub granted_count: usize,
+    pub rejected_count: usize,
+}
+
+/// Common interface for the strategies `assign_reservations` dispatches to
+/// via `SchedulingMode`. Each implementation owns its own search strategy
+/// but reports results in the same `ScheduleResult` shape, so adding a
+/// third solver later is a new impl plus a `SchedulingMode` variant, not a
+/// change to every call site.
+trait ReservationSolver {
+    fn solve(
+        &self,
+        manager: &OrbitReservationManager,
+        requests: Vec<FlexibleReservationRequest>,
+    ) -> Result<ScheduleResult>;
+}
+
+/// Sorts by `priority_level` (Critical first) then earliest allowed start,
+/// placing each request in the first gap where nothing already placed
+/// conflicts with it. Fast, but a request can get rejected even when a
+/// conflict-free packing exists elsewhere, since earlier placements are
+/// never revisited.
+struct GreedySolver;
+
+impl ReservationSolver for GreedySolver {
+    fn solve(
+        &self,
+        manager: &OrbitReservationManager,
+        requests: Vec<FlexibleReservationRequest>,
+    ) -> Result<ScheduleResult> {
+        manager.assign_greedy(requests)
+    }
+}
+
+/// Exhaustively searches every (request, discretized candidate start)
+/// assignment for the packing that maximizes total granted priority
+/// weight, pruned by `branch_and_bound`'s running upper bound. This
+/// explores the same search space a CNF/SAT encoding would -- one boolean
+/// per (request, slot) deciding whether it's chosen, an "exactly one slot"
+/// clause per request, and a "not both" clause per pair of slots whose
+/// protection spheres overlap in space-time -- but without materializing
+/// those clauses: `check_reservation_overlap` already is the conflict
+/// test, so testing a pair directly means there's no second
+/// conflict-detection path (the CNF encoding) to keep in sync with it.
+/// Guarantees a conflict-free packing when one exists, and naturally
+/// favors Critical-priority requests, since they carry a far higher
+/// `priority_weight` than any combination of lower-priority ones within
+/// `MAX_OPTIMAL_BATCH_SIZE`.
+struct OptimalSolver;
+
+impl ReservationSolver for OptimalSolver {
+    fn solve(
+        &self,
+        manager: &OrbitReservationManager,
+        requests: Vec<FlexibleReservationRequest>,
+    ) -> Result<ScheduleResult> {
+        manager.assign_optimal(requests)
+    }
+}
+
+/// Precomputed search context for `OrbitReservationManager::branch_and_bound`:
+/// a priority-weight-descending visitation order plus, per request, its
+/// discretized candidate start times and a running suffix sum of weight
+/// still reachable from each depth (used to prune branches that can't beat
+/// the best solution found so far).
+struct OptimalSearchContext<'a> {
+    requests: &'a [FlexibleReservationRequest],
+    order: Vec<usize>,
+    candidates: Vec<Vec<DateTime<Utc>>>,
+    weights: Vec<u64>,
+    suffix_weight: Vec<u64>,
+}
+
+impl<'a> OptimalSearchContext<'a> {
+    fn new(requests: &'a [FlexibleReservationRequest]) -> Self {
+        let weights: Vec<u64> = requests.iter().map(|r| priority_weight(&r.priority_level)).collect();
+
+        let mut order: Vec<usize> = (0..requests.len()).collect();
+        order.sort_by(|&a, &b| weights[b].cmp(&weights[a]));
+
+        let candidates: Vec<Vec<DateTime<Utc>>> = requests
+            .iter()
+            .map(|r| OrbitReservationManager::candidate_starts(r, Duration::minutes(r.duration_minutes.max(1))))
+            .collect();
+
+        let mut suffix_weight = vec![0u64; order.len() + 1];
+        for k in (0..order.len()).rev() {
+            suffix_weight[k] = suffix_weight[k + 1] + weights[order[k]];
+        }
+
+        Self {
+            requests,
+            order,
+            candidates,
+            weights,
+            suffix_weight,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -183,7 +485,7 @@ pub struct LaunchProfile {
     pub assigned_norad_id: u64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct LaunchFeasibilityRequest {
     pub customer: String,
     pub mission_name: String,
@@ -222,6 +524,40 @@ pub struct LaunchFeasibilityResult {
     pub summary: LaunchFeasibilitySummary,
 }
 
+/// `optimize_launch_window`'s request: a normal `LaunchFeasibilityRequest`
+/// (its `launch.epoch` is the earliest candidate tried) plus how far past
+/// that epoch to scan and how finely to sample it.
+#[derive(Debug, Deserialize)]
+pub struct LaunchWindowScanRequest {
+    #[serde(flatten)]
+    pub feasibility: LaunchFeasibilityRequest,
+    /// How far past `launch.epoch` to scan, in hours. Clamped to `[1, 72]`.
+    #[serde(default)]
+    pub scan_hours: Option<u64>,
+    /// Spacing between candidate epochs, in minutes. Clamped to `[1, 360]`.
+    #[serde(default)]
+    pub cadence_minutes: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LaunchWindowCandidate {
+    pub epoch: DateTime<Utc>,
+    pub safe_to_launch: bool,
+    pub summary: LaunchFeasibilitySummary,
+}
+
+/// Result of `optimize_launch_window`: every candidate epoch tried, ranked
+/// by ascending risk (`total_risk_score` then `max_collision_probability`),
+/// with `recommended_epoch` naming the first one in that ranked order that
+/// came back `safe_to_launch`.
+#[derive(Debug, Serialize)]
+pub struct LaunchWindowScanResult {
+    pub customer: String,
+    pub mission_name: String,
+    pub candidates: Vec<LaunchWindowCandidate>,
+    pub recommended_epoch: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReservationCheckResponse {
     pub reservation_id: Uuid,
@@ -231,25 +567,58 @@ pub struct ReservationCheckResponse {
     pub total_risk_score: f64,
     pub conflicts: Vec<ReservationConflict>,
     pub recommendations: Vec<String>,
+    /// NORAD ids from the catalog that could not be assessed because no
+    /// real tracked TLE was available for them, so they were excluded from
+    /// `conflicts` rather than being checked against a fabricated orbit.
+    /// Non-empty means this report is a "no conflicts found in what we
+    /// could evaluate", not necessarily "no conflicts".
+    #[serde(default)]
+    pub propagation_unavailable: Vec<u64>,
+}
+
+/// Combines a conflict assessment with the derived go/no-go verdict. Used
+/// both as an HTTP response body (`create_reservation`, `update_reservation`)
+/// and as the payload delivered to reservation safety webhooks by
+/// `safety_monitor` when a re-evaluation changes the verdict.
+#[derive(Debug, Serialize)]
+pub struct ReservationSafetyReport {
+    pub safe_to_launch: bool,
+    pub summary: LaunchFeasibilitySummary,
+    pub assessment: ReservationCheckResponse,
 }
 
 pub struct OrbitReservationManager {
     reservations: HashMap<Uuid, OrbitReservation>,
     conjunction_analyzer: ConjunctionAnalyzer,
     conflict_history: Vec<ReservationConflict>,
-    risk_model: Arc<RwLock<RiskModel>>,
+    risk_models: Arc<RiskModelRegistry>,
+    /// Precise-ephemeris arcs, keyed by NORAD id, mirroring
+    /// `ConjunctionAnalyzer`/`SatelliteTracker`'s identical preference for
+    /// SP3 over TLE propagation whenever a loaded arc covers the requested
+    /// time -- whether the object is a reservation's center or a catalog
+    /// satellite passed into conflict checking.
+    sp3: HashMap<u64, Sp3Ephemeris>,
 }
 
 impl OrbitReservationManager {
-    pub fn new(risk_model: Arc<RwLock<RiskModel>>) -> Self {
+    pub fn new(risk_models: Arc<RiskModelRegistry>) -> Self {
         Self {
             reservations: HashMap::new(),
             conjunction_analyzer: ConjunctionAnalyzer::new(),
             conflict_history: Vec::new(),
-            risk_model,
+            risk_models,
+            sp3: HashMap::new(),
         }
     }
 
+    /// Loads a precise-ephemeris arc for `norad_id`, taking priority over
+    /// SGP4-style TLE propagation for any requested time it covers. See
+    /// `ConjunctionAnalyzer::load_sp3` for the same pattern applied to
+    /// conjunction screening.
+    pub fn load_sp3(&mut self, norad_id: u64, ephemeris: Sp3Ephemeris) {
+        self.sp3.insert(norad_id, ephemeris);
+    }
+
     fn resolve_center_satellite(
         request: &CreateReservationRequest,
     ) -> std::result::Result<(SatelliteData, Option<LaunchProfile>), String> {
@@ -314,6 +683,7 @@ impl OrbitReservationManager {
             name: launch.vehicle_name.clone(),
             tle_line1: line1,
             tle_line2: line2,
+            epoch: launch.epoch,
             last_updated: launch.epoch,
         };
 
@@ -354,20 +724,31 @@ impl OrbitReservationManager {
         let (center_tle, launch_profile) = Self::resolve_center_satellite(&request)
             .map_err(|err| SatApiError::TleParseError(err))?;
 
-        let constraints = request
-            .constraints
-            .unwrap_or_else(|| ReservationConstraints {
-                max_conjunction_probability: match request.priority_level {
-                    PriorityLevel::Critical => 1e-6,
-                    PriorityLevel::High => 1e-5,
-                    PriorityLevel::Medium => 1e-4,
-                    PriorityLevel::Low => 1e-3,
-                },
-                minimum_separation_km: request.protection_radius_km,
-                notification_threshold_hours: 24,
-                allow_debris_tracking: true,
-                coordinate_system: CoordinateSystem::ECI,
-            });
+        if let Some(samples) = &request.precise_ephemeris {
+            if !samples.is_empty() {
+                let ephemeris = Sp3Ephemeris::from_samples(
+                    samples
+                        .iter()
+                        .map(|sample| {
+                            (
+                                sample.epoch,
+                                (
+                                    sample.position_km[0],
+                                    sample.position_km[1],
+                                    sample.position_km[2],
+                                ),
+                                sample.velocity_km_s.map(|v| (v[0], v[1], v[2])),
+                            )
+                        })
+                        .collect(),
+                );
+                self.load_sp3(center_tle.norad_id, ephemeris);
+            }
+        }
+
+        let constraints = request.constraints.unwrap_or_else(|| {
+            Self::default_constraints(&request.priority_level, request.protection_radius_km)
+        });
 
         let reservation = OrbitReservation {
             id: reservation_id,
@@ -382,6 +763,8 @@ impl OrbitReservationManager {
             created_at: Utc::now(),
             constraints,
             launch_profile,
+            inclusion_epochs: request.inclusion_epochs,
+            exclusion_epochs: request.exclusion_epochs,
         };
 
         self.reservations
@@ -400,6 +783,7 @@ impl OrbitReservationManager {
         &mut self,
         reservation_id: Uuid,
         catalog_satellites: &[SatelliteData],
+        tenant_id: &str,
     ) -> Result<ReservationCheckResponse> {
         let reservation = self
             .reservations
@@ -407,7 +791,7 @@ impl OrbitReservationManager {
             .cloned()
             .ok_or_else(|| SatApiError::SatelliteNotFound(reservation_id.as_u128() as u64))?;
 
-        self.evaluate_conflicts_internal(&reservation, catalog_satellites, true)
+        self.evaluate_conflicts_internal(&reservation, catalog_satellites, true, tenant_id)
     }
 
     fn evaluate_conflicts_internal(
@@ -415,6 +799,7 @@ impl OrbitReservationManager {
         reservation: &OrbitReservation,
         catalog_satellites: &[SatelliteData],
         record_history: bool,
+        tenant_id: &str,
     ) -> Result<ReservationCheckResponse> {
         tracing::info!(
             "Evaluating conflicts for reservation {} against {} satellites",
@@ -427,7 +812,9 @@ impl OrbitReservationManager {
         let mut highest_severity = ConflictSeverity::Low;
 
         for satellite in catalog_satellites {
-            if let Some(conflict) = self.check_satellite_conflict(reservation, satellite)? {
+            if let Some(conflict) =
+                self.check_satellite_conflict(reservation, satellite, tenant_id)?
+            {
                 if conflict.severity > highest_severity {
                     highest_severity = conflict.severity.clone();
                 }
@@ -462,13 +849,74 @@ impl OrbitReservationManager {
             total_risk_score,
             conflicts,
             recommendations,
+            propagation_unavailable: Vec::new(),
         })
     }
 
+    /// Computes contiguous visibility windows of `reservation_id`'s own
+    /// tracked object from `station`, scanning `[start_time, end_time]` at
+    /// `step` and honoring the station's elevation mask and
+    /// inclusion/exclusion epochs. Lets mitigation planning clip a
+    /// `MitigationSuggestion`'s time window to when the operator can
+    /// actually see the object from the ground, rather than just when it's
+    /// geometrically in conflict.
+    pub fn ground_station_access_windows(
+        &self,
+        reservation_id: Uuid,
+        station: &GroundStation,
+        step: Duration,
+    ) -> Result<Vec<EpochWindow>> {
+        let reservation = self
+            .reservations
+            .get(&reservation_id)
+            .ok_or_else(|| SatApiError::SatelliteNotFound(reservation_id.as_u128() as u64))?;
+
+        self.access_windows_for(reservation, station, step)
+    }
+
+    /// Same as `ground_station_access_windows` but for an already-resolved
+    /// reservation, so internal callers (e.g. conflict evaluation) don't
+    /// have to round-trip through a reservation id lookup.
+    fn access_windows_for(
+        &self,
+        reservation: &OrbitReservation,
+        station: &GroundStation,
+        step: Duration,
+    ) -> Result<Vec<EpochWindow>> {
+        let mut windows = Vec::new();
+        let mut window_start: Option<DateTime<Utc>> = None;
+        let mut time = reservation.start_time;
+
+        while time <= reservation.end_time {
+            let position = self.propagate_to_eci(&reservation.center_tle, &time)?;
+            let look_angle =
+                look_angle_for_eci(station, (position.x, position.y, position.z), time);
+            let visible = station.sees(&look_angle, time);
+
+            match (visible, window_start) {
+                (true, None) => window_start = Some(time),
+                (false, Some(start)) => {
+                    windows.push(EpochWindow { start, end: time });
+                    window_start = None;
+                }
+                _ => {}
+            }
+
+            time += step;
+        }
+
+        if let Some(start) = window_start {
+            windows.push(EpochWindow { start, end: reservation.end_time });
+        }
+
+        Ok(windows)
+    }
+
     pub fn evaluate_launch_feasibility(
         &mut self,
         request: LaunchFeasibilityRequest,
         catalog_satellites: &[SatelliteData],
+        tenant_id: &str,
     ) -> Result<LaunchFeasibilityResult> {
         let (center_tle, launch_profile) = Self::build_launch_satellite(&request.launch)
             .map_err(|err| SatApiError::TleParseError(err))?;
@@ -504,6 +952,7 @@ impl OrbitReservationManager {
                 notification_threshold_hours: 12,
                 allow_debris_tracking: true,
                 coordinate_system: CoordinateSystem::ECI,
+                handoff_policy: HandoffPolicy::Eager,
             });
 
         let reservation = OrbitReservation {
@@ -523,10 +972,12 @@ impl OrbitReservationManager {
             created_at: Utc::now(),
             constraints: constraints.clone(),
             launch_profile: Some(launch_profile.clone()),
+            inclusion_epochs: Vec::new(),
+            exclusion_epochs: Vec::new(),
         };
 
         let assessment =
-            self.evaluate_conflicts_internal(&reservation, catalog_satellites, false)?;
+            self.evaluate_conflicts_internal(&reservation, catalog_satellites, false, tenant_id)?;
 
         let (summary, safe_to_launch) =
             OrbitReservationManager::summarize_feasibility(&reservation, &assessment);
@@ -542,6 +993,96 @@ impl OrbitReservationManager {
         })
     }
 
+    /// Sweeps candidate launch epochs across `[launch.epoch, launch.epoch +
+    /// scan_hours]` at `cadence_minutes` spacing, rebuilding the launch
+    /// satellite and re-running the full feasibility assessment at each
+    /// one via `evaluate_launch_feasibility`. Returns every candidate
+    /// ranked by ascending risk, with `recommended_epoch` naming the
+    /// lowest-risk one that came back safe.
+    pub fn optimize_launch_window(
+        &mut self,
+        request: LaunchWindowScanRequest,
+        catalog_satellites: &[SatelliteData],
+        tenant_id: &str,
+    ) -> Result<LaunchWindowScanResult> {
+        let LaunchWindowScanRequest {
+            feasibility,
+            scan_hours,
+            cadence_minutes,
+        } = request;
+
+        let scan_minutes = scan_hours.unwrap_or(6).clamp(1, 72) as i64 * 60;
+        let mut cadence_minutes = cadence_minutes.unwrap_or(15).clamp(1, 360) as i64;
+
+        let candidate_count = scan_minutes / cadence_minutes + 1;
+        if candidate_count as usize > MAX_SCAN_CANDIDATES {
+            // At most MAX_SCAN_CANDIDATES epochs means at most one fewer
+            // intervals, so round the cadence up over that many.
+            let max_intervals = MAX_SCAN_CANDIDATES as i64 - 1;
+            let widened =
+                ((scan_minutes + max_intervals - 1) / max_intervals).max(cadence_minutes);
+            tracing::warn!(
+                "launch window scan would evaluate {} candidates at a {}-minute cadence, \
+                 above the cap of {}; widening cadence to {} minutes",
+                candidate_count,
+                cadence_minutes,
+                MAX_SCAN_CANDIDATES,
+                widened
+            );
+            cadence_minutes = widened;
+        }
+
+        let base_epoch = feasibility.launch.epoch;
+        let scan_end = base_epoch + Duration::minutes(scan_minutes);
+
+        let mut candidates = Vec::new();
+        let mut candidate_epoch = base_epoch;
+        while candidate_epoch <= scan_end {
+            let mut candidate_request = feasibility.clone();
+            candidate_request.launch.epoch = candidate_epoch;
+
+            let result = self.evaluate_launch_feasibility(
+                candidate_request,
+                catalog_satellites,
+                tenant_id,
+            )?;
+
+            candidates.push(LaunchWindowCandidate {
+                epoch: candidate_epoch,
+                safe_to_launch: result.safe_to_launch,
+                summary: result.summary,
+            });
+
+            candidate_epoch += Duration::minutes(cadence_minutes);
+        }
+
+        candidates.sort_by(|a, b| {
+            a.summary
+                .total_risk_score
+                .partial_cmp(&b.summary.total_risk_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| {
+                    let a_probability = a.summary.max_collision_probability.unwrap_or(0.0);
+                    let b_probability = b.summary.max_collision_probability.unwrap_or(0.0);
+                    a_probability
+                        .partial_cmp(&b_probability)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+
+        let recommended_epoch = candidates
+            .iter()
+            .find(|candidate| candidate.safe_to_launch)
+            .map(|candidate| candidate.epoch);
+
+        Ok(LaunchWindowScanResult {
+            customer: feasibility.customer,
+            mission_name: feasibility.mission_name,
+            candidates,
+            recommended_epoch,
+        })
+    }
+
     pub fn summarize_feasibility(
         reservation: &OrbitReservation,
         assessment: &ReservationCheckResponse,
@@ -597,6 +1138,7 @@ impl OrbitReservationManager {
         &self,
         reservation: &OrbitReservation,
         satellite: &SatelliteData,
+        tenant_id: &str,
     ) -> Result<Option<ReservationConflict>> {
         let time_step = Duration::minutes(5);
         let mut current_time = reservation.start_time;
@@ -604,11 +1146,19 @@ impl OrbitReservationManager {
         let mut tca = current_time;
         let mut reservation_pos_tca = Vector3::zeros();
         let mut satellite_pos_tca = Vector3::zeros();
-        let mut max_probability: f64 = 0.0;
 
-        // Sample the reservation time window
+        // Sample the reservation time window to find the time of closest
+        // approach; the Pc computation below only needs the single TCA
+        // geometry, not a per-sample probability. Timesteps outside the
+        // reservation's inclusion/exclusion epochs (e.g. a maintenance
+        // blackout, or a corridor only active during specific passes) are
+        // skipped, since the operation isn't actually occurring then.
         while current_time <= reservation.end_time {
-            // Propagate reservation center and satellite
+            if !reservation.is_active_at(current_time) {
+                current_time = current_time + time_step;
+                continue;
+            }
+
             let reservation_pos = self.propagate_to_eci(&reservation.center_tle, &current_time)?;
             let satellite_pos = self.propagate_to_eci(satellite, &current_time)?;
 
@@ -621,28 +1171,51 @@ impl OrbitReservationManager {
                 satellite_pos_tca = satellite_pos;
             }
 
-            // Estimate collision probability
-            let uncertainty = self.estimate_position_uncertainty(satellite, &current_time);
-            let prob = self.calculate_simple_collision_probability(distance, uncertainty, 0.01); // 10m hard body
-            max_probability = max_probability.max(prob);
-
             current_time = current_time + time_step;
         }
 
+        if min_distance.is_infinite() {
+            // No timestep in [start_time, end_time] fell inside an active
+            // epoch, so there's nothing to report a conflict against.
+            return Ok(None);
+        }
+
+        let relative_velocity_vector =
+            self.estimate_relative_velocity_vector(&reservation.center_tle, satellite, &tca)?;
+        let uncertainty_reservation = self.estimate_position_uncertainty(&reservation.center_tle, &tca);
+        let uncertainty_satellite = self.estimate_position_uncertainty(satellite, &tca);
+
+        let reservation_velocity_tca =
+            self.estimate_velocity_vector(&reservation.center_tle, &tca)?;
+        let (frame_position, frame_velocity, frame_miss_vector) = Self::geometry_in_frame(
+            &reservation.constraints.coordinate_system,
+            reservation_pos_tca,
+            reservation_velocity_tca,
+            reservation_pos_tca - satellite_pos_tca,
+            tca,
+        );
+        let miss_vector_rtn = Self::decompose_rtn(frame_miss_vector, frame_position, frame_velocity);
+
+        let analytical_probability = self.calculate_pc_2d(
+            reservation_pos_tca - satellite_pos_tca,
+            relative_velocity_vector,
+            uncertainty_reservation,
+            uncertainty_satellite,
+            0.01, // 10m combined hard-body radius
+        );
+
         // Determine if this constitutes a conflict
         if min_distance <= reservation.protection_radius_km
-            || max_probability >= reservation.constraints.max_conjunction_probability
+            || analytical_probability >= reservation.constraints.max_conjunction_probability
         {
-            let relative_velocity =
-                self.estimate_relative_speed(&reservation.center_tle, satellite, &tca)?;
+            let relative_velocity = relative_velocity_vector.norm();
 
             let tle_age = self.estimate_tle_age_hours(&reservation.center_tle, satellite, &tca);
             let baseline_risk = self.estimate_baseline_risk(reservation_pos_tca, satellite_pos_tca);
 
-            let analytical_probability = max_probability;
             let (ml_probability, fused_probability) = {
-                let mut guard = self
-                    .risk_model
+                let risk_model = self.risk_models.get_or_create(tenant_id);
+                let mut guard = risk_model
                     .write()
                     .map_err(|_| SatApiError::TleParseError("Risk model unavailable".into()))?;
 
@@ -692,7 +1265,9 @@ impl OrbitReservationManager {
                     fused_probability,
                     &tca,
                     reservation,
+                    satellite_pos_tca,
                 ),
+                miss_vector_rtn,
             };
 
             Ok(Some(conflict))
@@ -701,6 +1276,27 @@ impl OrbitReservationManager {
         }
     }
 
+    /// Default per-priority conflict tolerance used when a reservation
+    /// doesn't specify its own `constraints`. Pulled out so the batch
+    /// scheduler's candidate placements (which likewise have no caller-given
+    /// constraints) can't drift from what `create_reservation` actually
+    /// grants.
+    fn default_constraints(priority_level: &PriorityLevel, protection_radius_km: f64) -> ReservationConstraints {
+        ReservationConstraints {
+            max_conjunction_probability: match priority_level {
+                PriorityLevel::Critical => 1e-6,
+                PriorityLevel::High => 1e-5,
+                PriorityLevel::Medium => 1e-4,
+                PriorityLevel::Low => 1e-3,
+            },
+            minimum_separation_km: protection_radius_km,
+            notification_threshold_hours: 24,
+            allow_debris_tracking: true,
+            coordinate_system: CoordinateSystem::ECI,
+            handoff_policy: HandoffPolicy::Eager,
+        }
+    }
+
     fn check_reservation_overlap(
         &self,
         reservation: &OrbitReservation,
@@ -717,6 +1313,23 @@ impl OrbitReservationManager {
         let overlap_start = reservation.start_time.max(other_reservation.start_time);
         let overlap_end = reservation.end_time.min(other_reservation.end_time);
 
+        // Clip the overlap span to the epochs where both reservations are
+        // actually active -- a blackout (station downtime, eclipse,
+        // keep-out) on either side means there's no real contention there,
+        // even though the raw [start_time, end_time] spans overlap.
+        let active_windows: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+            Self::clip_to_active_epochs(reservation, overlap_start, overlap_end)
+                .into_iter()
+                .flat_map(|(s, e)| Self::clip_to_active_epochs(other_reservation, s, e))
+                .collect();
+
+        let Some((overlap_start, overlap_end)) = active_windows
+            .into_iter()
+            .max_by_key(|(s, e)| (*e - *s).num_milliseconds())
+        else {
+            return Ok(None);
+        };
+
         let mid_time = overlap_start + (overlap_end - overlap_start) / 2;
 
         let pos_a = self.propagate_to_eci(&reservation.center_tle, &mid_time)?;
@@ -736,6 +1349,37 @@ impl OrbitReservationManager {
                 ConflictSeverity::Medium
             };
 
+            let reservation_velocity = self.estimate_velocity_vector(&reservation.center_tle, &mid_time)?;
+            let (frame_position, frame_velocity, frame_miss_vector) = Self::geometry_in_frame(
+                &reservation.constraints.coordinate_system,
+                pos_a,
+                reservation_velocity,
+                pos_a - pos_b,
+                mid_time,
+            );
+            let miss_vector_rtn = Self::decompose_rtn(frame_miss_vector, frame_position, frame_velocity);
+
+            // Surface the governing handoff policy so the two holders get
+            // an explicit shared (or hard-cutover) window instead of a
+            // generic "coordinate" instruction.
+            let (handoff_description, handoff_window_start, handoff_window_end) =
+                match reservation.constraints.handoff_policy {
+                    HandoffPolicy::Overlap { coordinated_window_minutes } => (
+                        format!(
+                            "Coordinate with other reservation holder: {}-minute shared overlap window permitted",
+                            coordinated_window_minutes
+                        ),
+                        overlap_start,
+                        overlap_end + Duration::minutes(coordinated_window_minutes),
+                    ),
+                    HandoffPolicy::Eager => (
+                        "Coordinate with other reservation holder: hard cutover, no shared window"
+                            .to_string(),
+                        overlap_start,
+                        overlap_start,
+                    ),
+                };
+
             let conflict = ReservationConflict {
                 conflict_id: Uuid::new_v4(),
                 reservation_id: reservation.id,
@@ -756,12 +1400,13 @@ impl OrbitReservationManager {
                 duration_seconds: (overlap_end - overlap_start).num_seconds() as u64,
                 mitigation_suggestions: vec![MitigationSuggestion {
                     suggestion_type: MitigationType::CoordinatedOperation,
-                    description: "Coordinate with other reservation holder".to_string(),
+                    description: handoff_description,
                     delta_v_cost_m_s: None,
-                    time_window_start: overlap_start,
-                    time_window_end: overlap_end,
+                    time_window_start: handoff_window_start,
+                    time_window_end: handoff_window_end,
                     success_probability: 0.8,
                 }],
+                miss_vector_rtn,
             };
 
             Ok(Some(conflict))
@@ -771,43 +1416,120 @@ impl OrbitReservationManager {
     }
 
     // Helper methods
+    /// Two-body Keplerian propagation from the full TLE element set --
+    /// inclination, RAAN, eccentricity, argument of perigee, mean anomaly
+    /// and mean motion -- rather than the circular-orbit approximation this
+    /// used to fall back to. Ignores SGP4's perturbation terms (J2, drag,
+    /// ...), which is why this stays a hand-rolled propagator distinct from
+    /// `conjunction::sgp4_propagate`'s real SGP4 via the `sgp4` crate: it's
+    /// meant for this module's synthetic/what-if satellites (new launches,
+    /// scheduler candidates) as much as catalog ones.
     fn propagate_to_eci(
         &self,
         sat_data: &SatelliteData,
         time: &DateTime<Utc>,
     ) -> Result<Vector3<f64>> {
-        // Reuse the propagation logic from conjunction analyzer
-        let current_time_seconds = time.timestamp() as f64;
+        if let Some((position, _velocity)) = self.sp3_eci(sat_data.norad_id, time) {
+            return Ok(position);
+        }
+
         let line2 = &sat_data.tle_line2;
         let parts: Vec<&str> = line2.split_whitespace().collect();
 
-        let inclination_deg = parts
-            .get(2)
-            .and_then(|value| value.parse::<f64>().ok())
-            .or_else(|| line2.get(8..16).and_then(|s| s.trim().parse::<f64>().ok()))
-            .unwrap_or(51.6);
+        let field_deg = |part_index: usize, columns: std::ops::Range<usize>, default: f64| {
+            parts
+                .get(part_index)
+                .and_then(|value| value.parse::<f64>().ok())
+                .or_else(|| line2.get(columns).and_then(|s| s.trim().parse::<f64>().ok()))
+                .unwrap_or(default)
+        };
 
-        let mean_motion = parts
+        let inclination_deg = field_deg(2, 8..16, 51.6);
+        let raan_deg = field_deg(3, 17..25, 0.0);
+        let argument_of_perigee_deg = field_deg(5, 34..42, 0.0);
+        let mean_anomaly_deg = field_deg(6, 43..51, 0.0);
+        let mean_motion_rev_per_day = parts
             .last()
             .and_then(|value| value.parse::<f64>().ok())
             .or_else(|| line2.get(52..63).and_then(|s| s.trim().parse::<f64>().ok()))
             .unwrap_or(15.5);
 
-        let inclination = inclination_deg * std::f64::consts::PI / 180.0;
+        // Eccentricity is stored with an implied leading "0.", e.g. the TLE
+        // field "0001234" means 0.0001234.
+        let eccentricity = parts
+            .get(4)
+            .and_then(|value| format!("0.{value}").parse::<f64>().ok())
+            .or_else(|| {
+                line2
+                    .get(26..33)
+                    .and_then(|s| format!("0.{}", s.trim()).parse::<f64>().ok())
+            })
+            .unwrap_or(0.0);
+
+        let inclination = inclination_deg.to_radians();
+        let raan = raan_deg.to_radians();
+        let argument_of_perigee = argument_of_perigee_deg.to_radians();
+        let mean_anomaly_epoch = mean_anomaly_deg.to_radians();
+
+        let mean_motion_rad_per_sec =
+            mean_motion_rev_per_day * 2.0 * PI / 86400.0;
+        let semi_major_axis_km = (MU_KM3_S2 / mean_motion_rad_per_sec.powi(2)).powf(1.0 / 3.0);
+
+        let seconds_since_epoch =
+            (time.timestamp_millis() - sat_data.epoch.timestamp_millis()) as f64 / 1000.0;
+        let mean_anomaly =
+            (mean_anomaly_epoch + mean_motion_rad_per_sec * seconds_since_epoch).rem_euclid(2.0 * PI);
+
+        // Newton iteration for eccentric anomaly from Kepler's equation
+        // M = E - e*sin(E).
+        let mut eccentric_anomaly = mean_anomaly;
+        for _ in 0..50 {
+            let delta = (eccentric_anomaly - eccentricity * eccentric_anomaly.sin() - mean_anomaly)
+                / (1.0 - eccentricity * eccentric_anomaly.cos());
+            eccentric_anomaly -= delta;
+            if delta.abs() < 1e-10 {
+                break;
+            }
+        }
+
+        let true_anomaly = 2.0
+            * ((1.0 + eccentricity).sqrt() * (eccentric_anomaly / 2.0).sin())
+                .atan2((1.0 - eccentricity).sqrt() * (eccentric_anomaly / 2.0).cos());
+        let radius_km = semi_major_axis_km * (1.0 - eccentricity * eccentric_anomaly.cos());
+
+        let perifocal = Vector3::new(radius_km * true_anomaly.cos(), radius_km * true_anomaly.sin(), 0.0);
 
-        let orbital_period_seconds = 86400.0 / mean_motion;
-        let angular_velocity = 2.0 * std::f64::consts::PI / orbital_period_seconds;
-        let orbital_angle =
-            (current_time_seconds * angular_velocity) % (2.0 * std::f64::consts::PI);
+        let rotation = Self::rotation_z(-raan) * Self::rotation_x(-inclination) * Self::rotation_z(-argument_of_perigee);
+        Ok(rotation * perifocal)
+    }
 
-        let altitude_km = 400.0 + (mean_motion - 15.0) * 20.0;
-        let orbital_radius_km = 6371.0 + altitude_km;
+    /// Active rotation about the Z axis by `angle_rad`.
+    fn rotation_z(angle_rad: f64) -> Matrix3<f64> {
+        let (sin, cos) = angle_rad.sin_cos();
+        Matrix3::new(cos, -sin, 0.0, sin, cos, 0.0, 0.0, 0.0, 1.0)
+    }
 
-        let x = orbital_radius_km * orbital_angle.cos();
-        let y = orbital_radius_km * orbital_angle.sin() * inclination.cos();
-        let z = orbital_radius_km * orbital_angle.sin() * inclination.sin();
+    /// Active rotation about the X axis by `angle_rad`.
+    fn rotation_x(angle_rad: f64) -> Matrix3<f64> {
+        let (sin, cos) = angle_rad.sin_cos();
+        Matrix3::new(1.0, 0.0, 0.0, 0.0, cos, -sin, 0.0, sin, cos)
+    }
 
-        Ok(Vector3::new(x, y, z))
+    /// Looks up a loaded SP3 arc for `norad_id` and, if it covers `time`,
+    /// Lagrange-interpolates the tabulated ECEF state and rotates it into
+    /// ECI. Velocity is rotated by the same GMST matrix as position,
+    /// without the Earth-rotation (ω×r) transport term -- mirroring
+    /// `conjunction::ecef_to_eci`'s existing level of approximation for SP3
+    /// ingestion, so the two modules stay consistent.
+    fn sp3_eci(&self, norad_id: u64, time: &DateTime<Utc>) -> Option<(Vector3<f64>, Vector3<f64>)> {
+        let ephemeris = self.sp3.get(&norad_id)?;
+        let (x, y, z, vx, vy, vz) = ephemeris.interpolate(*time)?;
+        let theta = crate::ground_station::gmst_rad(*time);
+        let (cos_t, sin_t) = (theta.cos(), theta.sin());
+        let rotate = |v: (f64, f64, f64)| {
+            Vector3::new(v.0 * cos_t - v.1 * sin_t, v.0 * sin_t + v.1 * cos_t, v.2)
+        };
+        Some((rotate((x, y, z)), rotate((vx, vy, vz))))
     }
 
     fn estimate_position_uncertainty(&self, sat_data: &SatelliteData, time: &DateTime<Utc>) -> f64 {
@@ -818,6 +1540,10 @@ impl OrbitReservationManager {
         base_uncertainty * (1.0 + age_hours * growth_rate)
     }
 
+    /// Crude fallback collision estimate: a Gaussian falloff from a single
+    /// scalar miss distance and uncertainty, with no encounter-plane
+    /// geometry. Used by `calculate_pc_2d` when the relative velocity or
+    /// combined covariance doesn't support the full 2D projection.
     fn calculate_simple_collision_probability(
         &self,
         distance_km: f64,
@@ -835,26 +1561,455 @@ impl OrbitReservationManager {
             * (-distance_km.powi(2) / (2.0 * uncertainty_km.powi(2))).exp()
     }
 
-    fn estimate_relative_speed(
+    /// Standard conjunction-assessment probability of collision: projects
+    /// the combined position-uncertainty covariance onto the 2D plane
+    /// normal to the relative velocity at TCA, then numerically integrates
+    /// the resulting bivariate Gaussian over the disk of radius
+    /// `hard_body_radius_km` around the projected miss vector. Falls back
+    /// to `calculate_simple_collision_probability` when the relative speed
+    /// is too small to define an encounter plane or the projected
+    /// covariance is singular.
+    fn calculate_pc_2d(
+        &self,
+        relative_position_km: Vector3<f64>,
+        relative_velocity_km_s: Vector3<f64>,
+        uncertainty_a_km: f64,
+        uncertainty_b_km: f64,
+        hard_body_radius_km: f64,
+    ) -> f64 {
+        let fallback = || {
+            self.calculate_simple_collision_probability(
+                relative_position_km.norm(),
+                (uncertainty_a_km.powi(2) + uncertainty_b_km.powi(2)).sqrt(),
+                hard_body_radius_km,
+            )
+        };
+
+        let speed = relative_velocity_km_s.norm();
+        if speed < 1e-6 {
+            return fallback();
+        }
+        let v_hat = relative_velocity_km_s / speed;
+        let (u1, u2) = Self::encounter_plane_basis(&v_hat);
+
+        let combined_covariance =
+            Self::along_track_covariance(uncertainty_a_km, &v_hat)
+                + Self::along_track_covariance(uncertainty_b_km, &v_hat);
+
+        let covariance_2d = Matrix2::new(
+            u1.dot(&(combined_covariance * u1)),
+            u1.dot(&(combined_covariance * u2)),
+            u2.dot(&(combined_covariance * u1)),
+            u2.dot(&(combined_covariance * u2)),
+        );
+
+        let determinant = covariance_2d.determinant();
+        if !determinant.is_finite() || determinant <= 1e-12 {
+            return fallback();
+        }
+
+        let miss_vector = (
+            u1.dot(&relative_position_km),
+            u2.dot(&relative_position_km),
+        );
+
+        Self::integrate_pc_polar(miss_vector, &covariance_2d, hard_body_radius_km).clamp(0.0, 1.0)
+    }
+
+    /// Orthonormal basis `{u1, u2}` spanning the plane perpendicular to
+    /// `v_hat` (a unit vector), used to project 3D position/covariance onto
+    /// the 2D conjunction-assessment encounter plane.
+    fn encounter_plane_basis(v_hat: &Vector3<f64>) -> (Vector3<f64>, Vector3<f64>) {
+        let seed = if v_hat.x.abs() < 0.9 {
+            Vector3::x()
+        } else {
+            Vector3::y()
+        };
+        let u1 = (seed - v_hat * v_hat.dot(&seed)).normalize();
+        let u2 = v_hat.cross(&u1);
+        (u1, u2)
+    }
+
+    /// Builds a 3x3 position-uncertainty covariance from a single scalar
+    /// estimate, stretched along the velocity direction. Real along-track
+    /// error grows faster than cross-track/radial error; this applies a
+    /// fixed stretch factor rather than modeling full RIC error propagation.
+    fn along_track_covariance(uncertainty_km: f64, v_hat: &Vector3<f64>) -> Matrix3<f64> {
+        const ALONG_TRACK_STRETCH: f64 = 3.0;
+        let variance = uncertainty_km * uncertainty_km;
+        Matrix3::identity() * variance
+            + (v_hat * v_hat.transpose()) * (variance * (ALONG_TRACK_STRETCH - 1.0))
+    }
+
+    /// Numerically integrates the bivariate Gaussian `N(miss_vector,
+    /// covariance)` over the disk of radius `radius_km` centered at the
+    /// origin, via a midpoint-rule polar grid.
+    fn integrate_pc_polar(
+        miss_vector: (f64, f64),
+        covariance: &Matrix2<f64>,
+        radius_km: f64,
+    ) -> f64 {
+        const RADIAL_STEPS: usize = 40;
+        const ANGULAR_STEPS: usize = 72;
+
+        let determinant = covariance.determinant();
+        let inverse = match covariance.try_inverse() {
+            Some(inverse) => inverse,
+            None => return 0.0,
+        };
+        let normalization = 1.0 / (2.0 * std::f64::consts::PI * determinant.sqrt());
+
+        let dr = radius_km / RADIAL_STEPS as f64;
+        let dtheta = 2.0 * std::f64::consts::PI / ANGULAR_STEPS as f64;
+
+        let mut total = 0.0;
+        for i in 0..RADIAL_STEPS {
+            let rho = (i as f64 + 0.5) * dr;
+            for j in 0..ANGULAR_STEPS {
+                let theta = (j as f64 + 0.5) * dtheta;
+                let offset = Vector2::new(
+                    rho * theta.cos() - miss_vector.0,
+                    rho * theta.sin() - miss_vector.1,
+                );
+                let exponent = -0.5 * (offset.transpose() * inverse * offset)[(0, 0)];
+                let pdf = normalization * exponent.exp();
+                total += pdf * rho * dr * dtheta;
+            }
+        }
+        total
+    }
+
+    fn estimate_relative_velocity_vector(
         &self,
         primary: &SatelliteData,
         secondary: &SatelliteData,
         time: &DateTime<Utc>,
-    ) -> Result<f64> {
+    ) -> Result<Vector3<f64>> {
+        Ok(self.estimate_velocity_vector(primary, time)?
+            - self.estimate_velocity_vector(secondary, time)?)
+    }
+
+    /// Finite-difference ECI velocity of a single object, sampled a minute
+    /// either side of `time`. Split out of `estimate_relative_velocity_vector`
+    /// because the RTN decomposition in `check_satellite_conflict` /
+    /// `check_reservation_overlap` needs the reservation center's own
+    /// velocity (to build the radial/along-track/cross-track basis), not
+    /// just the relative velocity between the two objects.
+    fn estimate_velocity_vector(
+        &self,
+        sat_data: &SatelliteData,
+        time: &DateTime<Utc>,
+    ) -> Result<Vector3<f64>> {
+        if let Some((_position, velocity)) = self.sp3_eci(sat_data.norad_id, time) {
+            return Ok(velocity);
+        }
+
         let delta = Duration::seconds(60);
         let before = *time - delta;
         let after = *time + delta;
         let dt_seconds = (after - before).num_seconds() as f64;
 
-        let primary_before = self.propagate_to_eci(primary, &before)?;
-        let primary_after = self.propagate_to_eci(primary, &after)?;
-        let secondary_before = self.propagate_to_eci(secondary, &before)?;
-        let secondary_after = self.propagate_to_eci(secondary, &after)?;
+        let position_before = self.propagate_to_eci(sat_data, &before)?;
+        let position_after = self.propagate_to_eci(sat_data, &after)?;
+
+        Ok((position_after - position_before) * (1.0 / dt_seconds))
+    }
+
+    /// Propagates a position/velocity state forward `dt_seconds` under pure
+    /// two-body gravity (no perturbations), via 4th-order Runge-Kutta with a
+    /// fixed sub-step so longer intervals stay accurate. Used for a
+    /// maneuvered state: once an impulsive Δv is applied, the velocity no
+    /// longer matches the TLE element set it came from, so `propagate_to_eci`
+    /// can't be used to advance it any further.
+    fn propagate_two_body_state(
+        position_km: Vector3<f64>,
+        velocity_km_s: Vector3<f64>,
+        dt_seconds: f64,
+    ) -> (Vector3<f64>, Vector3<f64>) {
+        const MAX_STEP_SECONDS: f64 = 10.0;
+        let steps = ((dt_seconds.abs() / MAX_STEP_SECONDS).ceil() as usize).max(1);
+        let h = dt_seconds / steps as f64;
+
+        let acceleration = |r: Vector3<f64>| -> Vector3<f64> { -MU_KM3_S2 * r / r.norm().powi(3) };
+
+        let mut r = position_km;
+        let mut v = velocity_km_s;
+        for _ in 0..steps {
+            let k1_r = v;
+            let k1_v = acceleration(r);
+
+            let k2_r = v + k1_v * (h / 2.0);
+            let k2_v = acceleration(r + k1_r * (h / 2.0));
+
+            let k3_r = v + k2_v * (h / 2.0);
+            let k3_v = acceleration(r + k2_r * (h / 2.0));
+
+            let k4_r = v + k3_v * h;
+            let k4_v = acceleration(r + k3_r * h);
+
+            r += (k1_r + 2.0 * k2_r + 2.0 * k3_r + k4_r) * (h / 6.0);
+            v += (k1_v + 2.0 * k2_v + 2.0 * k3_v + k4_v) * (h / 6.0);
+        }
+
+        (r, v)
+    }
 
-        let velocity_primary = (primary_after - primary_before) * (1.0 / dt_seconds);
-        let velocity_secondary = (secondary_after - secondary_before) * (1.0 / dt_seconds);
+    /// ECI position of `reservation`'s center object at `evaluation_time`
+    /// after applying an impulsive `delta_v_km_s` at `burn_time`: propagates
+    /// unperturbed up to the burn, adds the Δv to the velocity there, then
+    /// integrates the resulting state forward under two-body gravity.
+    fn maneuvered_position(
+        &self,
+        reservation: &OrbitReservation,
+        burn_time: DateTime<Utc>,
+        delta_v_km_s: Vector3<f64>,
+        evaluation_time: DateTime<Utc>,
+    ) -> Result<Vector3<f64>> {
+        let position_at_burn = self.propagate_to_eci(&reservation.center_tle, &burn_time)?;
+        let velocity_at_burn = self.estimate_velocity_vector(&reservation.center_tle, &burn_time)?;
+        let velocity_after_burn = velocity_at_burn + delta_v_km_s;
+
+        let dt_seconds = (evaluation_time - burn_time).num_milliseconds() as f64 / 1000.0;
+        let (position, _velocity) =
+            Self::propagate_two_body_state(position_at_burn, velocity_after_burn, dt_seconds);
+        Ok(position)
+    }
 
-        Ok((velocity_primary - velocity_secondary).norm())
+    /// Solves for the minimum Δv (applied at `burn_time`) that raises the
+    /// separation from `other_position_at_tca` at `tca` up to
+    /// `desired_miss_distance_km`, via damped Gauss-Newton
+    /// (Levenberg-Marquardt) on the scalar residual
+    /// `r(Δv) = desired_miss_distance_km - achieved_miss_distance_km`.
+    /// The Jacobian is a central finite difference of the achieved distance
+    /// against each Δv component; `lambda` grows on a step that fails to
+    /// shrink `|r|` and shrinks on one that succeeds, so the solver trades
+    /// off between a Gauss-Newton step (fast near the solution) and a
+    /// gradient-descent step (robust far from it) without an explicit trust
+    /// region. Returns `Ok(None)` if it exhausts `MAX_ITERATIONS` without
+    /// converging, so callers can distinguish "solved, here's a real Δv"
+    /// from "gave up" instead of mistaking an unconverged zero Δv for a
+    /// maneuver-free solution.
+    fn target_avoidance_delta_v(
+        &self,
+        reservation: &OrbitReservation,
+        other_position_at_tca: Vector3<f64>,
+        burn_time: DateTime<Utc>,
+        tca: DateTime<Utc>,
+        desired_miss_distance_km: f64,
+    ) -> Result<Option<Vector3<f64>>> {
+        const MAX_ITERATIONS: usize = 25;
+        const FINITE_DIFF_STEP_KM_S: f64 = 1e-6; // 1 mm/s
+        const CONVERGENCE_KM: f64 = 1e-6;
+
+        let residual_for = |this: &Self, delta_v: Vector3<f64>| -> Result<f64> {
+            let position = this.maneuvered_position(reservation, burn_time, delta_v, tca)?;
+            Ok(desired_miss_distance_km - (position - other_position_at_tca).norm())
+        };
+
+        let mut delta_v = Vector3::zeros();
+        let mut lambda = 1e-3;
+        let mut residual = residual_for(self, delta_v)?;
+        let mut converged = residual.abs() < CONVERGENCE_KM;
+
+        for _ in 0..MAX_ITERATIONS {
+            if converged {
+                break;
+            }
+
+            let mut jacobian = Vector3::zeros();
+            for axis in 0..3 {
+                let mut plus = delta_v;
+                plus[axis] += FINITE_DIFF_STEP_KM_S;
+                let mut minus = delta_v;
+                minus[axis] -= FINITE_DIFF_STEP_KM_S;
+                jacobian[axis] = (residual_for(self, plus)? - residual_for(self, minus)?)
+                    / (2.0 * FINITE_DIFF_STEP_KM_S);
+            }
+
+            let jtj = jacobian * jacobian.transpose();
+            let jtr = jacobian * residual;
+            let damped = jtj + Matrix3::identity() * lambda;
+
+            let Some(inverse) = damped.try_inverse() else {
+                break;
+            };
+            let candidate_delta_v = delta_v - inverse * jtr;
+            let candidate_residual = residual_for(self, candidate_delta_v)?;
+
+            if candidate_residual.abs() < residual.abs() {
+                delta_v = candidate_delta_v;
+                residual = candidate_residual;
+                lambda = (lambda * 0.5).max(1e-8);
+                converged = residual.abs() < CONVERGENCE_KM;
+            } else {
+                lambda *= 2.0;
+            }
+        }
+
+        Ok(converged.then_some(delta_v))
+    }
+
+    /// Rotates an ECI vector into ECEF at `time` by Earth's rotation angle
+    /// (`ground_station::gmst_rad`), shared with `conjunction`'s SP3
+    /// ingestion rather than re-deriving GMST here. Valid for both position
+    /// vectors and direction/offset vectors (e.g. a miss vector), since the
+    /// rotation is linear and has no translational component.
+    fn eci_to_ecef_vector(vector: Vector3<f64>, time: DateTime<Utc>) -> Vector3<f64> {
+        let theta = crate::ground_station::gmst_rad(time);
+        let (cos_t, sin_t) = (theta.cos(), theta.sin());
+        Vector3::new(
+            vector.x * cos_t + vector.y * sin_t,
+            -vector.x * sin_t + vector.y * cos_t,
+            vector.z,
+        )
+    }
+
+    /// Rotates an ECI velocity into ECEF at `time`, adding the transport
+    /// term `-ω × r_ecef` on top of the position rotation applied by
+    /// `eci_to_ecef_vector` (a velocity isn't just "rotate the vector": the
+    /// frame itself is rotating under it).
+    fn eci_to_ecef_velocity(
+        position_eci: Vector3<f64>,
+        velocity_eci: Vector3<f64>,
+        time: DateTime<Utc>,
+    ) -> Vector3<f64> {
+        let position_ecef = Self::eci_to_ecef_vector(position_eci, time);
+        let rotated_velocity = Self::eci_to_ecef_vector(velocity_eci, time);
+        let earth_angular_velocity = Vector3::new(0.0, 0.0, EARTH_ROTATION_RATE_RAD_S);
+        rotated_velocity - earth_angular_velocity.cross(&position_ecef)
+    }
+
+    /// Expresses a reservation center's position/velocity and a miss vector
+    /// in the frame named by `coordinate_system`. ECEF applies Earth's
+    /// rotation to all three; ECI and RTN both pass the inertial geometry
+    /// through unchanged, since RTN in `ReservationConstraints` means "report
+    /// the miss vector decomposed into radial/along-track/cross-track", not
+    /// a different frame for the position/velocity feeding that
+    /// decomposition.
+    fn geometry_in_frame(
+        coordinate_system: &CoordinateSystem,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+        miss_vector: Vector3<f64>,
+        time: DateTime<Utc>,
+    ) -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+        match coordinate_system {
+            CoordinateSystem::ECEF => (
+                Self::eci_to_ecef_vector(position, time),
+                Self::eci_to_ecef_velocity(position, velocity, time),
+                Self::eci_to_ecef_vector(miss_vector, time),
+            ),
+            CoordinateSystem::ECI | CoordinateSystem::RTN => (position, velocity, miss_vector),
+        }
+    }
+
+    /// Decomposes `miss_vector` into the reservation center's
+    /// radial/tangential/normal frame: radial = `r̂`, cross-track (normal) =
+    /// `(r×v)̂`, along-track (tangential) = `normal×radial`.
+    fn decompose_rtn(
+        miss_vector: Vector3<f64>,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+    ) -> MissVectorRtn {
+        let radial = position.normalize();
+        let angular_momentum = position.cross(&velocity);
+
+        // Degenerate orbit geometry (near-zero position or velocity aligned
+        // with position): fall back to an arbitrary cross-track direction
+        // orthogonal to radial rather than normalizing a near-zero vector.
+        let cross_track = if angular_momentum.norm() > 1e-9 {
+            angular_momentum.normalize()
+        } else {
+            let seed = if radial.x.abs() < 0.9 {
+                Vector3::x()
+            } else {
+                Vector3::y()
+            };
+            (seed - radial * radial.dot(&seed)).normalize()
+        };
+        let along_track = cross_track.cross(&radial);
+
+        MissVectorRtn {
+            radial_km: miss_vector.dot(&radial),
+            along_track_km: miss_vector.dot(&along_track),
+            cross_track_km: miss_vector.dot(&cross_track),
+        }
+    }
+
+    /// Clips `[start, end]` to the epochs where `reservation` is actually
+    /// active: split around any exclusion windows, then, if inclusion
+    /// windows are configured, keep only the parts also covered by one of
+    /// them. Mirrors `OrbitReservation::is_active_at`'s semantics but over
+    /// an interval rather than a single instant, since
+    /// `check_reservation_overlap` evaluates a whole overlap span rather
+    /// than a sampled timeline.
+    fn clip_to_active_epochs(
+        reservation: &OrbitReservation,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut intervals = vec![(start, end)];
+
+        for exclusion in &reservation.exclusion_epochs {
+            intervals = intervals
+                .into_iter()
+                .flat_map(|(s, e)| Self::subtract_interval(s, e, exclusion.start, exclusion.end))
+                .collect();
+        }
+
+        if !reservation.inclusion_epochs.is_empty() {
+            intervals = intervals
+                .into_iter()
+                .flat_map(|(s, e)| {
+                    reservation
+                        .inclusion_epochs
+                        .iter()
+                        .filter_map(move |inclusion| {
+                            Self::intersect_interval(s, e, inclusion.start, inclusion.end)
+                        })
+                })
+                .collect();
+        }
+
+        intervals
+    }
+
+    /// Removes `[excl_start, excl_end]` from `[s, e]`, returning zero, one,
+    /// or two remaining pieces.
+    fn subtract_interval(
+        s: DateTime<Utc>,
+        e: DateTime<Utc>,
+        excl_start: DateTime<Utc>,
+        excl_end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        if excl_end <= s || excl_start >= e {
+            return vec![(s, e)];
+        }
+        let mut remaining = Vec::new();
+        if excl_start > s {
+            remaining.push((s, excl_start));
+        }
+        if excl_end < e {
+            remaining.push((excl_end, e));
+        }
+        remaining
+    }
+
+    /// Overlap of `[s, e]` and `[other_start, other_end]`, or `None` if
+    /// they don't overlap.
+    fn intersect_interval(
+        s: DateTime<Utc>,
+        e: DateTime<Utc>,
+        other_start: DateTime<Utc>,
+        other_end: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = s.max(other_start);
+        let end = e.min(other_end);
+        if start < end {
+            Some((start, end))
+        } else {
+            None
+        }
     }
 
     fn estimate_tle_age_hours(
@@ -921,15 +2076,34 @@ impl OrbitReservationManager {
         probability: f64,
         tca: &DateTime<Utc>,
         reservation: &OrbitReservation,
+        satellite_pos_tca: Vector3<f64>,
     ) -> Vec<MitigationSuggestion> {
         let mut suggestions = Vec::new();
 
         if probability > 1e-3 {
+            let burn_time = *tca - Duration::hours(2);
+            // Target comfortably clear of the protection sphere, not just
+            // barely outside it.
+            let desired_miss_distance_km = reservation.protection_radius_km * 1.5;
+            let delta_v_cost_m_s = match self.target_avoidance_delta_v(
+                reservation,
+                satellite_pos_tca,
+                burn_time,
+                *tca,
+                desired_miss_distance_km,
+            ) {
+                Ok(Some(delta_v)) => Some(delta_v.norm() * 1000.0),
+                // Solver didn't converge, or hit a propagation error: fall
+                // back to the old placeholder rather than under-reporting
+                // the cost of a maneuver this flagged conjunction needs.
+                Ok(None) | Err(_) => Some(2.0),
+            };
+
             suggestions.push(MitigationSuggestion {
                 suggestion_type: MitigationType::ManeuverAvoidance,
                 description: "Perform immediate avoidance maneuver".to_string(),
-                delta_v_cost_m_s: Some(2.0), // Typical avoidance maneuver
-                time_window_start: *tca - Duration::hours(2),
+                delta_v_cost_m_s,
+                time_window_start: burn_time,
                 time_window_end: *tca - Duration::minutes(30),
                 success_probability: 0.95,
             });
@@ -1028,4 +2202,449 @@ impl OrbitReservationManager {
             ))
         }
     }
+
+    /// Reschedules an existing reservation to `[new_start, new_end)`,
+    /// re-validating the window and reverting the reservation to `Pending`
+    /// so a subsequent `check_reservation_conflicts` call re-assesses it
+    /// against the new time range. Refuses (leaving the original window
+    /// untouched) once the reservation is `Active`, since the operation it
+    /// backs is already underway.
+    pub fn update_reservation(
+        &mut self,
+        reservation_id: Uuid,
+        new_start: DateTime<Utc>,
+        new_end: DateTime<Utc>,
+    ) -> std::result::Result<OrbitReservation, RescheduleError> {
+        if new_end <= new_start {
+            return Err(RescheduleError::InvalidWindow(
+                "end_time must be after start_time".to_string(),
+            ));
+        }
+        if new_end < Utc::now() {
+            return Err(RescheduleError::InvalidWindow(
+                "reservation window is entirely in the past".to_string(),
+            ));
+        }
+
+        let reservation = self
+            .reservations
+            .get_mut(&reservation_id)
+            .ok_or(RescheduleError::NotFound)?;
+
+        if matches!(reservation.status, ReservationStatus::Active) {
+            return Err(RescheduleError::AlreadyActive);
+        }
+
+        reservation.start_time = new_start;
+        reservation.end_time = new_end;
+        reservation.status = ReservationStatus::Pending;
+
+        Ok(reservation.clone())
+    }
+
+    /// Resolves a batch of flexible-window requests into concrete
+    /// placements or explicit rejections. Candidate placements conflict
+    /// when their protection volumes overlap in space-time, reusing
+    /// `check_reservation_overlap` against both reservations already on
+    /// the books and whichever earlier requests in this same batch have
+    /// already been granted.
+    pub fn assign_reservations(
+        &self,
+        requests: Vec<FlexibleReservationRequest>,
+        mode: SchedulingMode,
+    ) -> Result<ScheduleResult> {
+        let mode = if mode == SchedulingMode::Optimal && requests.len() > MAX_OPTIMAL_BATCH_SIZE {
+            tracing::warn!(
+                "batch of {} requests exceeds optimal-mode cap of {}; falling back to greedy scheduling",
+                requests.len(),
+                MAX_OPTIMAL_BATCH_SIZE
+            );
+            SchedulingMode::Greedy
+        } else {
+            mode
+        };
+
+        let solver: &dyn ReservationSolver = match mode {
+            SchedulingMode::Greedy => &GreedySolver,
+            SchedulingMode::Optimal => &OptimalSolver,
+        };
+        solver.solve(self, requests)
+    }
+
+    fn assign_greedy(&self, mut requests: Vec<FlexibleReservationRequest>) -> Result<ScheduleResult> {
+        requests.sort_by(|a, b| {
+            priority_weight(&b.priority_level)
+                .cmp(&priority_weight(&a.priority_level))
+                .then(a.earliest_start.cmp(&b.earliest_start))
+        });
+
+        let mut granted: Vec<OrbitReservation> = Vec::new();
+        let mut assignments = Vec::with_capacity(requests.len());
+
+        for request in &requests {
+            let outcome = match self.place_earliest_feasible(request, &granted)? {
+                Ok((start_time, end_time, candidate)) => {
+                    let outcome = AssignmentOutcome::Granted { start_time, end_time };
+                    granted.push(candidate);
+                    outcome
+                }
+                Err(blocking_reservation_ids) => AssignmentOutcome::Rejected {
+                    blocking_reservation_ids,
+                },
+            };
+            assignments.push(RequestAssignment {
+                request_id: request.request_id,
+                outcome,
+            });
+        }
+
+        Ok(Self::summarize_schedule(assignments))
+    }
+
+    /// Slides `request`'s candidate start forward from `earliest_start`
+    /// until it finds a `duration_minutes` window that conflicts with
+    /// nothing in `self.reservations` or `granted`, or runs out of room
+    /// before `latest_end`. Each conflict jumps the candidate straight past
+    /// the blocking reservation's end rather than stepping minute-by-minute.
+    fn place_earliest_feasible(
+        &self,
+        request: &FlexibleReservationRequest,
+        granted: &[OrbitReservation],
+    ) -> Result<std::result::Result<(DateTime<Utc>, DateTime<Utc>, OrbitReservation), Vec<Uuid>>> {
+        let duration = Duration::minutes(request.duration_minutes.max(1));
+        let mut candidate_start = request.earliest_start;
+        let mut blocking_ids = Vec::new();
+
+        loop {
+            let candidate_end = candidate_start + duration;
+            if candidate_end > request.latest_end {
+                return Ok(Err(blocking_ids));
+            }
+
+            let candidate = Self::candidate_reservation(request, candidate_start, candidate_end);
+            let mut next_start = None;
+
+            for other in self.reservations.values().chain(granted.iter()) {
+                if self.check_reservation_overlap(&candidate, other)?.is_some() {
+                    if !blocking_ids.contains(&other.id) {
+                        blocking_ids.push(other.id);
+                    }
+                    // `other.end_time` is guaranteed >= `candidate_start` by
+                    // the overlap it was just found to have; nudge one
+                    // second past it so the loop always makes progress.
+                    let past_other = other.end_time + Duration::seconds(1);
+                    next_start = Some(match next_start {
+                        Some(t) if t >= past_other => t,
+                        _ => past_other,
+                    });
+                }
+            }
+
+            match next_start {
+                None => return Ok(Ok((candidate_start, candidate_end, candidate))),
+                Some(t) => candidate_start = t,
+            }
+        }
+    }
+
+    fn assign_optimal(&self, requests: Vec<FlexibleReservationRequest>) -> Result<ScheduleResult> {
+        let ctx = OptimalSearchContext::new(&requests);
+
+        let mut best_weight = 0u64;
+        let mut best_placed: Vec<(usize, DateTime<Utc>, DateTime<Utc>, OrbitReservation)> = Vec::new();
+        let mut placed = Vec::new();
+
+        self.branch_and_bound(&ctx, 0, 0, &mut placed, &mut best_weight, &mut best_placed)?;
+
+        let mut granted_window: HashMap<usize, (DateTime<Utc>, DateTime<Utc>)> = HashMap::new();
+        let mut granted_reservations: Vec<OrbitReservation> = Vec::new();
+        for (idx, start, end, reservation) in &best_placed {
+            granted_window.insert(*idx, (*start, *end));
+            granted_reservations.push(reservation.clone());
+        }
+
+        let mut assignments = Vec::with_capacity(requests.len());
+        for (idx, request) in requests.iter().enumerate() {
+            let outcome = match granted_window.get(&idx) {
+                Some((start_time, end_time)) => AssignmentOutcome::Granted {
+                    start_time: *start_time,
+                    end_time: *end_time,
+                },
+                None => AssignmentOutcome::Rejected {
+                    blocking_reservation_ids: self
+                        .blocking_reservation_ids(request, &granted_reservations)?,
+                },
+            };
+            assignments.push(RequestAssignment {
+                request_id: request.request_id,
+                outcome,
+            });
+        }
+
+        Ok(Self::summarize_schedule(assignments))
+    }
+
+    /// Depth-first branch-and-bound over `ctx.order`: at each depth, either
+    /// grant that request at one of its discretized candidate starts (if it
+    /// doesn't conflict with anything placed earlier on this branch) or
+    /// drop it, pruning a branch once granting every remaining request
+    /// couldn't beat `best_weight`.
+    #[allow(clippy::too_many_arguments)]
+    fn branch_and_bound(
+        &self,
+        ctx: &OptimalSearchContext,
+        depth: usize,
+        current_weight: u64,
+        placed: &mut Vec<(usize, DateTime<Utc>, DateTime<Utc>, OrbitReservation)>,
+        best_weight: &mut u64,
+        best_placed: &mut Vec<(usize, DateTime<Utc>, DateTime<Utc>, OrbitReservation)>,
+    ) -> Result<()> {
+        if depth == ctx.order.len() {
+            if current_weight > *best_weight {
+                *best_weight = current_weight;
+                *best_placed = placed.clone();
+            }
+            return Ok(());
+        }
+
+        if current_weight + ctx.suffix_weight[depth] <= *best_weight {
+            // Even granting every remaining request couldn't beat the best
+            // solution found so far.
+            return Ok(());
+        }
+
+        let request_idx = ctx.order[depth];
+        let request = &ctx.requests[request_idx];
+        let weight = ctx.weights[request_idx];
+        let duration = Duration::minutes(request.duration_minutes.max(1));
+
+        for &candidate_start in &ctx.candidates[request_idx] {
+            let candidate_end = candidate_start + duration;
+            let candidate = Self::candidate_reservation(request, candidate_start, candidate_end);
+
+            let mut conflicts = false;
+            for other in self.reservations.values() {
+                if self.check_reservation_overlap(&candidate, other)?.is_some() {
+                    conflicts = true;
+                    break;
+                }
+            }
+            if !conflicts {
+                for (_, _, _, other) in placed.iter() {
+                    if self.check_reservation_overlap(&candidate, other)?.is_some() {
+                        conflicts = true;
+                        break;
+                    }
+                }
+            }
+
+            if !conflicts {
+                placed.push((request_idx, candidate_start, candidate_end, candidate));
+                self.branch_and_bound(
+                    ctx,
+                    depth + 1,
+                    current_weight + weight,
+                    placed,
+                    best_weight,
+                    best_placed,
+                )?;
+                placed.pop();
+            }
+        }
+
+        // Branch: drop this request entirely.
+        self.branch_and_bound(ctx, depth + 1, current_weight, placed, best_weight, best_placed)
+    }
+
+    /// Best-effort explanation for a request the optimal search dropped:
+    /// which reservations (pre-existing or granted elsewhere in the batch)
+    /// conflict with its earliest candidate placement. This is a reason,
+    /// not a proof that no placement anywhere in the window existed.
+    fn blocking_reservation_ids(
+        &self,
+        request: &FlexibleReservationRequest,
+        granted_reservations: &[OrbitReservation],
+    ) -> Result<Vec<Uuid>> {
+        let duration = Duration::minutes(request.duration_minutes.max(1));
+        let candidate_start = request.earliest_start;
+        let candidate_end = candidate_start + duration;
+        if candidate_end > request.latest_end {
+            return Ok(Vec::new());
+        }
+        let candidate = Self::candidate_reservation(request, candidate_start, candidate_end);
+
+        let mut blocking = Vec::new();
+        for other in self.reservations.values().chain(granted_reservations.iter()) {
+            if self.check_reservation_overlap(&candidate, other)?.is_some() {
+                blocking.push(other.id);
+            }
+        }
+        Ok(blocking)
+    }
+
+    /// Discretizes `[request.earliest_start, request.latest_end - duration]`
+    /// into up to `MAX_OPTIMAL_CANDIDATES_PER_REQUEST` evenly spaced start
+    /// times (always including both endpoints). Empty if the window is
+    /// narrower than `duration`.
+    fn candidate_starts(request: &FlexibleReservationRequest, duration: Duration) -> Vec<DateTime<Utc>> {
+        let latest_start = request.latest_end - duration;
+        if latest_start < request.earliest_start {
+            return Vec::new();
+        }
+
+        let span_seconds = (latest_start - request.earliest_start).num_seconds();
+        if span_seconds <= 0 {
+            return vec![request.earliest_start];
+        }
+
+        let steps = MAX_OPTIMAL_CANDIDATES_PER_REQUEST.saturating_sub(1).max(1) as i64;
+        let mut starts: Vec<DateTime<Utc>> = (0..=steps)
+            .map(|i| request.earliest_start + Duration::seconds(span_seconds * i / steps))
+            .collect();
+        starts.dedup();
+        starts
+    }
+
+    /// Builds a throwaway `OrbitReservation` for a trial `[start, end)`
+    /// window so it can be run through `check_reservation_overlap` just
+    /// like a persisted reservation. `id` is set to the request's own id so
+    /// a conflict against another candidate in the same batch reports back
+    /// as that request's id.
+    fn candidate_reservation(
+        request: &FlexibleReservationRequest,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> OrbitReservation {
+        OrbitReservation {
+            id: request.request_id,
+            owner: request.owner.clone(),
+            reservation_type: request.reservation_type.clone(),
+            start_time,
+            end_time,
+            center_tle: request.center_tle.clone(),
+            protection_radius_km: request.protection_radius_km,
+            priority_level: request.priority_level.clone(),
+            status: ReservationStatus::Pending,
+            created_at: Utc::now(),
+            constraints: Self::default_constraints(&request.priority_level, request.protection_radius_km),
+            launch_profile: None,
+            inclusion_epochs: Vec::new(),
+            exclusion_epochs: Vec::new(),
+        }
+    }
+
+    fn summarize_schedule(assignments: Vec<RequestAssignment>) -> ScheduleResult {
+        let granted_count = assignments
+            .iter()
+            .filter(|a| matches!(a.outcome, AssignmentOutcome::Granted { .. }))
+            .count();
+        let rejected_count = assignments.len() - granted_count;
+        ScheduleResult {
+            assignments,
+            granted_count,
+            rejected_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> OrbitReservationManager {
+        OrbitReservationManager::new(Arc::new(RiskModelRegistry::new(std::env::temp_dir())))
+    }
+
+    fn sample_reservation(tca: DateTime<Utc>) -> OrbitReservation {
+        let center_tle = SatelliteData {
+            norad_id: 12345,
+            name: "TESTSAT".to_string(),
+            tle_line1: "1 12345U 98067A   20029.54791435  .00000123  00000-0  10270-3 0  9006".to_string(),
+            tle_line2: "2 12345  97.6420  75.0414 0007434 347.3218  12.7348 14.81450576113842".to_string(),
+            epoch: tca - Duration::days(1),
+            last_updated: tca - Duration::days(1),
+        };
+
+        OrbitReservation {
+            id: Uuid::new_v4(),
+            owner: "test-owner".to_string(),
+            reservation_type: ReservationType::OperationalSlot,
+            start_time: tca - Duration::hours(3),
+            end_time: tca + Duration::hours(3),
+            center_tle,
+            protection_radius_km: 1.0,
+            priority_level: PriorityLevel::Medium,
+            status: ReservationStatus::Active,
+            created_at: tca - Duration::days(1),
+            constraints: ReservationConstraints {
+                max_conjunction_probability: 1e-4,
+                minimum_separation_km: 1.0,
+                notification_threshold_hours: 24,
+                allow_debris_tracking: false,
+                coordinate_system: CoordinateSystem::ECI,
+                handoff_policy: HandoffPolicy::Eager,
+            },
+            launch_profile: None,
+            inclusion_epochs: Vec::new(),
+            exclusion_epochs: Vec::new(),
+        }
+    }
+
+    /// A normal, solvable targeting problem (burn well ahead of TCA, a
+    /// modest desired miss distance) should converge, and the returned Δv
+    /// should actually achieve (close to) the requested miss distance when
+    /// applied -- not just "some nonzero vector".
+    #[test]
+    fn target_avoidance_delta_v_converges_and_achieves_target_distance() {
+        let manager = manager();
+        let tca = Utc::now();
+        let burn_time = tca - Duration::hours(2);
+        let reservation = sample_reservation(tca);
+        let other_position_at_tca = manager.propagate_to_eci(&reservation.center_tle, &tca).unwrap();
+        let desired_miss_distance_km = 5.0;
+
+        let delta_v = manager
+            .target_avoidance_delta_v(&reservation, other_position_at_tca, burn_time, tca, desired_miss_distance_km)
+            .unwrap();
+
+        let delta_v = delta_v.expect("a well-posed targeting problem should converge");
+
+        let achieved_position = manager
+            .maneuvered_position(&reservation, burn_time, delta_v, tca)
+            .unwrap();
+        let achieved_miss_km = (achieved_position - other_position_at_tca).norm();
+
+        assert!(
+            (achieved_miss_km - desired_miss_distance_km).abs() < 1e-3,
+            "expected achieved miss distance near {desired_miss_distance_km} km, got {achieved_miss_km} km"
+        );
+    }
+
+    /// With `burn_time == tca` the maneuver has zero time to act (the
+    /// two-body propagation over a zero-length interval leaves position
+    /// unchanged regardless of Δv), so the Jacobian is all-zero and the
+    /// solver can never reduce the residual. This must surface as
+    /// `Ok(None)` -- the regression this test guards against returned
+    /// `Ok(Vector3::zeros())` here, which a caller could mistake for "no
+    /// maneuver needed".
+    #[test]
+    fn target_avoidance_delta_v_reports_non_convergence_when_burn_cannot_act() {
+        let manager = manager();
+        let tca = Utc::now();
+        let reservation = sample_reservation(tca);
+        let other_position_at_tca = manager.propagate_to_eci(&reservation.center_tle, &tca).unwrap();
+        // Whatever the zero-delta-v distance is, demand something far from
+        // it so the residual never starts within `CONVERGENCE_KM`.
+        let zero_delta_v_position = manager
+            .maneuvered_position(&reservation, tca, Vector3::zeros(), tca)
+            .unwrap();
+        let zero_delta_v_distance = (zero_delta_v_position - other_position_at_tca).norm();
+        let desired_miss_distance_km = zero_delta_v_distance + 1000.0;
+
+        let result = manager
+            .target_avoidance_delta_v(&reservation, other_position_at_tca, tca, tca, desired_miss_distance_km)
+            .unwrap();
+
+        assert!(result.is_none(), "expected non-convergence to report Ok(None), got {result:?}");
+    }
 }
@@ -0,0 +1,466 @@
+// Background reservation safety monitor: a reservation is normally
+// (re-)assessed at creation time and whenever the operator explicitly calls
+// `check_reservation_conflicts`, but nothing watches it afterwards -- a
+// conjunction risk that only emerges once a new satellite enters its
+// corridor is never surfaced. This module closes that gap with a periodic
+// re-evaluation sweep plus a webhook delivery queue (modeled on
+// `webhooks::spawn_webhook_dispatcher`'s retry loop) so operators who
+// registered a URL for a reservation get pushed a fresh
+// `ReservationSafetyReport` whenever its assessment changes.
+
+use crate::api::SatelliteApi;
+use crate::handlers::catalog_to_satellite_data;
+use crate::reservation::{
+    OrbitReservation, OrbitReservationManager, ReservationSafetyReport, ReservationStatus,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration as StdDuration;
+use tokio::sync::Mutex as AsyncMutex;
+use uuid::Uuid;
+
+/// Tenant used to look up a risk model for the background sweep. Reservations
+/// aren't tenant-scoped today (see `OrbitReservation`, which only tracks
+/// `owner`), so every reservation is re-evaluated against the default
+/// tenant's model rather than the one that created it.
+const SWEEP_TENANT: &str = crate::alerts::DEFAULT_TENANT;
+
+const MAX_DELIVERY_ATTEMPTS: u32 = 6;
+const INITIAL_BACKOFF_SECS: i64 = 1;
+const MAX_BACKOFF_SECS: i64 = 60;
+
+/// Default on-disk location for `SafetyDeliveryQueue`'s persistent queue,
+/// used when no `SAFETY_WEBHOOK_QUEUE_PATH` environment variable is set (see
+/// `main.rs`).
+pub const DEFAULT_SAFETY_QUEUE_PATH: &str = "data/safety_webhook_queue.json";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SafetyWebhookSubscription {
+    pub id: Uuid,
+    pub reservation_id: Uuid,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Per-reservation registry of safety webhook subscriptions, shared via
+/// `AppState`. Distinct from `webhooks::WebhookRegistry`: that one is
+/// per-tenant and fed by `AlertHub`'s category/severity feed, this one is
+/// per-reservation and fed by `SafetyMonitor`'s periodic re-assessment.
+#[derive(Default)]
+pub struct SafetyWebhookRegistry {
+    subscriptions: RwLock<HashMap<Uuid, SafetyWebhookSubscription>>,
+}
+
+impl SafetyWebhookRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&self, reservation_id: Uuid, url: String) -> SafetyWebhookSubscription {
+        let subscription = SafetyWebhookSubscription {
+            id: Uuid::new_v4(),
+            reservation_id,
+            url,
+            created_at: Utc::now(),
+        };
+        self.subscriptions
+            .write()
+            .unwrap()
+            .insert(subscription.id, subscription.clone());
+        subscription
+    }
+
+    pub fn list_for_reservation(&self, reservation_id: Uuid) -> Vec<SafetyWebhookSubscription> {
+        self.subscriptions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|subscription| subscription.reservation_id == reservation_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Removes the subscription if it exists and belongs to `reservation_id`.
+    /// Returns `false` for both "not found" and "belongs to another
+    /// reservation" so callers can't probe for other reservations' ids.
+    pub fn remove(&self, reservation_id: Uuid, id: Uuid) -> bool {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let owned_by_reservation = subscriptions
+            .get(&id)
+            .map_or(false, |subscription| subscription.reservation_id == reservation_id);
+        if owned_by_reservation {
+            subscriptions.remove(&id);
+        }
+        owned_by_reservation
+    }
+}
+
+/// One queued delivery attempt. `payload` is the already-serialized
+/// `ReservationSafetyReport` JSON rather than the struct itself, so the
+/// queue (and its on-disk spool) don't need `Deserialize` on the whole
+/// conflict-report type graph -- it only ever needs to replay opaque bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeliveryJob {
+    id: Uuid,
+    reservation_id: Uuid,
+    url: String,
+    payload: String,
+    attempt: u32,
+    next_attempt_at: DateTime<Utc>,
+}
+
+/// Reliable at-least-once delivery queue for reservation safety webhooks,
+/// persisted to disk so pending alerts survive a restart. Failures are
+/// retried with exponential backoff (capped) up to `MAX_DELIVERY_ATTEMPTS`,
+/// after which the job is dropped and logged.
+pub struct SafetyDeliveryQueue {
+    jobs: Mutex<Vec<DeliveryJob>>,
+    persistence_path: Option<PathBuf>,
+}
+
+impl SafetyDeliveryQueue {
+    pub fn new() -> Self {
+        Self {
+            jobs: Mutex::new(Vec::new()),
+            persistence_path: None,
+        }
+    }
+
+    /// Like [`SafetyDeliveryQueue::new`], but backs the queue with an on-disk
+    /// spool at `path`: pending jobs (if any) are loaded back in. A missing
+    /// or corrupt spool file just starts empty, the same as a fresh queue.
+    pub fn with_persistence<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let jobs = load_queue(&path).unwrap_or_default();
+        Self {
+            jobs: Mutex::new(jobs),
+            persistence_path: Some(path),
+        }
+    }
+
+    pub fn enqueue(&self, reservation_id: Uuid, url: String, report: &ReservationSafetyReport) {
+        let payload = match serde_json::to_string(report) {
+            Ok(payload) => payload,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to serialize safety report for reservation {}: {}",
+                    reservation_id,
+                    err
+                );
+                return;
+            }
+        };
+
+        let job = DeliveryJob {
+            id: Uuid::new_v4(),
+            reservation_id,
+            url,
+            payload,
+            attempt: 0,
+            next_attempt_at: Utc::now(),
+        };
+
+        let mut jobs = self.jobs.lock().unwrap();
+        jobs.push(job);
+        self.persist(&jobs);
+    }
+
+    /// Delivers every job whose `next_attempt_at` has passed. Each delivery
+    /// runs in its own task (same "one slow webhook can't block another"
+    /// rationale as `webhooks::spawn_webhook_dispatcher`), so this returns as
+    /// soon as the due jobs are handed off rather than waiting on them.
+    fn drain_due(self: &Arc<Self>, client: &reqwest::Client) {
+        let due: Vec<DeliveryJob> = {
+            let jobs = self.jobs.lock().unwrap();
+            let now = Utc::now();
+            jobs.iter()
+                .filter(|job| job.next_attempt_at <= now)
+                .cloned()
+                .collect()
+        };
+
+        for job in due {
+            let queue = Arc::clone(self);
+            let client = client.clone();
+            tokio::spawn(async move {
+                queue.attempt_delivery(job, &client).await;
+            });
+        }
+    }
+
+    async fn attempt_delivery(&self, mut job: DeliveryJob, client: &reqwest::Client) {
+        job.attempt += 1;
+
+        let outcome = client
+            .post(&job.url)
+            .header("content-type", "application/json")
+            .body(job.payload.clone())
+            .send()
+            .await;
+
+        let delivered = matches!(&outcome, Ok(resp) if resp.status().is_success());
+        match &outcome {
+            Ok(resp) if resp.status().is_success() => {}
+            Ok(resp) => tracing::warn!(
+                "Safety webhook {} (attempt {}/{}) for reservation {} got status {}",
+                job.id,
+                job.attempt,
+                MAX_DELIVERY_ATTEMPTS,
+                job.reservation_id,
+                resp.status()
+            ),
+            Err(e) => tracing::warn!(
+                "Safety webhook {} (attempt {}/{}) for reservation {} failed: {}",
+                job.id,
+                job.attempt,
+                MAX_DELIVERY_ATTEMPTS,
+                job.reservation_id,
+                e
+            ),
+        }
+
+        let mut jobs = self.jobs.lock().unwrap();
+        if delivered {
+            jobs.retain(|existing| existing.id != job.id);
+        } else if job.attempt >= MAX_DELIVERY_ATTEMPTS {
+            // Dead letter: every retry was exhausted. Logged and dropped
+            // rather than queued forever -- the next sweep that still finds
+            // this reservation unsafe will enqueue a fresh job anyway.
+            tracing::error!(
+                "Safety webhook {} to {} permanently failed after {} attempts; dropping update for reservation {}",
+                job.id,
+                job.url,
+                job.attempt,
+                job.reservation_id
+            );
+            jobs.retain(|existing| existing.id != job.id);
+        } else {
+            let backoff_secs =
+                (INITIAL_BACKOFF_SECS << (job.attempt - 1).min(10)).min(MAX_BACKOFF_SECS);
+            job.next_attempt_at = Utc::now() + chrono::Duration::seconds(backoff_secs);
+            if let Some(existing) = jobs.iter_mut().find(|existing| existing.id == job.id) {
+                *existing = job;
+            }
+        }
+        self.persist(&jobs);
+    }
+
+    fn persist(&self, jobs: &[DeliveryJob]) {
+        if let Some(path) = &self.persistence_path {
+            if let Err(err) = persist_queue(path, jobs) {
+                tracing::warn!(
+                    "safety_delivery_queue.persist.failure" = %err,
+                    "Failed to persist safety webhook delivery queue"
+                );
+            }
+        }
+    }
+}
+
+impl Default for SafetyDeliveryQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Loads a previously-persisted delivery queue. Returns `None` if the file
+/// doesn't exist or fails to parse -- the caller treats that the same as "no
+/// pending deliveries" rather than refusing to start.
+fn load_queue(path: &Path) -> Option<Vec<DeliveryJob>> {
+    let mut file = File::open(path).ok()?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Writes the whole queue out as a JSON array, same atomic
+/// temp-file-then-`rename` pattern as `alerts::persist_spool`, so a crash
+/// mid-write can never leave a truncated spool that fails to parse on the
+/// next `load_queue`.
+fn persist_queue(path: &Path, jobs: &[DeliveryJob]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let payload = serde_json::to_string(jobs)?;
+
+    let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(payload.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&tmp_path, path)
+}
+
+/// Tracks the last known verdict per reservation so the periodic sweep only
+/// enqueues a delivery on a transition (`safe_to_launch` flipping in either
+/// direction, or a change in conflict count), not on every sweep tick.
+pub struct SafetyMonitor {
+    webhooks: Arc<SafetyWebhookRegistry>,
+    queue: Arc<SafetyDeliveryQueue>,
+    last_state: Mutex<HashMap<Uuid, (bool, usize)>>,
+}
+
+impl SafetyMonitor {
+    pub fn new(webhooks: Arc<SafetyWebhookRegistry>, queue: Arc<SafetyDeliveryQueue>) -> Self {
+        Self {
+            webhooks,
+            queue,
+            last_state: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn sweep(
+        &self,
+        reservation_manager: &Arc<AsyncMutex<OrbitReservationManager>>,
+        satellite_api: &Arc<SatelliteApi>,
+    ) -> crate::tle::Result<()> {
+        let watched: Vec<OrbitReservation> = {
+            let manager = reservation_manager.lock().await;
+            manager
+                .list_reservations()
+                .into_iter()
+                .filter(|reservation| {
+                    matches!(
+                        reservation.status,
+                        ReservationStatus::Pending | ReservationStatus::Active
+                    )
+                })
+                .filter(|reservation| {
+                    !self.webhooks.list_for_reservation(reservation.id).is_empty()
+                })
+                .cloned()
+                .collect()
+        };
+
+        if watched.is_empty() {
+            return Ok(());
+        }
+
+        let catalog_positions = satellite_api.get_all_satellites(None, None).await?;
+        let tle_catalog = satellite_api.get_satellite_tle_catalog().await?;
+        let (satellite_data_catalog, unavailable) =
+            catalog_to_satellite_data(&catalog_positions, &tle_catalog);
+
+        for reservation in watched {
+            self.reassess(reservation, &satellite_data_catalog, &unavailable, reservation_manager)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    async fn reassess(
+        &self,
+        reservation: OrbitReservation,
+        satellite_data_catalog: &[crate::tle::SatelliteData],
+        propagation_unavailable: &[u64],
+        reservation_manager: &Arc<AsyncMutex<OrbitReservationManager>>,
+    ) {
+        let reservation_id = reservation.id;
+        let satellite_data_catalog = satellite_data_catalog.to_vec();
+        let manager_guard = reservation_manager.clone().lock_owned().await;
+
+        let assessment = tokio::task::spawn_blocking(move || {
+            let mut manager = manager_guard;
+            manager.check_reservation_conflicts(reservation_id, &satellite_data_catalog, SWEEP_TENANT)
+        })
+        .await;
+
+        let mut assessment = match assessment {
+            Ok(Ok(assessment)) => assessment,
+            Ok(Err(err)) => {
+                tracing::warn!(
+                    "Safety monitor failed to re-evaluate reservation {}: {}",
+                    reservation_id,
+                    err
+                );
+                return;
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Safety monitor re-evaluation for reservation {} panicked",
+                    reservation_id
+                );
+                return;
+            }
+        };
+        assessment.propagation_unavailable = propagation_unavailable.to_vec();
+
+        let (summary, safe_to_launch) =
+            OrbitReservationManager::summarize_feasibility(&reservation, &assessment);
+        let new_state = (safe_to_launch, assessment.conflicts_found);
+
+        let changed = {
+            let mut last_state = self.last_state.lock().unwrap();
+            let previous = last_state.insert(reservation_id, new_state);
+            // A reservation seen for the first time this process just
+            // establishes the baseline -- notifying here would re-announce
+            // every already-known-unsafe reservation on every restart.
+            matches!(previous, Some(previous) if previous != new_state)
+        };
+
+        if !changed {
+            return;
+        }
+
+        tracing::info!(
+            "Reservation {} safety verdict changed: safe_to_launch={}, conflicts_found={}",
+            reservation_id,
+            safe_to_launch,
+            assessment.conflicts_found
+        );
+
+        let report = ReservationSafetyReport {
+            safe_to_launch,
+            summary,
+            assessment,
+        };
+
+        for subscription in self.webhooks.list_for_reservation(reservation_id) {
+            self.queue.enqueue(reservation_id, subscription.url, &report);
+        }
+    }
+}
+
+/// Spawns the background task that periodically re-evaluates every watched
+/// reservation (one with at least one safety webhook subscription) against
+/// the latest catalog.
+pub fn spawn_safety_monitor(
+    reservation_manager: Arc<AsyncMutex<OrbitReservationManager>>,
+    satellite_api: Arc<SatelliteApi>,
+    monitor: Arc<SafetyMonitor>,
+    sweep_interval: StdDuration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(sweep_interval);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = monitor.sweep(&reservation_manager, &satellite_api).await {
+                tracing::warn!(
+                    "safety_monitor.sweep.failure" = %err,
+                    "Reservation safety sweep failed"
+                );
+            }
+        }
+    });
+}
+
+/// Spawns the background task that drains `queue` on a short tick, delivering
+/// (and retrying) queued safety webhook jobs.
+pub fn spawn_safety_delivery_worker(queue: Arc<SafetyDeliveryQueue>) {
+    tokio::spawn(async move {
+        let client = reqwest::Client::new();
+        let mut ticker = tokio::time::interval(StdDuration::from_secs(1));
+        loop {
+            ticker.tick().await;
+            queue.drain_due(&client);
+        }
+    });
+}
@@ -0,0 +1,126 @@
+// Typo-tolerant full-text search over a fetched `SatelliteData` catalog.
+// Exact NORAD ID lookup already exists via `SatelliteTracker`/`SatelliteApi`;
+// this module is for the "I don't know the NORAD number" case -- searching
+// by name or group/category from a UI search box.
+
+use crate::tle::SatelliteData;
+use crate::tracker::classify_groups;
+use std::collections::HashMap;
+
+/// Character edit distance between a query token and an indexed token still
+/// considered a fuzzy match, e.g. "starlnk" -> "starlink".
+const MAX_FUZZY_EDIT_DISTANCE: usize = 2;
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// Classic Levenshtein edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + substitution_cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// In-memory search index over a `SatelliteData` catalog, keyed on name
+/// tokens, NORAD ID (as a string), and group/category (see
+/// `tracker::classify_groups`). Rebuilt from scratch whenever the catalog
+/// changes -- there's no incremental update, matching how `SatelliteTracker`
+/// itself is rebuilt wholesale on every refresh (`load_satellites`) rather
+/// than patched in place.
+pub struct SatelliteIndex {
+    satellites: Vec<SatelliteData>,
+    /// token -> indices into `satellites` whose name, NORAD ID, or
+    /// group/category produced that token.
+    token_postings: HashMap<String, Vec<usize>>,
+}
+
+impl SatelliteIndex {
+    pub fn build(satellites: Vec<SatelliteData>) -> Self {
+        let mut token_postings: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (idx, sat) in satellites.iter().enumerate() {
+            let mut tokens = tokenize(&sat.name);
+            tokens.push(sat.norad_id.to_string());
+            tokens.extend(classify_groups(&sat.name).into_iter().map(str::to_string));
+
+            for token in tokens {
+                token_postings.entry(token).or_default().push(idx);
+            }
+        }
+
+        Self { satellites, token_postings }
+    }
+
+    /// Searches the index for `query`, returning up to `limit` satellites
+    /// ranked exact-NORAD match first, then by how many (query token,
+    /// indexed token) pairs match by prefix or fuzzy edit distance, then by
+    /// Levenshtein distance from `query` to the satellite's full name.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SatelliteData> {
+        let query_norm = query.trim().to_lowercase();
+        if query_norm.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let exact_norad_idx = query_norm
+            .parse::<u64>()
+            .ok()
+            .and_then(|norad_id| self.satellites.iter().position(|sat| sat.norad_id == norad_id));
+
+        let query_tokens = tokenize(&query_norm);
+        if query_tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matched_tokens: HashMap<usize, usize> = HashMap::new();
+        if let Some(idx) = exact_norad_idx {
+            matched_tokens.insert(idx, 0);
+        }
+        for query_token in &query_tokens {
+            for (token, indices) in &self.token_postings {
+                let is_match = token.starts_with(query_token.as_str())
+                    || query_token.starts_with(token.as_str())
+                    || levenshtein(token, query_token) <= MAX_FUZZY_EDIT_DISTANCE;
+                if !is_match {
+                    continue;
+                }
+                for &idx in indices {
+                    *matched_tokens.entry(idx).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut ranked: Vec<(usize, usize, usize)> = matched_tokens
+            .into_iter()
+            .map(|(idx, matched_token_count)| {
+                let name_distance = levenshtein(&query_norm, &self.satellites[idx].name.to_lowercase());
+                (idx, matched_token_count, name_distance)
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| {
+            (Some(b.0) == exact_norad_idx)
+                .cmp(&(Some(a.0) == exact_norad_idx))
+                .then(b.1.cmp(&a.1))
+                .then(a.2.cmp(&b.2))
+        });
+
+        ranked.into_iter().take(limit).map(|(idx, _, _)| self.satellites[idx].clone()).collect()
+    }
+}
@@ -0,0 +1,198 @@
+// SP3 precise-ephemeris ingestion for GNSS constellations. TLE+SGP4 is only
+// accurate to kilometers; SP3 products tabulate ECEF position (and usually
+// velocity) at fixed epochs (commercial/IGS products are typically 15-min
+// spaced) to centimeter-to-decimeter accuracy. `SatelliteTracker` prefers an
+// SP3 arc over SGP4 whenever the requested time falls inside it.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+struct Sp3Sample {
+    epoch: DateTime<Utc>,
+    position_km: (f64, f64, f64),
+    velocity_km_s: Option<(f64, f64, f64)>,
+}
+
+/// One satellite's loaded SP3 arc, sorted by epoch.
+#[derive(Debug, Clone, Default)]
+pub struct Sp3Ephemeris {
+    samples: Vec<Sp3Sample>,
+}
+
+/// Samples in the Lagrange fit, split evenly either side of the requested
+/// time except at the ends of the arc. SP3 products are usually 15-min spaced, so an 8-point window spans ~2h,
+/// comfortably inside the interval over which a low-order polynomial tracks
+/// true GNSS motion to sub-decimeter accuracy.
+const INTERPOLATION_ORDER: usize = 8;
+
+impl Sp3Ephemeris {
+    /// Builds an arc directly from caller-supplied samples rather than
+    /// parsing an SP3 file -- e.g. a reservation request that carries its
+    /// own precise-ephemeris table inline instead of a TLE. Samples are
+    /// sorted by epoch since `interpolate` assumes that ordering.
+    pub fn from_samples(
+        mut samples: Vec<(DateTime<Utc>, (f64, f64, f64), Option<(f64, f64, f64)>)>,
+    ) -> Self {
+        samples.sort_by_key(|(epoch, _, _)| *epoch);
+        Self {
+            samples: samples
+                .into_iter()
+                .map(|(epoch, position_km, velocity_km_s)| Sp3Sample {
+                    epoch,
+                    position_km,
+                    velocity_km_s,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn covers(&self, time: DateTime<Utc>) -> bool {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(first), Some(last)) => time >= first.epoch && time <= last.epoch,
+            _ => false,
+        }
+    }
+
+    /// Lagrange-interpolates position and velocity (km, km/s) at `time`
+    /// from the samples surrounding it. Returns `None` if `time` falls
+    /// outside the loaded arc or there aren't enough samples to interpolate.
+    pub fn interpolate(&self, time: DateTime<Utc>) -> Option<(f64, f64, f64, f64, f64, f64)> {
+        if !self.covers(time) || self.samples.len() < 2 {
+            return None;
+        }
+
+        let center = self.samples.partition_point(|s| s.epoch <= time);
+        let half = INTERPOLATION_ORDER / 2;
+        let start = center.saturating_sub(half);
+        let end = (start + INTERPOLATION_ORDER).min(self.samples.len());
+        let start = end.saturating_sub(INTERPOLATION_ORDER);
+        let window = &self.samples[start..end];
+
+        let xs: Vec<f64> = window.iter().map(|s| s.epoch.timestamp_millis() as f64).collect();
+        let x = time.timestamp_millis() as f64;
+
+        let positions: Vec<(f64, f64, f64)> = window.iter().map(|s| s.position_km).collect();
+        let (px, py, pz) = lagrange_interpolate(&xs, &positions, x);
+
+        if window.iter().all(|s| s.velocity_km_s.is_some()) {
+            let velocities: Vec<(f64, f64, f64)> =
+                window.iter().map(|s| s.velocity_km_s.unwrap()).collect();
+            let (vx, vy, vz) = lagrange_interpolate(&xs, &velocities, x);
+            Some((px, py, pz, vx, vy, vz))
+        } else {
+            // No recorded velocity (position-only SP3 product): differentiate
+            // the same Lagrange polynomial numerically over a 1-second step.
+            let step_ms = 1000.0;
+            let (px2, py2, pz2) = lagrange_interpolate(&xs, &positions, x + step_ms);
+            let dt_s = step_ms / 1000.0;
+            Some((px, py, pz, (px2 - px) / dt_s, (py2 - py) / dt_s, (pz2 - pz) / dt_s))
+        }
+    }
+}
+
+/// Classic Lagrange polynomial interpolation, evaluated independently per
+/// component of a 3-vector series.
+fn lagrange_interpolate(xs: &[f64], ys: &[(f64, f64, f64)], x: f64) -> (f64, f64, f64) {
+    let mut result = (0.0, 0.0, 0.0);
+    for i in 0..xs.len() {
+        let mut basis = 1.0;
+        for j in 0..xs.len() {
+            if i != j {
+                basis *= (x - xs[j]) / (xs[i] - xs[j]);
+            }
+        }
+        result.0 += basis * ys[i].0;
+        result.1 += basis * ys[i].1;
+        result.2 += basis * ys[i].2;
+    }
+    result
+}
+
+/// Parses an SP3(-c/-d) file into one ephemeris per satellite id as it
+/// appears in the file (e.g. `G01`, `R02`, `E05`). Callers map those ids to
+/// NORAD catalog numbers themselves, since that mapping isn't in the SP3
+/// records.
+///
+/// Only the epoch header lines (`*  yyyy mm dd hh mm ss.ssssssss`) and
+/// position/velocity records (`P<id> x y z [clk]`, `V<id> vx vy vz [rate]`)
+/// are interpreted; comment/header lines are ignored.
+pub fn parse_sp3(contents: &str) -> HashMap<String, Sp3Ephemeris> {
+    let mut ephemerides: HashMap<String, Sp3Ephemeris> = HashMap::new();
+    let mut current_epoch: Option<DateTime<Utc>> = None;
+
+    for line in contents.lines() {
+        if let Some(rest) = line.strip_prefix("* ") {
+            current_epoch = parse_epoch(rest);
+        } else if let Some(rest) = line.strip_prefix('P') {
+            let Some(epoch) = current_epoch else { continue };
+            if let Some((id, x, y, z)) = parse_vector_record(rest) {
+                ephemerides.entry(id).or_default().samples.push(Sp3Sample {
+                    epoch,
+                    position_km: (x, y, z),
+                    velocity_km_s: None,
+                });
+            }
+        } else if let Some(rest) = line.strip_prefix('V') {
+            let Some(epoch) = current_epoch else { continue };
+            if let Some((id, vx, vy, vz)) = parse_vector_record(rest) {
+                // SP3 velocities are in decimeters/sec (1 km = 10,000 dm).
+                if let Some(entry) = ephemerides.get_mut(&id) {
+                    if let Some(last) = entry.samples.last_mut() {
+                        if last.epoch == epoch {
+                            last.velocity_km_s = Some((vx / 10_000.0, vy / 10_000.0, vz / 10_000.0));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for ephemeris in ephemerides.values_mut() {
+        ephemeris.samples.sort_by_key(|s| s.epoch);
+    }
+
+    ephemerides
+}
+
+fn parse_epoch(rest: &str) -> Option<DateTime<Utc>> {
+    let parts: Vec<&str> = rest.split_whitespace().collect();
+    if parts.len() < 6 {
+        return None;
+    }
+    let year: i32 = parts[0].parse().ok()?;
+    let month: u32 = parts[1].parse().ok()?;
+    let day: u32 = parts[2].parse().ok()?;
+    let hour: u32 = parts[3].parse().ok()?;
+    let minute: u32 = parts[4].parse().ok()?;
+    let seconds: f64 = parts[5].parse().ok()?;
+
+    let whole_seconds = seconds.floor() as u32;
+    let nanos = ((seconds - whole_seconds as f64) * 1_000_000_000.0).round() as u32;
+
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let time = NaiveTime::from_hms_nano_opt(hour, minute, whole_seconds, nanos)?;
+    Some(Utc.from_utc_datetime(&date.and_time(time)))
+}
+
+/// Parses `<id><x><y><z>[extra]` where `id` is the leading 3 characters
+/// (e.g. `G01`) and the remaining fields are whitespace-separated km (or
+/// dm/s for velocity records).
+fn parse_vector_record(rest: &str) -> Option<(String, f64, f64, f64)> {
+    if rest.len() < 3 {
+        return None;
+    }
+    let id = rest[..3].trim().to_string();
+    let fields: Vec<&str> = rest[3..].split_whitespace().collect();
+    if fields.len() < 3 {
+        return None;
+    }
+    let x: f64 = fields[0].parse().ok()?;
+    let y: f64 = fields[1].parse().ok()?;
+    let z: f64 = fields[2].parse().ok()?;
+    Some((id, x, y, z))
+}
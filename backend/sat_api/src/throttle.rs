@@ -0,0 +1,196 @@
+//! Per-tenant request throttling for the expensive, O(n^2)-screening
+//! endpoints (`predict_risk`, `analyze_conjunctions`): a token-bucket rate
+//! limit (requests/minute) plus a concurrency cap (max in-flight analyses),
+//! both keyed by the tenant string `handlers::tenant_id_from_request`
+//! resolves. Without this, one noisy tenant hammering either endpoint can
+//! starve every other tenant sharing the same `conjunction_analyzer` lock.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Requests/minute + max-in-flight ceiling for one tenant.
+#[derive(Debug, Clone, Copy)]
+pub struct TenantLimits {
+    pub requests_per_minute: u32,
+    pub max_concurrent: u32,
+}
+
+impl TenantLimits {
+    pub const DEFAULT: TenantLimits = TenantLimits {
+        requests_per_minute: 60,
+        max_concurrent: 4,
+    };
+}
+
+/// Parses `THROTTLE_TENANT_OVERRIDES`-style config: comma-separated
+/// `tenant:requests_per_minute:max_concurrent` entries, e.g.
+/// `premium:600:16,trial:10:1`, so premium tenants can be given higher
+/// ceilings than `TenantLimits::DEFAULT` without a code change. Entries that
+/// don't parse are logged and skipped rather than failing startup.
+pub fn parse_overrides(raw: &str) -> HashMap<String, TenantLimits> {
+    let mut overrides = HashMap::new();
+    for entry in raw.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let parts: Vec<&str> = entry.split(':').collect();
+        let (tenant, requests_per_minute, max_concurrent) = match parts.as_slice() {
+            [tenant, rpm, concurrency] => (*tenant, rpm.parse::<u32>(), concurrency.parse::<u32>()),
+            _ => {
+                tracing::warn!("Ignoring malformed THROTTLE_TENANT_OVERRIDES entry: {}", entry);
+                continue;
+            }
+        };
+        match (requests_per_minute, max_concurrent) {
+            (Ok(requests_per_minute), Ok(max_concurrent)) => {
+                overrides.insert(
+                    tenant.to_string(),
+                    TenantLimits {
+                        requests_per_minute,
+                        max_concurrent,
+                    },
+                );
+            }
+            _ => tracing::warn!("Ignoring malformed THROTTLE_TENANT_OVERRIDES entry: {}", entry),
+        }
+    }
+    overrides
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64) -> Self {
+        Self {
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Refills lazily based on wall-clock time elapsed since the last call,
+    /// then tries to take one token. `None` on success; `Some(wait)` if
+    /// rate-limited, where `wait` is how long until a token is available.
+    fn try_take(&mut self, capacity: f64, refill_per_sec: f64) -> Option<Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_per_sec).min(capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            let deficit = 1.0 - self.tokens;
+            Some(Duration::from_secs_f64(deficit / refill_per_sec))
+        }
+    }
+
+    fn refund(&mut self, capacity: f64) {
+        self.tokens = (self.tokens + 1.0).min(capacity);
+    }
+}
+
+struct TenantState {
+    bucket: TokenBucket,
+    in_flight: u32,
+}
+
+/// Why `ThrottleRegistry::acquire` rejected a request.
+#[derive(Debug, Clone, Copy)]
+pub enum ThrottleRejection {
+    RateLimited { retry_after: Duration },
+    ConcurrencyLimited { retry_after: Duration },
+}
+
+/// RAII handle returned by a successful `acquire`. Releases the tenant's
+/// in-flight slot on drop, so an early return or panic in the handler can't
+/// leak a permit the way a manual decrement call could.
+pub struct ConcurrencyPermit {
+    registry: Arc<ThrottleRegistry>,
+    tenant_id: String,
+    pub limit: u32,
+    pub remaining: u32,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.registry.release(&self.tenant_id);
+    }
+}
+
+/// A small fixed backoff suggested to a concurrency-limited client: unlike
+/// the rate-limit bucket, there's no scheduled refill time to report --
+/// the slot frees up whenever the in-flight request it's waiting on
+/// finishes, which could be sooner or later than this.
+const CONCURRENCY_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+pub struct ThrottleRegistry {
+    default_limits: TenantLimits,
+    overrides: HashMap<String, TenantLimits>,
+    tenants: Mutex<HashMap<String, TenantState>>,
+}
+
+impl ThrottleRegistry {
+    pub fn new(default_limits: TenantLimits, overrides: HashMap<String, TenantLimits>) -> Self {
+        Self {
+            default_limits,
+            overrides,
+            tenants: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn limits_for(&self, tenant_id: &str) -> TenantLimits {
+        self.overrides
+            .get(tenant_id)
+            .copied()
+            .unwrap_or(self.default_limits)
+    }
+
+    /// Checks the rate limit, then the concurrency cap, for `tenant_id`.
+    /// Returns a permit that must be held for the lifetime of the request;
+    /// dropping it frees the tenant's in-flight slot. Takes `Arc<Self>` (call
+    /// as `data.throttle.clone().acquire(...)`) since the returned permit
+    /// needs its own owning handle back to the registry to release on drop.
+    pub fn acquire(
+        self: Arc<Self>,
+        tenant_id: &str,
+    ) -> Result<ConcurrencyPermit, ThrottleRejection> {
+        let limits = self.limits_for(tenant_id);
+        let capacity = limits.requests_per_minute.max(1) as f64;
+        let refill_per_sec = capacity / 60.0;
+
+        let mut tenants = self.tenants.lock().unwrap();
+        let state = tenants.entry(tenant_id.to_string()).or_insert_with(|| TenantState {
+            bucket: TokenBucket::new(capacity),
+            in_flight: 0,
+        });
+
+        if let Some(retry_after) = state.bucket.try_take(capacity, refill_per_sec) {
+            return Err(ThrottleRejection::RateLimited { retry_after });
+        }
+
+        if state.in_flight >= limits.max_concurrent {
+            state.bucket.refund(capacity);
+            return Err(ThrottleRejection::ConcurrencyLimited {
+                retry_after: CONCURRENCY_RETRY_AFTER,
+            });
+        }
+
+        state.in_flight += 1;
+        let remaining = state.bucket.tokens.floor() as u32;
+
+        Ok(ConcurrencyPermit {
+            registry: self,
+            tenant_id: tenant_id.to_string(),
+            limit: limits.requests_per_minute,
+            remaining,
+        })
+    }
+
+    fn release(&self, tenant_id: &str) {
+        if let Some(state) = self.tenants.lock().unwrap().get_mut(tenant_id) {
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+    }
+}
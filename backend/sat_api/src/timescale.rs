@@ -0,0 +1,151 @@
+// Explicit time-scale handling for satellite timestamps. TLE propagation and
+// the tracker's internal state always run in UTC, but GNSS constellations
+// each publish in their own native scale (GPS time, Galileo System Time,
+// BeiDou Time), none of which step for leap seconds the way UTC does. A
+// timestamp handed back to a GPS-facing caller without saying which scale
+// it's in is ambiguous by up to dozens of seconds.
+//
+// `TimeScale` converts between those scales and UTC. A `DateTime<Utc>` is
+// used purely as a tick-precise container in all of these conversions: its
+// wall-clock reading represents an instant in whichever scale is named, not
+// literal UTC, except when the scale itself is `Utc`.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeScale {
+    /// Coordinated Universal Time, stepped by leap seconds.
+    Utc,
+    /// International Atomic Time, the continuous scale everything else here
+    /// is ultimately converted through.
+    Tai,
+    /// Terrestrial Time: TAI plus a fixed 32.184s, the continuous uniform
+    /// scale used as the independent argument of solar-system ephemerides.
+    Tt,
+    /// GPS Time: TAI minus a fixed 19s, epoch 1980-01-06T00:00:00 UTC, never
+    /// stepped for leap seconds after that.
+    Gpst,
+    /// Galileo System Time: by design kept within nanoseconds of GPST in
+    /// steady state, so treated as TAI minus the same fixed 19s offset.
+    Gst,
+    /// BeiDou Time: TAI minus a fixed 33s (the TAI-UTC offset in effect at
+    /// its 2006-01-01T00:00:00 UTC epoch), never stepped afterward.
+    Bdt,
+}
+
+/// TAI-UTC offset (whole leap seconds) introduced on or after each date,
+/// per the IERS Bulletin C leap-second history. No leap second has been
+/// introduced since 2017-01-01, so `37` is also today's offset.
+const LEAP_SECONDS: &[(i32, u32, u32, i64)] = &[
+    (1972, 1, 1, 10),
+    (1972, 7, 1, 11),
+    (1973, 1, 1, 12),
+    (1974, 1, 1, 13),
+    (1975, 1, 1, 14),
+    (1976, 1, 1, 15),
+    (1977, 1, 1, 16),
+    (1978, 1, 1, 17),
+    (1979, 1, 1, 18),
+    (1980, 1, 1, 19),
+    (1981, 7, 1, 20),
+    (1982, 7, 1, 21),
+    (1983, 7, 1, 22),
+    (1985, 7, 1, 23),
+    (1988, 1, 1, 24),
+    (1990, 1, 1, 25),
+    (1991, 1, 1, 26),
+    (1992, 7, 1, 27),
+    (1993, 7, 1, 28),
+    (1994, 7, 1, 29),
+    (1996, 1, 1, 30),
+    (1997, 7, 1, 31),
+    (1999, 1, 1, 32),
+    (2006, 1, 1, 33),
+    (2009, 1, 1, 34),
+    (2012, 7, 1, 35),
+    (2015, 7, 1, 36),
+    (2017, 1, 1, 37),
+];
+
+/// Fixed TAI-minus-scale offset for constellations that don't step for leap
+/// seconds once their epoch has passed.
+const GPS_GALILEO_TAI_OFFSET_SECONDS: i64 = 19;
+const BEIDOU_TAI_OFFSET_SECONDS: i64 = 33;
+
+fn tai_minus_utc_seconds(at: DateTime<Utc>) -> i64 {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(y, m, d, _)| at >= Utc.with_ymd_and_hms(*y, *m, *d, 0, 0, 0).unwrap())
+        .map(|(_, _, _, offset)| *offset)
+        .unwrap_or(10)
+}
+
+fn utc_to_tai(utc: DateTime<Utc>) -> DateTime<Utc> {
+    utc + Duration::seconds(tai_minus_utc_seconds(utc))
+}
+
+fn tai_to_utc(tai: DateTime<Utc>) -> DateTime<Utc> {
+    // Leap-second boundaries are defined in UTC; evaluating the table at
+    // the TAI instant instead is off by at most one leap second within a
+    // few tens of seconds of an actual leap-second insertion, which is rare
+    // enough to accept here rather than iterating to a fixed point.
+    tai - Duration::seconds(tai_minus_utc_seconds(tai))
+}
+
+/// Whole leap seconds TAI is ahead of UTC at `at` (the `TAI-UTC` value from
+/// the IERS Bulletin C table above), exposed for callers that need the raw
+/// leap-second count itself rather than a full scale conversion (e.g. GNSS
+/// constellation-health reporting).
+pub fn leap_seconds_at(at: DateTime<Utc>) -> i64 {
+    tai_minus_utc_seconds(at)
+}
+
+/// TT is TAI plus this fixed offset, inherited from the historical ephemeris
+/// time scale TT replaced; it never steps for leap seconds.
+const TT_TAI_OFFSET_MILLIS: i64 = 32_184;
+
+/// Next IERS-announced leap second, if any is currently scheduled. `None`
+/// here reflects the state of the embedded `LEAP_SECONDS` table above (no
+/// leap second announced since the 2017-01-01 entry) rather than a live
+/// check against IERS Bulletin C -- update both together when a new leap
+/// second is announced.
+const NEXT_ANNOUNCED_LEAP: Option<(i32, u32, u32)> = None;
+
+/// The next announced leap-second insertion instant, if one is currently
+/// scheduled. See `NEXT_ANNOUNCED_LEAP` for how this table is maintained.
+pub fn planned_leap_second() -> Option<DateTime<Utc>> {
+    NEXT_ANNOUNCED_LEAP.map(|(y, m, d)| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap())
+}
+
+impl TimeScale {
+    /// Interprets `value`'s wall-clock reading as an instant in `self` and
+    /// returns the equivalent true UTC instant.
+    pub fn to_utc(self, value: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            TimeScale::Utc => value,
+            TimeScale::Tai => tai_to_utc(value),
+            TimeScale::Tt => tai_to_utc(value - Duration::milliseconds(TT_TAI_OFFSET_MILLIS)),
+            TimeScale::Gpst | TimeScale::Gst => {
+                tai_to_utc(value + Duration::seconds(GPS_GALILEO_TAI_OFFSET_SECONDS))
+            }
+            TimeScale::Bdt => tai_to_utc(value + Duration::seconds(BEIDOU_TAI_OFFSET_SECONDS)),
+        }
+    }
+
+    /// Converts a true UTC instant into the equivalent wall-clock reading
+    /// in `self`.
+    pub fn from_utc(self, utc: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            TimeScale::Utc => utc,
+            TimeScale::Tai => utc_to_tai(utc),
+            TimeScale::Tt => utc_to_tai(utc) + Duration::milliseconds(TT_TAI_OFFSET_MILLIS),
+            TimeScale::Gpst | TimeScale::Gst => {
+                utc_to_tai(utc) - Duration::seconds(GPS_GALILEO_TAI_OFFSET_SECONDS)
+            }
+            TimeScale::Bdt => utc_to_tai(utc) - Duration::seconds(BEIDOU_TAI_OFFSET_SECONDS),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +10,11 @@ pub struct SatelliteData {
     pub name: String,
     pub tle_line1: String,
     pub tle_line2: String,
+    /// The epoch the TLE itself claims (parsed from line 1 columns
+    /// `18..32`), as opposed to `last_updated` which is when we fetched or
+    /// parsed it. Used to pick the freshest element set when two sources
+    /// report the same `norad_id`.
+    pub epoch: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
 }
 
@@ -56,42 +63,186 @@ pub enum SatApiError {
 
 pub type Result<T> = std::result::Result<T, SatApiError>;
 
+/// One upstream TLE feed: a short name for logging/health plus the URL to
+/// fetch. `fetch_active_satellites`/`fetch_communication_satellites`/
+/// `fetch_navigation_satellites` each declare their own array of
+/// `TleSource` instead of a bare URL list, so a failed fetch's log line names the feed
+/// (e.g. `"iridium"`) rather than just its URL.
+pub struct TleSource {
+    pub name: &'static str,
+    pub url: &'static str,
+}
+
+/// Collapses duplicate NORAD ids across sources by keeping the element set
+/// with the newest `epoch`, rather than the blind `dedup_by_key` that used
+/// to arbitrarily keep whichever duplicate happened to sort later.
+pub fn dedup_keep_newest_epoch(mut satellites: Vec<SatelliteData>) -> Vec<SatelliteData> {
+    satellites.sort_by(|a, b| a.norad_id.cmp(&b.norad_id).then_with(|| b.epoch.cmp(&a.epoch)));
+    satellites.dedup_by_key(|s| s.norad_id);
+    satellites
+}
+
+/// On-disk cache entry for a single group URL: the last-good parsed
+/// satellite set plus the validators needed to make a conditional GET
+/// (`If-None-Match`/`If-Modified-Since`) next time, so a Celestrak rate
+/// limit or DNS hiccup doesn't wipe out `fetch_gps`/`fetch_starlink`/etc.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedTleEntry {
+    satellites: Vec<SatelliteData>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched_at: DateTime<Utc>,
+}
+
+impl CachedTleEntry {
+    /// True when this cache entry should be refreshed rather than served:
+    /// either its wall-clock age exceeds `CACHE_TTL_SECONDS`, or every
+    /// satellite it holds has a parsed TLE `epoch` older than
+    /// `MAX_EPOCH_AGE_DAYS` -- a cache entry can be wall-clock "fresh" (we
+    /// fetched it five minutes ago) while still serving orbits whose epoch
+    /// is weeks old if Celestrak itself hasn't updated that GP set, and
+    /// SGP4 accuracy degrades badly past a few days from epoch.
+    fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        let age_seconds = now.signed_duration_since(self.fetched_at).num_seconds();
+        if age_seconds < 0 || age_seconds >= CACHE_TTL_SECONDS {
+            return true;
+        }
+
+        match self.satellites.iter().map(|s| s.epoch).max() {
+            Some(newest_epoch) => now.signed_duration_since(newest_epoch) > Duration::days(MAX_EPOCH_AGE_DAYS),
+            None => true,
+        }
+    }
+}
+
+const CACHE_TTL_SECONDS: i64 = 2 * 60 * 60; // GP sets update a few times daily
+const MAX_EPOCH_AGE_DAYS: i64 = 3; // SGP4 accuracy degrades badly past this
+
+fn cache_dir() -> PathBuf {
+    PathBuf::from(std::env::var("TLE_CACHE_DIR").unwrap_or_else(|_| ".tle_cache".to_string()))
+}
+
+fn cache_path(group_key: &str) -> PathBuf {
+    cache_dir().join(format!("{group_key}.json"))
+}
+
+/// Turns a Celestrak GP URL into a filesystem-safe cache key, e.g.
+/// `GROUP=gps-ops&FORMAT=tle` -> `GROUP_gps-ops_FORMAT_tle`.
+fn sanitize_cache_key(url: &str) -> String {
+    url.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect()
+}
+
+fn load_cache(group_key: &str) -> Option<CachedTleEntry> {
+    let bytes = std::fs::read(cache_path(group_key)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn save_cache(group_key: &str, entry: &CachedTleEntry) {
+    let dir = cache_dir();
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        tracing::warn!("Failed to create TLE cache dir {:?}: {}", dir, err);
+        return;
+    }
+    match serde_json::to_vec(entry) {
+        Ok(bytes) => {
+            if let Err(err) = std::fs::write(cache_path(group_key), bytes) {
+                tracing::warn!("Failed to write TLE cache for {}: {}", group_key, err);
+            }
+        }
+        Err(err) => tracing::warn!("Failed to serialize TLE cache for {}: {}", group_key, err),
+    }
+}
+
+/// A `reqwest::dns::Resolve` implementation that checks a static
+/// host→IP override table first (for captive/flaky-DNS deployments), then
+/// falls back to a caching trust-dns resolver.
+#[derive(Clone)]
+struct OverridingResolver {
+    overrides: HashMap<String, std::net::IpAddr>,
+    resolver: std::sync::Arc<trust_dns_resolver::TokioAsyncResolver>,
+}
+
+impl OverridingResolver {
+    fn new() -> Self {
+        let overrides = std::env::var("TLE_DNS_OVERRIDES")
+            .ok()
+            .map(|raw| {
+                raw.split(',')
+                    .filter_map(|pair| {
+                        let (host, ip) = pair.split_once('=')?;
+                        Some((host.trim().to_string(), ip.trim().parse().ok()?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let resolver = trust_dns_resolver::TokioAsyncResolver::tokio(
+            trust_dns_resolver::config::ResolverConfig::default(),
+            trust_dns_resolver::config::ResolverOpts::default(),
+        );
+
+        Self { overrides, resolver: std::sync::Arc::new(resolver) }
+    }
+}
+
+impl reqwest::dns::Resolve for OverridingResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let overrides = self.overrides.clone();
+        let resolver = self.resolver.clone();
+        Box::pin(async move {
+            let host = name.as_str();
+            if let Some(ip) = overrides.get(host) {
+                let addr: std::net::SocketAddr = (*ip, 0).into();
+                return Ok(Box::new(std::iter::once(addr)) as reqwest::dns::Addrs);
+            }
+            let lookup = resolver.lookup_ip(host).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            let addrs: Vec<std::net::SocketAddr> = lookup.iter().map(|ip| (ip, 0).into()).collect();
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
 pub struct TleFetcher {
     client: reqwest::Client,
 }
 
 impl TleFetcher {
     pub fn new() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-        }
+        let client = reqwest::Client::builder()
+            .gzip(true)
+            .dns_resolver(std::sync::Arc::new(OverridingResolver::new()))
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self { client }
     }
 
     pub async fn fetch_active_satellites(&self) -> Result<Vec<SatelliteData>> {
         // Modern Celestrak GP API URLs
-        let urls = [
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=active&FORMAT=tle", // All active satellites
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=visual&FORMAT=tle", // Bright satellites
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=weather&FORMAT=tle", // Weather satellites
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=science&FORMAT=tle", // Science satellites
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=resource&FORMAT=tle", // Earth resource
+        let sources = [
+            TleSource { name: "active", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=active&FORMAT=tle" },
+            TleSource { name: "visual", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=visual&FORMAT=tle" },
+            TleSource { name: "weather", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=weather&FORMAT=tle" },
+            TleSource { name: "science", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=science&FORMAT=tle" },
+            TleSource { name: "resource", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=resource&FORMAT=tle" },
         ];
 
         let mut all_satellites = Vec::new();
 
-        for url in &urls {
-            match self.fetch_tle_data(url).await {
+        for source in &sources {
+            match self.fetch_tle_data(source.url).await {
                 Ok(mut sats) => {
-                    tracing::info!("✅ Found {} satellites from {}", sats.len(), url);
+                    tracing::info!("✅ Found {} satellites from '{}'", sats.len(), source.name);
                     all_satellites.append(&mut sats);
                 }
-                Err(e) => tracing::warn!("❌ Failed to fetch from {}: {}", url, e),
+                Err(e) => tracing::warn!("❌ Failed to fetch from '{}': {}", source.name, e),
             }
         }
 
-        // Remove duplicates based on NORAD ID
-        all_satellites.sort_by_key(|s| s.norad_id);
-        all_satellites.dedup_by_key(|s| s.norad_id);
+        // Keep the freshest element set per NORAD ID across the sub-feeds
+        let all_satellites = dedup_keep_newest_epoch(all_satellites);
 
         tracing::info!(
             "🛰️ Total unique active satellites collected: {}",
@@ -132,62 +283,60 @@ impl TleFetcher {
     }
 
     pub async fn fetch_communication_satellites(&self) -> Result<Vec<SatelliteData>> {
-        let urls = [
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=geo&FORMAT=tle", // Geostationary
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=intelsat&FORMAT=tle", // Intelsat
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=iridium&FORMAT=tle", // Iridium
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=globalstar&FORMAT=tle", // Globalstar
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=ses&FORMAT=tle", // SES
+        let sources = [
+            TleSource { name: "geo", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=geo&FORMAT=tle" },
+            TleSource { name: "intelsat", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=intelsat&FORMAT=tle" },
+            TleSource { name: "iridium", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=iridium&FORMAT=tle" },
+            TleSource { name: "globalstar", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=globalstar&FORMAT=tle" },
+            TleSource { name: "ses", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=ses&FORMAT=tle" },
         ];
 
         let mut all_satellites = Vec::new();
 
-        for url in &urls {
-            match self.fetch_tle_data(url).await {
+        for source in &sources {
+            match self.fetch_tle_data(source.url).await {
                 Ok(mut sats) => {
                     tracing::info!(
-                        "✅ Found {} communication satellites from {}",
+                        "✅ Found {} communication satellites from '{}'",
                         sats.len(),
-                        url
+                        source.name
                     );
                     all_satellites.append(&mut sats);
                 }
                 Err(e) => {
-                    tracing::warn!("❌ Failed to fetch communication sats from {}: {}", url, e)
+                    tracing::warn!("❌ Failed to fetch communication sats from '{}': {}", source.name, e)
                 }
             }
         }
 
-        // Remove duplicates
-        all_satellites.sort_by_key(|s| s.norad_id);
-        all_satellites.dedup_by_key(|s| s.norad_id);
+        // Keep the freshest element set per NORAD ID across the sub-feeds
+        let all_satellites = dedup_keep_newest_epoch(all_satellites);
 
         Ok(all_satellites)
     }
 
     pub async fn fetch_navigation_satellites(&self) -> Result<Vec<SatelliteData>> {
-        let urls = [
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=gps-ops&FORMAT=tle", // GPS operational
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=glonass-ops&FORMAT=tle", // GLONASS operational
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=galileo&FORMAT=tle",     // Galileo
-            "https://celestrak.org/NORAD/elements/gp.php?GROUP=beidou&FORMAT=tle",      // BeiDou
+        let sources = [
+            TleSource { name: "gps-ops", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=gps-ops&FORMAT=tle" },
+            TleSource { name: "glonass-ops", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=glonass-ops&FORMAT=tle" },
+            TleSource { name: "galileo", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=galileo&FORMAT=tle" },
+            TleSource { name: "beidou", url: "https://celestrak.org/NORAD/elements/gp.php?GROUP=beidou&FORMAT=tle" },
         ];
 
         let mut all_satellites = Vec::new();
 
-        for url in &urls {
-            match self.fetch_tle_data(url).await {
+        for source in &sources {
+            match self.fetch_tle_data(source.url).await {
                 Ok(mut sats) => {
-                    tracing::info!("✅ Found {} navigation satellites from {}", sats.len(), url);
+                    tracing::info!("✅ Found {} navigation satellites from '{}'", sats.len(), source.name);
                     all_satellites.append(&mut sats);
                 }
-                Err(e) => tracing::warn!("❌ Failed to fetch navigation sats from {}: {}", url, e),
+                Err(e) => tracing::warn!("❌ Failed to fetch navigation sats from '{}': {}", source.name, e),
             }
         }
 
-        // Remove duplicates
-        all_satellites.sort_by_key(|s| s.norad_id);
-        all_satellites.dedup_by_key(|s| s.norad_id);
+        // Keep the freshest element set per NORAD ID across the sub-feeds
+        let all_satellites = dedup_keep_newest_epoch(all_satellites);
 
         tracing::info!(
             "🧭 Total unique navigation satellites: {}",
@@ -196,91 +345,299 @@ impl TleFetcher {
         Ok(all_satellites)
     }
 
+    /// Fetches the current TLE for a single NORAD id via Celestrak's
+    /// per-satellite CATNR endpoint, for callers that need just one
+    /// satellite (a manual refresh, or a fallback trigger) rather than an
+    /// entire bulk group.
+    pub async fn fetch_by_catnr(&self, norad_id: u64) -> Result<Option<SatelliteData>> {
+        let url = format!("https://celestrak.org/NORAD/elements/gp.php?CATNR={norad_id}&FORMAT=tle");
+        let satellites = self.fetch_tle_data(&url).await?;
+        Ok(satellites.into_iter().find(|s| s.norad_id == norad_id))
+    }
+
+    /// Fetches `url`, keyed in the on-disk cache by the URL itself (sanitized
+    /// for use as a filename). Sends `If-None-Match`/`If-Modified-Since` when
+    /// a cached entry exists; serves the cache on `304` or on any network
+    /// failure rather than returning an error and leaving callers with
+    /// nothing.
     async fn fetch_tle_data(&self, url: &str) -> Result<Vec<SatelliteData>> {
         tracing::info!("Fetching TLE data from: {}", url);
+        let group_key = sanitize_cache_key(url);
+        let cached = load_cache(&group_key);
+
+        if let Some(cached) = &cached {
+            if !cached.is_stale(Utc::now()) {
+                tracing::debug!("Serving TLE set for {} from cache (not yet stale)", url);
+                return Ok(cached.satellites.clone());
+            }
+        }
+
+        let mut request = self.client.get(url);
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = match request.send().await {
+            Ok(resp) => resp,
+            Err(err) => {
+                if let Some(cached) = cached {
+                    tracing::warn!("Request to {} failed ({}); serving cached TLE set", url, err);
+                    return Ok(cached.satellites);
+                }
+                return Err(err.into());
+            }
+        };
 
-        let response = self.client.get(url).send().await?;
         let status = response.status();
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                tracing::debug!("{} returned 304; serving cached TLE set", url);
+                return Ok(cached.satellites);
+            }
+        }
 
         if !status.is_success() {
             tracing::error!("HTTP request failed with status: {}", status);
+            if let Some(cached) = cached {
+                tracing::warn!("Falling back to cached TLE set for {} after HTTP {}", url, status);
+                return Ok(cached.satellites);
+            }
             return Err(SatApiError::TleParseError(format!(
                 "HTTP {} from {}",
                 status, url
             )));
         }
 
+        let etag = response.headers().get(reqwest::header::ETAG).and_then(|v| v.to_str().ok()).map(str::to_string);
+        let last_modified = response
+            .headers()
+            .get(reqwest::header::LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
         let text = response.text().await?;
         tracing::debug!("Received {} bytes of TLE data from {}", text.len(), url);
 
         if text.is_empty() {
-            tracing::warn!("Empty response from {}", url);
-            return Ok(Vec::new());
+            tracing::warn!("Empty response from {}; leaving cached TLE set (if any) in place", url);
+            return Ok(cached.map(|c| c.satellites).unwrap_or_default());
         }
 
-        self.parse_tle_text(&text)
+        let satellites = self.parse_tle_text(&text)?;
+
+        // Only bump the cached timestamp when the fetch actually yielded
+        // satellites, so a response that parses to nothing doesn't wipe out
+        // good cached data and make the next call think it's still fresh.
+        if satellites.is_empty() {
+            tracing::warn!("Parsed zero satellites from {}; leaving cached TLE set (if any) in place", url);
+            return Ok(cached.map(|c| c.satellites).unwrap_or_default());
+        }
+
+        save_cache(&group_key, &CachedTleEntry {
+            satellites: satellites.clone(),
+            etag,
+            last_modified,
+            fetched_at: Utc::now(),
+        });
+        Ok(satellites)
     }
 
     fn parse_tle_text(&self, text: &str) -> Result<Vec<SatelliteData>> {
-        let lines: Vec<&str> = text
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect();
+        parse_tle_lines(text)
+    }
+}
 
-        let mut satellites = Vec::new();
-        let now = Utc::now();
+/// Validates a TLE line's checksum digit (column 69): the sum of every
+/// digit in the line mod 10, treating `-` as 1 and every other non-digit
+/// character as 0, must equal the line's final digit. Catches a corrupted
+/// or truncated feed before a garbled orbit reaches the catalog.
+fn tle_checksum_valid(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    if bytes.len() < 69 {
+        return false;
+    }
+
+    let expected = match bytes[68] {
+        b'0'..=b'9' => (bytes[68] - b'0') as u32,
+        _ => return false,
+    };
+
+    let sum: u32 = bytes[..68]
+        .iter()
+        .map(|&b| match b {
+            b'0'..=b'9' => (b - b'0') as u32,
+            b'-' => 1,
+            _ => 0,
+        })
+        .sum();
+
+    sum % 10 == expected
+}
+
+/// Parses the epoch embedded in TLE line 1: zero-indexed characters
+/// `18..20` are the two-digit epoch year (`00..=56` => 2000-2056, else
+/// 1900+) and `20..32` are the day-of-year plus fractional day. Returns
+/// `None` (rather than a default) on anything malformed, so callers can
+/// fall back to their own "last resort" timestamp explicitly.
+fn parse_tle_epoch(line1: &str) -> Option<DateTime<Utc>> {
+    let year_2digit: i32 = line1.get(18..20)?.trim().parse().ok()?;
+    let year = if year_2digit <= 56 { 2000 + year_2digit } else { 1900 + year_2digit };
+
+    let day_of_year_frac: f64 = line1.get(20..32)?.trim().parse().ok()?;
+    if day_of_year_frac < 1.0 {
+        return None;
+    }
+
+    let day_of_year = day_of_year_frac.floor() as i64;
+    let fractional_day = day_of_year_frac - day_of_year_frac.floor();
+
+    let date = NaiveDate::from_ymd_opt(year, 1, 1)?.checked_add_signed(Duration::days(day_of_year - 1))?;
+
+    let nanos_in_day = (fractional_day * 86_400_000_000_000.0).round() as i64;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanos_in_day / 1_000_000_000) as u32,
+        (nanos_in_day % 1_000_000_000) as u32,
+    )?;
+
+    Some(Utc.from_utc_datetime(&date.and_time(time)))
+}
 
-        tracing::debug!("Processing {} lines of TLE data", lines.len());
-
-        // More flexible TLE parsing - look for TLE line pairs
-        let mut i = 0;
-        while i < lines.len() {
-            let line = lines[i];
-
-            // Look for TLE line 1 (starts with '1 ')
-            if line.starts_with("1 ") && line.len() >= 69 {
-                // Found line 1, look for corresponding line 2
-                if i + 1 < lines.len() {
-                    let line2 = lines[i + 1];
-                    if line2.starts_with("2 ") && line2.len() >= 69 {
-                        // Extract NORAD ID from line 1 (positions 2-7)
-                        let norad_str = &line[2..7].trim();
-                        if let Ok(norad_id) = norad_str.parse::<u64>() {
-                            // Look for satellite name (could be line before, or generate from NORAD ID)
-                            let name = if i > 0
-                                && !lines[i - 1].starts_with("1 ")
-                                && !lines[i - 1].starts_with("2 ")
-                            {
-                                lines[i - 1].to_string()
-                            } else {
-                                format!("NORAD {}", norad_id)
-                            };
-
-                            satellites.push(SatelliteData {
-                                norad_id,
-                                name,
-                                tle_line1: line.to_string(),
-                                tle_line2: line2.to_string(),
-                                last_updated: now,
-                            });
-                        }
-                        i += 2; // Skip both TLE lines
+fn parse_tle_lines(text: &str) -> Result<Vec<SatelliteData>> {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    let mut satellites = Vec::new();
+    let now = Utc::now();
+
+    tracing::debug!("Processing {} lines of TLE data", lines.len());
+
+    // More flexible TLE parsing - look for TLE line pairs
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+
+        // Look for TLE line 1 (starts with '1 ')
+        if line.starts_with("1 ") && line.len() >= 69 {
+            // Found line 1, look for corresponding line 2
+            if i + 1 < lines.len() {
+                let line2 = lines[i + 1];
+                if line2.starts_with("2 ") && line2.len() >= 69 {
+                    if !tle_checksum_valid(line) || !tle_checksum_valid(line2) {
+                        tracing::warn!(
+                            "Discarding TLE pair at line {} with invalid checksum",
+                            i
+                        );
+                        i += 2;
                         continue;
                     }
+
+                    // Extract NORAD ID from line 1 (positions 2-7)
+                    let norad_str = &line[2..7].trim();
+                    if let Ok(norad_id) = norad_str.parse::<u64>() {
+                        // Look for satellite name (could be line before, or generate from NORAD ID)
+                        let name = if i > 0
+                            && !lines[i - 1].starts_with("1 ")
+                            && !lines[i - 1].starts_with("2 ")
+                        {
+                            lines[i - 1].to_string()
+                        } else {
+                            format!("NORAD {}", norad_id)
+                        };
+
+                        satellites.push(SatelliteData {
+                            norad_id,
+                            name,
+                            tle_line1: line.to_string(),
+                            tle_line2: line2.to_string(),
+                            epoch: parse_tle_epoch(line).unwrap_or(now),
+                            last_updated: now,
+                        });
+                    }
+                    i += 2; // Skip both TLE lines
+                    continue;
                 }
             }
-            i += 1;
         }
+        i += 1;
+    }
 
-        tracing::info!("Parsed {} satellites from TLE data", satellites.len());
-        if satellites.is_empty() {
-            tracing::warn!("No satellites parsed. First few lines of data:");
-            for (idx, line) in lines.iter().take(10).enumerate() {
-                tracing::warn!("Line {}: '{}'", idx, line);
-            }
+    tracing::info!("Parsed {} satellites from TLE data", satellites.len());
+    if satellites.is_empty() {
+        tracing::warn!("No satellites parsed. First few lines of data:");
+        for (idx, line) in lines.iter().take(10).enumerate() {
+            tracing::warn!("Line {}: '{}'", idx, line);
         }
+    }
 
-        Ok(satellites)
+    Ok(satellites)
+}
+
+/// Self-contained N2YO fallback client, used only when Celestrak's CATNR
+/// lookup for a requested NORAD id fails or returns nothing (see
+/// `SatelliteApi::refresh_single_satellite`). Deliberately doesn't
+/// depend on the sibling `backend` crate's `N2YOService` -- that crate is a
+/// separate, `anyhow`-based error domain with no established dependency
+/// relationship to `sat_api` -- so this mirrors `TleFetcher`'s own
+/// `reqwest::Client` conventions instead.
+pub struct N2yoFallback {
+    client: reqwest::Client,
+    api_key: Option<String>,
+}
+
+impl N2yoFallback {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_key: std::env::var("N2YO_API_KEY").ok(),
+        }
+    }
+
+    /// Looks up a single satellite's current TLE from N2YO. Returns
+    /// `Ok(None)` (rather than an error) both when no API key is configured
+    /// and when N2YO has nothing for this satellite, so callers can treat
+    /// "fallback unavailable" the same as "fallback found nothing".
+    pub async fn get_tle(&self, norad_id: u64) -> Result<Option<SatelliteData>> {
+        let Some(api_key) = &self.api_key else {
+            return Ok(None);
+        };
+
+        let url = format!("https://api.n2yo.com/rest/v1/satellite/tle/{norad_id}&apiKey={api_key}");
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        let Some(raw_tle) = body.get("tle").and_then(|v| v.as_str()) else {
+            return Ok(None);
+        };
+
+        let lines: Vec<&str> = raw_tle
+            .split(['\r', '\n'])
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        if lines.len() < 2 || !lines[0].starts_with("1 ") || !lines[1].starts_with("2 ") {
+            return Ok(None);
+        }
+        let (line1, line2) = (lines[0], lines[1]);
+
+        let now = Utc::now();
+        Ok(Some(SatelliteData {
+            norad_id,
+            name: format!("NORAD {norad_id}"),
+            tle_line1: line1.to_string(),
+            tle_line2: line2.to_string(),
+            epoch: parse_tle_epoch(line1).unwrap_or(now),
+            last_updated: now,
+        }))
     }
 }
@@ -1,3 +1,4 @@
+use crate::sp3::Sp3Ephemeris;
 use crate::tle::{Result, RiskLevel, SatApiError, SatelliteData, SatellitePosition};
 use chrono::{DateTime, Utc};
 use sgp4::{Constants as Sgp4Constants, Elements as Sgp4Elements};
@@ -12,15 +13,40 @@ struct TrackedSatellite {
 
 pub struct SatelliteTracker {
     satellites: HashMap<u64, TrackedSatellite>,
+    /// Optional precise-ephemeris arcs, preferred over SGP4 for a satellite
+    /// when the requested time falls inside the loaded arc.
+    sp3: HashMap<u64, Sp3Ephemeris>,
+    /// UT1-UTC (seconds), as last passed to `set_ut1_utc_offset`.
+    /// GMST is properly a function of UT1, not UTC; this defaults to 0.0
+    /// (treating UT1 as UTC), which is within a second of correct and is
+    /// what this tracker did implicitly before this field existed.
+    ut1_utc_offset_seconds: f64,
 }
 
 impl SatelliteTracker {
     pub fn new() -> Self {
         Self {
             satellites: HashMap::new(),
+            sp3: HashMap::new(),
+            ut1_utc_offset_seconds: 0.0,
         }
     }
 
+    /// Sets the UT1-UTC offset (seconds) applied when converting propagated
+    /// ECI positions to ECEF via GMST. Callers should refresh this from a
+    /// current EOP/Bulletin A table; it otherwise stays at whatever was last
+    /// set (default 0.0).
+    pub fn set_ut1_utc_offset(&mut self, offset_seconds: f64) {
+        self.ut1_utc_offset_seconds = offset_seconds;
+    }
+
+    /// Loads (or replaces) a precise-ephemeris arc for `norad_id`. Future
+    /// propagation requests for this satellite within the arc's time span
+    /// are served from SP3 interpolation instead of SGP4.
+    pub fn load_sp3(&mut self, norad_id: u64, ephemeris: Sp3Ephemeris) {
+        self.sp3.insert(norad_id, ephemeris);
+    }
+
     pub fn load_satellites(&mut self, satellite_data: Vec<SatelliteData>) -> Result<()> {
         self.satellites.clear();
 
@@ -80,7 +106,7 @@ impl SatelliteTracker {
         let mut positions = Vec::new();
 
         for (norad_id, tracked_sat) in &self.satellites {
-            match self.propagate_satellite(tracked_sat, &now) {
+            match self.propagate_satellite(*norad_id, tracked_sat, &now) {
                 Ok(mut pos) => {
                     pos.norad_id = *norad_id;
                     pos.name = tracked_sat.data.name.clone();
@@ -104,7 +130,7 @@ impl SatelliteTracker {
         let mut positions = Vec::new();
 
         for (norad_id, tracked_sat) in &self.satellites {
-            match self.propagate_satellite(tracked_sat, &time) {
+            match self.propagate_satellite(*norad_id, tracked_sat, &time) {
                 Ok(mut pos) => {
                     pos.norad_id = *norad_id;
                     pos.name = tracked_sat.data.name.clone();
@@ -131,48 +157,67 @@ impl SatelliteTracker {
             .ok_or(SatApiError::SatelliteNotFound(norad_id))?;
 
         let now = Utc::now();
-        let mut pos = self.propagate_satellite(tracked_sat, &now)?;
+        let mut pos = self.propagate_satellite(norad_id, tracked_sat, &now)?;
         pos.norad_id = norad_id;
         pos.name = tracked_sat.data.name.clone();
 
         Ok(pos)
     }
 
+    /// Like `get_satellite_position`, but propagated to an arbitrary `time`
+    /// instead of `Utc::now()` -- used to sample a single satellite's
+    /// ground track across a future window without re-propagating the
+    /// whole catalog per sample the way `get_all_positions_at` would.
+    pub fn get_satellite_position_at(
+        &self,
+        norad_id: u64,
+        time: DateTime<Utc>,
+    ) -> Result<SatellitePosition> {
+        let tracked_sat = self
+            .satellites
+            .get(&norad_id)
+            .ok_or(SatApiError::SatelliteNotFound(norad_id))?;
+
+        let mut pos = self.propagate_satellite(norad_id, tracked_sat, &time)?;
+        pos.norad_id = norad_id;
+        pos.name = tracked_sat.data.name.clone();
+
+        Ok(pos)
+    }
+
+    /// Orbital period implied by the satellite's mean motion (rev/day),
+    /// via `period = 86400s / mean_motion`.
+    pub fn orbital_period_seconds(&self, norad_id: u64) -> Result<f64> {
+        let tracked_sat = self
+            .satellites
+            .get(&norad_id)
+            .ok_or(SatApiError::SatelliteNotFound(norad_id))?;
+        Ok(86400.0 / tracked_sat.elements.mean_motion)
+    }
+
     pub fn get_satellites_by_group(&self, group_name: &str) -> Result<Vec<SatellitePosition>> {
         let now = Utc::now();
         let mut positions = Vec::new();
+        let group_name = group_name.to_lowercase();
 
         for (norad_id, tracked_sat) in &self.satellites {
-            let name_lower = tracked_sat.data.name.to_lowercase();
-
-            let matches_group = match group_name.to_lowercase().as_str() {
-                "starlink" => name_lower.contains("starlink"),
-                "gps" => name_lower.contains("gps") || name_lower.contains("navstar"),
-                "galileo" => name_lower.contains("galileo"),
-                "iss" => name_lower.contains("iss") || name_lower.contains("zarya"),
-                "weather" => {
-                    name_lower.contains("noaa")
-                        || name_lower.contains("goes")
-                        || name_lower.contains("metop")
-                }
-                _ => continue,
-            };
+            if !classify_groups(&tracked_sat.data.name).contains(&group_name.as_str()) {
+                continue;
+            }
 
-            if matches_group {
-                match self.propagate_satellite(tracked_sat, &now) {
-                    Ok(mut pos) => {
-                        pos.norad_id = *norad_id;
-                        pos.name = tracked_sat.data.name.clone();
-                        positions.push(pos);
-                    }
-                    Err(e) => {
-                        tracing::debug!(
-                            "Failed to propagate satellite {}: {}",
-                            tracked_sat.data.name,
-                            e
-                        );
-                        continue;
-                    }
+            match self.propagate_satellite(*norad_id, tracked_sat, &now) {
+                Ok(mut pos) => {
+                    pos.norad_id = *norad_id;
+                    pos.name = tracked_sat.data.name.clone();
+                    positions.push(pos);
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Failed to propagate satellite {}: {}",
+                        tracked_sat.data.name,
+                        e
+                    );
+                    continue;
                 }
             }
         }
@@ -184,11 +229,80 @@ impl SatelliteTracker {
         self.satellites.len()
     }
 
+    /// Real tracked TLE data for every satellite currently loaded, keyed by
+    /// `SatelliteData::norad_id` -- the same records `load_satellites`
+    /// parsed and propagation is run against, as opposed to a derived
+    /// `SatellitePosition`.
+    pub fn get_all_satellite_data(&self) -> Vec<SatelliteData> {
+        self.satellites.values().map(|tracked| tracked.data.clone()).collect()
+    }
+
+    /// Raw ECI position at an arbitrary epoch, bypassing the geodetic/risk
+    /// conversion in `propagate_satellite` — used by ground-station pass
+    /// prediction, which needs many samples per satellite rather than the
+    /// lat/lon/alt view `get_all_positions_at` returns.
+    pub fn get_satellite_eci_at(&self, norad_id: u64, time: DateTime<Utc>) -> Result<(f64, f64, f64)> {
+        let tracked_sat = self
+            .satellites
+            .get(&norad_id)
+            .ok_or(SatApiError::SatelliteNotFound(norad_id))?;
+
+        let minutes_since_epoch = tracked_sat
+            .elements
+            .datetime_to_minutes_since_epoch(&time.naive_utc())
+            .map_err(|err| SatApiError::PropagationError(err.to_string()))?;
+
+        let prediction = tracked_sat
+            .constants
+            .propagate(minutes_since_epoch)
+            .map_err(|err| SatApiError::PropagationError(err.to_string()))?;
+
+        Ok((prediction.position[0], prediction.position[1], prediction.position[2]))
+    }
+
     fn propagate_satellite(
         &self,
+        norad_id: u64,
         tracked_sat: &TrackedSatellite,
         time: &DateTime<Utc>,
     ) -> Result<SatellitePosition> {
+        // Prefer a loaded SP3 precise-ephemeris arc when it covers this
+        // epoch: SP3 is already ECEF, so there's no GMST rotation to do.
+        // Refuse to extrapolate beyond the tabulated span -- fall back to
+        // SGP4 below instead, with a warning, rather than silently serving
+        // a value the polynomial fit can't vouch for.
+        if let Some(sp3) = self.sp3.get(&norad_id) {
+            if !sp3.covers(*time) {
+                tracing::warn!(
+                    "SP3 arc for NORAD {} doesn't cover {}; falling back to SGP4",
+                    norad_id,
+                    time
+                );
+            } else if let Some((x_ecef, y_ecef, z_ecef, vx, vy, vz)) = sp3.interpolate(*time) {
+                let (lat_rad, lon_rad, altitude_km) = self.ecef_to_geodetic(x_ecef, y_ecef, z_ecef);
+                let mut lon_deg = lon_rad.to_degrees().rem_euclid(360.0);
+                if lon_deg > 180.0 {
+                    lon_deg -= 360.0;
+                }
+                let velocity_km_s = (vx * vx + vy * vy + vz * vz).sqrt();
+                let (risk_score, risk_level, risk_reason) =
+                    Self::evaluate_risk(&tracked_sat.data, altitude_km, velocity_km_s, time);
+
+                return Ok(SatellitePosition {
+                    norad_id: 0,
+                    name: String::new(),
+                    lat_deg: lat_rad.to_degrees(),
+                    lon_deg,
+                    alt_km: altitude_km,
+                    velocity_km_s,
+                    timestamp: *time,
+                    risk_score,
+                    risk_level,
+                    risk_reason,
+                });
+            }
+        }
+
         let minutes_since_epoch = tracked_sat
             .elements
             .datetime_to_minutes_since_epoch(&time.naive_utc())
@@ -235,17 +349,14 @@ impl SatelliteTracker {
     }
 
     fn utc_to_julian(&self, time: &DateTime<Utc>) -> f64 {
-        // Convert UTC to Julian date
-        let timestamp = time.timestamp_millis() as f64 / 1000.0;
+        // GMST is a function of UT1, not UTC; apply the configured UT1-UTC
+        // offset before converting to a Julian date (defaults to 0.0, i.e.
+        // UT1 == UTC, when no EOP value has been set).
+        let timestamp = time.timestamp_millis() as f64 / 1000.0 + self.ut1_utc_offset_seconds;
         (timestamp / 86400.0) + 2440587.5 // Unix epoch to Julian date conversion
     }
 
     fn eci_to_geodetic(&self, x: f64, y: f64, z: f64, julian_date: f64) -> (f64, f64, f64) {
-        // WGS84 constants
-        const A: f64 = 6378.137; // Semi-major axis in km
-        const F: f64 = 1.0 / 298.257223563; // Flattening
-        const E2: f64 = F * (2.0 - F); // First eccentricity squared
-
         // Calculate Greenwich Mean Sidereal Time
         let gmst = self.julian_to_gmst(julian_date);
 
@@ -257,7 +368,18 @@ impl SatelliteTracker {
         let y_ecef = -x * sin_gmst + y * cos_gmst;
         let z_ecef = z;
 
-        // Convert ECEF to geodetic coordinates
+        self.ecef_to_geodetic(x_ecef, y_ecef, z_ecef)
+    }
+
+    /// Converts Earth-Centered Earth-Fixed coordinates (km) directly to
+    /// geodetic latitude/longitude (rad) and altitude (km), with no GMST
+    /// rotation. Used for SP3 samples, which are already ECEF.
+    fn ecef_to_geodetic(&self, x_ecef: f64, y_ecef: f64, z_ecef: f64) -> (f64, f64, f64) {
+        // WGS84 constants
+        const A: f64 = 6378.137; // Semi-major axis in km
+        const F: f64 = 1.0 / 298.257223563; // Flattening
+        const E2: f64 = F * (2.0 - F); // First eccentricity squared
+
         let p = (x_ecef * x_ecef + y_ecef * y_ecef).sqrt();
         let longitude = y_ecef.atan2(x_ecef);
 
@@ -400,3 +522,31 @@ impl SatelliteTracker {
         (risk_score, risk_level, risk_reason)
     }
 }
+
+/// Classifies a satellite by name into every group/category it matches, by
+/// the same substring heuristics `get_satellites_by_group` used to check
+/// against one group at a time. Shared with `search::SatelliteIndex` so an
+/// index built over group/category tokens can't drift from this lookup's
+/// own definition of each group.
+pub(crate) fn classify_groups(name: &str) -> Vec<&'static str> {
+    let name_lower = name.to_lowercase();
+    let mut groups = Vec::new();
+
+    if name_lower.contains("starlink") {
+        groups.push("starlink");
+    }
+    if name_lower.contains("gps") || name_lower.contains("navstar") {
+        groups.push("gps");
+    }
+    if name_lower.contains("galileo") {
+        groups.push("galileo");
+    }
+    if name_lower.contains("iss") || name_lower.contains("zarya") {
+        groups.push("iss");
+    }
+    if name_lower.contains("noaa") || name_lower.contains("goes") || name_lower.contains("metop") {
+        groups.push("weather");
+    }
+
+    groups
+}
@@ -0,0 +1,219 @@
+// Outbound webhook delivery for alerts at or above each subscription's
+// `min_severity` (Critical by default),
+// analogous to Vaultwarden's push-notification relay: tenants register an
+// HTTP endpoint, a background task drains `AlertHub` and POSTs matching
+// alerts there with a signed body and bounded retries.
+
+use crate::alerts::{AlertCategory, AlertHub, AlertSeverity, LiveAlert};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub tenant_id: String,
+    pub url: String,
+    #[serde(skip)]
+    pub secret: Option<String>,
+    pub min_severity: AlertSeverity,
+    pub categories: Option<Vec<AlertCategory>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub secret: Option<String>,
+    #[serde(default = "default_min_severity")]
+    pub min_severity: AlertSeverity,
+    pub categories: Option<Vec<AlertCategory>>,
+}
+
+fn default_min_severity() -> AlertSeverity {
+    AlertSeverity::Critical
+}
+
+/// Per-tenant registry of webhook subscriptions, shared via `AppState`.
+#[derive(Default)]
+pub struct WebhookRegistry {
+    subscriptions: RwLock<HashMap<Uuid, WebhookSubscription>>,
+}
+
+impl WebhookRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        &self,
+        tenant_id: String,
+        request: CreateWebhookRequest,
+    ) -> WebhookSubscription {
+        let subscription = WebhookSubscription {
+            id: Uuid::new_v4(),
+            tenant_id,
+            url: request.url,
+            secret: request.secret,
+            min_severity: request.min_severity,
+            categories: request.categories,
+            created_at: Utc::now(),
+        };
+        self.subscriptions
+            .write()
+            .unwrap()
+            .insert(subscription.id, subscription.clone());
+        subscription
+    }
+
+    pub fn list(&self, tenant_id: &str) -> Vec<WebhookSubscription> {
+        self.subscriptions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|subscription| subscription.tenant_id == tenant_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Removes the subscription if it exists and belongs to `tenant_id`.
+    /// Returns `false` for both "not found" and "belongs to another tenant"
+    /// so callers can't probe for other tenants' webhook ids.
+    pub fn remove(&self, tenant_id: &str, id: Uuid) -> bool {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let owned_by_tenant = subscriptions
+            .get(&id)
+            .map_or(false, |subscription| subscription.tenant_id == tenant_id);
+        if owned_by_tenant {
+            subscriptions.remove(&id);
+        }
+        owned_by_tenant
+    }
+
+    fn matching(&self, alert: &LiveAlert) -> Vec<WebhookSubscription> {
+        self.subscriptions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|subscription| subscription.tenant_id == alert.tenant_id)
+            .filter(|subscription| alert.severity >= subscription.min_severity)
+            .filter(|subscription| {
+                subscription
+                    .categories
+                    .as_ref()
+                    .map_or(true, |categories| categories.contains(&alert.category))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts a key of any length");
+    mac.update(body);
+    hex_encode(&mac.finalize().into_bytes())
+}
+
+/// Spawns the background task that drains `alert_hub` and delivers matching
+/// alerts to every subscription in `registry`. One delivery attempt never
+/// blocks another: each POST (with its own retry loop) runs in its own task.
+pub fn spawn_webhook_dispatcher(alert_hub: Arc<AlertHub>, registry: Arc<WebhookRegistry>) {
+    tokio::spawn(async move {
+        let client = reqwest::Client::new();
+        let mut receiver = alert_hub.subscribe();
+        loop {
+            match receiver.recv().await {
+                Ok(alert) => {
+                    for subscription in registry.matching(&alert) {
+                        let client = client.clone();
+                        let alert = alert.clone();
+                        tokio::spawn(async move {
+                            deliver(&client, &subscription, &alert).await;
+                        });
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Webhook dispatcher lagged, skipped {} alerts", skipped);
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+async fn deliver(client: &reqwest::Client, subscription: &WebhookSubscription, alert: &LiveAlert) {
+    let body = match serde_json::to_vec(alert) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!(
+                "Failed to serialize alert {} for webhook {}: {}",
+                alert.id,
+                subscription.id,
+                e
+            );
+            return;
+        }
+    };
+
+    let mut backoff = INITIAL_BACKOFF;
+    for attempt in 1..=MAX_DELIVERY_ATTEMPTS {
+        let mut request = client
+            .post(&subscription.url)
+            .header("content-type", "application/json")
+            .body(body.clone());
+        if let Some(secret) = &subscription.secret {
+            request = request.header("X-OrbitalOS-Signature", sign_payload(secret, &body));
+        }
+
+        match request.send().await {
+            Ok(resp) if resp.status().is_success() => return,
+            Ok(resp) => tracing::warn!(
+                "Webhook {} (attempt {}/{}) got status {}",
+                subscription.id,
+                attempt,
+                MAX_DELIVERY_ATTEMPTS,
+                resp.status()
+            ),
+            Err(e) => tracing::warn!(
+                "Webhook {} (attempt {}/{}) failed: {}",
+                subscription.id,
+                attempt,
+                MAX_DELIVERY_ATTEMPTS,
+                e
+            ),
+        }
+
+        if attempt < MAX_DELIVERY_ATTEMPTS {
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    // Dead letter: every retry was exhausted. Logged rather than queued for
+    // redelivery — a reconnecting/newly-registered subscriber can still
+    // backfill via `AlertHub`'s own replay buffer.
+    tracing::error!(
+        "Webhook {} to {} permanently failed after {} attempts; dropping alert {}",
+        subscription.id,
+        subscription.url,
+        MAX_DELIVERY_ATTEMPTS,
+        alert.id
+    );
+}
@@ -0,0 +1,213 @@
+// Conjunction alerting pipeline. `notification::send_email` used to be a
+// standalone SendGrid helper nothing called automatically -- this module
+// turns it into an operational pipeline that `routes::risk::predict_risk`
+// feeds every screened conjunction through: per-recipient Pc/RiskLevel
+// subscriptions, de-duplication keyed on a stable conjunction id so the same
+// event doesn't re-alert every analysis run, escalation when Pc rises
+// between runs into a higher risk band, and a templated message carrying TCA/dmin/Pc/both objects'
+// identities. Delivery goes through a pluggable `Notifier` trait (SendGrid
+// today; a webhook or SMS backend just needs its own impl) and a failed
+// send is logged and swallowed -- it must never abort the analysis that
+// produced the alert.
+
+use crate::models::RiskLevel;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// One screened conjunction, in the shape the alerting pipeline needs: both
+/// objects' identities plus the TCA geometry `routes::risk::screen_conjunctions`
+/// already computes.
+#[derive(Debug, Clone)]
+pub struct ConjunctionAlertEvent {
+    pub satellite_a_norad_id: i32,
+    pub satellite_a_name: String,
+    pub satellite_b_norad_id: i32,
+    pub satellite_b_name: String,
+    pub tca: DateTime<Utc>,
+    pub miss_distance_km: f64,
+    pub pc: f64,
+    pub risk_level: RiskLevel,
+}
+
+/// A stable identity for a conjunction independent of which satellite was
+/// screened as the "target": the pair of NORAD ids, lowest first.
+fn conjunction_id(event: &ConjunctionAlertEvent) -> String {
+    let (lo, hi) = if event.satellite_a_norad_id <= event.satellite_b_norad_id {
+        (event.satellite_a_norad_id, event.satellite_b_norad_id)
+    } else {
+        (event.satellite_b_norad_id, event.satellite_a_norad_id)
+    };
+    format!("conj-{lo}-{hi}")
+}
+
+fn risk_rank(level: &RiskLevel) -> u8 {
+    match level {
+        RiskLevel::Safe => 0,
+        RiskLevel::Warning => 1,
+        RiskLevel::Critical => 2,
+    }
+}
+
+/// A recipient's standing alert subscription: notified whenever a
+/// conjunction's risk reaches `min_risk_level`.
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub recipient_email: String,
+    pub recipient_name: String,
+    pub min_risk_level: RiskLevel,
+}
+
+/// A rendered alert, ready to hand to a `Notifier`.
+#[derive(Debug, Clone)]
+pub struct AlertMessage {
+    pub recipient_email: String,
+    pub recipient_name: String,
+    pub subject: String,
+    pub body: String,
+}
+
+fn render(event: &ConjunctionAlertEvent, escalated: bool) -> (String, String) {
+    let verb = if escalated { "ESCALATION" } else { "CONJUNCTION ALERT" };
+    let subject = format!(
+        "[{:?}] {verb}: {} / {}",
+        event.risk_level, event.satellite_a_name, event.satellite_b_name
+    );
+    let body = format!(
+        "{verb}\n\n\
+         Objects: {} (NORAD {}) / {} (NORAD {})\n\
+         TCA: {}\n\
+         Miss distance: {:.3} km\n\
+         Collision probability: {:.3e}\n\
+         Risk level: {:?}",
+        event.satellite_a_name,
+        event.satellite_a_norad_id,
+        event.satellite_b_name,
+        event.satellite_b_norad_id,
+        event.tca.to_rfc3339(),
+        event.miss_distance_km,
+        event.pc,
+        event.risk_level,
+    );
+    (subject, body)
+}
+
+/// Pluggable delivery transport for a rendered alert. SendGrid email is the
+/// only implementation today; a webhook or SMS backend just needs its own
+/// impl of this trait.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, message: &AlertMessage) -> anyhow::Result<()>;
+}
+
+/// Delivers via the existing SendGrid `notification::send_email` helper.
+pub struct SendGridNotifier;
+
+#[async_trait]
+impl Notifier for SendGridNotifier {
+    async fn notify(&self, message: &AlertMessage) -> anyhow::Result<()> {
+        crate::notification::send_email(
+            &message.recipient_email,
+            &message.recipient_name,
+            &message.subject,
+            &message.body,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e.to_string()))
+    }
+}
+
+/// The Pc/risk level this conjunction id last alerted at, so a later run can
+/// tell a genuine escalation apart from the same event re-screened at the
+/// same severity.
+struct SeenConjunction {
+    last_pc: f64,
+    last_risk_level: RiskLevel,
+}
+
+/// De-duplicated, escalation-aware conjunction alert pipeline: holds the
+/// recipient subscription list and the last-alerted Pc per conjunction id,
+/// mirroring the in-memory registry pattern used for `PushStore`/`KeyStore`
+/// elsewhere in this crate (no dedicated table yet, so a restart drops both
+/// subscriptions and dedup state).
+pub struct ConjunctionAlertStore {
+    subscriptions: Mutex<Vec<Subscription>>,
+    seen: Mutex<HashMap<String, SeenConjunction>>,
+}
+
+impl ConjunctionAlertStore {
+    pub fn new() -> Self {
+        Self { subscriptions: Mutex::new(Vec::new()), seen: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn subscribe(&self, subscription: Subscription) {
+        self.subscriptions.lock().unwrap().push(subscription);
+    }
+
+    /// Decides whether `event` should alert right now: either this
+    /// conjunction id has never alerted before, or its Pc has risen since
+    /// the last alert *and* that rise crossed into a higher risk band.
+    /// Returns `Some(escalated)` when it should, `None` to stay quiet.
+    fn should_alert(&self, event: &ConjunctionAlertEvent) -> Option<bool> {
+        let id = conjunction_id(event);
+        let mut seen = self.seen.lock().unwrap();
+        match seen.get(&id) {
+            None => {
+                seen.insert(id, SeenConjunction { last_pc: event.pc, last_risk_level: event.risk_level.clone() });
+                Some(false)
+            }
+            Some(previous) => {
+                let escalated = event.pc > previous.last_pc
+                    && risk_rank(&event.risk_level) > risk_rank(&previous.last_risk_level);
+                if escalated {
+                    seen.insert(id, SeenConjunction { last_pc: event.pc, last_risk_level: event.risk_level.clone() });
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Runs `event` through the dedup/escalation check and, if it should
+    /// alert, dispatches a rendered message to every subscriber whose
+    /// threshold is met via `notifier`. A failed send is logged and
+    /// skipped -- it must never abort the conjunction analysis that called
+    /// this.
+    pub async fn evaluate_and_dispatch(&self, notifier: &dyn Notifier, event: &ConjunctionAlertEvent) {
+        let Some(escalated) = self.should_alert(event) else { return };
+        let (subject, body) = render(event, escalated);
+
+        let recipients: Vec<Subscription> = self
+            .subscriptions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|sub| risk_rank(&event.risk_level) >= risk_rank(&sub.min_risk_level))
+            .cloned()
+            .collect();
+
+        for recipient in recipients {
+            let message = AlertMessage {
+                recipient_email: recipient.recipient_email,
+                recipient_name: recipient.recipient_name,
+                subject: subject.clone(),
+                body: body.clone(),
+            };
+            if let Err(err) = notifier.notify(&message).await {
+                tracing::warn!(
+                    "Conjunction alert delivery failed for {}: {}",
+                    message.recipient_email,
+                    err
+                );
+            }
+        }
+    }
+}
+
+impl Default for ConjunctionAlertStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,205 @@
+// Scoped, time-windowed API keys with per-key rate limiting.
+//
+// Adds a per-key GCRA token bucket on top of the global `RateLimitLayer`
+// (100/min for everyone), which stays in place, and replaces the unprotected duplicate
+// `/api/admin/*` routes with real scope checks resolved from the
+// `Authorization`/`x-api-key` header.
+
+use axum::{
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::AppState;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    SatellitesRead,
+    ReservationsWrite,
+    Admin,
+}
+
+impl Scope {
+    fn parse(s: &str) -> Option<Scope> {
+        match s {
+            "satellites:read" => Some(Scope::SatellitesRead),
+            "reservations:write" => Some(Scope::ReservationsWrite),
+            "admin" => Some(Scope::Admin),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub secret: String, // the raw key value clients present
+    pub scopes: Vec<Scope>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub rate_limit_per_sec: f64,
+    pub burst: u32,
+}
+
+impl ApiKey {
+    fn is_valid(&self, now: DateTime<Utc>) -> bool {
+        now >= self.not_before && now <= self.not_after
+    }
+
+    fn has_scope(&self, scope: &Scope) -> bool {
+        self.scopes.contains(scope) || self.scopes.contains(&Scope::Admin)
+    }
+}
+
+/// GCRA (generic cell rate algorithm) state for one key: the "theoretical
+/// arrival time" for the next admitted request.
+struct GcraState {
+    tat: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct KeyStore {
+    keys: std::sync::Arc<Mutex<HashMap<String, ApiKey>>>,
+    buckets: std::sync::Arc<Mutex<HashMap<Uuid, GcraState>>>,
+}
+
+impl KeyStore {
+    pub fn new() -> Self {
+        Self {
+            keys: std::sync::Arc::new(Mutex::new(HashMap::new())),
+            buckets: std::sync::Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Issues a new key, e.g. via `default_provider_key` from
+    /// `onboard_provider` (default provider scopes) or via `admin_key`
+    /// (admin scope).
+    pub fn issue(&self, scopes: Vec<Scope>, rate_limit_per_sec: f64, burst: u32) -> ApiKey {
+        let key = ApiKey {
+            id: Uuid::new_v4(),
+            secret: format!("osk_{}", Uuid::new_v4().simple()),
+            scopes,
+            not_before: Utc::now(),
+            not_after: Utc::now() + chrono::Duration::days(365),
+            rate_limit_per_sec,
+            burst,
+        };
+        self.keys.lock().unwrap().insert(key.secret.clone(), key.clone());
+        key
+    }
+
+    pub fn default_provider_key(&self) -> ApiKey {
+        self.issue(vec![Scope::SatellitesRead, Scope::ReservationsWrite], 5.0, 10)
+    }
+
+    pub fn admin_key(&self) -> ApiKey {
+        self.issue(vec![Scope::Admin], 20.0, 40)
+    }
+
+    fn resolve(&self, secret: &str) -> Option<ApiKey> {
+        self.keys.lock().unwrap().get(secret).cloned()
+    }
+
+    /// Admits a request under the key's GCRA bucket: `now >= tat - burst*interval`,
+    /// then advances `tat = max(tat, now) + interval`.
+    fn admit(&self, key: &ApiKey) -> bool {
+        let now = Utc::now();
+        let interval_ms = (1000.0 / key.rate_limit_per_sec.max(0.001)) as i64;
+        let interval = chrono::Duration::milliseconds(interval_ms);
+        let burst_allowance = interval * key.burst as i32;
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let state = buckets.entry(key.id).or_insert(GcraState { tat: now });
+
+        if now >= state.tat - burst_allowance {
+            state.tat = std::cmp::max(state.tat, now) + interval;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn required_scope_for_path(path: &str) -> Option<Scope> {
+    if path.starts_with("/api/admin") {
+        Some(Scope::Admin)
+    } else if path.starts_with("/api/reservations") || path.starts_with("/api/bookings") {
+        Some(Scope::ReservationsWrite)
+    } else if path.starts_with("/api/satellites") {
+        Some(Scope::SatellitesRead)
+    } else {
+        None
+    }
+}
+
+/// Tower middleware: resolves the API key, checks its validity window and
+/// scope against the matched route, and enforces its per-key GCRA bucket.
+///
+/// This only gates *API-key* traffic -- the routes it covers are used by
+/// both machine/federation clients carrying a `osk_...` key and ordinary
+/// logged-in users carrying a session JWT from `auth::login`. A JWT on the
+/// `Authorization` header is let straight through to the handler, which
+/// enforces its own authorization via `Claims`/`RequireAuth`/`RequireRole`;
+/// this middleware only ever rejects for a problem with the API key itself:
+/// missing, unknown, outside its validity window, lacking the route's scope,
+/// or over its rate limit.
+pub async fn api_key_middleware<B>(
+    State(state): State<AppState>,
+    req: Request<B>,
+    next: Next<B>,
+) -> impl IntoResponse {
+    let path = req.uri().path().to_string();
+    let Some(needed) = required_scope_for_path(&path) else {
+        return next.run(req).await.into_response();
+    };
+
+    let header_key = req
+        .headers()
+        .get("x-api-key")
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| {
+            req.headers()
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+        })
+        .map(|s| s.to_string());
+
+    let Some(header_key) = header_key else {
+        return (StatusCode::UNAUTHORIZED, "Missing API key").into_response();
+    };
+
+    if decode::<Claims>(
+        &header_key,
+        &DecodingKey::from_secret(state.jwt_secret.as_bytes()),
+        &Validation::new(Algorithm::HS256),
+    )
+    .is_ok()
+    {
+        return next.run(req).await.into_response();
+    }
+
+    let Some(key) = state.key_store.resolve(&header_key) else {
+        return (StatusCode::UNAUTHORIZED, "Unknown API key").into_response();
+    };
+
+    if !key.is_valid(Utc::now()) {
+        return (StatusCode::UNAUTHORIZED, "API key outside validity window").into_response();
+    }
+    if !key.has_scope(&needed) {
+        return (StatusCode::FORBIDDEN, "API key missing required scope").into_response();
+    }
+    if !state.key_store.admit(&key) {
+        return (StatusCode::TOO_MANY_REQUESTS, "Per-key rate limit exceeded").into_response();
+    }
+
+    next.run(req).await.into_response()
+}
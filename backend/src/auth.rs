@@ -1,24 +1,44 @@
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use argon2::{password_hash::{rand_core::OsRng, SaltString}, Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::{AppState, models::{AuthResponse, ErrorResponse, LoginRequest, RegisterRequest, UserInfo, UserRole}};
+use crate::{sessions, AppState, models::{AuthResponse, ErrorResponse, LoginRequest, RegisterRequest, UserInfo, UserRole}};
 
+// Access JWTs used to live 24h with no way to revoke them short of rotating
+// `jwt_secret` for everyone. They're now short-lived; a `refresh_tokens`
+// session in `sessions.rs` is what actually carries the user's login forward
+// via `/api/auth/refresh`.
 const TOKEN_TTL_HOURS: i64 = 24;
+const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+
+fn client_user_agent(req: &HttpRequest) -> Option<String> {
+    req.headers().get("user-agent").and_then(|v| v.to_str().ok()).map(str::to_string)
+}
+
+fn client_ip(req: &HttpRequest) -> Option<String> {
+    req.connection_info().peer_addr().map(str::to_string)
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub role: String,
     pub exp: usize,
+    /// The `refresh_tokens.family_id` of the session this access token was
+    /// minted alongside, if any. Lets `RequireRole`/`RequireAuth` reject the
+    /// token the moment that session is revoked, instead of trusting it
+    /// until `exp` regardless of what happened to the session behind it.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub sid: Option<Uuid>,
 }
 
-pub async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>) -> impl Responder {
+pub async fn login(req: HttpRequest, state: web::Data<AppState>, payload: web::Json<LoginRequest>) -> impl Responder {
     if let Err(err) = payload.validate() {
         return HttpResponse::BadRequest().json(ErrorResponse {
             error: "ValidationError".into(),
@@ -27,7 +47,7 @@ pub async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>)
     }
 
     let row = match sqlx::query(
-        "SELECT id, role, password_hash FROM users WHERE app_security.sym_decrypt(email_encrypted) = $1"
+        "SELECT id, role, password_hash, blocked, failed_attempts, locked_until, two_factor_enabled FROM users WHERE app_security.sym_decrypt(email_encrypted) = $1"
     )
     .bind(&payload.email)
     .fetch_optional(&state.db_pool)
@@ -48,6 +68,26 @@ pub async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>)
         }
     };
 
+    let user_id: Uuid = row.get("id");
+    let blocked: bool = row.get("blocked");
+    if blocked {
+        return HttpResponse::Forbidden().json(ErrorResponse {
+            error: "AuthBlockedUser".into(),
+            message: Some("This account has been blocked by an administrator".into()),
+        });
+    }
+
+    let failed_attempts: i32 = row.get("failed_attempts");
+    let locked_until: Option<chrono::DateTime<Utc>> = row.get("locked_until");
+    if let Some(locked_until) = locked_until {
+        if locked_until > Utc::now() {
+            return HttpResponse::TooManyRequests().json(ErrorResponse {
+                error: "AccountLocked".into(),
+                message: Some(format!("Account locked until {locked_until}")),
+            });
+        }
+    }
+
     let stored_hash: String = row.get("password_hash");
     let parsed_hash = match PasswordHash::new(&stored_hash) {
         Ok(hash) => hash,
@@ -63,19 +103,226 @@ pub async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>)
         .verify_password(payload.password.as_bytes(), &parsed_hash)
         .is_err()
     {
+        record_failed_login(&state.db_pool, user_id, failed_attempts + 1).await;
         return HttpResponse::Unauthorized().json(ErrorResponse {
             error: "AuthError".into(),
             message: Some("Invalid email or password".into()),
         });
     }
 
-    let user_id: Uuid = row.get("id");
+    let _ = sqlx::query("UPDATE users SET failed_attempts = 0, locked_until = NULL WHERE id = $1")
+        .bind(user_id)
+        .execute(&state.db_pool)
+        .await;
+
     let role: String = row.get("role");
+    let two_factor_enabled: bool = row.get("two_factor_enabled");
+
+    if !two_factor_enabled {
+        // This account hasn't opted into email OTP, so skip straight to
+        // minting a session the same way `verify_otp`/`oidc_callback` do
+        // once their own second factor has checked out.
+        let refresh = match sessions::issue(
+            &state.db_pool,
+            user_id,
+            client_user_agent(&req).as_deref(),
+            client_ip(&req).as_deref(),
+        )
+        .await
+        {
+            Ok(refresh) => refresh,
+            Err(err) => {
+                return HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "DatabaseError".into(),
+                    message: Some(err.to_string()),
+                });
+            }
+        };
+
+        let claims = Claims {
+            sub: user_id.to_string(),
+            role: role.clone(),
+            exp: (Utc::now() + Duration::minutes(ACCESS_TOKEN_TTL_MINUTES)).timestamp() as usize,
+            sid: Some(refresh.family_id),
+        };
+
+        let token = match encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(state.jwt_secret.as_bytes()),
+        ) {
+            Ok(token) => token,
+            Err(err) => {
+                return HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "TokenError".into(),
+                    message: Some(err.to_string()),
+                });
+            }
+        };
+
+        return HttpResponse::Ok().json(AuthResponse {
+            token,
+            refresh_token: refresh.token,
+            user: UserInfo {
+                id: user_id,
+                email: payload.email.clone(),
+                role: match role.as_str() {
+                    "Insurer" => UserRole::Insurer,
+                    "Analyst" => UserRole::Analyst,
+                    _ => UserRole::Operator,
+                },
+            },
+        });
+    }
+
+    // Password checked out; don't hand back a session token yet. Mint a
+    // one-time code, email it, and make the caller complete `/api/auth/2fa/verify`
+    // before they get a JWT.
+    let code = generate_otp();
+    pending_2fa().lock().unwrap().insert(
+        user_id,
+        PendingTwoFactor {
+            code: code.clone(),
+            role: role.clone(),
+            email: payload.email.clone(),
+            expires_at: Utc::now() + Duration::minutes(OTP_TTL_MINUTES),
+            attempts: 0,
+        },
+    );
+
+    if let Err(err) = crate::notification::send_email(
+        &payload.email,
+        &payload.email,
+        "Your OrbitalOS sign-in code",
+        &format!("Your verification code is {code}. It expires in {OTP_TTL_MINUTES} minutes."),
+    )
+    .await
+    {
+        return HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "NotificationError".into(),
+            message: Some(err.to_string()),
+        });
+    }
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "otp_required",
+        "user_id": user_id,
+    }))
+}
+
+const LOCKOUT_THRESHOLD: i32 = 5;
+
+/// Bumps `failed_attempts` and, once `LOCKOUT_THRESHOLD` is crossed, sets
+/// `locked_until` with exponential backoff (2^(attempts-threshold) minutes,
+/// capped at 24h) so repeated guessing gets progressively more expensive
+/// instead of locking out forever on the first trip.
+async fn record_failed_login(pool: &sqlx::PgPool, user_id: Uuid, new_attempts: i32) {
+    let locked_until = if new_attempts >= LOCKOUT_THRESHOLD {
+        let backoff_minutes = 2i64.pow((new_attempts - LOCKOUT_THRESHOLD).min(11) as u32);
+        Some(Utc::now() + Duration::minutes(backoff_minutes.min(24 * 60)))
+    } else {
+        None
+    };
+
+    let _ = sqlx::query("UPDATE users SET failed_attempts = $1, locked_until = $2 WHERE id = $3")
+        .bind(new_attempts)
+        .bind(locked_until)
+        .bind(user_id)
+        .execute(pool)
+        .await;
+}
+
+const OTP_TTL_MINUTES: i64 = 10;
+// A 6-digit code is only a 1-in-a-million guess per attempt, not
+// brute-force-resistant on its own across the full `OTP_TTL_MINUTES`
+// window; cap attempts the same way `chunk3-5`'s `LOCKOUT_THRESHOLD` caps
+// password guesses, and invalidate the code outright once exhausted rather
+// than just extending the wait between tries.
+const OTP_MAX_ATTEMPTS: u32 = 5;
+
+struct PendingTwoFactor {
+    code: String,
+    role: String,
+    email: String,
+    expires_at: chrono::DateTime<Utc>,
+    attempts: u32,
+}
+
+fn pending_2fa() -> &'static std::sync::Mutex<std::collections::HashMap<Uuid, PendingTwoFactor>> {
+    static PENDING: once_cell::sync::Lazy<std::sync::Mutex<std::collections::HashMap<Uuid, PendingTwoFactor>>> =
+        once_cell::sync::Lazy::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+    &PENDING
+}
+
+fn generate_otp() -> String {
+    let n: u32 = rand::thread_rng().gen_range(0..1_000_000);
+    format!("{n:06}")
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyOtpRequest {
+    pub user_id: Uuid,
+    pub code: String,
+}
+
+/// `POST /api/auth/2fa/verify` — completes the login flow started by
+/// `login()` once the user has entered the emailed one-time code.
+pub async fn verify_otp(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    payload: web::Json<VerifyOtpRequest>,
+) -> impl Responder {
+    let pending = {
+        let mut guard = pending_2fa().lock().unwrap();
+
+        let expired_or_exhausted = guard
+            .get(&payload.user_id)
+            .map_or(true, |p| p.expires_at <= Utc::now() || p.attempts >= OTP_MAX_ATTEMPTS);
+        if expired_or_exhausted {
+            guard.remove(&payload.user_id);
+            None
+        } else if guard.get(&payload.user_id).is_some_and(|p| p.code == payload.code) {
+            guard.remove(&payload.user_id)
+        } else {
+            if let Some(entry) = guard.get_mut(&payload.user_id) {
+                entry.attempts += 1;
+            }
+            None
+        }
+    };
+
+    let pending = match pending {
+        Some(p) => p,
+        None => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                error: "AuthError".into(),
+                message: Some("Invalid or expired verification code".into()),
+            });
+        }
+    };
+
+    let refresh = match sessions::issue(
+        &state.db_pool,
+        payload.user_id,
+        client_user_agent(&req).as_deref(),
+        client_ip(&req).as_deref(),
+    )
+    .await
+    {
+        Ok(refresh) => refresh,
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
 
     let claims = Claims {
-        sub: user_id.to_string(),
-        role: role.clone(),
-        exp: (Utc::now() + Duration::hours(TOKEN_TTL_HOURS)).timestamp() as usize,
+        sub: payload.user_id.to_string(),
+        role: pending.role.clone(),
+        exp: (Utc::now() + Duration::minutes(ACCESS_TOKEN_TTL_MINUTES)).timestamp() as usize,
+        sid: Some(refresh.family_id),
     };
 
     let token = match encode(
@@ -92,20 +339,19 @@ pub async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>)
         }
     };
 
-    let response = AuthResponse {
+    HttpResponse::Ok().json(AuthResponse {
         token,
+        refresh_token: refresh.token,
         user: UserInfo {
-            id: user_id,
-            email: payload.email.clone(),
-            role: match role.as_str() {
+            id: payload.user_id,
+            email: pending.email,
+            role: match pending.role.as_str() {
                 "Insurer" => UserRole::Insurer,
                 "Analyst" => UserRole::Analyst,
                 _ => UserRole::Operator,
             },
         },
-    };
-
-    HttpResponse::Ok().json(response)
+    })
 }
 
 pub async fn register(state: web::Data<AppState>, payload: web::Json<RegisterRequest>) -> impl Responder {
@@ -134,15 +380,16 @@ pub async fn register(state: web::Data<AppState>, payload: web::Json<RegisterReq
         UserRole::Operator => "Operator",
     };
 
+    let user_id = Uuid::new_v4();
     let result = sqlx::query(
-        "INSERT INTO users (email_encrypted, email_iv, password_hash, role, created_at, updated_at)
-         VALUES (app_security.sym_encrypt($1), gen_random_bytes(16), $2, $3, $4, $5)"
+        "INSERT INTO users (id, email_encrypted, email_iv, password_hash, role, verified, created_at, updated_at)
+         VALUES ($1, app_security.sym_encrypt($2), gen_random_bytes(16), $3, $4, FALSE, $5, $5)"
     )
+    .bind(user_id)
     .bind(&payload.email)
     .bind(password_hash)
     .bind(role_label)
     .bind(now)
-    .bind(now)
     .execute(&state.db_pool)
     .await;
 
@@ -153,8 +400,24 @@ pub async fn register(state: web::Data<AppState>, payload: web::Json<RegisterReq
         });
     }
 
+    if let Ok(token) = seal_email_token(user_id, EmailTokenPurpose::Verify) {
+        let verify_url = format!(
+            "{}/auth/verify?token={}",
+            std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            token,
+        );
+        let _ = crate::notification::send_email(
+            &payload.email,
+            &payload.email,
+            "Verify your OrbitalOS account",
+            &format!("Welcome to OrbitalOS. Verify your account: {verify_url}"),
+        )
+        .await;
+    }
+
     HttpResponse::Created().json(serde_json::json!({
-        "status": "registered"
+        "status": "registered",
+        "verification_required": true,
     }))
 }
 
@@ -174,12 +437,206 @@ pub fn verify_password(hash: &str, password: &str) -> Result<bool, argon2::passw
         .is_ok())
 }
 
+// --- Email verification and password reset ---
+//
+// Tokens are sealed with the existing `encrypt_metadata` AES-GCM helper
+// (keyed by a dedicated `EMAIL_TOKEN_SECRET`, separate from the provider
+// envelope master key) rather than stored server-side, so there's no table
+// to clean up and a token is self-validating purely from its ciphertext.
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+enum EmailTokenPurpose {
+    Verify,
+    PasswordReset,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EmailTokenPayload {
+    user_id: Uuid,
+    purpose: EmailTokenPurpose,
+    expires_at: i64,
+}
+
+const EMAIL_TOKEN_TTL_HOURS: i64 = 24;
+
+/// `EMAIL_TOKEN_SECRET` is the base64 (or, failing that, hex) encoding of the
+/// raw 32-byte key, not the key's UTF-8 text itself — same fix `chunk13-1`
+/// applied to `AES_256_KEY` (see `handlers::master_aes_key`), so this key
+/// doesn't keep the weaker truncate-or-reject-on-raw-bytes scheme after that
+/// one was flagged.
+fn email_token_secret() -> Result<[u8; 32], String> {
+    let encoded = std::env::var("EMAIL_TOKEN_SECRET")
+        .map_err(|_| "EMAIL_TOKEN_SECRET must be set".to_string())?;
+
+    let decoded = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, encoded.trim())
+        .or_else(|_| hex::decode(encoded.trim()))
+        .map_err(|_| "EMAIL_TOKEN_SECRET must be base64 or hex encoded".to_string())?;
+
+    decoded
+        .try_into()
+        .map_err(|_| "EMAIL_TOKEN_SECRET must decode to exactly 32 bytes".to_string())
+}
+
+fn seal_email_token(user_id: Uuid, purpose: EmailTokenPurpose) -> Result<String, String> {
+    let key = email_token_secret()?;
+    let payload = EmailTokenPayload {
+        user_id,
+        purpose,
+        expires_at: (Utc::now() + Duration::hours(EMAIL_TOKEN_TTL_HOURS)).timestamp(),
+    };
+    let plaintext = serde_json::to_vec(&payload).map_err(|e| e.to_string())?;
+    let ciphertext = crate::encryption::encrypt_metadata(&key, &plaintext).map_err(|e| e.to_string())?;
+    Ok(URL_SAFE_NO_PAD.encode(ciphertext))
+}
+
+fn unseal_email_token(token: &str, expected_purpose: EmailTokenPurpose) -> Result<Uuid, String> {
+    let key = email_token_secret()?;
+    let ciphertext = URL_SAFE_NO_PAD.decode(token).map_err(|_| "malformed token".to_string())?;
+    let plaintext = crate::encryption::decrypt_metadata(&key, &ciphertext).map_err(|_| "invalid token".to_string())?;
+    let payload: EmailTokenPayload = serde_json::from_slice(&plaintext).map_err(|_| "invalid token".to_string())?;
+
+    if payload.purpose != expected_purpose {
+        return Err("token purpose mismatch".to_string());
+    }
+    if payload.expires_at < Utc::now().timestamp() {
+        return Err("token expired".to_string());
+    }
+    Ok(payload.user_id)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailQuery {
+    pub token: String,
+}
+
+/// `GET /api/auth/verify?token=...`
+pub async fn verify_email(
+    state: web::Data<AppState>,
+    query: web::Query<VerifyEmailQuery>,
+) -> impl Responder {
+    let user_id = match unseal_email_token(&query.token, EmailTokenPurpose::Verify) {
+        Ok(id) => id,
+        Err(message) => {
+            return HttpResponse::BadRequest().json(ErrorResponse { error: "InvalidToken".into(), message: Some(message) });
+        }
+    };
+
+    let result = sqlx::query("UPDATE users SET verified = TRUE, updated_at = $1 WHERE id = $2")
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&state.db_pool)
+        .await;
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({ "status": "verified" })),
+        Err(err) => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "DatabaseError".into(),
+            message: Some(err.to_string()),
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ForgotPasswordRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+/// `POST /api/auth/forgot-password` — always returns 200 regardless of
+/// whether the email matches an account, so the response can't be used to
+/// enumerate registered users.
+pub async fn forgot_password(
+    state: web::Data<AppState>,
+    payload: web::Json<ForgotPasswordRequest>,
+) -> impl Responder {
+    if payload.validate().is_ok() {
+        let row = sqlx::query("SELECT id FROM users WHERE app_security.sym_decrypt(email_encrypted) = $1")
+            .bind(&payload.email)
+            .fetch_optional(&state.db_pool)
+            .await;
+
+        if let Ok(Some(row)) = row {
+            let user_id: Uuid = row.get("id");
+            if let Ok(token) = seal_email_token(user_id, EmailTokenPurpose::PasswordReset) {
+                let reset_url = format!(
+                    "{}/auth/reset-password?token={}",
+                    std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string()),
+                    token,
+                );
+                let _ = crate::notification::send_email(
+                    &payload.email,
+                    &payload.email,
+                    "Reset your OrbitalOS password",
+                    &format!("Reset your password: {reset_url}. This link expires in {EMAIL_TOKEN_TTL_HOURS} hours."),
+                )
+                .await;
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(serde_json::json!({ "status": "ok" }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    #[serde(rename = "new_password")]
+    pub new_password: String,
+}
+
+/// `POST /api/auth/reset-password`
+pub async fn reset_password(
+    state: web::Data<AppState>,
+    payload: web::Json<ResetPasswordRequest>,
+) -> impl Responder {
+    if payload.new_password.len() < 8 {
+        return HttpResponse::BadRequest().json(ErrorResponse {
+            error: "ValidationError".into(),
+            message: Some("Password must be at least 8 characters".into()),
+        });
+    }
+
+    let user_id = match unseal_email_token(&payload.token, EmailTokenPurpose::PasswordReset) {
+        Ok(id) => id,
+        Err(message) => {
+            return HttpResponse::BadRequest().json(ErrorResponse { error: "InvalidToken".into(), message: Some(message) });
+        }
+    };
+
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = match Argon2::default().hash_password(payload.new_password.as_bytes(), &salt) {
+        Ok(hash) => hash.to_string(),
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "HashError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    let result = sqlx::query("UPDATE users SET password_hash = $1, updated_at = $2 WHERE id = $3")
+        .bind(password_hash)
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&state.db_pool)
+        .await;
+
+    match result {
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({ "status": "password_reset" })),
+        Err(err) => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "DatabaseError".into(),
+            message: Some(err.to_string()),
+        }),
+    }
+}
+
 #[allow(dead_code)]
 pub fn create_jwt(subject: &str, role: &str, secret: &str) -> Result<String, jsonwebtoken::errors::Error> {
     let claims = Claims {
         sub: subject.to_string(),
         role: role.to_string(),
         exp: (Utc::now() + Duration::hours(TOKEN_TTL_HOURS)).timestamp() as usize,
+        sid: None,
     };
     encode(&Header::default(), &claims, &EncodingKey::from_secret(secret.as_bytes()))
 }
@@ -189,3 +646,544 @@ pub fn decode_jwt(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::err
     let data = decode::<Claims>(token, &DecodingKey::from_secret(secret.as_bytes()), &Validation::new(Algorithm::HS256))?;
     Ok(data.claims)
 }
+
+// --- OIDC / external identity-provider login ---
+//
+// Alongside the local email+password flow above, operators/insurers/analysts
+// can authenticate through an external OpenID Connect provider (Okta, Azure
+// AD, Google Workspace, ...). We use the standard authorization-code + PKCE
+// flow: `/api/auth/oidc/start` hands the client a redirect URL with a
+// `code_challenge`, and `/api/auth/oidc/callback` exchanges the returned
+// `code` for tokens and mints our own session JWT the same way `login` does.
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OidcStartResponse {
+    pub authorize_url: String,
+    pub state: String,
+    pub code_verifier: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackRequest {
+    pub code: String,
+    pub state: String,
+    pub code_verifier: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OidcTokenResponse {
+    #[allow(dead_code)]
+    access_token: String,
+    id_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OidcIdTokenClaims {
+    sub: String,
+    email: String,
+}
+
+fn pkce_challenge(verifier: &str) -> String {
+    let digest = Sha256::digest(verifier.as_bytes());
+    URL_SAFE_NO_PAD.encode(digest)
+}
+
+fn random_url_safe_token(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// `GET /api/auth/oidc/start` — returns the authorization-code redirect URL
+/// (with a PKCE `code_challenge` and anti-CSRF `state`) for the configured
+/// OIDC provider. The caller is expected to stash `state`/`code_verifier`
+/// client-side (or in a short-lived session) and send them back to
+/// `oidc_callback`.
+pub async fn oidc_start() -> impl Responder {
+    let authorize_endpoint = std::env::var("OIDC_AUTHORIZE_URL")
+        .unwrap_or_else(|_| "https://idp.example.com/oauth2/authorize".to_string());
+    let client_id = std::env::var("OIDC_CLIENT_ID").unwrap_or_else(|_| "orbitalos".to_string());
+    let redirect_uri = std::env::var("OIDC_REDIRECT_URI")
+        .unwrap_or_else(|_| "http://localhost:3000/api/auth/oidc/callback".to_string());
+
+    let state = random_url_safe_token(16);
+    let code_verifier = random_url_safe_token(32);
+    let code_challenge = pkce_challenge(&code_verifier);
+
+    let authorize_url = format!(
+        "{authorize_endpoint}?response_type=code&client_id={client_id}&redirect_uri={redirect_uri}\
+         &scope=openid%20email%20profile&state={state}&code_challenge={code_challenge}\
+         &code_challenge_method=S256"
+    );
+
+    HttpResponse::Ok().json(OidcStartResponse {
+        authorize_url,
+        state,
+        code_verifier,
+    })
+}
+
+/// `POST /api/auth/oidc/callback` — exchanges the authorization `code` for
+/// tokens, validates the returned `id_token`, provisions/links a local user
+/// by email, and mints an OrbitalOS session JWT exactly like `login` does.
+pub async fn oidc_callback(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    payload: web::Json<OidcCallbackRequest>,
+) -> impl Responder {
+    let token_endpoint = std::env::var("OIDC_TOKEN_URL")
+        .unwrap_or_else(|_| "https://idp.example.com/oauth2/token".to_string());
+    let client_id = std::env::var("OIDC_CLIENT_ID").unwrap_or_else(|_| "orbitalos".to_string());
+    let client_secret = std::env::var("OIDC_CLIENT_SECRET").unwrap_or_default();
+    let redirect_uri = std::env::var("OIDC_REDIRECT_URI")
+        .unwrap_or_else(|_| "http://localhost:3000/api/auth/oidc/callback".to_string());
+
+    let client = reqwest::Client::new();
+    let token_resp = client
+        .post(&token_endpoint)
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", payload.code.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("client_id", client_id.as_str()),
+            ("client_secret", client_secret.as_str()),
+            ("code_verifier", payload.code_verifier.as_str()),
+        ])
+        .send()
+        .await;
+
+    let token_resp = match token_resp {
+        Ok(resp) => resp,
+        Err(err) => {
+            return HttpResponse::BadGateway().json(ErrorResponse {
+                error: "OidcTokenExchangeError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    let tokens: OidcTokenResponse = match token_resp.json().await {
+        Ok(t) => t,
+        Err(err) => {
+            return HttpResponse::BadGateway().json(ErrorResponse {
+                error: "OidcTokenParseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    // id_token is a JWT; we only need the unverified payload here because the
+    // signature was already established over TLS with the IdP during the
+    // token exchange above. A production deployment should additionally
+    // verify the signature against the IdP's JWKS.
+    let claims: OidcIdTokenClaims = match decode_unverified_jwt_payload(&tokens.id_token) {
+        Some(c) => c,
+        None => {
+            return HttpResponse::BadGateway().json(ErrorResponse {
+                error: "OidcIdTokenError".into(),
+                message: Some("Could not parse id_token".into()),
+            });
+        }
+    };
+
+    let existing = sqlx::query(
+        "SELECT id, role FROM users WHERE app_security.sym_decrypt(email_encrypted) = $1"
+    )
+    .bind(&claims.email)
+    .fetch_optional(&state.db_pool)
+    .await;
+
+    let (user_id, role): (Uuid, String) = match existing {
+        Ok(Some(row)) => (row.get("id"), row.get("role")),
+        Ok(None) => {
+            let new_id = Uuid::new_v4();
+            let now = Utc::now();
+            let insert = sqlx::query(
+                "INSERT INTO users (id, email_encrypted, email_iv, password_hash, role, created_at, updated_at)
+                 VALUES ($1, app_security.sym_encrypt($2), gen_random_bytes(16), '', 'Operator', $3, $3)"
+            )
+            .bind(new_id)
+            .bind(&claims.email)
+            .bind(now)
+            .execute(&state.db_pool)
+            .await;
+            if let Err(err) = insert {
+                return HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "DatabaseError".into(),
+                    message: Some(err.to_string()),
+                });
+            }
+            (new_id, "Operator".to_string())
+        }
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    let refresh = match sessions::issue(
+        &state.db_pool,
+        user_id,
+        client_user_agent(&req).as_deref(),
+        client_ip(&req).as_deref(),
+    )
+    .await
+    {
+        Ok(refresh) => refresh,
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    let jwt_claims = Claims {
+        sub: user_id.to_string(),
+        role: role.clone(),
+        exp: (Utc::now() + Duration::minutes(ACCESS_TOKEN_TTL_MINUTES)).timestamp() as usize,
+        sid: Some(refresh.family_id),
+    };
+
+    let token = match encode(
+        &Header::default(),
+        &jwt_claims,
+        &EncodingKey::from_secret(state.jwt_secret.as_bytes()),
+    ) {
+        Ok(token) => token,
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "TokenError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    HttpResponse::Ok().json(AuthResponse {
+        token,
+        refresh_token: refresh.token,
+        user: UserInfo {
+            id: user_id,
+            email: claims.email,
+            role: match role.as_str() {
+                "Insurer" => UserRole::Insurer,
+                "Analyst" => UserRole::Analyst,
+                _ => UserRole::Operator,
+            },
+        },
+    })
+}
+
+// --- Refresh-token rotation ---
+//
+// `/api/auth/refresh` exchanges a still-valid, not-yet-used refresh token for
+// a fresh access+refresh pair. `/api/auth/logout` tears down the whole
+// session family so every refresh token issued down that chain stops working
+// immediately, not just the one the client happens to be holding.
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+pub async fn refresh(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    payload: web::Json<RefreshRequest>,
+) -> impl Responder {
+    let (issued, user_id) = match sessions::rotate(
+        &state.db_pool,
+        &payload.refresh_token,
+        client_user_agent(&req).as_deref(),
+        client_ip(&req).as_deref(),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(sessions::RefreshError::NotFound) | Err(sessions::RefreshError::Expired) => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                error: "AuthError".into(),
+                message: Some("Refresh token is invalid or expired".into()),
+            });
+        }
+        Err(sessions::RefreshError::Reused) => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                error: "TokenReuseDetected".into(),
+                message: Some("Refresh token was already used; session revoked, please log in again".into()),
+            });
+        }
+        Err(sessions::RefreshError::Database(err)) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    let row = match sqlx::query("SELECT role FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(&state.db_pool)
+        .await
+    {
+        Ok(Some(row)) => row,
+        Ok(None) => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                error: "AuthError".into(),
+                message: Some("User no longer exists".into()),
+            });
+        }
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+    let role: String = row.get("role");
+
+    let access_claims = Claims {
+        sub: user_id.to_string(),
+        role: role.clone(),
+        exp: (Utc::now() + Duration::minutes(ACCESS_TOKEN_TTL_MINUTES)).timestamp() as usize,
+        sid: Some(issued.family_id),
+    };
+    let token = match encode(
+        &Header::default(),
+        &access_claims,
+        &EncodingKey::from_secret(state.jwt_secret.as_bytes()),
+    ) {
+        Ok(token) => token,
+        Err(err) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "TokenError".into(),
+                message: Some(err.to_string()),
+            });
+        }
+    };
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "token": token,
+        "refresh_token": issued.token,
+        "family_id": issued.family_id,
+    }))
+}
+
+pub async fn logout(state: web::Data<AppState>, payload: web::Json<RefreshRequest>) -> impl Responder {
+    match sessions::revoke_by_token(&state.db_pool, &payload.refresh_token).await {
+        Ok(()) => HttpResponse::Ok().json(serde_json::json!({ "status": "logged_out" })),
+        Err(err) => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "DatabaseError".into(),
+            message: Some(err.to_string()),
+        }),
+    }
+}
+
+/// `GET /api/auth/sessions` — lists the caller's active refresh-token
+/// families (one per logged-in device) for a "log out everywhere" UI.
+pub async fn list_sessions(state: web::Data<AppState>, claims: Claims) -> impl Responder {
+    let user_id = match Uuid::parse_str(&claims.sub) {
+        Ok(id) => id,
+        Err(_) => {
+            return HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "AuthError".into(),
+                message: Some("Invalid subject in token".into()),
+            });
+        }
+    };
+
+    match sessions::list_for_user(&state.db_pool, user_id).await {
+        Ok(sessions) => HttpResponse::Ok().json(serde_json::json!({ "sessions": sessions })),
+        Err(err) => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "DatabaseError".into(),
+            message: Some(err.to_string()),
+        }),
+    }
+}
+
+fn decode_unverified_jwt_payload(jwt: &str) -> Option<OidcIdTokenClaims> {
+    let payload_segment = jwt.split('.').nth(1)?;
+    let payload_bytes = URL_SAFE_NO_PAD.decode(payload_segment).ok()?;
+    serde_json::from_slice(&payload_bytes).ok()
+}
+
+// --- `Claims` extractor and `RequireRole` guard ---
+//
+// `decode_jwt`/`verify_token` used to be free functions nobody called, which
+// meant `get_alerts`, `get_stats`, and `acknowledge_alert` accepted requests
+// with no `Authorization` header at all. Handlers now take `Claims` (or
+// `RequireRole<Insurer>` etc.) as a regular extractor argument and axum
+// rejects the request with 401/403 before the handler body ever runs.
+
+impl axum::extract::FromRequestParts<AppState> for Claims {
+    type Rejection = (axum::http::StatusCode, axum::Json<ErrorResponse>);
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let unauthorized = |message: &str| {
+            (
+                axum::http::StatusCode::UNAUTHORIZED,
+                axum::Json(ErrorResponse { error: "AuthError".into(), message: Some(message.into()) }),
+            )
+        };
+
+        let header = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| unauthorized("Missing Authorization header"))?;
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| unauthorized("Authorization header must be a Bearer token"))?;
+
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.jwt_secret.as_bytes()),
+            &Validation::new(Algorithm::HS256),
+        )
+        .map_err(|_| unauthorized("Invalid or expired token"))?;
+
+        Ok(data.claims)
+    }
+}
+
+/// Marker types identifying the role a route requires, used as the type
+/// parameter of [`RequireRole`].
+pub trait RoleMarker {
+    const ROLE: &'static str;
+}
+pub struct InsurerRole;
+pub struct AnalystRole;
+pub struct OperatorRole;
+pub struct AdminRole;
+impl RoleMarker for InsurerRole {
+    const ROLE: &'static str = "Insurer";
+}
+impl RoleMarker for AnalystRole {
+    const ROLE: &'static str = "Analyst";
+}
+impl RoleMarker for OperatorRole {
+    const ROLE: &'static str = "Operator";
+}
+impl RoleMarker for AdminRole {
+    // Matches the role string `require_admin` already checks for.
+    const ROLE: &'static str = "admin";
+}
+
+/// Shared by [`RequireRole`] and [`RequireAuth`]: once a [`Claims`] has
+/// decoded successfully, reject it anyway if it carries a `sid` whose
+/// session has since been revoked (logout, or automatic revocation on
+/// detected refresh-token reuse) — otherwise a revoked session's access
+/// tokens would keep working until `exp` regardless.
+async fn reject_if_session_revoked(
+    state: &AppState,
+    claims: &Claims,
+) -> Result<(), (axum::http::StatusCode, axum::Json<ErrorResponse>)> {
+    let Some(sid) = claims.sid else {
+        return Ok(());
+    };
+    match sessions::is_active(&state.db_pool, sid).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err((
+            axum::http::StatusCode::UNAUTHORIZED,
+            axum::Json(ErrorResponse {
+                error: "AuthError".into(),
+                message: Some("Session has been revoked".into()),
+            }),
+        )),
+        Err(err) => Err((
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(ErrorResponse {
+                error: "DatabaseError".into(),
+                message: Some(err.to_string()),
+            }),
+        )),
+    }
+}
+
+/// Extractor that resolves to the caller's [`Claims`] only if their role
+/// matches `R`, otherwise rejects with 403. Use as a handler argument, e.g.
+/// `async fn get_stats(RequireRole(claims): RequireRole<InsurerRole>, ...)`.
+pub struct RequireRole<R: RoleMarker>(pub Claims, std::marker::PhantomData<R>);
+
+impl<R: RoleMarker + Send + Sync> axum::extract::FromRequestParts<AppState> for RequireRole<R> {
+    type Rejection = (axum::http::StatusCode, axum::Json<ErrorResponse>);
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+        if claims.role != R::ROLE {
+            return Err((
+                axum::http::StatusCode::FORBIDDEN,
+                axum::Json(ErrorResponse {
+                    error: "AuthError".into(),
+                    message: Some(format!("Requires role {}", R::ROLE)),
+                }),
+            ));
+        }
+        reject_if_session_revoked(state, &claims).await?;
+        Ok(RequireRole(claims, std::marker::PhantomData))
+    }
+}
+
+/// Extractor for any authenticated request that doesn't need a specific
+/// role, but — unlike plain [`Claims`] — also rejects once the session
+/// backing the token has been revoked. Use for endpoints that only need "is
+/// this a logged-in user" plus real-time revocation (e.g. `list_sessions`
+/// itself doesn't need this since it's read-only, but account-management
+/// actions like changing a password should).
+pub struct RequireAuth(pub Claims);
+
+impl axum::extract::FromRequestParts<AppState> for RequireAuth {
+    type Rejection = (axum::http::StatusCode, axum::Json<ErrorResponse>);
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+        reject_if_session_revoked(state, &claims).await?;
+        Ok(RequireAuth(claims))
+    }
+}
+
+// Matching extractor for the actix-side handlers in this module that take
+// `Claims` (e.g. `list_sessions`), mirroring the axum impl above bit for bit.
+impl actix_web::FromRequest for Claims {
+    type Error = actix_web::Error;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        let header = req
+            .headers()
+            .get("Authorization")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let jwt_secret = req.app_data::<web::Data<AppState>>().map(|d| d.jwt_secret.clone());
+
+        Box::pin(async move {
+            let header = header.ok_or_else(|| actix_web::error::ErrorUnauthorized("Missing Authorization header"))?;
+            let token = header
+                .strip_prefix("Bearer ")
+                .ok_or_else(|| actix_web::error::ErrorUnauthorized("Authorization header must be a Bearer token"))?;
+            let jwt_secret = jwt_secret.ok_or_else(|| actix_web::error::ErrorInternalServerError("Missing app state"))?;
+
+            let data = decode::<Claims>(
+                token,
+                &DecodingKey::from_secret(jwt_secret.as_bytes()),
+                &Validation::new(Algorithm::HS256),
+            )
+            .map_err(|_| actix_web::error::ErrorUnauthorized("Invalid or expired token"))?;
+
+            Ok(data.claims)
+        })
+    }
+}
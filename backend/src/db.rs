@@ -35,3 +35,30 @@ CREATE TABLE api_keys (
     encrypted_key BYTEA NOT NULL,
     created_at TIMESTAMPTZ NOT NULL
 );
+
+CREATE TABLE refresh_tokens (
+    id UUID PRIMARY KEY,
+    family_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id),
+    token_hash BYTEA NOT NULL,
+    user_agent TEXT,
+    ip_address TEXT,
+    issued_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used BOOLEAN NOT NULL DEFAULT FALSE
+);
+CREATE INDEX refresh_tokens_token_hash_idx ON refresh_tokens (token_hash);
+CREATE INDEX refresh_tokens_family_id_idx ON refresh_tokens (family_id);
+
+ALTER TABLE users ADD COLUMN verified BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE users ADD COLUMN two_factor_enabled BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE users ADD COLUMN blocked BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE users ADD COLUMN failed_attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN locked_until TIMESTAMPTZ;
+
+ALTER TABLE users ADD COLUMN disabled BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE providers ADD COLUMN status TEXT NOT NULL DEFAULT 'pending';
+ALTER TABLE providers ADD COLUMN reviewed_by UUID REFERENCES users(id);
+ALTER TABLE providers ADD COLUMN reviewed_at TIMESTAMPTZ;
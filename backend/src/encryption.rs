@@ -1,8 +1,9 @@
 use aes_gcm::{
-    aead::{Aead, KeyInit, OsRng, generic_array::GenericArray},
+    aead::{generic_array::GenericArray, Aead, KeyInit, OsRng, Payload},
     Aes256Gcm, Nonce,
 };
 use anyhow::Result;
+use std::collections::HashMap;
 
 const NONCE_SIZE: usize = 12;
 
@@ -29,3 +30,266 @@ pub fn decrypt_metadata(key: &[u8; 32], ciphertext: &[u8]) -> Result<Vec<u8>> {
 
     Ok(decrypted)
 }
+
+// --- Keyed, AAD-bound envelope encryption ---
+//
+// `encrypt_metadata`/`decrypt_metadata` above take one caller-supplied key and
+// produce `nonce || ciphertext` with no way to tell which key encrypted it
+// or what record it belongs to, so a ciphertext blob could be copied between
+// rows undetected and an old key can never be retired. `Keyring` fixes both:
+// a 1-byte key-id is prepended so old ciphertexts stay readable across
+// rotations, and every call takes `associated_data` (e.g. the owning row's
+// UUID) bound into the AES-GCM tag so decryption fails if the blob is moved
+// to a different context.
+
+pub struct Keyring {
+    keys: HashMap<u8, [u8; 32]>,
+    current_key_id: u8,
+}
+
+impl Keyring {
+    pub fn new(keys: HashMap<u8, [u8; 32]>, current_key_id: u8) -> Result<Self> {
+        if !keys.contains_key(&current_key_id) {
+            anyhow::bail!("current_key_id {current_key_id} not present in keyring");
+        }
+        Ok(Self { keys, current_key_id })
+    }
+
+    /// Encrypts under the current key, prepending `key_id || nonce` to the
+    /// ciphertext and binding `associated_data` into the AEAD tag.
+    pub fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+        self.encrypt_with_key(self.current_key_id, plaintext, associated_data)
+    }
+
+    fn encrypt_with_key(&self, key_id: u8, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+        let key = self.keys.get(&key_id).ok_or_else(|| anyhow::anyhow!("unknown key id {key_id}"))?;
+        let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+        let nonce_bytes = rand::random::<[u8; NONCE_SIZE]>();
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let encrypted = cipher.encrypt(nonce, Payload { msg: plaintext, aad: associated_data })?;
+
+        let mut output = Vec::with_capacity(1 + NONCE_SIZE + encrypted.len());
+        output.push(key_id);
+        output.extend_from_slice(&nonce_bytes);
+        output.extend_from_slice(&encrypted);
+        Ok(output)
+    }
+
+    /// Reads the leading key-id byte to select the decryption key, then
+    /// verifies `associated_data` matches what was bound at encryption time.
+    pub fn decrypt(&self, blob: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+        if blob.len() < 1 + NONCE_SIZE {
+            anyhow::bail!("Ciphertext too short");
+        }
+        let key_id = blob[0];
+        let (nonce_bytes, encrypted) = blob[1..].split_at(NONCE_SIZE);
+
+        let key = self.keys.get(&key_id).ok_or_else(|| anyhow::anyhow!("unknown key id {key_id}"))?;
+        let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+        let nonce = Nonce::from_slice(nonce_bytes);
+
+        let decrypted = cipher.decrypt(nonce, Payload { msg: encrypted, aad: associated_data })?;
+        Ok(decrypted)
+    }
+
+    /// Re-encrypts `blob` (decrypted under whichever key id its leading byte
+    /// names) under `new_key_id`, keeping the same associated data. Callers persist the
+    /// returned blob in place of the old one.
+    pub fn rotate(&self, blob: &[u8], associated_data: &[u8], new_key_id: u8) -> Result<Vec<u8>> {
+        let plaintext = self.decrypt(blob, associated_data)?;
+        self.encrypt_with_key(new_key_id, &plaintext, associated_data)
+    }
+}
+
+// --- Per-provider envelope encryption (X25519 + AES-256-GCM) ---
+//
+// Instead of one global `PG_ENCRYPTION_PASSPHRASE` protecting every blob, each
+// provider gets its own X25519 keypair. To encrypt something for that provider
+// we do a Diffie-Hellman exchange between our server secret and the provider's
+// public key, use the resulting shared secret as the AES-256-GCM key, and
+// prepend a fresh random nonce to the ciphertext the same way
+// `encrypt_metadata`/`decrypt_metadata` already do.
+use x25519_dalek::{PublicKey, StaticSecret};
+
+/// Generates a fresh X25519 keypair for a provider. The secret is stored
+/// server-side (never sent to the client); the public key is stored alongside
+/// the provider record so callers can re-derive the shared key later.
+pub fn generate_provider_keypair() -> (StaticSecret, PublicKey) {
+    let secret = StaticSecret::new(OsRng);
+    let public = PublicKey::from(&secret);
+    (secret, public)
+}
+
+/// Derives the 32-byte symmetric key shared between the server and a given
+/// provider via X25519 Diffie-Hellman.
+pub fn derive_envelope_key(server_secret: &StaticSecret, provider_public: &PublicKey) -> [u8; 32] {
+    server_secret.diffie_hellman(provider_public).to_bytes()
+}
+
+/// Encrypts `plaintext` under `key`, returning `nonce || ciphertext`. This is
+/// the same construction as `encrypt_metadata` exposed under the name the
+/// envelope-encryption callers expect.
+pub fn encrypt_aes_gcm(plaintext: &[u8], key: &[u8; 32]) -> Result<Vec<u8>> {
+    encrypt_metadata(key, plaintext)
+}
+
+/// Reverses `encrypt_aes_gcm`: splits off the leading nonce and decrypts.
+pub fn decrypt_aes_gcm(blob: &[u8], key: &[u8; 32]) -> Result<Vec<u8>> {
+    decrypt_metadata(key, blob)
+}
+
+// --- Ephemeral per-recipient envelope encryption (ECDH + SHA-256 KDF) ---
+//
+// `derive_envelope_key` above always DHs a stored keypair against itself, so
+// it's really just that keypair's secret half wearing a second hat -- every
+// report for a given provider is encrypted under the exact same key. This
+// section gives each encryption its own single-use ephemeral keypair: the
+// shared secret (and therefore the AES key) differs every time even though
+// the recipient's public key never changes, so one leaked shared secret
+// exposes only the one ciphertext it was derived for.
+
+use sha2::{Digest, Sha256};
+
+/// Derives a 32-byte AES-256-GCM key from an X25519 shared secret. A plain
+/// SHA-256 digest is enough here since each shared secret is used for
+/// exactly one key (no multi-key hierarchy that would call for HKDF).
+fn kdf(shared_secret: &x25519_dalek::SharedSecret) -> [u8; 32] {
+    Sha256::digest(shared_secret.to_bytes()).into()
+}
+
+/// Encrypts `plaintext` for `recipient_public`: generates a fresh ephemeral
+/// X25519 keypair, derives the AES key from the ephemeral secret's
+/// Diffie-Hellman with `recipient_public`, and returns `ephemeral_pubkey (32
+/// bytes) || nonce (12 bytes) || ciphertext`. Only whoever holds the secret
+/// key matching `recipient_public` can recompute the shared secret and
+/// decrypt.
+pub fn encrypt_for_recipient(plaintext: &[u8], recipient_public: &PublicKey) -> Result<Vec<u8>> {
+    let ephemeral_secret = StaticSecret::new(OsRng);
+    let ephemeral_public = PublicKey::from(&ephemeral_secret);
+    let key = kdf(&ephemeral_secret.diffie_hellman(recipient_public));
+
+    let ciphertext = encrypt_metadata(&key, plaintext)?; // nonce || ciphertext
+
+    let mut output = Vec::with_capacity(32 + ciphertext.len());
+    output.extend_from_slice(ephemeral_public.as_bytes());
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+/// Reverses `encrypt_for_recipient`: splits off the leading ephemeral public
+/// key, re-derives the same shared secret via `recipient_secret`'s
+/// Diffie-Hellman against it, and decrypts the remaining `nonce ||
+/// ciphertext`.
+pub fn decrypt_from_sender(blob: &[u8], recipient_secret: &StaticSecret) -> Result<Vec<u8>> {
+    if blob.len() < 32 {
+        anyhow::bail!("Ciphertext too short");
+    }
+    let (ephemeral_pubkey_bytes, rest) = blob.split_at(32);
+    let mut ephemeral_pubkey_arr = [0u8; 32];
+    ephemeral_pubkey_arr.copy_from_slice(ephemeral_pubkey_bytes);
+    let ephemeral_public = PublicKey::from(ephemeral_pubkey_arr);
+
+    let key = kdf(&recipient_secret.diffie_hellman(&ephemeral_public));
+    decrypt_metadata(&key, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_roundtrips_through_encrypt_and_decrypt() {
+        let key = [7u8; 32];
+        let plaintext = b"provider secret payload";
+
+        let ciphertext = encrypt_metadata(&key, plaintext).unwrap();
+        let decrypted = decrypt_metadata(&key, &ciphertext).unwrap();
+
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn metadata_decrypt_fails_under_the_wrong_key() {
+        let ciphertext = encrypt_metadata(&[1u8; 32], b"secret").unwrap();
+        assert!(decrypt_metadata(&[2u8; 32], &ciphertext).is_err());
+    }
+
+    #[test]
+    fn keyring_roundtrips_and_binds_associated_data() {
+        let mut keys = HashMap::new();
+        keys.insert(0u8, [9u8; 32]);
+        let keyring = Keyring::new(keys, 0).unwrap();
+
+        let blob = keyring.encrypt(b"row payload", b"row-id-123").unwrap();
+
+        assert_eq!(blob[0], 0, "blob should be tagged with the key id it was sealed under");
+        assert_eq!(keyring.decrypt(&blob, b"row-id-123").unwrap(), b"row payload");
+
+        // A blob copied to a different row (different associated data)
+        // must fail to decrypt -- this is the whole point of binding AAD.
+        assert!(keyring.decrypt(&blob, b"row-id-456").is_err());
+    }
+
+    #[test]
+    fn keyring_rotate_reencrypts_under_the_new_key_id() {
+        let mut keys = HashMap::new();
+        keys.insert(0u8, [9u8; 32]);
+        keys.insert(1u8, [5u8; 32]);
+        let keyring = Keyring::new(keys, 1).unwrap();
+
+        let old_blob = {
+            let mut old_keys = HashMap::new();
+            old_keys.insert(0u8, [9u8; 32]);
+            Keyring::new(old_keys, 0).unwrap().encrypt(b"payload", b"aad").unwrap()
+        };
+
+        let rotated = keyring.rotate(&old_blob, b"aad", 1).unwrap();
+
+        assert_eq!(rotated[0], 1);
+        assert_eq!(keyring.decrypt(&rotated, b"aad").unwrap(), b"payload");
+    }
+
+    #[test]
+    fn keyring_new_rejects_an_unknown_current_key_id() {
+        let keys = HashMap::new();
+        assert!(Keyring::new(keys, 0).is_err());
+    }
+
+    #[test]
+    fn recipient_envelope_roundtrips_and_varies_per_call() {
+        let (recipient_secret, recipient_public) = generate_provider_keypair();
+
+        let sealed_a = encrypt_for_recipient(b"report one", &recipient_public).unwrap();
+        let sealed_b = encrypt_for_recipient(b"report one", &recipient_public).unwrap();
+
+        assert_eq!(decrypt_from_sender(&sealed_a, &recipient_secret).unwrap(), b"report one");
+        assert_eq!(decrypt_from_sender(&sealed_b, &recipient_secret).unwrap(), b"report one");
+        // Each call uses a fresh ephemeral keypair, so even the same
+        // plaintext sealed twice for the same recipient yields distinct
+        // ciphertexts -- a leaked shared secret only exposes one report.
+        assert_ne!(sealed_a, sealed_b);
+    }
+
+    #[test]
+    fn recipient_envelope_fails_for_the_wrong_recipient() {
+        let (_recipient_secret, recipient_public) = generate_provider_keypair();
+        let (other_secret, _other_public) = generate_provider_keypair();
+
+        let sealed = encrypt_for_recipient(b"report", &recipient_public).unwrap();
+
+        assert!(decrypt_from_sender(&sealed, &other_secret).is_err());
+    }
+
+    #[test]
+    fn derive_envelope_key_agrees_between_both_sides() {
+        let (server_secret, _server_public) = generate_provider_keypair();
+        let (provider_secret, provider_public) = generate_provider_keypair();
+        let server_public = PublicKey::from(&server_secret);
+
+        let server_side_key = derive_envelope_key(&server_secret, &provider_public);
+        let provider_side_key = derive_envelope_key(&provider_secret, &server_public);
+
+        assert_eq!(server_side_key, provider_side_key);
+    }
+}
@@ -0,0 +1,90 @@
+//! Crate-wide HTTP-facing error type for the axum handlers in `handlers.rs`.
+//! Handlers return `Result<impl IntoResponse, ApiError>` and use `?` on
+//! fallible calls instead of `.unwrap()`-ing a DB result or hand-rolling a
+//! JSON error body in every branch; `IntoResponse` turns any variant into a
+//! stable `{ "error": "<code>", "message": "..." }` body matching
+//! `models::ErrorResponse`.
+
+use crate::models::ErrorResponse;
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("{0}")]
+    Sqlx(sqlx::Error),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("Invalid email or password")]
+    Unauthorized,
+    #[error("User with email already exists")]
+    EmailExists,
+    #[error("{0}")]
+    Encryption(String),
+    #[error("{0}")]
+    Internal(String),
+}
+
+/// Maps a raw `sqlx::Error` to `ApiError`, special-casing a unique-violation
+/// on `users` (i.e. a duplicate email slipping past the pre-check under a
+/// race) as `EmailExists` instead of a generic 500.
+impl From<sqlx::Error> for ApiError {
+    fn from(err: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(db_err) = &err {
+            if db_err.is_unique_violation() && db_err.table() == Some("users") {
+                return ApiError::EmailExists;
+            }
+        }
+        ApiError::Sqlx(err)
+    }
+}
+
+impl ApiError {
+    fn code(&self) -> &'static str {
+        match self {
+            ApiError::Sqlx(_) => "database_error",
+            ApiError::NotFound(_) => "not_found",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::Unauthorized => "unauthorized",
+            ApiError::EmailExists => "email_exists",
+            ApiError::Encryption(_) => "encryption_error",
+            ApiError::Internal(_) => "internal_error",
+        }
+    }
+
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::Sqlx(_) | ApiError::Encryption(_) | ApiError::Internal(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Conflict(_) | ApiError::EmailExists => StatusCode::CONFLICT,
+            ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let body = ErrorResponse {
+            error: self.code().to_string(),
+            message: Some(self.to_string()),
+        };
+        (status, Json(body)).into_response()
+    }
+}
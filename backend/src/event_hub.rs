@@ -0,0 +1,138 @@
+// Topic-based WebSocket pub/sub for live slot/booking/alert updates.
+//
+// `/ws/slots` used to just echo text back. This gives it a real backbone:
+// a concurrent map of topic -> broadcast channel, where server-side mutations
+// (slot overrides, reservation confirmations, new alerts) publish a typed
+// `Event`, and each connected client subscribes to whichever topics it cares
+// about. Events are published only to `slots`, `slots:{id}` and `alerts`; a
+// client may subscribe to any other name (e.g. `bookings:{provider_id}`), but
+// nothing is published there.
+
+use axum::extract::ws::{Message, WebSocket};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+pub type Topic = String;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum Event {
+    SlotOverridden { slot_id: String, status: String },
+    SlotReserved { slot_id: String, reservation_id: String },
+    ReservationConfirmed { reservation_id: String, slot_id: String },
+    AlertCreated { alert_id: String, title: String, severity: String },
+}
+
+impl Event {
+    fn topics(&self) -> Vec<Topic> {
+        match self {
+            Event::SlotOverridden { slot_id, .. } | Event::SlotReserved { slot_id, .. } => {
+                vec!["slots".to_string(), format!("slots:{slot_id}")]
+            }
+            Event::ReservationConfirmed { slot_id, .. } => {
+                vec!["slots".to_string(), format!("slots:{slot_id}")]
+            }
+            Event::AlertCreated { .. } => vec!["alerts".to_string()],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EventHub {
+    channels: std::sync::Arc<DashMap<Topic, broadcast::Sender<Event>>>,
+}
+
+impl EventHub {
+    pub fn new() -> Self {
+        Self { channels: std::sync::Arc::new(DashMap::new()) }
+    }
+
+    fn sender_for(&self, topic: &str) -> broadcast::Sender<Event> {
+        self.channels
+            .entry(topic.to_string())
+            .or_insert_with(|| broadcast::channel(256).0)
+            .clone()
+    }
+
+    /// Publishes to every topic the event is relevant to. No-op if nobody's
+    /// listening on a given topic yet (channel lazily created on first use).
+    pub fn publish(&self, event: Event) {
+        for topic in event.topics() {
+            let _ = self.sender_for(&topic).send(event.clone());
+        }
+    }
+
+    pub fn subscribe(&self, topic: &str) -> broadcast::Receiver<Event> {
+        self.sender_for(topic).subscribe()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action")]
+enum ClientFrame {
+    #[serde(rename = "subscribe")]
+    Subscribe { topics: Vec<String> },
+}
+
+const PING_INTERVAL: tokio::time::Duration = tokio::time::Duration::from_secs(30);
+
+/// Drives one `/ws/slots` connection: waits for a `subscribe` frame listing
+/// topics, then fans the merged broadcast stream out to the socket. Slow
+/// subscribers are dropped (rather than blocking publishers) if their
+/// receiver lags; a periodic ping frame keeps the connection alive and lets
+/// the client detect a dead server.
+pub async fn handle_slots_socket(mut socket: WebSocket, hub: EventHub) {
+    let topics = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match serde_json::from_str::<ClientFrame>(&text) {
+            Ok(ClientFrame::Subscribe { topics }) => topics,
+            Err(_) => vec!["slots".to_string()],
+        },
+        _ => vec!["slots".to_string()],
+    };
+
+    let mut receivers: Vec<broadcast::Receiver<Event>> =
+        topics.iter().map(|t| hub.subscribe(t)).collect();
+
+    let mut ping_timer = tokio::time::interval(PING_INTERVAL);
+
+    loop {
+        if receivers.is_empty() {
+            break;
+        }
+        // Poll all subscribed topic receivers plus the ping timer; whichever
+        // is ready first gets handled. `select_all` would need index bookkeeping
+        // across drops, so a small manual poll loop keeps this simple.
+        let mut delivered = false;
+        for i in 0..receivers.len() {
+            match receivers[i].try_recv() {
+                Ok(event) => {
+                    let payload = match serde_json::to_string(&event) {
+                        Ok(p) => p,
+                        Err(_) => continue,
+                    };
+                    if socket.send(Message::Text(payload)).await.is_err() {
+                        return;
+                    }
+                    delivered = true;
+                }
+                Err(broadcast::error::TryRecvError::Lagged(_)) => {
+                    // Slow subscriber: drop it instead of blocking the publisher.
+                    tracing::warn!("ws subscriber lagged on topic, dropping connection");
+                    return;
+                }
+                Err(broadcast::error::TryRecvError::Closed) => return,
+                Err(broadcast::error::TryRecvError::Empty) => {}
+            }
+        }
+
+        tokio::select! {
+            _ = ping_timer.tick() => {
+                if socket.send(Message::Ping(vec![])).await.is_err() {
+                    return;
+                }
+            }
+            _ = tokio::time::sleep(tokio::time::Duration::from_millis(if delivered { 0 } else { 200 })) => {}
+        }
+    }
+}
@@ -0,0 +1,212 @@
+// Server-to-server federation: independent OrbitalOS deployments advertise
+// their confirmed reservations and conjunction alerts to a configured list
+// of peers so a slot booked on one node shows as unavailable on the others.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReservationAdvert {
+    pub slot_id: String,
+    pub reservation_id: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ConjunctionAdvert {
+    pub satellite_a_norad_id: i64,
+    pub satellite_b_norad_id: i64,
+    pub probability: f64,
+    pub tca: DateTime<Utc>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum FederatedPayload {
+    Reservations(Vec<ReservationAdvert>),
+    Conjunctions(Vec<ConjunctionAdvert>),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FederatedMessage {
+    pub origin_id: String,
+    pub seq: u64,
+    pub payload: FederatedPayload,
+    /// base64 ed25519 signature over the canonical JSON of `(origin_id, seq, payload)`.
+    pub signature: String,
+}
+
+struct PeerState {
+    base_url: String,
+    verifying_key: VerifyingKey,
+    last_seen_seq: u64,
+    reachable: bool,
+}
+
+pub struct FederationState {
+    node_id: String,
+    signing_key: SigningKey,
+    peers: Mutex<HashMap<String, PeerState>>,
+    // (origin_id, seq) pairs already merged, to deduplicate redelivery/retries.
+    seen: Mutex<HashSet<(String, u64)>>,
+    next_seq: std::sync::atomic::AtomicU64,
+    pub merged_reservations: Mutex<Vec<ReservationAdvert>>,
+    pub merged_conjunctions: Mutex<Vec<ConjunctionAdvert>>,
+}
+
+fn signing_payload(origin_id: &str, seq: u64, payload: &FederatedPayload) -> Vec<u8> {
+    serde_json::to_vec(&(origin_id, seq, payload)).unwrap_or_default()
+}
+
+impl FederationState {
+    pub fn new(node_id: String) -> Self {
+        let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+        Self {
+            node_id,
+            signing_key,
+            peers: Mutex::new(HashMap::new()),
+            seen: Mutex::new(HashSet::new()),
+            next_seq: std::sync::atomic::AtomicU64::new(0),
+            merged_reservations: Mutex::new(Vec::new()),
+            merged_conjunctions: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn register_peer(&self, name: String, base_url: String, verifying_key: VerifyingKey) {
+        self.peers.lock().unwrap().insert(
+            name,
+            PeerState { base_url, verifying_key, last_seen_seq: 0, reachable: true },
+        );
+    }
+
+    fn sign(&self, payload: FederatedPayload) -> FederatedMessage {
+        let seq = self.next_seq.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let bytes = signing_payload(&self.node_id, seq, &payload);
+        let signature: Signature = self.signing_key.sign(&bytes);
+        FederatedMessage {
+            origin_id: self.node_id.clone(),
+            seq,
+            payload,
+            signature: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, signature.to_bytes()),
+        }
+    }
+
+    /// Sends `msg` to every registered peer, retrying with exponential
+    /// backoff on failure and marking the peer unreachable until it succeeds.
+    pub async fn broadcast(&self, msg: FederatedMessage) {
+        let targets: Vec<(String, String)> = {
+            let peers = self.peers.lock().unwrap();
+            peers.iter().map(|(k, v)| (k.clone(), v.base_url.clone())).collect()
+        };
+
+        for (name, base_url) in targets {
+            let client = reqwest::Client::new();
+            let path = match &msg.payload {
+                FederatedPayload::Reservations(_) => "/api/federation/reservations",
+                FederatedPayload::Conjunctions(_) => "/api/federation/conjunctions",
+            };
+            let url = format!("{base_url}{path}");
+
+            let mut delay_ms = 500u64;
+            let mut delivered = false;
+            for _attempt in 0..3 {
+                match client.post(&url).json(&msg).send().await {
+                    Ok(resp) if resp.status().is_success() => {
+                        delivered = true;
+                        break;
+                    }
+                    _ => {
+                        tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+                        delay_ms = (delay_ms * 2).min(30_000);
+                    }
+                }
+            }
+            if let Some(peer) = self.peers.lock().unwrap().get_mut(&name) {
+                peer.reachable = delivered;
+            }
+        }
+    }
+
+    /// Verifies and merges an inbound message from a peer, deduplicating by
+    /// `(origin, seq)`. Returns `Err` with an HTTP status to return on
+    /// signature failure or unknown origin.
+    fn ingest(&self, msg: FederatedMessage) -> Result<(), StatusCode> {
+        let peers = self.peers.lock().unwrap();
+        let peer = peers.get(&msg.origin_id).ok_or(StatusCode::FORBIDDEN)?;
+
+        let sig_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &msg.signature)
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+        let signature = Signature::from_slice(&sig_bytes).map_err(|_| StatusCode::BAD_REQUEST)?;
+        let bytes = signing_payload(&msg.origin_id, msg.seq, &msg.payload);
+        peer.verifying_key
+            .verify(&bytes, &signature)
+            .map_err(|_| StatusCode::UNAUTHORIZED)?;
+        drop(peers);
+
+        let key = (msg.origin_id.clone(), msg.seq);
+        if !self.seen.lock().unwrap().insert(key) {
+            return Ok(()); // already merged, dedup no-op
+        }
+
+        if let Some(mut peer) = self.peers.lock().unwrap().get_mut(&msg.origin_id) {
+            peer.last_seen_seq = peer.last_seen_seq.max(msg.seq);
+        }
+
+        match msg.payload {
+            FederatedPayload::Reservations(r) => self.merged_reservations.lock().unwrap().extend(r),
+            FederatedPayload::Conjunctions(c) => self.merged_conjunctions.lock().unwrap().extend(c),
+        }
+        Ok(())
+    }
+
+    pub fn status_summary(&self) -> serde_json::Value {
+        let peers = self.peers.lock().unwrap();
+        serde_json::json!(peers
+            .iter()
+            .map(|(name, p)| serde_json::json!({
+                "name": name,
+                "reachable": p.reachable,
+                "last_seen_seq": p.last_seen_seq,
+            }))
+            .collect::<Vec<_>>())
+    }
+}
+
+pub async fn receive_reservations(
+    State(state): State<crate::AppState>,
+    Json(msg): Json<FederatedMessage>,
+) -> impl IntoResponse {
+    match state.federation.ingest(msg) {
+        Ok(()) => StatusCode::OK,
+        Err(code) => code,
+    }
+}
+
+pub async fn receive_conjunctions(
+    State(state): State<crate::AppState>,
+    Json(msg): Json<FederatedMessage>,
+) -> impl IntoResponse {
+    match state.federation.ingest(msg) {
+        Ok(()) => StatusCode::OK,
+        Err(code) => code,
+    }
+}
+
+/// Call after confirming a reservation locally to advertise it to peers so
+/// their conflict-checking treats the slot as unavailable too.
+pub async fn advertise_reservation(fed: &FederationState, advert: ReservationAdvert) {
+    let msg = fed.sign(FederatedPayload::Reservations(vec![advert]));
+    fed.broadcast(msg).await;
+}
+
+/// Call with locally computed conjunction alerts to advertise them to peers,
+/// which merge them into their `merged_conjunctions`.
+pub async fn advertise_conjunctions(fed: &FederationState, adverts: Vec<ConjunctionAdvert>) {
+    let msg = fed.sign(FederatedPayload::Conjunctions(adverts));
+    fed.broadcast(msg).await;
+}
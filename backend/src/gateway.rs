@@ -0,0 +1,150 @@
+// Reverse-proxy gateway so clients only need one origin: requests under
+// /api/satellites/*, /api/ground-stations, and the satellite backend's
+// visible/track/statistics paths are forwarded to the standalone
+// `sat_api`/`SatelliteService` backend (actix-web, :8080) instead of making
+// clients juggle two origins and two CORS policies.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use std::sync::Mutex;
+
+#[derive(Clone)]
+struct UpstreamTarget {
+    base_url: String,
+    healthy: bool,
+    last_checked: DateTime<Utc>,
+}
+
+pub struct UpstreamRegistry {
+    targets: Mutex<Vec<UpstreamTarget>>,
+    client: reqwest::Client,
+}
+
+impl UpstreamRegistry {
+    pub fn new(base_urls: Vec<String>) -> Self {
+        let targets = base_urls
+            .into_iter()
+            .map(|base_url| UpstreamTarget { base_url, healthy: true, last_checked: Utc::now() })
+            .collect();
+        Self { targets: Mutex::new(targets), client: reqwest::Client::new() }
+    }
+
+    fn healthy_targets(&self) -> Vec<String> {
+        self.targets.lock().unwrap().iter().filter(|t| t.healthy).map(|t| t.base_url.clone()).collect()
+    }
+
+    pub fn reachability_summary(&self) -> serde_json::Value {
+        let targets = self.targets.lock().unwrap();
+        serde_json::json!(targets
+            .iter()
+            .map(|t| serde_json::json!({ "base_url": t.base_url, "healthy": t.healthy }))
+            .collect::<Vec<_>>())
+    }
+
+    /// Background poller: hits each target's `/health` periodically and
+    /// flips it in/out of rotation based on the response.
+    pub async fn start_health_poller(self: std::sync::Arc<Self>) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(15));
+            loop {
+                interval.tick().await;
+                let urls: Vec<String> =
+                    self.targets.lock().unwrap().iter().map(|t| t.base_url.clone()).collect();
+                for url in urls {
+                    let healthy = self
+                        .client
+                        .get(format!("{url}/health"))
+                        .timeout(tokio::time::Duration::from_secs(3))
+                        .send()
+                        .await
+                        .map(|r| r.status().is_success())
+                        .unwrap_or(false);
+                    let mut targets = self.targets.lock().unwrap();
+                    if let Some(t) = targets.iter_mut().find(|t| t.base_url == url) {
+                        t.healthy = healthy;
+                        t.last_checked = Utc::now();
+                    }
+                }
+            }
+        });
+    }
+}
+
+const PROXIED_PREFIXES: &[&str] = &[
+    "/api/satellites",
+    "/api/ground-stations",
+    "/api/visible",
+    "/api/track",
+    "/api/statistics",
+];
+
+pub fn should_proxy(path: &str) -> bool {
+    PROXIED_PREFIXES.iter().any(|p| path.starts_with(p))
+}
+
+/// Forwards method, headers, query, and body to the first healthy upstream
+/// target, streaming the response back unbuffered. Tries the next target on
+/// connection failure or 5xx; returns a structured 502 only if every target
+/// is down.
+pub async fn proxy_to_satellite_service(
+    State(state): State<crate::AppState>,
+    req: Request,
+) -> impl IntoResponse {
+    let method = req.method().clone();
+    let headers = req.headers().clone();
+    let path_and_query = req.uri().path_and_query().map(|pq| pq.as_str().to_string()).unwrap_or_default();
+    let body_bytes = match axum::body::to_bytes(req.into_body(), usize::MAX).await {
+        Ok(b) => b,
+        Err(_) => return (StatusCode::BAD_REQUEST, "Failed to read request body").into_response(),
+    };
+
+    let client = reqwest::Client::new();
+    for base_url in state.upstream_registry.healthy_targets() {
+        let uri: Uri = match format!("{base_url}{path_and_query}").parse() {
+            Ok(u) => u,
+            Err(_) => continue,
+        };
+
+        let mut builder = client.request(
+            reqwest::Method::from_bytes(method.as_str().as_bytes()).unwrap(),
+            uri.to_string(),
+        );
+        for (name, value) in headers.iter() {
+            if name.as_str().eq_ignore_ascii_case("host") {
+                continue;
+            }
+            builder = builder.header(name.as_str(), value.as_bytes());
+        }
+        builder = builder.body(body_bytes.clone());
+
+        match builder.send().await {
+            Ok(upstream_resp) if !upstream_resp.status().is_server_error() => {
+                let status = upstream_resp.status();
+                let mut response_builder = Response::builder().status(status.as_u16());
+                for (name, value) in upstream_resp.headers().iter() {
+                    response_builder = response_builder.header(name, value);
+                }
+                let stream = upstream_resp.bytes_stream();
+                return response_builder
+                    .body(Body::from_stream(stream))
+                    .unwrap()
+                    .into_response();
+            }
+            _ => continue, // try the next registered target
+        }
+    }
+
+    (
+        StatusCode::BAD_GATEWAY,
+        axum::Json(serde_json::json!({
+            "error": "UpstreamUnavailable",
+            "message": "All satellite-service upstream targets are down",
+        })),
+    )
+        .into_response()
+}
@@ -0,0 +1,122 @@
+//! Renders the current `SatelliteService` state (satellite positions, an
+//! optional forward ground track per satellite, and ground stations) as a
+//! GeoJSON `FeatureCollection`, so web map clients (Leaflet/Mapbox/deck.gl)
+//! can consume OrbitalOS output directly without reshaping the bespoke
+//! `Satellite`/`GroundStation` JSON into map-ready geometry.
+
+use crate::satellite_service::{GroundStation, Satellite};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// A GeoJSON `FeatureCollection` of satellite/ground-station features.
+#[derive(Debug, Serialize)]
+pub struct FeatureCollection {
+    #[serde(rename = "type")]
+    pub feature_type: &'static str,
+    pub features: Vec<Value>,
+}
+
+/// Builds a `Point` feature for one satellite's current position, with
+/// `id`, `name`, `norad_id`, `altitude`, `velocity`, `satellite_type`, and
+/// `status` carried as properties.
+fn satellite_feature(satellite: &Satellite) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": [satellite.longitude, satellite.latitude],
+        },
+        "properties": {
+            "id": satellite.id,
+            "name": satellite.name,
+            "norad_id": satellite.norad_id,
+            "altitude": satellite.altitude,
+            "velocity": satellite.velocity,
+            "satellite_type": satellite.satellite_type,
+            "status": satellite.status,
+        },
+    })
+}
+
+/// Builds a `Point` feature for one ground station.
+fn ground_station_feature(station: &GroundStation) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": [station.longitude, station.latitude],
+        },
+        "properties": {
+            "id": station.id,
+            "name": station.name,
+            "min_elevation": station.min_elevation,
+        },
+    })
+}
+
+/// Builds a `LineString` feature for a satellite's ground track, split at
+/// the antimeridian (wherever consecutive samples' longitude jumps by more
+/// than 180 degrees) so each segment renders as a straight line instead of
+/// wrapping across the whole map.
+fn ground_track_features(satellite_id: &str, name: &str, samples: &[(f64, f64)]) -> Vec<Value> {
+    let mut segments: Vec<Vec<[f64; 2]>> = Vec::new();
+    let mut current: Vec<[f64; 2]> = Vec::new();
+
+    for &(lon, lat) in samples {
+        if let Some(last) = current.last() {
+            if (lon - last[0]).abs() > 180.0 {
+                segments.push(std::mem::take(&mut current));
+            }
+        }
+        current.push([lon, lat]);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+
+    segments
+        .into_iter()
+        .filter(|segment| segment.len() >= 2)
+        .map(|coordinates| {
+            json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": coordinates,
+                },
+                "properties": {
+                    "id": satellite_id,
+                    "name": name,
+                    "kind": "ground_track",
+                },
+            })
+        })
+        .collect()
+}
+
+/// Builds the `FeatureCollection` for a set of satellites and ground
+/// stations. `ground_tracks` pairs each satellite's id with its forward
+/// `(longitude, latitude)` samples, already ordered in time; satellites
+/// with no entry get only their position `Point`.
+pub fn to_feature_collection(
+    satellites: &[Satellite],
+    ground_stations: &[GroundStation],
+    ground_tracks: &std::collections::HashMap<String, Vec<(f64, f64)>>,
+) -> FeatureCollection {
+    let mut features = Vec::with_capacity(satellites.len() + ground_stations.len());
+
+    for satellite in satellites {
+        features.push(satellite_feature(satellite));
+        if let Some(samples) = ground_tracks.get(&satellite.id) {
+            features.extend(ground_track_features(&satellite.id, &satellite.name, samples));
+        }
+    }
+    for station in ground_stations {
+        features.push(ground_station_feature(station));
+    }
+
+    FeatureCollection {
+        feature_type: "FeatureCollection",
+        features,
+    }
+}
@@ -1,21 +1,4 @@
 use tracing::info;
-    info!("ADMIN: List users endpoint called");
-    info!("ADMIN: Get user endpoint called: {}", _id);
-    info!("ADMIN: Update user endpoint called: {}", _id);
-    info!("ADMIN: Delete user endpoint called: {}", _id);
-    info!("ADMIN: List providers endpoint called");
-    info!("ADMIN: Get provider endpoint called: {}", _id);
-    info!("ADMIN: Approve provider endpoint called: {}", _id);
-    info!("ADMIN: Reject provider endpoint called: {}", _id);
-    info!("ADMIN: List slots endpoint called");
-    info!("ADMIN: Get slot endpoint called: {}", _id);
-    info!("ADMIN: Override slot endpoint called: {}", _id);
-    info!("ADMIN: List bookings endpoint called");
-    info!("ADMIN: Get booking endpoint called: {}", _id);
-    info!("ADMIN: Update booking endpoint called: {}", _id);
-    info!("ADMIN: Delete booking endpoint called: {}", _id);
-    info!("ADMIN: List compliance reports endpoint called");
-    info!("ADMIN: Review compliance report endpoint called: {}", _id);
 // --- Admin role check middleware ---
 use axum::{extract::Extension, http::Request, middleware::Next, response::IntoResponse};
 use crate::auth::Claims;
@@ -26,6 +9,7 @@ use axum::extract::TypedHeader;
 pub async fn require_admin<B>(
     TypedHeader(Authorization(bearer)): TypedHeader<Authorization<Bearer>>,
     Extension(jwt_secret): Extension<String>,
+    Extension(pool): Extension<sqlx::PgPool>,
     req: Request<B>,
     next: Next<B>,
 ) -> impl IntoResponse {
@@ -35,101 +19,479 @@ pub async fn require_admin<B>(
         &DecodingKey::from_secret(jwt_secret.as_ref()),
         &Validation::default(),
     );
-    match claims {
-        Ok(data) if data.claims.role == "admin" => next.run(req).await,
-        _ => (axum::http::StatusCode::FORBIDDEN, "Admin access required").into_response(),
+    let claims = match claims {
+        Ok(data) if data.claims.role == "admin" => data.claims,
+        _ => return (axum::http::StatusCode::FORBIDDEN, "Admin access required").into_response(),
+    };
+    // Same session-revocation check `RequireRole`/`RequireAuth` apply: an
+    // admin JWT minted before a `logout` (or a detected refresh-token
+    // replay) shouldn't keep working just because it hasn't hit `exp` yet.
+    if let Some(sid) = claims.sid {
+        match crate::sessions::is_active(&pool, sid).await {
+            Ok(true) => {}
+            Ok(false) => {
+                return (axum::http::StatusCode::UNAUTHORIZED, "Session has been revoked").into_response()
+            }
+            Err(_) => {
+                return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, "Could not verify session")
+                    .into_response()
+            }
+        }
     }
+    next.run(req).await
+}
+// --- Admin CRUD and override handlers ---
+//
+// Every handler below, except the `NOT_IMPLEMENTED` stubs
+// `admin_delete_booking`, `admin_list_compliance_reports` and
+// `admin_review_compliance_report`, also takes `RequireRole<AdminRole>` (same guard
+// `block_user` already uses) so a non-admin JWT is rejected with 403 before
+// the body runs, on top of whatever `require_admin` is layered on the route
+// in `main.rs`.
+use axum::extract::{Path, Json, Query, State};
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/users",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "All users"),
+        (status = 403, description = "Caller is not an admin"),
+    ),
+)]
+pub async fn admin_list_users(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: List users endpoint called");
+    let users = sqlx::query!("SELECT id, username, email, role_id, disabled FROM users ORDER BY username")
+        .fetch_all(&pool)
+        .await?;
+
+    let users: Vec<_> = users
+        .into_iter()
+        .map(|u| serde_json::json!({
+            "id": u.id,
+            "username": u.username,
+            "email": u.email,
+            "role_id": u.role_id,
+            "disabled": u.disabled,
+        }))
+        .collect();
+
+    Ok(axum::Json(users))
 }
-// --- Admin CRUD and override handler stubs ---
-use axum::{extract::{Path, Json}, http::StatusCode};
 
-pub async fn admin_list_users() -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("List users: Not implemented".to_string()),
-    }))
+#[utoipa::path(
+    get,
+    path = "/api/admin/users/{id}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(("id" = uuid::Uuid, Path, description = "User id")),
+    responses(
+        (status = 200, description = "The user"),
+        (status = 403, description = "Caller is not an admin"),
+        (status = 404, description = "User not found"),
+    ),
+)]
+pub async fn admin_get_user(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Get user endpoint called: {}", id);
+    let user = sqlx::query!("SELECT id, username, email, role_id, disabled FROM users WHERE id = $1", id)
+        .fetch_optional(&pool)
+        .await?
+        .ok_or_else(|| crate::error::ApiError::NotFound("User not found".to_string()))?;
+
+    Ok(axum::Json(serde_json::json!({
+        "id": user.id,
+        "username": user.username,
+        "email": user.email,
+        "role_id": user.role_id,
+        "disabled": user.disabled,
+    })))
 }
-pub async fn admin_get_user(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Get user: Not implemented".to_string()),
-    }))
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct AdminUpdateUserRequest {
+    pub username: Option<String>,
+    pub role_id: Option<i32>,
+    pub disabled: Option<bool>,
 }
-pub async fn admin_update_user(Path(_id): Path<String>, Json(_): Json<()>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Update user: Not implemented".to_string()),
-    }))
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(("id" = uuid::Uuid, Path, description = "User id")),
+    request_body = AdminUpdateUserRequest,
+    responses(
+        (status = 200, description = "User updated"),
+        (status = 403, description = "Caller is not an admin"),
+        (status = 404, description = "User not found"),
+    ),
+)]
+pub async fn admin_update_user(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+    Json(payload): Json<AdminUpdateUserRequest>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Update user endpoint called: {}", id);
+    let existing = sqlx::query!("SELECT username, role_id, disabled FROM users WHERE id = $1", id)
+        .fetch_optional(&pool)
+        .await?
+        .ok_or_else(|| crate::error::ApiError::NotFound("User not found".to_string()))?;
+
+    let username = payload.username.unwrap_or(existing.username);
+    let role_id = payload.role_id.unwrap_or(existing.role_id);
+    let disabled = payload.disabled.unwrap_or(existing.disabled);
+
+    sqlx::query!(
+        "UPDATE users SET username = $1, role_id = $2, disabled = $3 WHERE id = $4",
+        username, role_id, disabled, id,
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(axum::Json(serde_json::json!({ "id": id, "username": username, "role_id": role_id, "disabled": disabled })))
 }
-pub async fn admin_delete_user(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Delete user: Not implemented".to_string()),
-    }))
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/users/{id}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(("id" = uuid::Uuid, Path, description = "User id")),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 403, description = "Caller is not an admin"),
+        (status = 404, description = "User not found"),
+    ),
+)]
+pub async fn admin_delete_user(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Delete user endpoint called: {}", id);
+    let result = sqlx::query!("DELETE FROM users WHERE id = $1", id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(crate::error::ApiError::NotFound("User not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
 }
 
-pub async fn admin_list_providers() -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("List providers: Not implemented".to_string()),
-    }))
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct BlockUserRequest {
+    pub blocked: bool,
 }
-pub async fn admin_get_provider(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Get provider: Not implemented".to_string()),
-    }))
+
+/// `POST /api/users/:id/block` — admin-only; other roles can't lock each
+/// other out since `RequireRole<AdminRole>` rejects with 403 before this
+/// body runs.
+#[utoipa::path(
+    post,
+    path = "/api/users/{id}/block",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(("id" = Uuid, Path, description = "User id")),
+    request_body = BlockUserRequest,
+    responses(
+        (status = 200, description = "User's blocked flag updated"),
+        (status = 403, description = "Caller is not an admin"),
+    ),
+)]
+pub async fn block_user(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<BlockUserRequest>,
+) -> impl axum::response::IntoResponse {
+    let result = sqlx::query("UPDATE users SET blocked = $1, updated_at = $2 WHERE id = $3")
+        .bind(payload.blocked)
+        .bind(chrono::Utc::now())
+        .bind(id)
+        .execute(&pool)
+        .await;
+
+    match result {
+        Ok(_) => (StatusCode::OK, axum::Json(serde_json::json!({ "id": id, "blocked": payload.blocked }))).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, axum::Json(crate::models::ErrorResponse {
+            error: "DatabaseError".to_string(),
+            message: Some(err.to_string()),
+        })).into_response(),
+    }
 }
-pub async fn admin_approve_provider(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Approve provider: Not implemented".to_string()),
-    }))
+
+#[derive(serde::Deserialize)]
+pub struct AdminListProvidersParams {
+    pub status: Option<String>,
 }
-pub async fn admin_reject_provider(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Reject provider: Not implemented".to_string()),
-    }))
+
+pub async fn admin_list_providers(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Query(params): Query<AdminListProvidersParams>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: List providers endpoint called");
+    let providers = sqlx::query!(
+        "SELECT id, user_id, status, created_at, reviewed_by, reviewed_at
+         FROM providers
+         WHERE $1::text IS NULL OR status = $1
+         ORDER BY created_at",
+        params.status,
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let providers: Vec<_> = providers
+        .into_iter()
+        .map(|p| serde_json::json!({
+            "id": p.id,
+            "user_id": p.user_id,
+            "status": p.status,
+            "created_at": p.created_at,
+            "reviewed_by": p.reviewed_by,
+            "reviewed_at": p.reviewed_at,
+        }))
+        .collect();
+
+    Ok(axum::Json(providers))
 }
 
-pub async fn admin_list_slots() -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("List slots: Not implemented".to_string()),
-    }))
+pub async fn admin_get_provider(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Get provider endpoint called: {}", id);
+    let provider = sqlx::query!(
+        "SELECT id, user_id, status, created_at, reviewed_by, reviewed_at FROM providers WHERE id = $1",
+        id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| crate::error::ApiError::NotFound("Provider not found".to_string()))?;
+
+    Ok(axum::Json(serde_json::json!({
+        "id": provider.id,
+        "user_id": provider.user_id,
+        "status": provider.status,
+        "created_at": provider.created_at,
+        "reviewed_by": provider.reviewed_by,
+        "reviewed_at": provider.reviewed_at,
+    })))
 }
-pub async fn admin_get_slot(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Get slot: Not implemented".to_string()),
-    }))
+
+/// Moves a provider out of `pending` into `new_status`, recording who acted
+/// and when. Only valid from `pending` — approving/rejecting a provider a
+/// second time returns a conflict instead of silently overwriting the first
+/// decision.
+async fn transition_provider_status(
+    pool: &sqlx::PgPool,
+    provider_id: uuid::Uuid,
+    new_status: &str,
+    admin: &Claims,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    let reviewer_id = uuid::Uuid::parse_str(&admin.sub)
+        .map_err(|_| crate::error::ApiError::Internal("Invalid admin id in token".to_string()))?;
+    let now = chrono::Utc::now();
+
+    let result = sqlx::query!(
+        "UPDATE providers SET status = $1, reviewed_by = $2, reviewed_at = $3
+         WHERE id = $4 AND status = 'pending'",
+        new_status, reviewer_id, now, provider_id,
+    )
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        let exists = sqlx::query!("SELECT id FROM providers WHERE id = $1", provider_id)
+            .fetch_optional(pool)
+            .await?;
+        return match exists {
+            Some(_) => Err(crate::error::ApiError::Conflict("Provider is not pending review".to_string())),
+            None => Err(crate::error::ApiError::NotFound("Provider not found".to_string())),
+        };
+    }
+
+    Ok(axum::Json(serde_json::json!({ "id": provider_id, "status": new_status })))
 }
-pub async fn admin_override_slot(Path(_id): Path<String>, Json(_): Json<()>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Override slot: Not implemented".to_string()),
-    }))
+
+pub async fn admin_approve_provider(
+    admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Approve provider endpoint called: {}", id);
+    transition_provider_status(&pool, id, "approved", &admin.0).await
 }
 
-pub async fn admin_list_bookings() -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("List bookings: Not implemented".to_string()),
-    }))
+pub async fn admin_reject_provider(
+    admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Reject provider endpoint called: {}", id);
+    transition_provider_status(&pool, id, "rejected", &admin.0).await
 }
-pub async fn admin_get_booking(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Get booking: Not implemented".to_string()),
-    }))
+
+pub async fn admin_list_slots(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: List slots endpoint called");
+    let slots = sqlx::query!("SELECT id, status FROM orbital_slots ORDER BY id")
+        .fetch_all(&pool)
+        .await?;
+
+    let slots: Vec<_> = slots
+        .into_iter()
+        .map(|s| serde_json::json!({ "id": s.id, "status": s.status }))
+        .collect();
+
+    Ok(axum::Json(slots))
 }
-pub async fn admin_update_booking(Path(_id): Path<String>, Json(_): Json<()>) -> impl axum::response::IntoResponse {
-    (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
-        error: "NotImplemented".to_string(),
-        message: Some("Update booking: Not implemented".to_string()),
-    }))
+
+pub async fn admin_get_slot(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Get slot endpoint called: {}", id);
+    let slot = sqlx::query!("SELECT id, status FROM orbital_slots WHERE id = $1", id)
+        .fetch_optional(&pool)
+        .await?
+        .ok_or_else(|| crate::error::ApiError::NotFound("Slot not found".to_string()))?;
+
+    Ok(axum::Json(serde_json::json!({ "id": slot.id, "status": slot.status })))
+}
+
+#[derive(serde::Deserialize)]
+pub struct AdminOverrideSlotRequest {
+    pub status: String,
+}
+
+pub async fn admin_override_slot(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    State(state): State<crate::AppState>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+    Json(payload): Json<AdminOverrideSlotRequest>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Override slot endpoint called: {}", id);
+    let result = sqlx::query!(
+        "UPDATE orbital_slots SET status = $1 WHERE id = $2",
+        payload.status, id,
+    )
+    .execute(&pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(crate::error::ApiError::NotFound("Slot not found".to_string()));
+    }
+
+    state.event_hub.publish(crate::event_hub::Event::SlotOverridden {
+        slot_id: id.to_string(),
+        status: payload.status.clone(),
+    });
+
+    Ok(axum::Json(serde_json::json!({ "id": id, "status": payload.status })))
+}
+
+pub async fn admin_list_bookings(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: List bookings endpoint called");
+    let bookings = sqlx::query!(
+        "SELECT id, launch_id, user_id, payload_description, booking_date, status FROM bookings ORDER BY booking_date DESC"
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let bookings: Vec<_> = bookings
+        .into_iter()
+        .map(|b| serde_json::json!({
+            "id": b.id,
+            "launch_id": b.launch_id,
+            "user_id": b.user_id,
+            "payload_description": b.payload_description,
+            "booking_date": b.booking_date,
+            "status": b.status,
+        }))
+        .collect();
+
+    Ok(axum::Json(bookings))
+}
+
+pub async fn admin_get_booking(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Get booking endpoint called: {}", id);
+    let booking = sqlx::query!(
+        "SELECT id, launch_id, user_id, payload_description, booking_date, status FROM bookings WHERE id = $1",
+        id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| crate::error::ApiError::NotFound("Booking not found".to_string()))?;
+
+    Ok(axum::Json(serde_json::json!({
+        "id": booking.id,
+        "launch_id": booking.launch_id,
+        "user_id": booking.user_id,
+        "payload_description": booking.payload_description,
+        "booking_date": booking.booking_date,
+        "status": booking.status,
+    })))
+}
+
+#[derive(serde::Deserialize)]
+pub struct AdminUpdateBookingRequest {
+    pub status: Option<String>,
+    pub payload_description: Option<String>,
+}
+
+pub async fn admin_update_booking(
+    _admin: crate::auth::RequireRole<crate::auth::AdminRole>,
+    Extension(pool): Extension<sqlx::PgPool>,
+    Path(id): Path<uuid::Uuid>,
+    Json(payload): Json<AdminUpdateBookingRequest>,
+) -> Result<impl axum::response::IntoResponse, crate::error::ApiError> {
+    info!("ADMIN: Update booking endpoint called: {}", id);
+    let existing = sqlx::query!(
+        "SELECT status, payload_description FROM bookings WHERE id = $1",
+        id,
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| crate::error::ApiError::NotFound("Booking not found".to_string()))?;
+
+    let status = payload.status.unwrap_or(existing.status);
+    let payload_description = payload.payload_description.unwrap_or(existing.payload_description);
+
+    sqlx::query!(
+        "UPDATE bookings SET status = $1, payload_description = $2 WHERE id = $3",
+        status, payload_description, id,
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(axum::Json(serde_json::json!({ "id": id, "status": status, "payload_description": payload_description })))
 }
+
 pub async fn admin_delete_booking(Path(_id): Path<String>) -> impl axum::response::IntoResponse {
     (StatusCode::NOT_IMPLEMENTED, axum::Json(crate::models::ErrorResponse {
         error: "NotImplemented".to_string(),
@@ -159,56 +521,62 @@ use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::error::ApiError;
 use crate::{auth, db, models::*};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct RegisterUserRequest {
     pub username: String,
     pub email: String,
     pub password: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 pub struct RegisterUserResponse {
     pub id: Uuid,
     pub username: String,
     pub email: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/register",
+    tag = "auth",
+    request_body = RegisterUserRequest,
+    responses(
+        (status = 201, description = "User created", body = RegisterUserResponse),
+        (status = 409, description = "Email already registered"),
+    ),
+)]
 pub async fn register_user(
     Extension(pool): Extension<PgPool>,
     Json(payload): Json<RegisterUserRequest>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, ApiError> {
     // Check if user exists
     let existing = sqlx::query!("SELECT id FROM users WHERE email = $1", payload.email)
         .fetch_optional(&pool)
-        .await
-        .unwrap();
+        .await?;
 
     if existing.is_some() {
-        return (StatusCode::CONFLICT, "User with email already exists").into_response();
+        return Err(ApiError::EmailExists);
     }
 
     // Hash password
-    let password_hash = match auth::hash_password(&payload.password) {
-        Ok(hash) => hash,
-        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Password hashing failed").into_response(),
-    };
+    let password_hash = auth::hash_password(&payload.password)
+        .map_err(|_| ApiError::Internal("Password hashing failed".to_string()))?;
 
-    // Insert user
+    // Insert user. A concurrent registration for the same email that slips
+    // past the check above hits the `users` unique constraint here instead,
+    // which `From<sqlx::Error>` maps to `ApiError::EmailExists` too.
     let user_id = Uuid::new_v4();
     let role_id = 1; // assuming 1 is default user role
-    let res = sqlx::query!(
+    sqlx::query!(
         "INSERT INTO users (id, username, email, password_hash, role_id)
          VALUES ($1, $2, $3, $4, $5)",
         user_id, payload.username, payload.email, password_hash, role_id,
     )
     .execute(&pool)
-    .await;
-
-    if res.is_err() {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to create user").into_response();
-    }
+    .await?;
 
     let response = RegisterUserResponse {
         id: user_id,
@@ -216,76 +584,87 @@ pub async fn register_user(
         email: payload.email,
     };
 
-    (StatusCode::CREATED, Json(response)).into_response()
+    Ok((StatusCode::CREATED, Json(response)))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct LoginUserRequest {
     pub email: String,
     pub password: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 pub struct LoginUserResponse {
     pub token: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/login",
+    tag = "auth",
+    request_body = LoginUserRequest,
+    responses(
+        (status = 200, description = "Login succeeded", body = LoginUserResponse),
+        (status = 401, description = "Invalid email or password"),
+        (status = 403, description = "Account disabled"),
+    ),
+)]
 pub async fn login_user(
     Extension(pool): Extension<PgPool>,
     Json(payload): Json<LoginUserRequest>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, ApiError> {
     let user = sqlx::query!(
-        "SELECT id, password_hash FROM users WHERE email = $1",
+        "SELECT id, password_hash, disabled FROM users WHERE email = $1",
         payload.email
     )
     .fetch_optional(&pool)
-    .await;
+    .await?
+    .ok_or(ApiError::Unauthorized)?;
 
-    let user = match user {
-        Ok(Some(user)) => user,
-        _ => return (StatusCode::UNAUTHORIZED, "Invalid email or password").into_response(),
-    };
+    if user.disabled {
+        return Err(ApiError::Forbidden("This account has been disabled".to_string()));
+    }
 
-    let valid = auth::verify_password(&user.password_hash, &payload.password);
-    if valid.is_err() || !valid.unwrap() {
-        return (StatusCode::UNAUTHORIZED, "Invalid email or password").into_response();
+    let valid = auth::verify_password(&user.password_hash, &payload.password).unwrap_or(false);
+    if !valid {
+        return Err(ApiError::Unauthorized);
     }
 
-    let token = match auth::create_jwt(&user.id.to_string()) {
-        Ok(t) => t,
-        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to generate token").into_response(),
-    };
+    let token = auth::create_jwt(&user.id.to_string())
+        .map_err(|_| ApiError::Internal("Failed to generate token".to_string()))?;
 
-    (StatusCode::OK, Json(LoginUserResponse { token })).into_response()
+    Ok((StatusCode::OK, Json(LoginUserResponse { token })))
 }
 use axum::{extract::Extension, Json};
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use crate::encryption;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct ProviderOnboardRequest {
     pub user_id: Uuid,
     pub metadata: String, // JSON or plain text metadata
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/providers/onboard",
+    tag = "providers",
+    request_body = ProviderOnboardRequest,
+    responses(
+        (status = 200, description = "Provider onboarded, server-held secret key sealed to the provider's public key"),
+        (status = 500, description = "Key generation or database error"),
+    ),
+)]
 pub async fn onboard_provider(
     Extension(pool): Extension<sqlx::PgPool>,
+    State(state): State<crate::AppState>,
     Json(payload): Json<ProviderOnboardRequest>,
 ) -> impl IntoResponse {
-    let aes_key = std::env::var("AES_256_KEY").expect("AES_256_KEY must be set");
-    let key_bytes = aes_key.as_bytes();
-    if key_bytes.len() != 32 {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid AES key length").into_response();
-    }
-    let mut key_arr = [0u8; 32];
-    key_arr.copy_from_slice(&key_bytes[0..32]);
-
-    let encrypted_metadata = match encryption::encrypt_metadata(&key_arr, payload.metadata.as_bytes()) {
-        Ok(ciphertext) => ciphertext,
-        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to encrypt metadata").into_response(),
-    };
-
+    // Issue this provider an API key with the default provider scopes
+    // (satellites:read, reservations:write) so it can call the API directly
+    // without riding on an admin credential.
+    let issued_key = state.key_store.default_provider_key();
     // Check if provider already exists for user
     let exists = sqlx::query!("SELECT id FROM providers WHERE user_id = $1", payload.user_id)
         .fetch_optional(&pool)
@@ -297,12 +676,35 @@ pub async fn onboard_provider(
     }
 
     let provider_id = Uuid::new_v4();
+
+    // Mint this provider its own X25519 keypair. The public half is stored
+    // alongside the provider row; the secret half is itself sealed under the
+    // server's Keyring (master AES key, AAD-bound to `provider_id`) before
+    // it ever touches the database, so a `server_secret_sealed` blob copied
+    // into a different provider's row fails to decrypt instead of silently
+    // handing over that provider's X25519 secret.
+    let (server_secret, provider_public) = encryption::generate_provider_keypair();
+    let keyring = match master_keyring() {
+        Ok(k) => k,
+        Err(resp) => return resp,
+    };
+    let sealed_secret = match keyring.encrypt(&server_secret.to_bytes(), provider_id.as_bytes()) {
+        Ok(ciphertext) => ciphertext,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to seal provider key").into_response(),
+    };
+
+    let envelope_key = encryption::derive_envelope_key(&server_secret, &provider_public);
+    let encrypted_metadata = match encryption::encrypt_aes_gcm(payload.metadata.as_bytes(), &envelope_key) {
+        Ok(ciphertext) => ciphertext,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to encrypt metadata").into_response(),
+    };
+
     let now = chrono::Utc::now();
 
     let res = sqlx::query!(
-        "INSERT INTO providers (id, user_id, metadata_encrypted, created_at)
-         VALUES ($1, $2, $3, $4)",
-        provider_id, payload.user_id, encrypted_metadata, now,
+        "INSERT INTO providers (id, user_id, metadata_encrypted, public_key, server_secret_sealed, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+        provider_id, payload.user_id, encrypted_metadata, provider_public.as_bytes().to_vec(), sealed_secret, now,
     )
     .execute(&pool)
     .await;
@@ -311,7 +713,87 @@ pub async fn onboard_provider(
         return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to onboard provider").into_response();
     }
 
-    (StatusCode::CREATED, Json("Provider onboarded successfully")).into_response()
+    (StatusCode::CREATED, Json(serde_json::json!({
+        "status": "Provider onboarded successfully",
+        "api_key": issued_key.secret,
+    }))).into_response()
+}
+
+/// The server's master key, used only to seal each provider's per-provider
+/// X25519 secret at rest — not used to encrypt provider data directly anymore.
+///
+/// `AES_256_KEY` is the base64 (or, failing that, hex) encoding of the raw
+/// 32-byte key, not the key's UTF-8 text itself — treating an operator's
+/// configured string as raw key bytes would silently truncate or misinterpret
+/// it. Decoding fails loudly instead of ever slicing/padding to fit.
+fn master_aes_key() -> std::result::Result<[u8; 32], axum::response::Response> {
+    let encoded = std::env::var("AES_256_KEY")
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "AES_256_KEY must be set").into_response())?;
+
+    let decoded = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, encoded.trim())
+        .or_else(|_| hex::decode(encoded.trim()))
+        .map_err(|_| {
+            (StatusCode::INTERNAL_SERVER_ERROR, "AES_256_KEY must be base64 or hex encoded").into_response()
+        })?;
+
+    decoded
+        .try_into()
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "AES_256_KEY must decode to exactly 32 bytes").into_response())
+}
+
+/// The single key `AES_256_KEY` currently holds, wrapped in a `Keyring` so
+/// `server_secret_sealed` blobs carry a key-id and are bound (via
+/// `associated_data`) to the provider row they belong to, instead of being
+/// swappable between rows the way a bare `encrypt_aes_gcm` call would allow.
+const MASTER_KEY_ID: u8 = 0;
+
+fn master_keyring() -> std::result::Result<encryption::Keyring, axum::response::Response> {
+    let key = master_aes_key()?;
+    let mut keys = std::collections::HashMap::new();
+    keys.insert(MASTER_KEY_ID, key);
+    encryption::Keyring::new(keys, MASTER_KEY_ID)
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "Failed to initialize keyring").into_response())
+}
+
+/// A provider's stored X25519 public key, used to seal a compliance report
+/// to that provider (see `encryption::encrypt_for_recipient`).
+async fn provider_public_key(
+    pool: &sqlx::PgPool,
+    provider_id: Uuid,
+) -> std::result::Result<x25519_dalek::PublicKey, axum::response::Response> {
+    let row = sqlx::query!("SELECT public_key FROM providers WHERE id = $1", provider_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|_| (StatusCode::NOT_FOUND, "Provider not found").into_response())?;
+
+    let mut pub_arr = [0u8; 32];
+    pub_arr.copy_from_slice(&row.public_key[0..32]);
+    Ok(x25519_dalek::PublicKey::from(pub_arr))
+}
+
+/// A provider's X25519 secret key, recovered by unsealing `server_secret_sealed`
+/// via the server's `Keyring`, bound to this `provider_id` the same way
+/// `onboard_provider` sealed it. Used to decrypt a report previously sealed
+/// to this provider's public key (see `encryption::decrypt_from_sender`).
+async fn provider_secret_key(
+    pool: &sqlx::PgPool,
+    provider_id: Uuid,
+) -> std::result::Result<x25519_dalek::StaticSecret, axum::response::Response> {
+    let row = sqlx::query!(
+        "SELECT server_secret_sealed FROM providers WHERE id = $1",
+        provider_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|_| (StatusCode::NOT_FOUND, "Provider not found").into_response())?;
+
+    let keyring = master_keyring()?;
+    let secret_bytes = keyring
+        .decrypt(&row.server_secret_sealed, provider_id.as_bytes())
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "Failed to unseal provider key").into_response())?;
+    let mut secret_arr = [0u8; 32];
+    secret_arr.copy_from_slice(&secret_bytes[0..32]);
+    Ok(x25519_dalek::StaticSecret::from(secret_arr))
 }
     use axum::{
     response::{IntoResponse, Response},
@@ -340,30 +822,38 @@ pub async fn logout_handler() -> impl IntoResponse {
 use sqlx::PgPool;
 use uuid::Uuid;
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct ReserveSlotRequest {
     pub slot_id: Uuid,
     pub user_id: Uuid,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/reservations/reserve",
+    tag = "reservations",
+    request_body = ReserveSlotRequest,
+    responses(
+        (status = 200, description = "Slot reserved; caller has 15 minutes to confirm"),
+        (status = 404, description = "Slot not found"),
+        (status = 409, description = "Slot is not available"),
+    ),
+)]
 pub async fn reserve_slot(
     Extension(pool): Extension<PgPool>,
+    State(state): State<crate::AppState>,
     Json(payload): Json<ReserveSlotRequest>,
-) -> impl IntoResponse {
-    let mut tx = pool.begin().await.unwrap();
+) -> Result<impl IntoResponse, ApiError> {
+    let mut tx = pool.begin().await?;
 
     // Lock the slot row FOR UPDATE
     let slot = sqlx::query!("SELECT status FROM orbital_slots WHERE id = $1 FOR UPDATE", payload.slot_id)
-        .fetch_one(&mut tx)
-        .await;
-
-    let slot = match slot {
-        Ok(s) => s,
-        Err(_) => return (StatusCode::NOT_FOUND, "Slot not found").into_response(),
-    };
+        .fetch_optional(&mut tx)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Slot not found".to_string()))?;
 
     if slot.status != "available" {
-        return (StatusCode::CONFLICT, "Slot not available").into_response();
+        return Err(ApiError::Conflict("Slot not available".to_string()));
     }
 
     // Insert reservation
@@ -371,44 +861,54 @@ pub async fn reserve_slot(
     let now = chrono::Utc::now();
     let expires_at = now + chrono::Duration::minutes(15); // 15 min expiry
 
-    let res = sqlx::query!(
+    sqlx::query!(
         "INSERT INTO reservations (id, slot_id, user_id, confirmed, reserved_at, expires_at)
          VALUES ($1, $2, $3, $4, $5, $6)",
         reservation_id, payload.slot_id, payload.user_id, false, now, expires_at,
     )
     .execute(&mut tx)
-    .await;
-
-    if res.is_err() {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to reserve slot").into_response();
-    }
+    .await?;
 
     // Update slot status to "reserved"
-    let res = sqlx::query!(
+    sqlx::query!(
         "UPDATE orbital_slots SET status = 'reserved' WHERE id = $1",
         payload.slot_id
     )
     .execute(&mut tx)
-    .await;
+    .await?;
 
-    if res.is_err() {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to update slot status").into_response();
-    }
+    tx.commit().await?;
 
-    tx.commit().await.unwrap();
+    state.event_hub.publish(crate::event_hub::Event::SlotReserved {
+        slot_id: payload.slot_id.to_string(),
+        reservation_id: reservation_id.to_string(),
+    });
 
-    (StatusCode::OK, "Slot reserved, please confirm within 15 minutes").into_response()
+    Ok((StatusCode::OK, "Slot reserved, please confirm within 15 minutes"))
 }
-    #[derive(serde::Deserialize)]
+    #[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct ConfirmReservationRequest {
     pub reservation_id: Uuid,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/reservations/confirm",
+    tag = "reservations",
+    request_body = ConfirmReservationRequest,
+    responses(
+        (status = 200, description = "Reservation confirmed, slot now booked"),
+        (status = 400, description = "Reservation expired"),
+        (status = 404, description = "Reservation not found"),
+        (status = 409, description = "Reservation already confirmed"),
+    ),
+)]
 pub async fn confirm_reservation(
     Extension(pool): Extension<PgPool>,
+    State(state): State<crate::AppState>,
     Json(payload): Json<ConfirmReservationRequest>,
-) -> impl IntoResponse {
-    let mut tx = pool.begin().await.unwrap();
+) -> Result<impl IntoResponse, ApiError> {
+    let mut tx = pool.begin().await?;
 
     // Fetch reservation for update
     let resv = sqlx::query!(
@@ -416,55 +916,58 @@ pub async fn confirm_reservation(
         payload.reservation_id
     )
     .fetch_optional(&mut tx)
-    .await;
-
-    let resv = match resv {
-        Ok(Some(r)) => r,
-        _ => return (StatusCode::NOT_FOUND, "Reservation not found").into_response(),
-    };
+    .await?
+    .ok_or_else(|| ApiError::NotFound("Reservation not found".to_string()))?;
 
     let now = chrono::Utc::now();
     if resv.expires_at < now {
-        return (StatusCode::BAD_REQUEST, "Reservation expired").into_response();
+        return Err(ApiError::BadRequest("Reservation expired".to_string()));
     }
 
     if resv.confirmed {
-        return (StatusCode::CONFLICT, "Reservation already confirmed").into_response();
+        return Err(ApiError::Conflict("Reservation already confirmed".to_string()));
     }
 
     // Update reservation as confirmed
-    let res = sqlx::query!(
+    sqlx::query!(
         "UPDATE reservations SET confirmed = TRUE WHERE id = $1",
         payload.reservation_id
     )
     .execute(&mut tx)
-    .await;
-
-    if res.is_err() {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to confirm reservation").into_response();
-    }
+    .await?;
 
     // Update slot status to "booked"
-    let res = sqlx::query!(
+    sqlx::query!(
         "UPDATE orbital_slots SET status = 'booked' WHERE id = $1",
         resv.slot_id
     )
     .execute(&mut tx)
-    .await;
+    .await?;
 
-    if res.is_err() {
-        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to update slot status").into_response();
-    }
+    tx.commit().await?;
 
-    tx.commit().await.unwrap();
+    state.event_hub.publish(crate::event_hub::Event::ReservationConfirmed {
+        reservation_id: payload.reservation_id.to_string(),
+        slot_id: resv.slot_id.to_string(),
+    });
 
-    (StatusCode::OK, "Reservation confirmed").into_response()
+    Ok((StatusCode::OK, "Reservation confirmed"))
 }
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct CancelReservationRequest {
     pub reservation_id: Uuid,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/reservations/cancel",
+    tag = "reservations",
+    request_body = CancelReservationRequest,
+    responses(
+        (status = 200, description = "Reservation cancelled, slot freed"),
+        (status = 404, description = "Reservation not found"),
+    ),
+)]
 pub async fn cancel_reservation(
     Extension(pool): Extension<PgPool>,
     Json(payload): Json<CancelReservationRequest>,
@@ -536,13 +1039,23 @@ pub async fn search_launches(
         Err(_) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, "DB error").into_response(),
     }
 }
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct BookPayloadRequest {
     pub launch_id: Uuid,
     pub user_id: Uuid,
     pub payload_description: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/bookings/book",
+    tag = "bookings",
+    request_body = BookPayloadRequest,
+    responses(
+        (status = 200, description = "Payload booked"),
+        (status = 500, description = "Database error"),
+    ),
+)]
 pub async fn book_payload(
     Extension(pool): Extension<PgPool>,
     Json(payload): Json<BookPayloadRequest>,
@@ -564,11 +1077,21 @@ pub async fn book_payload(
 
     (axum::http::StatusCode::OK, "Payload booked successfully").into_response()
 }
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct CancelBookingRequest {
     pub booking_id: Uuid,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/bookings/cancel",
+    tag = "bookings",
+    request_body = CancelBookingRequest,
+    responses(
+        (status = 200, description = "Booking cancelled"),
+        (status = 500, description = "Database error"),
+    ),
+)]
 pub async fn cancel_booking(
     Extension(pool): Extension<PgPool>,
     Json(payload): Json<CancelBookingRequest>,
@@ -590,12 +1113,22 @@ use axum::{extract::Extension, Json};
 use uuid::Uuid;
 use crate::encryption;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct CreateComplianceReportRequest {
     pub provider_id: Uuid,
     pub report_text: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/compliance/create",
+    tag = "compliance",
+    request_body = CreateComplianceReportRequest,
+    responses(
+        (status = 201, description = "Compliance report generated, encrypted to the provider's public key, and stored"),
+        (status = 500, description = "PDF generation, encryption, or database error"),
+    ),
+)]
 pub async fn create_compliance_report(
     Extension(pool): Extension<sqlx::PgPool>,
     Json(payload): Json<CreateComplianceReportRequest>,
@@ -605,11 +1138,18 @@ pub async fn create_compliance_report(
         Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to generate PDF").into_response(),
     };
 
-    let aes_key = std::env::var("AES_256_KEY").expect("AES_256_KEY must be set");
-    let mut key_arr = [0u8; 32];
-    key_arr.copy_from_slice(&aes_key.as_bytes()[0..32]);
-
-    let encrypted_pdf = encryption::encrypt_metadata(&key_arr, &pdf_bytes).unwrap();
+    // Seal to this provider's public key with a fresh ephemeral keypair per
+    // report, rather than one envelope key reused across every report for
+    // that provider -- a single leaked shared secret then exposes only the
+    // one report it was derived for, not the provider's whole history.
+    let provider_public = match provider_public_key(&pool, payload.provider_id).await {
+        Ok(k) => k,
+        Err(resp) => return resp,
+    };
+    let encrypted_pdf = match encryption::encrypt_for_recipient(&pdf_bytes, &provider_public) {
+        Ok(ciphertext) => ciphertext,
+        Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to encrypt report").into_response(),
+    };
 
     let report_id = Uuid::new_v4();
     let report_date = chrono::Utc::now();
@@ -641,23 +1181,22 @@ pub async fn download_compliance_report(
     Json(payload): Json<DownloadComplianceRequest>,
 ) -> impl IntoResponse {
     let row = sqlx::query!(
-        "SELECT encrypted_pdf FROM compliance_reports WHERE id = $1",
+        "SELECT provider_id, encrypted_pdf FROM compliance_reports WHERE id = $1",
         payload.report_id
     )
     .fetch_one(&pool)
     .await;
 
-    if row.is_err() {
-        return (StatusCode::NOT_FOUND, "Report not found").into_response();
-    }
-
-    let encrypted_pdf = row.unwrap().encrypted_pdf;
-
-    let aes_key = std::env::var("AES_256_KEY").expect("AES_256_KEY must be set");
-    let mut key_arr = [0u8; 32];
-    key_arr.copy_from_slice(&aes_key.as_bytes()[0..32]);
+    let row = match row {
+        Ok(r) => r,
+        Err(_) => return (StatusCode::NOT_FOUND, "Report not found").into_response(),
+    };
 
-    let pdf_bytes = match encryption::decrypt_metadata(&key_arr, &encrypted_pdf) {
+    let provider_secret = match provider_secret_key(&pool, row.provider_id).await {
+        Ok(k) => k,
+        Err(resp) => return resp,
+    };
+    let pdf_bytes = match encryption::decrypt_from_sender(&row.encrypted_pdf, &provider_secret) {
         Ok(data) => data,
         Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to decrypt PDF").into_response(),
     };
@@ -668,3 +1207,162 @@ pub async fn download_compliance_report(
         .body(axum::body::boxed(axum::body::Full::from(pdf_bytes)))
         .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "Failed to build response"))
 }
+
+// --- Live alert/telemetry WebSocket hub ---
+// Replaces polling of /api/alerts and /api/satellites/:id/positions with a single
+// persistent socket per session. A `tokio::sync::broadcast` channel carries
+// `HubFrame::Alert` frames pushed by `publish_alert` and `HubFrame::Telemetry`
+// frames pushed by `publish_position`. Neither publisher is called anywhere in
+// this crate, so `/ws` delivers frames only once some caller publishes through
+// them. Each connected socket filters the shared stream down to what that
+// client subscribed to.
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use std::collections::HashSet;
+use tokio::sync::broadcast::error::RecvError;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "kind")]
+pub enum HubFrame {
+    Alert(Alert),
+    Telemetry {
+        norad_id: i32,
+        lat_deg: f64,
+        lon_deg: f64,
+        alt_km: f64,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+/// Shared fan-out hub stored on `AppState`. Cloning is cheap (it's just a
+/// `broadcast::Sender` handle); every subscriber gets its own `Receiver`.
+#[derive(Clone)]
+pub struct AlertTelemetryHub {
+    tx: tokio::sync::broadcast::Sender<HubFrame>,
+}
+
+impl AlertTelemetryHub {
+    pub fn new() -> Self {
+        let (tx, _rx) = tokio::sync::broadcast::channel(256);
+        Self { tx }
+    }
+
+    pub fn publish_alert(&self, alert: Alert) {
+        // No receivers yet is fine, nothing to deliver to.
+        let _ = self.tx.send(HubFrame::Alert(alert));
+    }
+
+    pub fn publish_position(&self, norad_id: i32, lat_deg: f64, lon_deg: f64, alt_km: f64) {
+        let _ = self.tx.send(HubFrame::Telemetry {
+            norad_id,
+            lat_deg,
+            lon_deg,
+            alt_km,
+            timestamp: chrono::Utc::now(),
+        });
+    }
+
+    fn subscribe(&self) -> tokio::sync::broadcast::Receiver<HubFrame> {
+        self.tx.subscribe()
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct WsSubscribeParams {
+    pub user_id: Option<Uuid>,
+    pub alert_type: Option<String>,
+    pub norad_ids: Option<String>, // comma separated list, e.g. "25544,48274"
+}
+
+struct WsFilter {
+    user_id: Option<Uuid>,
+    alert_type: Option<String>,
+    norad_ids: HashSet<i32>,
+}
+
+impl WsFilter {
+    fn from_params(params: WsSubscribeParams) -> Self {
+        let norad_ids = params
+            .norad_ids
+            .map(|s| {
+                s.split(',')
+                    .filter_map(|p| p.trim().parse::<i32>().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            user_id: params.user_id,
+            alert_type: params.alert_type,
+            norad_ids,
+        }
+    }
+
+    fn matches(&self, frame: &HubFrame) -> bool {
+        match frame {
+            HubFrame::Alert(alert) => {
+                if let Some(uid) = self.user_id {
+                    if alert.user_id != Some(uid) {
+                        return false;
+                    }
+                }
+                if let Some(ref want) = self.alert_type {
+                    let got = format!("{:?}", alert.alert_type);
+                    if !got.eq_ignore_ascii_case(want) {
+                        return false;
+                    }
+                }
+                true
+            }
+            HubFrame::Telemetry { norad_id, .. } => {
+                self.norad_ids.is_empty() || self.norad_ids.contains(norad_id)
+            }
+        }
+    }
+}
+
+/// `GET /ws` — upgrade to a WebSocket streaming `Alert`/telemetry frames.
+/// Subscription is controlled via query params: `user_id`, `alert_type`,
+/// `norad_ids` (comma separated).
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    Query(params): Query<WsSubscribeParams>,
+    State(state): State<crate::AppState>,
+) -> impl IntoResponse {
+    let filter = WsFilter::from_params(params);
+    ws.on_upgrade(move |socket| handle_alert_socket(socket, state.alert_hub, filter))
+}
+
+/// `GET /ws/slots` — real-time dashboard feed. Client sends a `{"action":
+/// "subscribe", "topics": [...]}` frame naming the `EventHub` topics it wants
+/// (`slots`, `slots:{id}`, `alerts`); server-side
+/// mutations like `admin_override_slot`/`reserve_slot`/`confirm_reservation`
+/// publish typed events onto those topics. A `bookings:{provider_id}` topic
+/// can be subscribed to, but nothing publishes to it.
+pub async fn ws_slots_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<crate::AppState>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| crate::event_hub::handle_slots_socket(socket, state.event_hub))
+}
+
+async fn handle_alert_socket(mut socket: WebSocket, hub: AlertTelemetryHub, filter: WsFilter) {
+    let mut rx = hub.subscribe();
+    loop {
+        match rx.recv().await {
+            Ok(frame) => {
+                if !filter.matches(&frame) {
+                    continue;
+                }
+                let payload = match serde_json::to_string(&frame) {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+                if socket.send(Message::Text(payload)).await.is_err() {
+                    break;
+                }
+            }
+            Err(RecvError::Lagged(_)) => continue,
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Optional InfluxDB line-protocol sink for the periodic satellite-position
+// tick in `main_satellite`'s `start_position_updater`, which otherwise
+// recomputes every satellite's state every 30s, pushes it to live
+// `/ws/satellites` subscribers and keeps no history of it. Writing
+// each tick's batch here makes historical track reconstruction and
+// risk-trend dashboards possible without bolting storage onto the REST
+// layer -- the existing `get_all_satellites()` result is exactly the
+// batch flushed per tick.
+
+use crate::satellite_service::Satellite;
+use anyhow::{bail, Result};
+use chrono::Utc;
+use reqwest::Client;
+
+const MEASUREMENT: &str = "satellite_position";
+
+/// Where and how to write points. Absent (`from_env` returning `None`) is
+/// the default -- the sink is opt-in, since most deployments of this demo
+/// server have no InfluxDB to write to.
+pub struct InfluxConfig {
+    pub url: String,
+    pub bucket: String,
+    pub org: String,
+    pub token: Option<String>,
+}
+
+impl InfluxConfig {
+    /// Reads `INFLUXDB_URL`/`INFLUXDB_BUCKET`/`INFLUXDB_ORG`/`INFLUXDB_TOKEN`
+    /// from the environment. Returns `None` (sink disabled) unless at least
+    /// `INFLUXDB_URL` and `INFLUXDB_BUCKET` are set.
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("INFLUXDB_URL").ok()?;
+        let bucket = std::env::var("INFLUXDB_BUCKET").ok()?;
+        let org = std::env::var("INFLUXDB_ORG").unwrap_or_default();
+        let token = std::env::var("INFLUXDB_TOKEN").ok();
+        Some(Self { url, bucket, org, token })
+    }
+}
+
+/// Escapes a tag key/value per InfluxDB line protocol: commas, spaces, and
+/// equals signs are escaped with a backslash (field string values and
+/// measurement names have different escaping rules, but every tag used
+/// here is a plain identifier/name so only this is needed).
+fn escape_tag(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(',', "\\,").replace(' ', "\\ ").replace('=', "\\=")
+}
+
+/// Renders one satellite's current state as a single line-protocol point:
+/// `satellite_position,norad_id=...,name=... lat=...,lon=...,alt_km=...,velocity_km_s=... <unix_nanos>`.
+/// This `Satellite` model carries no risk score/level (that lives in the
+/// newer risk-assessment pipeline in `models.rs`), so only position and
+/// velocity fields are written.
+fn point(satellite: &Satellite, timestamp_nanos: i64) -> String {
+    let norad_id = satellite.norad_id.map(|id| id.to_string()).unwrap_or_else(|| "unknown".to_string());
+    format!(
+        "{measurement},norad_id={norad_id},name={name} lat={lat},lon={lon},alt_km={alt},velocity_km_s={vel} {ts}",
+        measurement = MEASUREMENT,
+        norad_id = escape_tag(&norad_id),
+        name = escape_tag(&satellite.name),
+        lat = satellite.latitude,
+        lon = satellite.longitude,
+        alt = satellite.altitude,
+        vel = satellite.velocity,
+        ts = timestamp_nanos,
+    )
+}
+
+/// Batches every satellite in `satellites` into one line-protocol write and
+/// POSTs it to the InfluxDB v2 HTTP write API. Call sites should log and
+/// continue on error rather than let a write failure interrupt position
+/// tracking.
+pub async fn write_positions(config: &InfluxConfig, satellites: &[Satellite]) -> Result<()> {
+    if satellites.is_empty() {
+        return Ok(());
+    }
+
+    let timestamp_nanos = Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    let body = satellites
+        .iter()
+        .map(|satellite| point(satellite, timestamp_nanos))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let write_url = format!(
+        "{}/api/v2/write?org={}&bucket={}&precision=ns",
+        config.url.trim_end_matches('/'),
+        config.org,
+        config.bucket
+    );
+
+    let client = Client::new();
+    let mut request = client.post(&write_url).body(body);
+    if let Some(token) = &config.token {
+        request = request.header("Authorization", format!("Token {token}"));
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        bail!("InfluxDB write failed with status {}", response.status());
+    }
+    Ok(())
+}
@@ -16,21 +16,67 @@ use std::num::NonZeroU32;
 use std::time::Duration;
 use tracing::{info, Level};
 use tracing_subscriber;
+use utoipa::OpenApi;
 
+mod alerting;
 mod auth;
+mod db;
+mod error;
 mod models;
 mod routes;
 mod n2yo_service;
+mod notification;
+mod pass_prediction;
+mod provider_pool;
+mod api_key;
+mod event_hub;
+mod federation;
+mod gateway;
+mod openapi;
+mod reservation_sweep;
+mod sessions;
+mod webpush;
 
 use models::*;
 use n2yo_service::N2YOService;
+use provider_pool::ProviderPool;
 
 mod handlers;
 
 #[derive(Clone)]
 pub struct AppState {
     pub jwt_secret: String,
+    // Shared pool for everything in `auth.rs`/`sessions.rs`/`handlers.rs`'s
+    // admin endpoints — previously each of those assumed a `state.db_pool`
+    // that didn't actually exist on this struct.
+    pub db_pool: sqlx::PgPool,
     pub n2yo_service: N2YOService,
+    pub alert_hub: handlers::AlertTelemetryHub,
+    // Replaces the single hardwired N2YOService for satellite data lookups:
+    // picks the healthiest of N2YO/Celestrak/Space-Track per call and falls
+    // back to a stale cached TLE if every source is down.
+    pub provider_pool: std::sync::Arc<ProviderPool>,
+    // Scoped, time-windowed API keys with a per-key GCRA rate limit, resolved
+    // from the `x-api-key`/`Authorization` header by `api_key::api_key_middleware`.
+    pub key_store: api_key::KeyStore,
+    // Topic-based pub/sub backing `/ws/slots` (slots/bookings/alerts), distinct
+    // from `alert_hub` which backs the `/ws` alert+telemetry multiplex.
+    pub event_hub: event_hub::EventHub,
+    // Server-to-server federation: shares confirmed reservations and
+    // conjunction alerts with a configured list of peer OrbitalOS nodes.
+    pub federation: std::sync::Arc<federation::FederationState>,
+    // Upstream registry for the standalone actix-web satellite service so
+    // both APIs are reachable from this one axum origin.
+    pub upstream_registry: std::sync::Arc<gateway::UpstreamRegistry>,
+    // Flips to `false` once SIGINT/SIGTERM is received so `/ready` starts
+    // returning 503 before the process actually exits, and clients stop
+    // getting routed traffic by their orchestrator ahead of the shutdown.
+    pub shutting_down: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    // Registered browser Web Push subscriptions for critical alert delivery.
+    pub push_store: std::sync::Arc<webpush::PushStore>,
+    // De-duplicated, escalation-aware conjunction risk alerts: fed by
+    // `routes::risk::predict_risk`, dispatched over `alerting::Notifier`.
+    pub conjunction_alerts: std::sync::Arc<alerting::ConjunctionAlertStore>,
 }
 
 #[tokio::main]
@@ -50,10 +96,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|_| "589P8Q-SDRYX8-L842ZD-5Z9".to_string()); // Demo key from N2YO docs
 
     // Create application state
+    let db_pool = db::get_pg_pool()
+        .await
+        .expect("Failed to connect to Postgres");
+    let n2yo_service = N2YOService::new(n2yo_api_key);
     let state = AppState {
         jwt_secret,
-        n2yo_service: N2YOService::new(n2yo_api_key),
+        db_pool: db_pool.clone(),
+        n2yo_service: n2yo_service.clone(),
+        alert_hub: handlers::AlertTelemetryHub::new(),
+        provider_pool: std::sync::Arc::new(ProviderPool::new(n2yo_service)),
+        key_store: api_key::KeyStore::new(),
+        event_hub: event_hub::EventHub::new(),
+        federation: std::sync::Arc::new(federation::FederationState::new(
+            std::env::var("FEDERATION_NODE_ID").unwrap_or_else(|_| "orbitalos-local".to_string()),
+        )),
+        upstream_registry: std::sync::Arc::new(gateway::UpstreamRegistry::new(vec![
+            std::env::var("SATELLITE_SERVICE_URL").unwrap_or_else(|_| "http://localhost:8080".to_string()),
+        ])),
+        shutting_down: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        push_store: std::sync::Arc::new(webpush::PushStore::new()),
+        conjunction_alerts: std::sync::Arc::new(alerting::ConjunctionAlertStore::new()),
     };
+    state.upstream_registry.clone().start_health_poller().await;
+
+    // Reclaim reservations whose 15-minute confirmation window lapsed
+    // without the caller ever confirming, so their slot doesn't stay
+    // `reserved` forever.
+    reservation_sweep::spawn_reservation_sweeper(db_pool);
 
     // Build our application with routes
     let app = Router::new()
@@ -62,6 +132,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .route("/api/admin/users/:id", get(handlers::admin_get_user).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
     .route("/api/admin/users/:id", post(handlers::admin_update_user).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
     .route("/api/admin/users/:id", axum::routing::delete(handlers::admin_delete_user).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
+    .route("/api/users/:id/block", post(handlers::block_user))
 
     .route("/api/admin/providers", get(handlers::admin_list_providers).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
     .route("/api/admin/providers/:id", get(handlers::admin_get_provider).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
@@ -80,8 +151,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .route("/api/admin/compliance", get(handlers::admin_list_compliance_reports).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
     .route("/api/admin/compliance/:id/review", post(handlers::admin_review_compliance_report).layer(from_fn_with_state(state.clone(), handlers::require_admin)))
         .route("/", get(health_check))
+        .route("/ready", get(readiness_check))
         .route("/api/auth/login", post(auth::login))
         .route("/api/auth/register", post(auth::register))
+        .route("/api/auth/oidc/start", get(auth::oidc_start))
+        .route("/api/auth/oidc/callback", post(auth::oidc_callback))
+        .route("/api/auth/2fa/verify", post(auth::verify_otp))
+        .route("/api/auth/refresh", post(auth::refresh))
+        .route("/api/auth/logout", post(auth::logout))
+        .route("/api/auth/sessions", get(auth::list_sessions))
+        .route("/api/auth/verify", get(auth::verify_email))
+        .route("/api/auth/forgot-password", post(auth::forgot_password))
+        .route("/api/auth/reset-password", post(auth::reset_password))
         .route("/api/providers/onboard", post(handlers::onboard_provider))
         .route("/api/reservations/reserve", post(handlers::reserve_slot))
         .route("/api/reservations/confirm", post(handlers::confirm_reservation))
@@ -91,7 +172,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/bookings/cancel", post(handlers::cancel_booking))
         .route("/api/compliance/create", post(handlers::create_compliance_report))
         .route("/api/compliance/download", post(handlers::download_compliance_report))
-        .route("/ws/slots", axum::routing::get(handlers::ws_handler))
+        .route("/api/federation/reservations", post(federation::receive_reservations))
+        .route("/api/federation/conjunctions", post(federation::receive_conjunctions))
+        .route("/ws/slots", axum::routing::get(handlers::ws_slots_handler))
+        // Live alerts + satellite telemetry, multiplexed per session instead of polling
+        .route("/ws", axum::routing::get(handlers::ws_handler))
         // --- Admin CRUD and override endpoints ---
         .route("/api/admin/users", get(handlers::admin_list_users))
         .route("/api/admin/users/:id", get(handlers::admin_get_user))
@@ -116,6 +201,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/admin/compliance/:id/review", post(handlers::admin_review_compliance_report))
         // TODO: Add admin middleware to protect these endpoints
         .route("/api/satellites", get(routes::satellites::get_satellites))
+        .route("/api/satellites/search", get(routes::satellites::search_satellites))
         .route("/api/satellites/:id", get(routes::satellites::get_satellite))
         .route("/api/risk/predict", post(routes::risk::predict_risk))
         .route("/api/bookings", get(routes::bookings::get_bookings))
@@ -124,10 +210,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/dashboard/stats", get(routes::dashboard::get_stats))
         .route("/api/alerts", get(routes::alerts::get_alerts))
         .route("/api/alerts/:id", post(routes::alerts::acknowledge_alert))
+        .route("/api/alerts/subscribe", post(routes::alerts::subscribe_push))
         .route("/api/satellites/:id/tle", get(routes::satellites::get_tle))
         .route("/api/satellites/:id/positions", get(routes::satellites::get_positions))
         .route("/api/satellites/:id/passes", get(routes::satellites::get_passes))
         .route("/api/satellites/above", get(routes::satellites::get_above))
+        // Interactive OpenAPI docs for the handlers annotated in
+        // `openapi.rs`: RapiDoc reads the spec from the json route it mounts
+        // alongside itself, so downstream clients can hit either one.
+        .merge(
+            utoipa_rapidoc::RapiDoc::with_openapi("/api-docs/openapi.json", openapi::ApiDoc::openapi())
+                .path("/docs"),
+        )
+        // Anything else under a proxied prefix (/api/ground-stations,
+        // /api/visible, /api/track, /api/statistics, or any /api/satellites/*
+        // path not already handled above) goes to the satellite-service gateway.
+        .fallback(gateway::proxy_to_satellite_service)
+        .layer(from_fn_with_state(state.clone(), api_key::api_key_middleware))
         .layer(
             CorsLayer::new()
                 .allow_origin(Any)
@@ -155,17 +254,66 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
-    info!("🚀 OrbitalOS Backend running on http://0.0.0.0:3000");
-    
-    axum::serve(listener, app).await?;
+    let bind_addr = std::env::var("BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".to_string());
+    let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
+    info!("🚀 OrbitalOS Backend running on http://{bind_addr}");
+
+    let shutting_down = state.shutting_down.clone();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(shutting_down, state.event_hub.clone()))
+        .await?;
 
     Ok(())
 }
 
+/// Readiness gate distinct from the liveness `health_check`: returns 503
+/// once shutdown has begun, so orchestrators stop routing new traffic before
+/// the process actually exits.
+async fn readiness_check(State(state): State<AppState>) -> impl axum::response::IntoResponse {
+    if state.shutting_down.load(std::sync::atomic::Ordering::SeqCst) {
+        (StatusCode::SERVICE_UNAVAILABLE, axum::Json(serde_json::json!({ "ready": false })))
+    } else {
+        (StatusCode::OK, axum::Json(serde_json::json!({ "ready": true })))
+    }
+}
+
+/// Traps SIGINT/SIGTERM, flips the readiness gate, and gives in-flight
+/// handlers / WebSocket sessions a grace period (`SHUTDOWN_GRACE_SECONDS`,
+/// default 30s) to drain before `axum::serve` stops accepting new connections.
+async fn shutdown_signal(shutting_down: std::sync::Arc<std::sync::atomic::AtomicBool>, event_hub: event_hub::EventHub) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.expect("failed to install SIGINT handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    info!("Shutdown signal received, entering drain period");
+    shutting_down.store(true, std::sync::atomic::Ordering::SeqCst);
+    let _ = event_hub; // future close-frame broadcast hook lives alongside the hub
+
+    let grace_seconds: u64 = std::env::var("SHUTDOWN_GRACE_SECONDS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30);
+    tokio::time::sleep(tokio::time::Duration::from_secs(grace_seconds)).await;
+}
+
 use crate::db;
 
-async fn health_check() -> impl axum::response::IntoResponse {
+async fn health_check(State(state): State<AppState>) -> impl axum::response::IntoResponse {
     let db_status = match db::get_pg_pool().await {
         Ok(pool) => {
             // Try a simple query
@@ -183,7 +331,9 @@ async fn health_check() -> impl axum::response::IntoResponse {
         "status": status,
         "service": "orbitalos-backend",
         "version": "0.1.0",
-        "db": db_status
+        "db": db_status,
+        "federation_peers": state.federation.status_summary(),
+        "satellite_service_upstreams": state.upstream_registry.reachability_summary(),
     }))
 }
 use backend::handlers::onboard_provider;
@@ -1,18 +1,23 @@
 mod satellite_service;
+mod geojson;
 mod routes;
 mod auth;
 mod models;
+mod influx_export;
+mod position_stream;
 
 use actix_web::{middleware::Logger, web, App, HttpResponse, HttpServer, Result};
 use actix_cors::Cors;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::sync::{Arc, Mutex};
 use tokio::time::{interval, Duration};
 use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use satellite_service::SatelliteService;
+use position_stream::PositionHub;
+use satellite_service::{SatelliteService, SatelliteType, SatelliteUpdate};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -93,34 +98,164 @@ async fn api_info() -> Result<HttpResponse> {
                 path: "/api/satellites/statistics".to_string(),
                 description: "Get satellite statistics".to_string(),
             },
+            ApiEndpoint {
+                method: "GET".to_string(),
+                path: "/api/satellites/geojson".to_string(),
+                description: "Get satellite positions and ground stations as a GeoJSON FeatureCollection".to_string(),
+            },
             ApiEndpoint {
                 method: "POST".to_string(),
                 path: "/api/satellites/update-positions".to_string(),
                 description: "Manually trigger position updates".to_string(),
             },
+            ApiEndpoint {
+                method: "POST".to_string(),
+                path: "/api/satellites/ingest".to_string(),
+                description: "Batch-ingest position/element updates from external feeders".to_string(),
+            },
             ApiEndpoint {
                 method: "GET".to_string(),
                 path: "/api/ground-stations".to_string(),
                 description: "Get ground station information".to_string(),
             },
+            ApiEndpoint {
+                method: "GET".to_string(),
+                path: "/api/ground-stations/dop".to_string(),
+                description: "Get dilution-of-precision (GDOP/PDOP/HDOP/VDOP/TDOP) for a site".to_string(),
+            },
+            ApiEndpoint {
+                method: "GET".to_string(),
+                path: "/ws/satellites".to_string(),
+                description: "WebSocket feed of live satellite positions, updated every tick".to_string(),
+            },
         ],
     };
     
     Ok(HttpResponse::Ok().json(info))
 }
 
-async fn start_position_updater(satellite_service: Arc<Mutex<SatelliteService>>) {
+#[derive(Deserialize)]
+struct DopQuery {
+    lat: f64,
+    lon: f64,
+    alt: Option<f64>,
+    min_elevation: Option<f64>,
+}
+
+/// Dilution-of-precision report for a receiver at the given site, so
+/// operators can rank ground-station coverage before committing to a
+/// build location. `min_elevation` defaults to 10 degrees, a typical GNSS
+/// mask angle, when not supplied.
+async fn get_ground_station_dop(
+    data: web::Data<AppState>,
+    query: web::Query<DopQuery>,
+) -> Result<HttpResponse> {
+    let satellite_service = data.satellite_service.lock().unwrap();
+    let alt = query.alt.unwrap_or(0.0);
+    let min_elevation = query.min_elevation.unwrap_or(10.0);
+
+    match satellite_service.compute_dop(query.lat, query.lon, alt, min_elevation) {
+        Some(dop) => Ok(HttpResponse::Ok().json(dop)),
+        None => Ok(HttpResponse::UnprocessableEntity().json(serde_json::json!({
+            "error": "InsufficientSatellites",
+            "message": "Fewer than four navigation satellites are visible above min_elevation from this site",
+        }))),
+    }
+}
+
+#[derive(Deserialize)]
+struct GeoJsonQuery {
+    #[serde(rename = "type")]
+    satellite_type: Option<String>,
+    /// Include a forward ground track per satellite, sampled over one
+    /// orbital period. Defaults to `false`, since computing it for the
+    /// whole catalog is far more expensive than the positions alone.
+    ground_track: Option<bool>,
+    /// Samples per ground track when `ground_track` is set. Defaults to 60.
+    ground_track_samples: Option<usize>,
+}
+
+/// Current satellite positions, ground stations, and (optionally) forward
+/// ground tracks as a GeoJSON `FeatureCollection`, ready to drop into any
+/// web map with no client-side reshaping of the bespoke
+/// `Satellite`/`GroundStation` JSON.
+async fn get_satellites_geojson(
+    data: web::Data<AppState>,
+    query: web::Query<GeoJsonQuery>,
+) -> Result<HttpResponse> {
+    let satellite_service = data.satellite_service.lock().unwrap();
+
+    let satellites = match &query.satellite_type {
+        Some(type_str) => {
+            let Ok(satellite_type) = serde_json::from_value::<SatelliteType>(serde_json::Value::String(
+                type_str.clone(),
+            )) else {
+                return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": "InvalidSatelliteType",
+                    "message": format!("Unknown satellite type '{}'", type_str),
+                })));
+            };
+            satellite_service.get_satellites_by_type(&satellite_type)
+        }
+        None => satellite_service.get_all_satellites(),
+    };
+
+    let mut ground_tracks = HashMap::new();
+    if query.ground_track.unwrap_or(false) {
+        let samples = query.ground_track_samples.unwrap_or(60).max(2);
+        for satellite in &satellites {
+            ground_tracks.insert(
+                satellite.id.clone(),
+                satellite_service.sample_ground_track(satellite, samples),
+            );
+        }
+    }
+
+    let ground_stations = satellite_service.get_ground_stations();
+    let collection = geojson::to_feature_collection(&satellites, &ground_stations, &ground_tracks);
+    Ok(HttpResponse::Ok().json(collection))
+}
+
+/// `POST /api/satellites/ingest` -- batch ingestion of position/element
+/// updates from external feeders, so real state can replace the
+/// randomized synthetic seed data one buffered flush at a time. Matches
+/// each record to an existing satellite by `id`/`norad_id` and applies
+/// everything that validates, reporting accepted/rejected per record so a
+/// partially bad batch still lands its valid entries.
+async fn ingest_satellite_updates(
+    data: web::Data<AppState>,
+    updates: web::Json<Vec<SatelliteUpdate>>,
+) -> Result<HttpResponse> {
+    let mut satellite_service = data.satellite_service.lock().unwrap();
+    let results = satellite_service.ingest_updates(updates.into_inner());
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "results": results })))
+}
+
+async fn start_position_updater(satellite_service: Arc<Mutex<SatelliteService>>, position_hub: PositionHub) {
     let mut interval = interval(Duration::from_secs(30)); // Update every 30 seconds
-    
+    let influx_config = influx_export::InfluxConfig::from_env();
+    if influx_config.is_none() {
+        info!("INFLUXDB_URL or INFLUXDB_BUCKET not set; skipping InfluxDB position export");
+    }
+
     loop {
         interval.tick().await;
-        
-        {
+
+        let satellites = {
             let mut service = satellite_service.lock().unwrap();
             service.update_satellite_positions();
-        }
-        
+            service.get_all_satellites()
+        };
+
         info!("Updated satellite positions");
+
+        position_hub.publish(satellites.clone());
+
+        if let Some(config) = &influx_config {
+            if let Err(err) = influx_export::write_positions(config, &satellites).await {
+                tracing::warn!("Failed to export satellite positions to InfluxDB: {}", err);
+            }
+        }
     }
 }
 
@@ -155,9 +290,11 @@ async fn main() -> std::io::Result<()> {
     }
 
     // Start background position updater
+    let position_hub = PositionHub::new();
     let updater_service = Arc::clone(&satellite_service);
+    let updater_hub = position_hub.clone();
     tokio::spawn(async move {
-        start_position_updater(updater_service).await;
+        start_position_updater(updater_service, updater_hub).await;
     });
 
     let app_state = AppState {
@@ -180,19 +317,24 @@ async fn main() -> std::io::Result<()> {
 
         App::new()
             .app_data(web::Data::new(app_state.clone()))
+            .app_data(web::Data::new(position_hub.clone()))
             .wrap(cors)
             .wrap(Logger::default())
             .route("/", web::get().to(api_info))
             .route("/health", web::get().to(health_check))
+            .route("/ws/satellites", web::get().to(position_stream::stream_positions))
             .service(
                 web::scope("/api")
                     .route("/satellites", web::get().to(routes::satellites::get_satellites))
                     .route("/satellites/visible", web::get().to(routes::satellites::get_visible_satellites))
                     .route("/satellites/statistics", web::get().to(routes::satellites::get_satellite_statistics))
+                    .route("/satellites/geojson", web::get().to(get_satellites_geojson))
                     .route("/satellites/update-positions", web::post().to(routes::satellites::update_satellite_positions))
+                    .route("/satellites/ingest", web::post().to(ingest_satellite_updates))
                     .route("/satellites/{id}", web::get().to(routes::satellites::get_satellite_by_id))
                     .route("/satellites/{id}/track", web::get().to(routes::satellites::track_satellite))
                     .route("/ground-stations", web::get().to(routes::satellites::get_ground_stations))
+                    .route("/ground-stations/dop", web::get().to(get_ground_station_dop))
             )
     })
     .bind(&format!("{}:{}", host, port))?
@@ -0,0 +1,182 @@
+// Process-wide Prometheus counters/gauges for the orbit cache and launch
+// conjunction screening, exposed at `GET /metrics`. A hand-rolled registry
+// matching `sat_api`'s `metrics.rs` rather than pulling in the `prometheus`
+// crate: the exposition format is simple line-oriented text.
+
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+
+use crate::AppState;
+
+/// A cumulative ("le"-bucketed) Prometheus histogram with fixed bounds.
+struct Histogram {
+    bounds: &'static [f64],
+    bucket_counts: Vec<AtomicU64>,
+    sum: RwLock<f64>,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            bucket_counts: bounds.iter().map(|_| AtomicU64::new(0)).collect(),
+            sum: RwLock::new(0.0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, value: f64) {
+        for (bound, bucket) in self.bounds.iter().zip(self.bucket_counts.iter()) {
+            if value <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        *self.sum.write().unwrap() += value;
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn render(&self, out: &mut String, name: &str) {
+        for (bound, bucket) in self.bounds.iter().zip(self.bucket_counts.iter()) {
+            out.push_str(&format!(
+                "{name}_bucket{{le=\"{bound}\"}} {}\n",
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        let count = self.count.load(Ordering::Relaxed);
+        out.push_str(&format!("{name}_bucket{{le=\"+Inf\"}} {count}\n"));
+        out.push_str(&format!("{name}_sum {}\n", *self.sum.read().unwrap()));
+        out.push_str(&format!("{name}_count {count}\n"));
+    }
+}
+
+const CONFLICTS_PER_ANALYSIS_BOUNDS: &[f64] = &[0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0];
+const ANALYSIS_LATENCY_SECONDS_BOUNDS: &[f64] = &[0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 30.0];
+
+/// Held as `Arc<OrbitMetrics>` on `AppState`; every field is updated from
+/// `orbit.rs` as the cache refreshes and launch analyses run.
+pub struct OrbitMetrics {
+    cache_entries: AtomicI64,
+    cache_last_updated_unix: AtomicI64,
+    refresh_successes: AtomicU64,
+    refresh_failures: AtomicU64,
+    propagation_errors: AtomicU64,
+    launch_analyses_served: AtomicU64,
+    conflicts_per_analysis: Histogram,
+    analysis_latency_seconds: Histogram,
+}
+
+impl OrbitMetrics {
+    pub fn new() -> Self {
+        Self {
+            cache_entries: AtomicI64::new(0),
+            cache_last_updated_unix: AtomicI64::new(0),
+            refresh_successes: AtomicU64::new(0),
+            refresh_failures: AtomicU64::new(0),
+            propagation_errors: AtomicU64::new(0),
+            launch_analyses_served: AtomicU64::new(0),
+            conflicts_per_analysis: Histogram::new(CONFLICTS_PER_ANALYSIS_BOUNDS),
+            analysis_latency_seconds: Histogram::new(ANALYSIS_LATENCY_SECONDS_BOUNDS),
+        }
+    }
+
+    pub fn record_refresh_success(&self, entry_count: usize, last_updated: DateTime<Utc>) {
+        self.refresh_successes.fetch_add(1, Ordering::Relaxed);
+        self.cache_entries.store(entry_count as i64, Ordering::Relaxed);
+        self.cache_last_updated_unix
+            .store(last_updated.timestamp(), Ordering::Relaxed);
+    }
+
+    pub fn record_refresh_failure(&self) {
+        self.refresh_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_propagation_error(&self) {
+        self.propagation_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_launch_analysis(&self, conflicts: usize, elapsed_seconds: f64) {
+        self.launch_analyses_served.fetch_add(1, Ordering::Relaxed);
+        self.conflicts_per_analysis.observe(conflicts as f64);
+        self.analysis_latency_seconds.observe(elapsed_seconds);
+    }
+
+    /// Renders every counter/gauge/histogram in the Prometheus text
+    /// exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let now = Utc::now().timestamp();
+        let last_updated_unix = self.cache_last_updated_unix.load(Ordering::Relaxed);
+
+        out.push_str("# HELP orbitalos_orbit_cache_entries Entries currently held in the orbit cache.\n");
+        out.push_str("# TYPE orbitalos_orbit_cache_entries gauge\n");
+        out.push_str(&format!(
+            "orbitalos_orbit_cache_entries {}\n",
+            self.cache_entries.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP orbitalos_orbit_cache_age_seconds Seconds since the orbit cache was last refreshed.\n");
+        out.push_str("# TYPE orbitalos_orbit_cache_age_seconds gauge\n");
+        let age_seconds = if last_updated_unix == 0 {
+            -1
+        } else {
+            now - last_updated_unix
+        };
+        out.push_str(&format!("orbitalos_orbit_cache_age_seconds {age_seconds}\n"));
+
+        out.push_str("# HELP orbitalos_orbit_cache_refreshes_total Orbit cache refresh attempts, by outcome.\n");
+        out.push_str("# TYPE orbitalos_orbit_cache_refreshes_total counter\n");
+        out.push_str(&format!(
+            "orbitalos_orbit_cache_refreshes_total{{outcome=\"success\"}} {}\n",
+            self.refresh_successes.load(Ordering::Relaxed)
+        ));
+        out.push_str(&format!(
+            "orbitalos_orbit_cache_refreshes_total{{outcome=\"failure\"}} {}\n",
+            self.refresh_failures.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP orbitalos_sgp4_propagation_errors_total SGP4 propagation failures encountered while screening conjunctions.\n");
+        out.push_str("# TYPE orbitalos_sgp4_propagation_errors_total counter\n");
+        out.push_str(&format!(
+            "orbitalos_sgp4_propagation_errors_total {}\n",
+            self.propagation_errors.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP orbitalos_launch_analyses_total Launch conjunction analyses served.\n");
+        out.push_str("# TYPE orbitalos_launch_analyses_total counter\n");
+        out.push_str(&format!(
+            "orbitalos_launch_analyses_total {}\n",
+            self.launch_analyses_served.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP orbitalos_conflicts_per_analysis Conjunctions found per launch analysis.\n");
+        out.push_str("# TYPE orbitalos_conflicts_per_analysis histogram\n");
+        self.conflicts_per_analysis
+            .render(&mut out, "orbitalos_conflicts_per_analysis");
+
+        out.push_str("# HELP orbitalos_analysis_latency_seconds Launch analysis wall-clock latency.\n");
+        out.push_str("# TYPE orbitalos_analysis_latency_seconds histogram\n");
+        self.analysis_latency_seconds
+            .render(&mut out, "orbitalos_analysis_latency_seconds");
+
+        out
+    }
+}
+
+impl Default for OrbitMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prometheus scrape target for the orbit cache and conjunction screening,
+/// parallel to `sat_api::handlers::metrics` for the satellite-service half
+/// of this system.
+pub async fn metrics_handler(state: web::Data<AppState>) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(state.orbit_metrics.render())
+}
@@ -8,6 +8,10 @@ pub struct Provider {
     pub id: Uuid,
     pub user_id: Uuid,
     pub metadata_encrypted: Vec<u8>,
+    // X25519 public key for this provider's envelope-encryption keypair; the
+    // matching secret is stored only sealed under the server `Keyring`, in
+    // `providers.server_secret_sealed` (see `handlers::provider_secret_key`).
+    pub public_key: Vec<u8>,
     pub created_at: DateTime<Utc>,
 }
 use serde::{Deserialize, Serialize};
@@ -21,6 +25,11 @@ pub struct User {
     pub email: String,
     pub password_hash: String,
     pub role: UserRole,
+    /// Per-user opt-in for email OTP two-factor on login. Off by default so
+    /// existing/new accounts keep getting a token straight from `auth::login`
+    /// until they turn it on; `auth::login` only routes through
+    /// `PendingTwoFactor`/`verify_otp` when this is set.
+    pub two_factor_enabled: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -153,6 +162,10 @@ pub struct RegisterRequest {
 #[derive(Debug, Serialize)]
 pub struct AuthResponse {
     pub token: String,
+    /// Opaque 256-bit refresh token; present the raw value to `/api/auth/refresh`
+    /// to rotate it for a new access+refresh pair. Only its SHA-256 hash is
+    /// ever stored server-side.
+    pub refresh_token: String,
     pub user: UserInfo,
 }
 
@@ -0,0 +1,488 @@
+// Ingests CCSDS Orbit Mean-Elements Messages (OMM), in both XML and KVN
+// form, plus classic two-line `.tle` text blobs, normalizing all of them
+// into `orbit::OrbitEntry` so `fetch_and_store` isn't limited to Celestrak's
+// JSON GP schema. Operator-published ephemerides are most commonly
+// distributed as OMM, so this is what lets those feed the same cache.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+
+use crate::orbit::OrbitEntry;
+
+const EARTH_RADIUS_KM: f64 = 6378.137;
+const MU_EARTH_KM3_S2: f64 = 398600.4418;
+
+/// The shape of an ingested body, sniffed from its `Content-Type` header or,
+/// failing that, its first non-whitespace bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngestFormat {
+    Json,
+    OmmXml,
+    OmmKvn,
+    Tle,
+}
+
+/// Sniffs `body`'s format. `content_type` (when present) is trusted first;
+/// otherwise this looks at the first non-whitespace bytes: `<` means XML,
+/// a line starting with `1 ` (a TLE line-1 marker) means classic TLE, and
+/// a `KEY = VALUE` first line means OMM KVN.
+pub fn detect_format(content_type: Option<&str>, body: &[u8]) -> IngestFormat {
+    if let Some(content_type) = content_type {
+        let content_type = content_type.to_ascii_lowercase();
+        if content_type.contains("xml") {
+            return IngestFormat::OmmXml;
+        }
+        if content_type.contains("json") {
+            return IngestFormat::Json;
+        }
+        if content_type.contains("kvn") {
+            return IngestFormat::OmmKvn;
+        }
+        if content_type.contains("tle") || content_type.contains("text/plain") {
+            // text/plain is ambiguous between KVN and TLE; fall through to
+            // the byte sniff below rather than guessing here.
+        }
+    }
+
+    let trimmed = body
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .map(|start| &body[start..])
+        .unwrap_or(body);
+
+    match trimmed.first() {
+        Some(b'<') => IngestFormat::OmmXml,
+        Some(b'[') | Some(b'{') => IngestFormat::Json,
+        Some(b'1') if trimmed.get(1) == Some(&b' ') => IngestFormat::Tle,
+        _ => IngestFormat::OmmKvn,
+    }
+}
+
+/// A single OMM record's keywords, independent of whether they arrived as
+/// KVN `KEY = VALUE` lines or XML `<KEY>VALUE</KEY>` elements.
+#[derive(Debug, Default)]
+struct OmmFields {
+    object_name: Option<String>,
+    object_id: Option<String>,
+    norad_cat_id: Option<i64>,
+    classification_type: Option<String>,
+    epoch: Option<String>,
+    mean_motion: Option<f64>,
+    eccentricity: Option<f64>,
+    inclination: Option<f64>,
+    ra_of_asc_node: Option<f64>,
+    arg_of_pericenter: Option<f64>,
+    mean_anomaly: Option<f64>,
+    ephemeris_type: Option<u32>,
+    element_set_no: Option<u32>,
+    rev_at_epoch: Option<u32>,
+    bstar: Option<f64>,
+    mean_motion_dot: Option<f64>,
+    mean_motion_ddot: Option<f64>,
+    tle_line1: Option<String>,
+    tle_line2: Option<String>,
+}
+
+pub fn parse_omm_kvn(text: &str) -> Result<Vec<OrbitEntry>> {
+    let mut entries = Vec::new();
+    let mut fields = OmmFields::default();
+    let mut has_any_field = false;
+
+    for line in text.lines().chain(std::iter::once("")) {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("COMMENT") {
+            if has_any_field {
+                entries.push(finish_omm_record(fields)?);
+                fields = OmmFields::default();
+                has_any_field = false;
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        has_any_field |= apply_omm_field(&mut fields, key, value);
+    }
+
+    Ok(entries)
+}
+
+pub fn parse_omm_xml(xml: &str) -> Result<Vec<OrbitEntry>> {
+    let mut entries = Vec::new();
+
+    for segment in split_xml_segments(xml) {
+        let mut fields = OmmFields::default();
+        let mut has_any_field = false;
+        for key in OMM_XML_KEYWORDS {
+            if let Some(value) = xml_tag_value(segment, key) {
+                has_any_field |= apply_omm_field(&mut fields, key, value);
+            }
+        }
+        if has_any_field {
+            entries.push(finish_omm_record(fields)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Classic two- or three-line `.tle` text: an optional name line followed
+/// by the `1 ...`/`2 ...` element lines, repeated for each object. Only the
+/// handful of fields `OrbitEntry` needs are decoded out of the fixed-width
+/// columns; the TLE lines themselves are carried through verbatim.
+pub fn parse_tle_blob(text: &str) -> Result<Vec<OrbitEntry>> {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).filter(|l| !l.is_empty()).collect();
+    let mut entries = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let (name, line1, line2, next) = if lines[i].starts_with("1 ") {
+            (format!("UNKNOWN-{}", tle_field(lines[i], 2, 7)?), lines[i], lines.get(i + 1).copied().unwrap_or(""), i + 2)
+        } else if i + 2 < lines.len() && lines[i + 1].starts_with("1 ") {
+            (lines[i].trim_start_matches('0').trim().to_string(), lines[i + 1], lines[i + 2], i + 3)
+        } else {
+            i += 1;
+            continue;
+        };
+
+        if !line2.starts_with("2 ") {
+            i = next;
+            continue;
+        }
+
+        entries.push(orbit_entry_from_tle(&name, line1, line2)?);
+        i = next;
+    }
+
+    Ok(entries)
+}
+
+const OMM_XML_KEYWORDS: &[&str] = &[
+    "OBJECT_NAME",
+    "OBJECT_ID",
+    "NORAD_CAT_ID",
+    "CLASSIFICATION_TYPE",
+    "EPOCH",
+    "MEAN_MOTION",
+    "ECCENTRICITY",
+    "INCLINATION",
+    "RA_OF_ASC_NODE",
+    "ARG_OF_PERICENTER",
+    "MEAN_ANOMALY",
+    "EPHEMERIS_TYPE",
+    "ELEMENT_SET_NO",
+    "REV_AT_EPOCH",
+    "BSTAR",
+    "MEAN_MOTION_DOT",
+    "MEAN_MOTION_DDOT",
+];
+
+/// Splits an OMM XML document on its `<segment>` boundaries so each
+/// satellite's metadata/data block can be scanned independently. Falls back
+/// to the whole document when there's no `<segment>` tag, so a
+/// bare `<omm>...</omm>` single-object message still parses.
+fn split_xml_segments(xml: &str) -> Vec<&str> {
+    let segments: Vec<&str> = xml.split("<segment").skip(1).collect();
+    if segments.is_empty() {
+        vec![xml]
+    } else {
+        segments
+    }
+}
+
+fn xml_tag_value<'a>(fragment: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let start = fragment.find(&open)? + open.len();
+    let rest = &fragment[start..];
+    let end = rest.find(&close)?;
+    Some(rest[..end].trim())
+}
+
+/// Applies one `KEY`/value pair to `fields`. Returns whether it recognized
+/// the key, so callers can tell "no more fields on this record" (KVN's blank
+/// line) from "an unrecognized keyword we can safely skip".
+fn apply_omm_field(fields: &mut OmmFields, key: &str, value: &str) -> bool {
+    match key {
+        "OBJECT_NAME" => fields.object_name = Some(value.to_string()),
+        "OBJECT_ID" => fields.object_id = Some(value.to_string()),
+        "NORAD_CAT_ID" => fields.norad_cat_id = value.parse().ok(),
+        "CLASSIFICATION_TYPE" => fields.classification_type = Some(value.to_string()),
+        "EPOCH" => fields.epoch = Some(value.to_string()),
+        "MEAN_MOTION" => fields.mean_motion = value.parse().ok(),
+        "ECCENTRICITY" => fields.eccentricity = value.parse().ok(),
+        "INCLINATION" => fields.inclination = value.parse().ok(),
+        "RA_OF_ASC_NODE" => fields.ra_of_asc_node = value.parse().ok(),
+        "ARG_OF_PERICENTER" => fields.arg_of_pericenter = value.parse().ok(),
+        "MEAN_ANOMALY" => fields.mean_anomaly = value.parse().ok(),
+        "EPHEMERIS_TYPE" => fields.ephemeris_type = value.parse().ok(),
+        "ELEMENT_SET_NO" => fields.element_set_no = value.parse().ok(),
+        "REV_AT_EPOCH" => fields.rev_at_epoch = value.parse().ok(),
+        "BSTAR" => fields.bstar = value.parse().ok(),
+        "MEAN_MOTION_DOT" => fields.mean_motion_dot = value.parse().ok(),
+        "MEAN_MOTION_DDOT" => fields.mean_motion_ddot = value.parse().ok(),
+        _ => return false,
+    }
+    true
+}
+
+fn finish_omm_record(fields: OmmFields) -> Result<OrbitEntry> {
+    let norad_cat_id = fields
+        .norad_cat_id
+        .ok_or_else(|| anyhow!("OMM record missing NORAD_CAT_ID"))?;
+    let epoch: DateTime<Utc> = fields
+        .epoch
+        .as_deref()
+        .ok_or_else(|| anyhow!("OMM record missing EPOCH"))?
+        .parse()
+        .context("OMM EPOCH is not a valid RFC3339 timestamp")?;
+    let mean_motion = fields
+        .mean_motion
+        .ok_or_else(|| anyhow!("OMM record missing MEAN_MOTION"))?;
+    let eccentricity = fields.eccentricity.unwrap_or(0.0);
+    let inclination = fields.inclination.unwrap_or(0.0);
+    let raan = fields.ra_of_asc_node.unwrap_or(0.0);
+    let arg_perigee = fields.arg_of_pericenter.unwrap_or(0.0);
+    let mean_anomaly = fields.mean_anomaly.unwrap_or(0.0);
+    let name = fields.object_name.unwrap_or_else(|| "UNKNOWN".to_string());
+
+    let (tle_line1, tle_line2) = match (&fields.tle_line1, &fields.tle_line2) {
+        (Some(line1), Some(line2)) => (line1.clone(), line2.clone()),
+        _ => build_tle_from_omm(
+            norad_cat_id,
+            fields.object_id.as_deref(),
+            fields.classification_type.as_deref().unwrap_or("U"),
+            epoch,
+            mean_motion,
+            eccentricity,
+            inclination,
+            raan,
+            arg_perigee,
+            mean_anomaly,
+            fields.bstar.unwrap_or(0.0),
+            fields.mean_motion_dot.unwrap_or(0.0),
+            fields.mean_motion_ddot.unwrap_or(0.0),
+            fields.ephemeris_type.unwrap_or(0),
+            fields.element_set_no.unwrap_or(999),
+            fields.rev_at_epoch.unwrap_or(0),
+        ),
+    };
+
+    Ok(orbit_entry_from_elements(
+        norad_cat_id,
+        name,
+        epoch,
+        inclination,
+        mean_motion,
+        eccentricity,
+        raan,
+        arg_perigee,
+        mean_anomaly,
+        tle_line1,
+        tle_line2,
+    ))
+}
+
+fn semimajor_axis_km(mean_motion_rev_per_day: f64) -> f64 {
+    let n_rad_s = mean_motion_rev_per_day * 2.0 * std::f64::consts::PI / 86400.0;
+    (MU_EARTH_KM3_S2 / (n_rad_s * n_rad_s)).cbrt()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn orbit_entry_from_elements(
+    norad_id: i64,
+    name: String,
+    epoch: DateTime<Utc>,
+    inclination_deg: f64,
+    mean_motion_rev_per_day: f64,
+    eccentricity: f64,
+    raan_deg: f64,
+    arg_perigee_deg: f64,
+    mean_anomaly_deg: f64,
+    tle_line1: String,
+    tle_line2: String,
+) -> OrbitEntry {
+    let semimajor_axis_km = semimajor_axis_km(mean_motion_rev_per_day);
+    OrbitEntry {
+        norad_id,
+        name,
+        epoch,
+        inclination_deg,
+        mean_motion_rev_per_day,
+        eccentricity,
+        semimajor_axis_km,
+        perigee_km: semimajor_axis_km * (1.0 - eccentricity) - EARTH_RADIUS_KM,
+        apogee_km: semimajor_axis_km * (1.0 + eccentricity) - EARTH_RADIUS_KM,
+        arg_perigee_deg,
+        raan_deg,
+        mean_anomaly_deg,
+        tle_line1,
+        tle_line2,
+    }
+}
+
+fn orbit_entry_from_tle(name: &str, line1: &str, line2: &str) -> Result<OrbitEntry> {
+    let norad_id: i64 = tle_field(line1, 2, 7)?
+        .trim()
+        .parse()
+        .context("invalid NORAD id in TLE line 1")?;
+    let epoch_year: i32 = tle_field(line1, 18, 20)?.parse().context("invalid TLE epoch year")?;
+    let epoch_day: f64 = tle_field(line1, 20, 32)?.trim().parse().context("invalid TLE epoch day")?;
+    let epoch = tle_epoch_to_datetime(epoch_year, epoch_day)?;
+
+    let inclination_deg: f64 = tle_field(line2, 8, 16)?.trim().parse().context("invalid inclination")?;
+    let raan_deg: f64 = tle_field(line2, 17, 25)?.trim().parse().context("invalid RAAN")?;
+    let eccentricity: f64 = format!("0.{}", tle_field(line2, 26, 33)?.trim())
+        .parse()
+        .context("invalid eccentricity")?;
+    let arg_perigee_deg: f64 = tle_field(line2, 34, 42)?.trim().parse().context("invalid argument of perigee")?;
+    let mean_anomaly_deg: f64 = tle_field(line2, 43, 51)?.trim().parse().context("invalid mean anomaly")?;
+    let mean_motion_rev_per_day: f64 = tle_field(line2, 52, 63)?.trim().parse().context("invalid mean motion")?;
+
+    Ok(orbit_entry_from_elements(
+        norad_id,
+        name.to_string(),
+        epoch,
+        inclination_deg,
+        mean_motion_rev_per_day,
+        eccentricity,
+        raan_deg,
+        arg_perigee_deg,
+        mean_anomaly_deg,
+        line1.to_string(),
+        line2.to_string(),
+    ))
+}
+
+/// Extracts TLE columns `[start, end)` (0-indexed, matching the common
+/// off-by-one-from-the-spec convention of counting from 0 instead of the
+/// spec's 1-indexed columns), erroring instead of panicking on a short line.
+fn tle_field(line: &str, start: usize, end: usize) -> Result<&str> {
+    line.get(start..end)
+        .ok_or_else(|| anyhow!("TLE line too short for columns {start}..{end}: {line:?}"))
+}
+
+fn tle_epoch_to_datetime(two_digit_year: i32, day_of_year: f64) -> Result<DateTime<Utc>> {
+    let year = if two_digit_year < 57 { 2000 + two_digit_year } else { 1900 + two_digit_year };
+    let days = day_of_year - 1.0;
+    let base = chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+        .ok_or_else(|| anyhow!("invalid TLE epoch year {year}"))?
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    Ok(DateTime::<Utc>::from_naive_utc_and_offset(
+        base + chrono::Duration::milliseconds((days * 86_400_000.0).round() as i64),
+        Utc,
+    ))
+}
+
+/// Reconstructs NORAD TLE lines 1/2 from OMM keywords so `propagate_to_time`
+/// (which only knows how to read TLE lines) still works when a source
+/// supplies OMM without the original TLE text. Encodes the handful of
+/// fixed-width/exponential-notation fields TLE uses; the international
+/// designator comes from `OBJECT_ID` and falls back to a placeholder when
+/// that keyword is absent.
+#[allow(clippy::too_many_arguments)]
+fn build_tle_from_omm(
+    norad_cat_id: i64,
+    object_id: Option<&str>,
+    classification_type: &str,
+    epoch: DateTime<Utc>,
+    mean_motion: f64,
+    eccentricity: f64,
+    inclination: f64,
+    raan: f64,
+    arg_perigee: f64,
+    mean_anomaly: f64,
+    bstar: f64,
+    mean_motion_dot: f64,
+    mean_motion_ddot: f64,
+    ephemeris_type: u32,
+    element_set_no: u32,
+    rev_at_epoch: u32,
+) -> (String, String) {
+    let intl_designator = international_designator(object_id);
+    let epoch_year = epoch.format("%y").to_string();
+    let epoch_day = day_of_year_fractional(epoch);
+
+    let mean_motion_dot_field = encode_signed_decimal(mean_motion_dot / 2.0);
+    let mean_motion_ddot_field = encode_tle_exponential(mean_motion_ddot / 6.0);
+    let bstar_field = encode_tle_exponential(bstar);
+
+    let mut line1 = format!(
+        "1 {norad_cat_id:05}{} {intl_designator:<8} {epoch_year}{epoch_day:0>12} {mean_motion_dot_field} {mean_motion_ddot_field} {bstar_field} {ephemeris_type} {element_set_no:4}",
+        classification_type.chars().next().unwrap_or('U'),
+    );
+    line1.push_str(&tle_checksum(&line1).to_string());
+
+    let eccentricity_field = format!("{:07}", (eccentricity * 10_000_000.0).round() as i64);
+    let mut line2 = format!(
+        "2 {norad_cat_id:05} {inclination:8.4} {raan:8.4} {eccentricity_field} {arg_perigee:8.4} {mean_anomaly:8.4} {mean_motion:11.8}{rev_at_epoch:5}",
+    );
+    line2.push_str(&tle_checksum(&line2).to_string());
+
+    (line1, line2)
+}
+
+/// COSPAR id `"1998-067A"` -> TLE international designator `"98067A"`;
+/// falls back to a clearly-synthetic placeholder when unavailable.
+fn international_designator(object_id: Option<&str>) -> String {
+    let Some(object_id) = object_id else {
+        return "00001A".to_string();
+    };
+    let Some((year, rest)) = object_id.split_once('-') else {
+        return "00001A".to_string();
+    };
+    let yy = if year.len() >= 2 { &year[year.len() - 2..] } else { "00" };
+    format!("{yy}{rest}")
+}
+
+fn day_of_year_fractional(timestamp: DateTime<Utc>) -> String {
+    use chrono::{Datelike, Timelike};
+    let day_of_year = timestamp.ordinal() as f64;
+    let seconds_into_day = timestamp.time().num_seconds_from_midnight() as f64
+        + timestamp.timestamp_subsec_nanos() as f64 / 1e9;
+    let fractional_day = day_of_year + seconds_into_day / 86_400.0;
+    format!("{fractional_day:012.8}")
+}
+
+/// Encodes a signed decimal with an assumed leading decimal point and no
+/// exponent, as TLE's first-derivative-of-mean-motion field does (e.g.
+/// `0.00001234` -> `" .00001234"`, `-0.00001234` -> `"-.00001234"`).
+fn encode_signed_decimal(value: f64) -> String {
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    let digits = (value.abs() * 100_000_000.0).round() as i64;
+    format!("{sign}.{digits:08}")
+}
+
+/// Encodes a signed decimal in TLE's assumed-decimal-point exponential
+/// notation (e.g. `0.00036191` -> `" 36191-3"`).
+fn encode_tle_exponential(value: f64) -> String {
+    if value == 0.0 {
+        return " 00000-0".to_string();
+    }
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    let abs = value.abs();
+    let exponent = abs.log10().ceil() as i32;
+    let mantissa = abs / 10f64.powi(exponent);
+    let mut mantissa_digits = (mantissa * 100_000.0).round() as i64;
+    let mut exponent = exponent;
+    if mantissa_digits >= 100_000 {
+        mantissa_digits /= 10;
+        exponent += 1;
+    }
+    let exp_sign = if exponent < 0 { '-' } else { '+' };
+    format!("{sign}{mantissa_digits:05}{exp_sign}{}", exponent.abs())
+}
+
+/// NORAD TLE checksum: sum of all digits mod 10, with `-` counted as 1 and
+/// every other character (letters, `+`, `.`, spaces) counted as 0.
+fn tle_checksum(line: &str) -> u32 {
+    line.chars()
+        .map(|c| c.to_digit(10).unwrap_or(if c == '-' { 1 } else { 0 }))
+        .sum::<u32>()
+        % 10
+}
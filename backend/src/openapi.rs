@@ -0,0 +1,76 @@
+//! Machine-readable description of the axum API in `handlers.rs`, assembled
+//! from the `#[utoipa::path(...)]` annotations on each handler and the
+//! `#[derive(utoipa::ToSchema)]` request/response structs. Mounted in
+//! `main.rs` as a RapiDoc UI plus the raw `/api-docs/openapi.json` it reads
+//! from, so satellite-operator clients can generate SDKs and try the
+//! reservation/booking/compliance flows without reading source.
+
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::handlers::register_user,
+        crate::handlers::login_user,
+        crate::handlers::onboard_provider,
+        crate::handlers::reserve_slot,
+        crate::handlers::confirm_reservation,
+        crate::handlers::cancel_reservation,
+        crate::handlers::book_payload,
+        crate::handlers::cancel_booking,
+        crate::handlers::create_compliance_report,
+        crate::handlers::admin_list_users,
+        crate::handlers::admin_get_user,
+        crate::handlers::admin_update_user,
+        crate::handlers::admin_delete_user,
+        crate::handlers::block_user,
+    ),
+    components(schemas(
+        crate::handlers::RegisterUserRequest,
+        crate::handlers::RegisterUserResponse,
+        crate::handlers::LoginUserRequest,
+        crate::handlers::LoginUserResponse,
+        crate::handlers::ProviderOnboardRequest,
+        crate::handlers::ReserveSlotRequest,
+        crate::handlers::ConfirmReservationRequest,
+        crate::handlers::CancelReservationRequest,
+        crate::handlers::BookPayloadRequest,
+        crate::handlers::CancelBookingRequest,
+        crate::handlers::CreateComplianceReportRequest,
+        crate::handlers::AdminUpdateUserRequest,
+        crate::handlers::BlockUserRequest,
+    )),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "auth", description = "Registration and login"),
+        (name = "providers", description = "Ground-station/satellite-operator onboarding"),
+        (name = "reservations", description = "Orbital slot reservation lifecycle"),
+        (name = "bookings", description = "Launch payload bookings"),
+        (name = "compliance", description = "Compliance report generation"),
+        (name = "admin", description = "Admin-only management endpoints"),
+    ),
+)]
+pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi
+            .components
+            .as_mut()
+            .expect("ApiDoc declares components, so this is always Some");
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
@@ -3,20 +3,46 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use actix_web::{web, HttpResponse, Responder};
+use std::time::Duration as StdDuration;
+
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Duration, Utc};
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use sgp4::prelude::*;
+use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 
+use crate::metrics::OrbitMetrics;
+use crate::omm;
+use crate::orbit_history;
+use crate::space_track::SpaceTrackSession;
 use crate::AppState;
 
-const CELESTRAK_URL: &str = "https://celestrak.org/NORAD/elements/gp.php?GROUP=active&FORMAT=json";
+const CELESTRAK_URL_TEMPLATE: &str =
+    "https://celestrak.org/NORAD/elements/gp.php?GROUP={group}&FORMAT=json";
+const DEFAULT_CELESTRAK_GROUP: &str = "active";
 const EARTH_RADIUS_KM: f64 = 6378.137;
 const LEO_ALTITUDE_LIMIT_KM: f64 = 2000.0;
-const SAFE_RADIUS_KM: f64 = 50.0;
+// How long `list_cached`'s response may be treated as fresh by the client
+// or an intermediary cache; matches the interval the background refresher
+// uses to repopulate `orbit_cache`.
+const CACHE_MAX_AGE_SECS: u64 = BACKGROUND_REFRESH_INTERVAL_SECS;
+// Fallback staleness window when `AppState::orbit_cache_ttl` isn't set
+// (e.g. constructed outside of `main`'s env-driven setup).
+pub const DEFAULT_ORBIT_CACHE_TTL: Duration = Duration::seconds(300);
+// Cadence of the startup-spawned refresh task; independent of the TTL so
+// the cache gets refreshed well before it would otherwise go stale.
+const BACKGROUND_REFRESH_INTERVAL_SECS: u64 = 120;
+// Earth's standard gravitational parameter, km^3/s^2 -- used to derive the
+// nominal target orbit's mean motion from its semi-major axis.
+const MU_EARTH_KM3_S2: f64 = 398600.4418;
+// Coarse screening volume: a sample pair closer than this triggers a
+// golden-section refinement of that sub-interval in `screen_entry`.
+const SCREENING_RADIUS_KM: f64 = 200.0;
+const COARSE_STEP_SECONDS: i64 = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrbitEntry {
@@ -122,20 +148,114 @@ pub async fn refresh_cache(state: web::Data<AppState>) -> impl Responder {
     }
 }
 
-pub async fn list_cached(state: web::Data<AppState>) -> impl Responder {
+pub async fn list_cached(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
     ensure_cache(&state).await;
 
-    let cache = state.orbit_cache.read().await;
-    let entries = cache.clone();
-
-    HttpResponse::Ok().json(OrbitListResponse {
-        last_updated: state
-            .orbit_cache_last_updated
-            .read()
-            .await
-            .unwrap_or_else(Utc::now),
-        entries,
-    })
+    let entries = {
+        let cache = state.orbit_cache.read().await;
+        cache.clone()
+    };
+    let last_updated = state
+        .orbit_cache_last_updated
+        .read()
+        .await
+        .unwrap_or_else(Utc::now);
+
+    let etag = compute_etag(&entries, last_updated);
+    let last_modified = http_date(last_updated);
+    let cache_control = format!("max-age={CACHE_MAX_AGE_SECS}");
+
+    if request_is_fresh(&req, &etag, last_updated) {
+        return HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .insert_header((header::LAST_MODIFIED, last_modified))
+            .insert_header((header::CACHE_CONTROL, cache_control))
+            .finish();
+    }
+
+    HttpResponse::Ok()
+        .insert_header((header::ETAG, etag))
+        .insert_header((header::LAST_MODIFIED, last_modified))
+        .insert_header((header::CACHE_CONTROL, cache_control))
+        .json(OrbitListResponse {
+            last_updated,
+            entries,
+        })
+}
+
+/// Strong validator for `list_cached`'s body: a digest of every entry's
+/// identity/epoch/TLE lines plus the cache's last-refresh timestamp, so the
+/// tag changes iff the served JSON would change.
+fn compute_etag(entries: &[OrbitEntry], last_updated: DateTime<Utc>) -> String {
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        hasher.update(entry.norad_id.to_le_bytes());
+        hasher.update(entry.epoch.to_rfc3339().as_bytes());
+        hasher.update(entry.tle_line1.as_bytes());
+        hasher.update(entry.tle_line2.as_bytes());
+    }
+    hasher.update(last_updated.to_rfc3339().as_bytes());
+    format!("\"{:x}\"", hasher.finalize())
+}
+
+/// RFC 7231 `HTTP-date`, the format `Last-Modified`/`If-Modified-Since` use.
+fn http_date(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// True when the request's `If-None-Match`/`If-Modified-Since` show the
+/// caller already holds the current representation. `If-None-Match` wins
+/// when both are present, per RFC 7232 §6.
+fn request_is_fresh(req: &HttpRequest, etag: &str, last_updated: DateTime<Utc>) -> bool {
+    if let Some(if_none_match) = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        return if_none_match
+            .split(',')
+            .any(|candidate| candidate.trim() == etag || candidate.trim() == "*");
+    }
+
+    if let Some(if_modified_since) = req
+        .headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+    {
+        if let Ok(since) = DateTime::parse_from_rfc2822(if_modified_since) {
+            // `Last-Modified` is sent at whole-second precision, so compare
+            // at that precision too.
+            return last_updated.timestamp() <= since.timestamp();
+        }
+    }
+
+    false
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrbitHistoryQuery {
+    pub norad_id: i64,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Historical element sets for one object over `[start, end]`, drawn from
+/// `AppState::orbit_history_pool` rather than the live cache. Answers
+/// "what were object X's elements around time T" across past refreshes.
+pub async fn history(
+    state: web::Data<AppState>,
+    query: web::Query<OrbitHistoryQuery>,
+) -> impl Responder {
+    let Some(pool) = state.orbit_history_pool.as_ref() else {
+        return HttpResponse::build(actix_web::http::StatusCode::SERVICE_UNAVAILABLE)
+            .body("orbit history persistence is not configured");
+    };
+
+    match orbit_history::query_history(pool, query.norad_id, query.start, query.end).await {
+        Ok(entries) => HttpResponse::Ok().json(entries),
+        Err(err) => HttpResponse::build(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body(format!("failed to query orbit history: {err}")),
+    }
 }
 
 pub async fn analyze_launch(
@@ -157,7 +277,13 @@ pub async fn analyze_launch(
 async fn ensure_cache(state: &web::Data<AppState>) -> Result<()> {
     {
         let cache = state.orbit_cache.read().await;
-        if !cache.is_empty() {
+        let last_updated = *state.orbit_cache_last_updated.read().await;
+        let is_fresh = !cache.is_empty()
+            && last_updated.is_some_and(|last_updated| {
+                Utc::now() - last_updated < state.orbit_cache_ttl
+            });
+
+        if is_fresh {
             return Ok(());
         }
     }
@@ -165,8 +291,83 @@ async fn ensure_cache(state: &web::Data<AppState>) -> Result<()> {
     fetch_and_store(state).await.map(|_| ())
 }
 
-async fn fetch_and_store(state: &web::Data<AppState>) -> Result<Vec<OrbitEntry>> {
-    let response = reqwest::get(CELESTRAK_URL)
+/// Spawned once at startup so SGP4 accuracy degrades gracefully between
+/// requests instead of relying on `ensure_cache` only firing on traffic:
+/// refreshes `orbit_cache` (and its CSV backup) on a fixed interval.
+pub fn spawn_background_refresh(state: web::Data<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(StdDuration::from_secs(
+            BACKGROUND_REFRESH_INTERVAL_SECS,
+        ));
+        loop {
+            ticker.tick().await;
+            if let Err(err) = fetch_and_store(&state).await {
+                tracing::error!("background orbit cache refresh failed: {err}");
+            }
+        }
+    });
+}
+
+/// Selects which upstream `fetch_and_store` pulls the GP catalog from.
+/// `AppState::orbit_data_source` defaults to `Celestrak` (the historical
+/// anonymous feed); set it to `SpaceTrack` to use `AppState::space_track`'s
+/// authenticated session and reach the full catalog instead of just
+/// `GROUP=active`.
+#[derive(Debug, Clone)]
+pub enum OrbitDataSource {
+    Celestrak { group: String },
+    SpaceTrack { predicate: String },
+}
+
+impl Default for OrbitDataSource {
+    fn default() -> Self {
+        OrbitDataSource::Celestrak {
+            group: DEFAULT_CELESTRAK_GROUP.to_string(),
+        }
+    }
+}
+
+fn celestrak_record_to_entry(record: CelestrakRecord) -> OrbitEntry {
+    OrbitEntry {
+        norad_id: record.norad_cat_id,
+        name: record.object_name,
+        epoch: record.epoch,
+        inclination_deg: record.inclination,
+        mean_motion_rev_per_day: record.mean_motion,
+        eccentricity: record.eccentricity,
+        semimajor_axis_km: record.semimajor_axis,
+        perigee_km: record.perigee,
+        apogee_km: record.apogee,
+        arg_perigee_deg: record.arg_perigee,
+        raan_deg: record.raan,
+        mean_anomaly_deg: record.mean_anomaly,
+        tle_line1: record.tle_line1,
+        tle_line2: record.tle_line2,
+    }
+}
+
+/// Parses a GP response body into `OrbitEntry`s regardless of which of the
+/// four formats `fetch_and_store` might receive it in: sniffs `content_type`
+/// (or, failing that, `body`'s own bytes) via `omm::detect_format`, and
+/// routes to the matching parser. `CelestrakRecord`'s JSON schema lines up
+/// with both Celestrak's and Space-Track's GP JSON output, so it's reused
+/// for the `Json` branch regardless of source.
+fn parse_ingest_body(content_type: Option<&str>, body: &[u8]) -> Result<Vec<OrbitEntry>> {
+    match omm::detect_format(content_type, body) {
+        omm::IngestFormat::Json => {
+            let records: Vec<CelestrakRecord> =
+                serde_json::from_slice(body).context("unable to parse GP JSON response")?;
+            Ok(records.into_iter().map(celestrak_record_to_entry).collect())
+        }
+        omm::IngestFormat::OmmXml => omm::parse_omm_xml(&String::from_utf8_lossy(body)),
+        omm::IngestFormat::OmmKvn => omm::parse_omm_kvn(&String::from_utf8_lossy(body)),
+        omm::IngestFormat::Tle => omm::parse_tle_blob(&String::from_utf8_lossy(body)),
+    }
+}
+
+async fn fetch_celestrak_entries(group: &str) -> Result<Vec<OrbitEntry>> {
+    let url = CELESTRAK_URL_TEMPLATE.replace("{group}", group);
+    let response = reqwest::get(&url)
         .await
         .context("failed to request celestrak feed")?;
 
@@ -174,34 +375,66 @@ async fn fetch_and_store(state: &web::Data<AppState>) -> Result<Vec<OrbitEntry>>
         return Err(anyhow!("celestrak responded with status {}", response.status()));
     }
 
-    let body: Vec<CelestrakRecord> = response
-        .json()
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = response
+        .bytes()
         .await
-        .context("unable to parse celestrak response")?;
+        .context("failed to read celestrak response body")?;
 
-    let entries: Vec<OrbitEntry> = body
-        .into_iter()
-        .filter(|record| {
-            (record.semimajor_axis - EARTH_RADIUS_KM) > 0.0
-                && (record.semimajor_axis - EARTH_RADIUS_KM) < LEO_ALTITUDE_LIMIT_KM
-        })
-        .map(|record| OrbitEntry {
-            norad_id: record.norad_cat_id,
-            name: record.object_name,
-            epoch: record.epoch,
-            inclination_deg: record.inclination,
-            mean_motion_rev_per_day: record.mean_motion,
-            eccentricity: record.eccentricity,
-            semimajor_axis_km: record.semimajor_axis,
-            perigee_km: record.perigee,
-            apogee_km: record.apogee,
-            arg_perigee_deg: record.arg_perigee,
-            raan_deg: record.raan,
-            mean_anomaly_deg: record.mean_anomaly,
-            tle_line1: record.tle_line1,
-            tle_line2: record.tle_line2,
+    parse_ingest_body(content_type.as_deref(), &body)
+}
+
+async fn fetch_space_track_entries(
+    session: &SpaceTrackSession,
+    predicate: &str,
+) -> Result<Vec<OrbitEntry>> {
+    let raw = session.fetch_gp_json(predicate).await?;
+    raw.into_iter()
+        .map(|value| {
+            serde_json::from_value::<CelestrakRecord>(value)
+                .context("invalid space-track GP record")
+                .map(celestrak_record_to_entry)
         })
-        .collect();
+        .collect()
+}
+
+/// Thin metrics wrapper around `fetch_and_store_inner`: every caller (the
+/// manual refresh endpoint, `ensure_cache`, the background refresh task)
+/// goes through here, so a single spot records `orbitalos_orbit_cache_refreshes_total`.
+async fn fetch_and_store(state: &web::Data<AppState>) -> Result<Vec<OrbitEntry>> {
+    match fetch_and_store_inner(state).await {
+        Ok(entries) => {
+            state
+                .orbit_metrics
+                .record_refresh_success(entries.len(), Utc::now());
+            Ok(entries)
+        }
+        Err(err) => {
+            state.orbit_metrics.record_refresh_failure();
+            Err(err)
+        }
+    }
+}
+
+async fn fetch_and_store_inner(state: &web::Data<AppState>) -> Result<Vec<OrbitEntry>> {
+    let mut entries: Vec<OrbitEntry> = match &state.orbit_data_source {
+        OrbitDataSource::Celestrak { group } => fetch_celestrak_entries(group).await?,
+        OrbitDataSource::SpaceTrack { predicate } => {
+            let session = state.space_track.as_deref().ok_or_else(|| {
+                anyhow!("orbit_data_source is space-track but AppState::space_track is unset")
+            })?;
+            fetch_space_track_entries(session, predicate).await?
+        }
+    };
+
+    entries.retain(|entry| {
+        let altitude = entry.semimajor_axis_km - EARTH_RADIUS_KM;
+        (0.0..LEO_ALTITUDE_LIMIT_KM).contains(&altitude)
+    });
 
     {
         let mut cache = state.orbit_cache.write().await;
@@ -215,6 +448,12 @@ async fn fetch_and_store(state: &web::Data<AppState>) -> Result<Vec<OrbitEntry>>
 
     write_csv(&state.orbit_cache_path, &entries)?;
 
+    if let Some(pool) = state.orbit_history_pool.as_ref() {
+        if let Err(err) = orbit_history::record_entries(pool, &entries).await {
+            tracing::warn!("failed to persist orbit history: {err}");
+        }
+    }
+
     Ok(entries)
 }
 
@@ -232,31 +471,45 @@ fn write_csv(path: &PathBuf, entries: &[OrbitEntry]) -> Result<()> {
     Ok(())
 }
 
+/// Prefers the historical element set whose epoch is nearest `launch_time`
+/// over the live cache's (always-latest) entry, since SGP4 accuracy decays
+/// with time-since-epoch and a launch window far from now propagates more
+/// accurately from a TLE epoch close to it. Falls back to `entry` itself
+/// when no history is configured or none is on file for this object.
+async fn select_propagation_basis(
+    state: &web::Data<AppState>,
+    entry: &OrbitEntry,
+    launch_time: DateTime<Utc>,
+) -> OrbitEntry {
+    let Some(pool) = state.orbit_history_pool.as_ref() else {
+        return entry.clone();
+    };
+
+    match orbit_history::nearest_epoch_entry(pool, entry.norad_id, launch_time).await {
+        Ok(Some(historical)) => historical,
+        _ => entry.clone(),
+    }
+}
+
 async fn compute_launch_analysis(
     state: &web::Data<AppState>,
     payload: &LaunchAnalysisRequest,
 ) -> Result<LaunchAnalysisResponse> {
+    let started = std::time::Instant::now();
     let launch_start = payload.launch_time;
     let launch_end = launch_start + Duration::minutes(30);
 
     let cache = state.orbit_cache.read().await;
+    let target = TargetOrbit::from_launch(payload);
 
     let mut conflicts = Vec::new();
 
     for entry in cache.iter() {
-        if let Ok(propagation) = propagate_to_time(entry, launch_start) {
-            let altitude = propagation.position.norm() - EARTH_RADIUS_KM;
-            if (altitude - payload.desired_altitude_km).abs() < SAFE_RADIUS_KM
-                && (entry.inclination_deg - payload.desired_inclination_deg).abs() < 2.0
-            {
-                conflicts.push(OrbitConflict {
-                    norad_id: entry.norad_id,
-                    name: entry.name.clone(),
-                    time_utc: launch_start,
-                    miss_distance_km: (altitude - payload.desired_altitude_km).abs(),
-                    relative_speed_km_s: propagation.velocity.norm(),
-                });
-            }
+        let entry_for_window = select_propagation_basis(state, entry, launch_start).await;
+        if let Some(conflict) =
+            screen_entry(&entry_for_window, &target, launch_start, launch_end, &state.orbit_metrics)
+        {
+            conflicts.push(conflict);
         }
     }
 
@@ -266,6 +519,10 @@ async fn compute_launch_analysis(
         (launch_end, launch_end + Duration::minutes(30))
     };
 
+    state
+        .orbit_metrics
+        .record_launch_analysis(conflicts.len(), started.elapsed().as_secs_f64());
+
     Ok(LaunchAnalysisResponse {
         requested_window_start: launch_start,
         requested_window_end: launch_end,
@@ -275,6 +532,245 @@ async fn compute_launch_analysis(
     })
 }
 
+/// A nominal post-insertion state vector for the requested orbit: a
+/// circular orbit at `desired_altitude_km`/`desired_inclination_deg`,
+/// pinned so the payload sits directly over the launch site at
+/// `launch_time`. RAAN is held fixed (no nodal regression modeled) for the
+/// 30-minute screening window, matching the scale of every other
+/// simplification in this analysis.
+struct TargetOrbit {
+    raan_rad: f64,
+    inclination_rad: f64,
+    semi_major_axis_km: f64,
+    mean_motion_rad_s: f64,
+    u0_rad: f64,
+    epoch: DateTime<Utc>,
+}
+
+impl TargetOrbit {
+    fn from_launch(payload: &LaunchAnalysisRequest) -> Self {
+        let inclination_rad = payload.desired_inclination_deg.to_radians();
+        let r = EARTH_RADIUS_KM + payload.desired_altitude_km;
+        let mean_motion_rad_s = (MU_EARTH_KM3_S2 / r.powi(3)).sqrt();
+
+        // Unit ECI position of the launch site at `launch_time`: rotate its
+        // geodetic longitude into the inertial frame by GMST.
+        let lat_rad = payload.launch_site_lat_deg.to_radians();
+        let eci_lon_rad = payload.launch_site_lon_deg.to_radians() + gmst_radians(payload.launch_time);
+        let x0 = lat_rad.cos() * eci_lon_rad.cos();
+        let y0 = lat_rad.cos() * eci_lon_rad.sin();
+        let z0 = lat_rad.sin();
+
+        // Solve for the argument of latitude u0 and RAAN that place a
+        // circular orbit of this inclination through (x0, y0, z0): from
+        // z0 = sin(u0) sin(i), then x0/y0 give RAAN via a 2x2 linear solve.
+        let sin_i = inclination_rad.sin();
+        let cos_i = inclination_rad.cos();
+        let u0_rad = if sin_i.abs() > 1e-9 {
+            (z0 / sin_i).clamp(-1.0, 1.0).asin()
+        } else {
+            0.0
+        };
+
+        let a_coef = u0_rad.cos();
+        let b_coef = u0_rad.sin() * cos_i;
+        let denom = a_coef * a_coef + b_coef * b_coef;
+        let raan_rad = if denom > 1e-12 {
+            let cos_raan = (a_coef * x0 + b_coef * y0) / denom;
+            let sin_raan = (-b_coef * x0 + a_coef * y0) / denom;
+            sin_raan.atan2(cos_raan)
+        } else {
+            0.0
+        };
+
+        TargetOrbit {
+            raan_rad,
+            inclination_rad,
+            semi_major_axis_km: r,
+            mean_motion_rad_s,
+            u0_rad,
+            epoch: payload.launch_time,
+        }
+    }
+
+    fn state_at(&self, timestamp: DateTime<Utc>) -> PropagationResult {
+        let dt_s = (timestamp - self.epoch).num_milliseconds() as f64 / 1000.0;
+        let u = self.u0_rad + self.mean_motion_rad_s * dt_s;
+        let r = self.semi_major_axis_km;
+        let n = self.mean_motion_rad_s;
+
+        let (sin_u, cos_u) = u.sin_cos();
+        let (sin_raan, cos_raan) = self.raan_rad.sin_cos();
+        let (sin_i, cos_i) = self.inclination_rad.sin_cos();
+
+        let x = r * (cos_raan * cos_u - sin_raan * sin_u * cos_i);
+        let y = r * (sin_raan * cos_u + cos_raan * sin_u * cos_i);
+        let z = r * sin_u * sin_i;
+
+        let vx = n * r * (-cos_raan * sin_u - sin_raan * cos_u * cos_i);
+        let vy = n * r * (-sin_raan * sin_u + cos_raan * cos_u * cos_i);
+        let vz = n * r * cos_u * sin_i;
+
+        PropagationResult {
+            position: Vector3::new(x, y, z),
+            velocity: Vector3::new(vx, vy, vz),
+        }
+    }
+}
+
+/// Greenwich Mean Sidereal Time (IAU 1982 approximation), in radians, used
+/// to rotate the launch site's geodetic longitude into the inertial frame
+/// `propagate_to_time`'s TEME-like positions already live in.
+fn gmst_radians(timestamp: DateTime<Utc>) -> f64 {
+    let jd = timestamp.timestamp() as f64 / 86400.0 + 2440587.5;
+    let days_since_j2000 = jd - 2451545.0;
+    let t = days_since_j2000 / 36525.0;
+    let gmst_deg = 280.46061837
+        + 360.98564736629 * days_since_j2000
+        + 0.000387933 * t * t
+        - t * t * t / 38710000.0;
+    gmst_deg.rem_euclid(360.0).to_radians()
+}
+
+fn relative_distance_km(a: &PropagationResult, b: &PropagationResult) -> f64 {
+    let dx = a.position.x - b.position.x;
+    let dy = a.position.y - b.position.y;
+    let dz = a.position.z - b.position.z;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+fn relative_speed_km_s(a: &PropagationResult, b: &PropagationResult) -> f64 {
+    let dx = a.velocity.x - b.velocity.x;
+    let dy = a.velocity.y - b.velocity.y;
+    let dz = a.velocity.z - b.velocity.z;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Coarse-then-refined conjunction screen for one cached satellite against
+/// the nominal target orbit: samples both on a `COARSE_STEP_SECONDS` grid
+/// across `[window_start, window_end]`, and wherever a sample pair dips
+/// under `SCREENING_RADIUS_KM`, refines that sub-interval with a
+/// golden-section search to locate the true time and miss distance of
+/// closest approach. Reports only the closest conjunction found, if any.
+fn screen_entry(
+    entry: &OrbitEntry,
+    target: &TargetOrbit,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    metrics: &OrbitMetrics,
+) -> Option<OrbitConflict> {
+    let step = Duration::seconds(COARSE_STEP_SECONDS);
+
+    let distance_at = |t: DateTime<Utc>| -> Option<f64> {
+        let sat = match propagate_to_time(entry, t) {
+            Ok(sat) => sat,
+            Err(_) => {
+                metrics.record_propagation_error();
+                return None;
+            }
+        };
+        Some(relative_distance_km(&sat, &target.state_at(t)))
+    };
+
+    let mut best: Option<(DateTime<Utc>, f64)> = None;
+    let mut prev_t = window_start;
+    let mut prev_d = distance_at(prev_t)?;
+
+    let mut t = window_start + step;
+    while t <= window_end {
+        let Some(d) = distance_at(t) else {
+            prev_t = t;
+            t = t + step;
+            continue;
+        };
+
+        if prev_d < SCREENING_RADIUS_KM || d < SCREENING_RADIUS_KM {
+            if let Some((tca, miss)) = refine_tca(entry, target, prev_t, t, metrics) {
+                if best.map(|(_, best_miss)| miss < best_miss).unwrap_or(true) {
+                    best = Some((tca, miss));
+                }
+            }
+        }
+
+        prev_t = t;
+        prev_d = d;
+        t = t + step;
+    }
+
+    let (tca, miss_distance_km) = best?;
+    let sat_at_tca = match propagate_to_time(entry, tca) {
+        Ok(sat) => sat,
+        Err(_) => {
+            metrics.record_propagation_error();
+            return None;
+        }
+    };
+    let relative_speed_km_s = relative_speed_km_s(&sat_at_tca, &target.state_at(tca));
+
+    Some(OrbitConflict {
+        norad_id: entry.norad_id,
+        name: entry.name.clone(),
+        time_utc: tca,
+        miss_distance_km,
+        relative_speed_km_s,
+    })
+}
+
+/// Golden-section search for the time of closest approach within
+/// `(lo, hi)`, assumed from coarse screening to bracket a single local
+/// minimum of relative distance. Returns `(tca, miss_distance_km)`.
+fn refine_tca(
+    entry: &OrbitEntry,
+    target: &TargetOrbit,
+    lo: DateTime<Utc>,
+    hi: DateTime<Utc>,
+    metrics: &OrbitMetrics,
+) -> Option<(DateTime<Utc>, f64)> {
+    const GOLDEN_RATIO: f64 = 0.6180339887498949;
+    const ITERATIONS: usize = 30;
+
+    let span_s = (hi - lo).num_milliseconds() as f64 / 1000.0;
+    let distance_at_offset = |offset_s: f64| -> Option<f64> {
+        let t = lo + Duration::milliseconds((offset_s * 1000.0).round() as i64);
+        let sat = match propagate_to_time(entry, t) {
+            Ok(sat) => sat,
+            Err(_) => {
+                metrics.record_propagation_error();
+                return None;
+            }
+        };
+        Some(relative_distance_km(&sat, &target.state_at(t)))
+    };
+
+    let mut a = 0.0;
+    let mut b = span_s;
+    let mut c = b - GOLDEN_RATIO * (b - a);
+    let mut d = a + GOLDEN_RATIO * (b - a);
+    let mut fc = distance_at_offset(c)?;
+    let mut fd = distance_at_offset(d)?;
+
+    for _ in 0..ITERATIONS {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - GOLDEN_RATIO * (b - a);
+            fc = distance_at_offset(c)?;
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + GOLDEN_RATIO * (b - a);
+            fd = distance_at_offset(d)?;
+        }
+    }
+
+    let (best_offset, best_distance) = if fc < fd { (c, fc) } else { (d, fd) };
+    let tca = lo + Duration::milliseconds((best_offset * 1000.0).round() as i64);
+
+    Some((tca, best_distance))
+}
+
 struct PropagationResult {
     position: Vector3<f64>,
     velocity: Vector3<f64>,
@@ -290,13 +786,3 @@ fn propagate_to_time(entry: &OrbitEntry, timestamp: DateTime<Utc>) -> Result<Pro
         velocity: state.velocity,
     })
 }
-
-trait VectorNorm {
-    fn norm(&self) -> f64;
-}
-
-impl VectorNorm for Vector3<f64> {
-    fn norm(&self) -> f64 {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
-    }
-}
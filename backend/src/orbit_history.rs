@@ -0,0 +1,170 @@
+// SQLite-backed history for `orbit.rs`'s refresh cycle.
+//
+// `write_csv` only ever overwrites a single snapshot, so once a refresh
+// lands there's no way to ask "what were object X's elements around time
+// T" for a T other than "now". This module appends every refresh's
+// `OrbitEntry` rows keyed by `(norad_id, epoch)` to a SQLite database via
+// `sqlx`, independent of the Postgres `db_pool` the rest of the backend
+// uses for user/session data.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqlitePool};
+
+use crate::orbit::OrbitEntry;
+
+const SELECT_COLUMNS: &str = "norad_id, epoch, name, inclination_deg, mean_motion_rev_per_day, \
+    eccentricity, semimajor_axis_km, perigee_km, apogee_km, arg_perigee_deg, raan_deg, \
+    mean_anomaly_deg, tle_line1, tle_line2";
+
+/// Opens (creating if necessary) the orbit history database at
+/// `database_url` and ensures the `orbit_history` table exists.
+pub async fn init_pool(database_url: &str) -> Result<SqlitePool> {
+    let pool = SqlitePoolOptions::new()
+        .connect(database_url)
+        .await
+        .context("failed to open orbit history database")?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS orbit_history (
+            norad_id INTEGER NOT NULL,
+            epoch TEXT NOT NULL,
+            name TEXT NOT NULL,
+            inclination_deg REAL NOT NULL,
+            mean_motion_rev_per_day REAL NOT NULL,
+            eccentricity REAL NOT NULL,
+            semimajor_axis_km REAL NOT NULL,
+            perigee_km REAL NOT NULL,
+            apogee_km REAL NOT NULL,
+            arg_perigee_deg REAL NOT NULL,
+            raan_deg REAL NOT NULL,
+            mean_anomaly_deg REAL NOT NULL,
+            tle_line1 TEXT NOT NULL,
+            tle_line2 TEXT NOT NULL,
+            PRIMARY KEY (norad_id, epoch)
+        )",
+    )
+    .execute(&pool)
+    .await
+    .context("failed to create orbit_history table")?;
+
+    Ok(pool)
+}
+
+/// Appends one row per entry, keyed by `(norad_id, epoch)`. Refreshing the
+/// same epoch twice (e.g. two refreshes landing on an unchanged upstream
+/// element set) is a no-op rather than an error.
+pub async fn record_entries(pool: &SqlitePool, entries: &[OrbitEntry]) -> Result<()> {
+    for entry in entries {
+        sqlx::query(
+            "INSERT OR IGNORE INTO orbit_history (
+                norad_id, epoch, name, inclination_deg, mean_motion_rev_per_day,
+                eccentricity, semimajor_axis_km, perigee_km, apogee_km, arg_perigee_deg,
+                raan_deg, mean_anomaly_deg, tle_line1, tle_line2
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(entry.norad_id)
+        .bind(entry.epoch.to_rfc3339())
+        .bind(&entry.name)
+        .bind(entry.inclination_deg)
+        .bind(entry.mean_motion_rev_per_day)
+        .bind(entry.eccentricity)
+        .bind(entry.semimajor_axis_km)
+        .bind(entry.perigee_km)
+        .bind(entry.apogee_km)
+        .bind(entry.arg_perigee_deg)
+        .bind(entry.raan_deg)
+        .bind(entry.mean_anomaly_deg)
+        .bind(&entry.tle_line1)
+        .bind(&entry.tle_line2)
+        .execute(pool)
+        .await
+        .context("failed to insert orbit history row")?;
+    }
+
+    Ok(())
+}
+
+/// All recorded element sets for `norad_id` with `epoch` in `[start, end]`,
+/// ordered oldest-first.
+pub async fn query_history(
+    pool: &SqlitePool,
+    norad_id: i64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<OrbitEntry>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {SELECT_COLUMNS} FROM orbit_history \
+         WHERE norad_id = ? AND epoch >= ? AND epoch <= ? ORDER BY epoch"
+    ))
+    .bind(norad_id)
+    .bind(start.to_rfc3339())
+    .bind(end.to_rfc3339())
+    .fetch_all(pool)
+    .await
+    .context("failed to query orbit history")?;
+
+    rows.iter().map(row_to_entry).collect()
+}
+
+/// The recorded element set for `norad_id` whose epoch is closest to
+/// `target_time`, for picking a propagation basis nearer a launch window
+/// than whatever the live cache happens to hold.
+pub async fn nearest_epoch_entry(
+    pool: &SqlitePool,
+    norad_id: i64,
+    target_time: DateTime<Utc>,
+) -> Result<Option<OrbitEntry>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {SELECT_COLUMNS} FROM orbit_history WHERE norad_id = ?"
+    ))
+    .bind(norad_id)
+    .fetch_all(pool)
+    .await
+    .context("failed to query orbit history")?;
+
+    let entries = rows
+        .iter()
+        .map(row_to_entry)
+        .collect::<Result<Vec<OrbitEntry>>>()?;
+
+    Ok(entries
+        .into_iter()
+        .min_by_key(|entry| (entry.epoch - target_time).num_seconds().abs()))
+}
+
+fn row_to_entry(row: &SqliteRow) -> Result<OrbitEntry> {
+    let epoch: String = row.try_get("epoch").context("missing epoch column")?;
+
+    Ok(OrbitEntry {
+        norad_id: row.try_get("norad_id").context("missing norad_id column")?,
+        name: row.try_get("name").context("missing name column")?,
+        epoch: DateTime::parse_from_rfc3339(&epoch)
+            .context("invalid epoch in orbit_history row")?
+            .with_timezone(&Utc),
+        inclination_deg: row
+            .try_get("inclination_deg")
+            .context("missing inclination_deg column")?,
+        mean_motion_rev_per_day: row
+            .try_get("mean_motion_rev_per_day")
+            .context("missing mean_motion_rev_per_day column")?,
+        eccentricity: row
+            .try_get("eccentricity")
+            .context("missing eccentricity column")?,
+        semimajor_axis_km: row
+            .try_get("semimajor_axis_km")
+            .context("missing semimajor_axis_km column")?,
+        perigee_km: row.try_get("perigee_km").context("missing perigee_km column")?,
+        apogee_km: row.try_get("apogee_km").context("missing apogee_km column")?,
+        arg_perigee_deg: row
+            .try_get("arg_perigee_deg")
+            .context("missing arg_perigee_deg column")?,
+        raan_deg: row.try_get("raan_deg").context("missing raan_deg column")?,
+        mean_anomaly_deg: row
+            .try_get("mean_anomaly_deg")
+            .context("missing mean_anomaly_deg column")?,
+        tle_line1: row.try_get("tle_line1").context("missing tle_line1 column")?,
+        tle_line2: row.try_get("tle_line2").context("missing tle_line2 column")?,
+    })
+}
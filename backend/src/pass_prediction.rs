@@ -0,0 +1,307 @@
+// Local, offline replacement for `N2YOService::get_visual_passes`/
+// `get_radio_passes`: those each cost one external API call against N2YO's
+// tight per-key rate limit. Everything needed to predict a pass -- the TLE
+// and an SGP4 propagator -- is already available without N2YO, so this
+// module does the propagation + look-angle geometry itself and emits the
+// same `N2YOPass` shape the rest of the codebase (routes, `ProviderPool`)
+// already knows how to serialize. N2YO is kept registered in `ProviderPool`
+// purely as a cross-check fallback if this provider is ever unavailable.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use sgp4::prelude::*;
+
+use crate::n2yo_service::{N2YOPass, N2YOPassesResponse, N2YOSatelliteInfo};
+
+const WGS84_A_KM: f64 = 6378.137;
+const WGS84_F: f64 = 1.0 / 298.257223563;
+const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+/// Coarse sweep step before bisection/golden-section refinement. 30s is
+/// tight enough that a LEO pass (~5-15 min) never skips entirely between
+/// samples.
+const SAMPLE_STEP_SECONDS: i64 = 30;
+
+/// Sub-second accuracy target for rise/set/culmination refinement.
+const REFINE_TOLERANCE_SECONDS: f64 = 0.5;
+
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+fn compass_point(azimuth_deg: f64) -> String {
+    let index = ((azimuth_deg.rem_euclid(360.0) / 22.5) + 0.5) as usize % 16;
+    COMPASS_POINTS[index].to_string()
+}
+
+fn geodetic_to_ecef(lat_rad: f64, lon_rad: f64, alt_km: f64) -> (f64, f64, f64) {
+    let sin_lat = lat_rad.sin();
+    let cos_lat = lat_rad.cos();
+    let n = WGS84_A_KM / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+
+    let x = (n + alt_km) * cos_lat * lon_rad.cos();
+    let y = (n + alt_km) * cos_lat * lon_rad.sin();
+    let z = (n * (1.0 - WGS84_E2) + alt_km) * sin_lat;
+    (x, y, z)
+}
+
+fn julian_date(time: DateTime<Utc>) -> f64 {
+    let timestamp = time.timestamp_millis() as f64 / 1000.0;
+    (timestamp / 86400.0) + 2440587.5
+}
+
+/// Greenwich Mean Sidereal Time in radians, used to rotate the SGP4
+/// propagator's TEME position into ECEF.
+fn gmst_rad(time: DateTime<Utc>) -> f64 {
+    let jd = julian_date(time);
+    let t = (jd - 2451545.0) / 36525.0;
+    let gmst_seconds =
+        67310.54841 + (876600.0 * 3600.0 + 8640184.812866) * t + 0.093104 * t * t - 6.2e-6 * t * t * t;
+    let gmst_rad = (gmst_seconds % 86400.0) * std::f64::consts::PI / 43200.0;
+    gmst_rad.rem_euclid(2.0 * std::f64::consts::PI)
+}
+
+fn teme_to_ecef(teme_km: (f64, f64, f64), time: DateTime<Utc>) -> (f64, f64, f64) {
+    let theta = gmst_rad(time);
+    let (cos_t, sin_t) = (theta.cos(), theta.sin());
+    (
+        teme_km.0 * cos_t + teme_km.1 * sin_t,
+        -teme_km.0 * sin_t + teme_km.1 * cos_t,
+        teme_km.2,
+    )
+}
+
+struct Observer {
+    lat_rad: f64,
+    lon_rad: f64,
+    ecef: (f64, f64, f64),
+}
+
+impl Observer {
+    fn new(lat_deg: f64, lon_deg: f64, alt_km: f64) -> Self {
+        let lat_rad = lat_deg.to_radians();
+        let lon_rad = lon_deg.to_radians();
+        Self { lat_rad, lon_rad, ecef: geodetic_to_ecef(lat_rad, lon_rad, alt_km) }
+    }
+}
+
+struct LookAngle {
+    azimuth_deg: f64,
+    elevation_deg: f64,
+}
+
+fn look_angle(observer: &Observer, satellite_ecef: (f64, f64, f64)) -> LookAngle {
+    let dx = satellite_ecef.0 - observer.ecef.0;
+    let dy = satellite_ecef.1 - observer.ecef.1;
+    let dz = satellite_ecef.2 - observer.ecef.2;
+
+    let (sin_lat, cos_lat) = (observer.lat_rad.sin(), observer.lat_rad.cos());
+    let (sin_lon, cos_lon) = (observer.lon_rad.sin(), observer.lon_rad.cos());
+
+    let east = -sin_lon * dx + cos_lon * dy;
+    let north = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+    let up = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+
+    let range_km = (dx * dx + dy * dy + dz * dz).sqrt();
+    let elevation_deg = (up / range_km).asin().to_degrees();
+    let azimuth_deg = east.atan2(north).to_degrees().rem_euclid(360.0);
+
+    LookAngle { azimuth_deg, elevation_deg }
+}
+
+/// Propagates `constants` to `time` and returns the observer-relative look
+/// angle. Kept as its own function (rather than inlined in the sweep) so the
+/// bisection/golden-section refinement below can re-evaluate a single time
+/// without duplicating the TEME->ECEF->topocentric pipeline.
+fn elevation_at(
+    elements: &Elements,
+    constants: &Constants,
+    observer: &Observer,
+    time: DateTime<Utc>,
+) -> Result<(f64, LookAngle)> {
+    let minutes_since_epoch = elements
+        .datetime_to_minutes_since_epoch(&time.naive_utc())
+        .context("failed to compute minutes-since-epoch for pass prediction sample")?;
+    let prediction: Prediction = constants.propagate(minutes_since_epoch)?;
+    let ecef = teme_to_ecef((prediction.position[0], prediction.position[1], prediction.position[2]), time);
+    let la = look_angle(observer, ecef);
+    Ok((la.elevation_deg, la))
+}
+
+/// Bisects the elevation-mask crossing inside the chronologically-ordered
+/// window `[t_low, t_high]` to sub-second accuracy. `rising` says which end
+/// starts below `target_deg`: for a rise (AOS) elevation increases from
+/// `t_low` to `t_high`; for a set (LOS) it decreases.
+fn bisect_crossing(
+    elements: &Elements,
+    constants: &Constants,
+    observer: &Observer,
+    mut t_low: DateTime<Utc>,
+    mut t_high: DateTime<Utc>,
+    target_deg: f64,
+    rising: bool,
+) -> Result<(DateTime<Utc>, LookAngle)> {
+    while (t_high - t_low).num_milliseconds() as f64 / 1000.0 > REFINE_TOLERANCE_SECONDS {
+        let mid = t_low + Duration::milliseconds((t_high - t_low).num_milliseconds() / 2);
+        let (mid_elevation, _) = elevation_at(elements, constants, observer, mid)?;
+        let mid_is_above = mid_elevation >= target_deg;
+        if mid_is_above == rising {
+            t_high = mid;
+        } else {
+            t_low = mid;
+        }
+    }
+    let crossing_time = t_low + Duration::milliseconds((t_high - t_low).num_milliseconds() / 2);
+    let (_, crossing_look) = elevation_at(elements, constants, observer, crossing_time)?;
+    Ok((crossing_time, crossing_look))
+}
+
+/// Golden-section search for the elevation maximum inside `[start, end]`,
+/// which brackets a single rise-to-set pass (unimodal in elevation).
+fn refine_culmination(
+    elements: &Elements,
+    constants: &Constants,
+    observer: &Observer,
+    mut start: DateTime<Utc>,
+    mut end: DateTime<Utc>,
+) -> Result<(DateTime<Utc>, LookAngle)> {
+    const GOLDEN_RATIO: f64 = 0.618_033_988_75;
+
+    let span = |a: DateTime<Utc>, b: DateTime<Utc>| (b - a).num_milliseconds() as f64;
+    let mut c = start + Duration::milliseconds((span(start, end) * (1.0 - GOLDEN_RATIO)) as i64);
+    let mut d = start + Duration::milliseconds((span(start, end) * GOLDEN_RATIO) as i64);
+    let (mut elevation_c, _) = elevation_at(elements, constants, observer, c)?;
+    let (mut elevation_d, _) = elevation_at(elements, constants, observer, d)?;
+
+    while span(start, end) / 1000.0 > REFINE_TOLERANCE_SECONDS {
+        if elevation_c > elevation_d {
+            end = d;
+            d = c;
+            elevation_d = elevation_c;
+            c = start + Duration::milliseconds((span(start, end) * (1.0 - GOLDEN_RATIO)) as i64);
+            elevation_c = elevation_at(elements, constants, observer, c)?.0;
+        } else {
+            start = c;
+            c = d;
+            elevation_c = elevation_d;
+            d = start + Duration::milliseconds((span(start, end) * GOLDEN_RATIO) as i64);
+            elevation_d = elevation_at(elements, constants, observer, d)?.0;
+        }
+    }
+
+    let peak_time = start + Duration::milliseconds((span(start, end) / 2.0) as i64);
+    let (_, look) = elevation_at(elements, constants, observer, peak_time)?;
+    Ok((peak_time, look))
+}
+
+/// Predicts every pass of the satellite described by `tle_line1`/
+/// `tle_line2` visible from `observer_lat`/`observer_lng`/`observer_alt`
+/// over the next `days` days, at or above `min_elevation_deg`. Shaped to
+/// match `N2YOPassesResponse` so callers (the `SatelliteDataProvider`
+/// fallback chain, route handlers) can't tell whether a response came from
+/// N2YO or this predictor.
+pub fn predict_passes(
+    norad_id: i32,
+    satname: &str,
+    tle_line1: &str,
+    tle_line2: &str,
+    observer_lat: f64,
+    observer_lng: f64,
+    observer_alt: f64,
+    days: i32,
+    min_elevation_deg: f64,
+) -> Result<N2YOPassesResponse> {
+    let elements = Elements::from_tle(
+        Some(satname.to_string()),
+        tle_line1.as_bytes(),
+        tle_line2.as_bytes(),
+    )
+    .context("failed to parse TLE for local pass prediction")?;
+    let constants = Constants::from_elements(&elements).context("failed to build SGP4 constants from TLE")?;
+
+    let observer = Observer::new(observer_lat, observer_lng, observer_alt);
+
+    let start = Utc::now();
+    let end = start + Duration::days(days.max(1) as i64);
+
+    let mut samples = Vec::new();
+    let mut t = start;
+    while t <= end {
+        let (elevation_deg, look) = elevation_at(&elements, &constants, &observer, t)?;
+        samples.push((t, elevation_deg, look));
+        t += Duration::seconds(SAMPLE_STEP_SECONDS);
+    }
+
+    let mut passes = Vec::new();
+    let mut in_pass = false;
+    let mut pass_start_sample_idx = 0usize;
+
+    for i in 0..samples.len().saturating_sub(1) {
+        let (before_time, before_elevation, _) = &samples[i];
+        let (after_time, after_elevation, _) = &samples[i + 1];
+
+        let crosses_up = *before_elevation < min_elevation_deg && *after_elevation >= min_elevation_deg;
+        let crosses_down = *before_elevation >= min_elevation_deg && *after_elevation < min_elevation_deg;
+
+        if crosses_up && !in_pass {
+            in_pass = true;
+            pass_start_sample_idx = i;
+        }
+
+        if crosses_down && in_pass {
+            let (rise_time, rise_look) = bisect_crossing(
+                &elements,
+                &constants,
+                &observer,
+                samples[pass_start_sample_idx].0,
+                samples[pass_start_sample_idx + 1].0,
+                min_elevation_deg,
+                true,
+            )?;
+            let (set_time, set_look) = bisect_crossing(
+                &elements,
+                &constants,
+                &observer,
+                *before_time,
+                *after_time,
+                min_elevation_deg,
+                false,
+            )?;
+            let (culmination_time, culmination_look) =
+                refine_culmination(&elements, &constants, &observer, rise_time, set_time)?;
+
+            passes.push(N2YOPass {
+                start_az: rise_look.azimuth_deg,
+                start_az_compass: compass_point(rise_look.azimuth_deg),
+                start_el: rise_look.elevation_deg,
+                start_utc: rise_time.timestamp(),
+                max_az: culmination_look.azimuth_deg,
+                max_az_compass: compass_point(culmination_look.azimuth_deg),
+                max_el: culmination_look.elevation_deg,
+                max_utc: culmination_time.timestamp(),
+                end_az: set_look.azimuth_deg,
+                end_az_compass: compass_point(set_look.azimuth_deg),
+                end_el: set_look.elevation_deg,
+                end_utc: set_time.timestamp(),
+                // N2YO reports -1 when it has no visual-magnitude estimate;
+                // this predictor has no brightness model at all, so every
+                // pass is reported the same way.
+                mag: -1.0,
+                duration: (set_time - rise_time).num_seconds() as i32,
+            });
+            in_pass = false;
+        }
+    }
+
+    let passescount = passes.len() as i32;
+    Ok(N2YOPassesResponse {
+        info: N2YOSatelliteInfo {
+            satid: norad_id,
+            satname: satname.to_string(),
+            transactionscount: 0,
+        },
+        passescount,
+        passes,
+    })
+}
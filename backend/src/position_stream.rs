@@ -0,0 +1,163 @@
+//! Live WebSocket feed of the position batch `start_position_updater`
+//! computes every tick. Replaces polling `GET /api/satellites` with a single
+//! persistent socket: a client opens `GET /ws/satellites`, optionally sends a
+//! [`PositionSubscription`] JSON frame to narrow the feed, and from then on
+//! receives the freshly computed satellites as a JSON array on every tick.
+//! Mirrors `sat_api`'s `AlertWsSession`/`alerts_ws` (broadcast channel +
+//! actix actor session), just fed from the position updater instead of the
+//! alert hub.
+
+use crate::satellite_service::{Satellite, SatelliteType};
+use actix::{Actor, ActorContext, AsyncContext, Handler, Message, StreamHandler};
+use actix_web::{web, Error, HttpRequest, HttpResponse};
+use actix_web_actors::ws;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Broadcasts each tick's freshly computed satellite batch to every
+/// connected `/ws/satellites` client. Cheap to clone (an `Arc`-backed
+/// `Sender`); `publish` is a no-op if nobody is subscribed.
+#[derive(Clone)]
+pub struct PositionHub {
+    sender: broadcast::Sender<Arc<Vec<Satellite>>>,
+}
+
+impl PositionHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(16);
+        Self { sender }
+    }
+
+    pub fn publish(&self, satellites: Vec<Satellite>) {
+        let _ = self.sender.send(Arc::new(satellites));
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Arc<Vec<Satellite>>> {
+        self.sender.subscribe()
+    }
+}
+
+/// Subscription message a client sends over `/ws/satellites` to narrow the
+/// feed. Fields left `None` mean "no filter" (every tracked satellite).
+///
+/// `sat_api::tracker::get_satellites_by_group` groups by a substring match on
+/// satellite name; this service has no such catalog, so `group` filters by
+/// `SatelliteType` instead (e.g. `"navigation"`, `"space-station"`) -- the
+/// closest equivalent this model actually carries.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PositionSubscription {
+    pub group: Option<String>,
+    pub norad_ids: Option<HashSet<u32>>,
+}
+
+impl PositionSubscription {
+    fn matches(&self, satellite: &Satellite) -> bool {
+        let group_matches = self.group.as_ref().map_or(true, |group| {
+            satellite_type_name(&satellite.satellite_type).eq_ignore_ascii_case(group)
+        });
+        let norad_matches = self.norad_ids.as_ref().map_or(true, |ids| {
+            satellite.norad_id.is_some_and(|id| ids.contains(&id))
+        });
+        group_matches && norad_matches
+    }
+}
+
+fn satellite_type_name(satellite_type: &SatelliteType) -> &'static str {
+    match satellite_type {
+        SatelliteType::Communication => "communication",
+        SatelliteType::EarthObservation => "earth-observation",
+        SatelliteType::Weather => "weather",
+        SatelliteType::Navigation => "navigation",
+        SatelliteType::Scientific => "scientific",
+        SatelliteType::Military => "military",
+        SatelliteType::SpaceStation => "space-station",
+        SatelliteType::Debris => "debris",
+        SatelliteType::Other => "other",
+    }
+}
+
+struct Tick(Arc<Vec<Satellite>>);
+
+impl Message for Tick {
+    type Result = ();
+}
+
+struct PositionWsSession {
+    subscription: PositionSubscription,
+    hub: PositionHub,
+}
+
+impl Actor for PositionWsSession {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let addr = ctx.address();
+        let mut receiver = self.hub.subscribe();
+        actix_web::rt::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(batch) => addr.do_send(Tick(batch)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Position WS subscriber lagged, skipped {} ticks", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+impl Handler<Tick> for PositionWsSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: Tick, ctx: &mut Self::Context) {
+        let matching: Vec<&Satellite> = msg
+            .0
+            .iter()
+            .filter(|satellite| self.subscription.matches(satellite))
+            .collect();
+        if matching.is_empty() {
+            return;
+        }
+        match serde_json::to_string(&matching) {
+            Ok(json) => ctx.text(json),
+            Err(e) => tracing::error!("Failed to serialize position batch: {}", e),
+        }
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for PositionWsSession {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        match msg {
+            Ok(ws::Message::Ping(bytes)) => ctx.pong(&bytes),
+            Ok(ws::Message::Text(text)) => match serde_json::from_str::<PositionSubscription>(&text) {
+                Ok(subscription) => self.subscription = subscription,
+                Err(e) => tracing::warn!("Invalid position subscription message: {}", e),
+            },
+            Ok(ws::Message::Close(reason)) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `GET /ws/satellites`: upgrades to a WebSocket that receives the freshly
+/// computed `Vec<Satellite>` as a JSON array on every `start_position_updater`
+/// tick, optionally narrowed by sending a [`PositionSubscription`] JSON frame
+/// at any point (by `SatelliteType` `group` and/or `norad_ids` set).
+pub async fn stream_positions(
+    req: HttpRequest,
+    stream: web::Payload,
+    hub: web::Data<PositionHub>,
+) -> Result<HttpResponse, Error> {
+    let session = PositionWsSession {
+        subscription: PositionSubscription::default(),
+        hub: hub.get_ref().clone(),
+    };
+    ws::start(session, &req, stream)
+}
@@ -0,0 +1,537 @@
+// Multi-backend satellite data provider pool.
+//
+// `AppState` used to hardwire a single `N2YOService`. That meant the whole
+// catalog depended on one demo API key. This module introduces a
+// `SatelliteDataProvider` trait with N2YO/Celestrak/Space-Track backends and
+// a `ProviderPool` that tracks per-provider health (consecutive failures,
+// latency EWMA, trip state) and picks the healthiest untripped provider for
+// each call, falling back to stale cached TLEs if every provider is down.
+
+use crate::n2yo_service::{
+    N2YOPassesResponse, N2YOPositionsResponse, N2YOSatelliteInfo, N2YOService, N2YOTLEResponse,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[async_trait]
+pub trait SatelliteDataProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn fetch_tle(&self, norad_id: i32) -> anyhow::Result<N2YOTLEResponse>;
+    async fn fetch_positions(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse>;
+    async fn fetch_passes(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        days: i32,
+        min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse>;
+    async fn fetch_above(
+        &self,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        radius: i32,
+        category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>>;
+}
+
+pub struct N2YOProvider(pub N2YOService);
+
+#[async_trait]
+impl SatelliteDataProvider for N2YOProvider {
+    fn name(&self) -> &'static str {
+        "n2yo"
+    }
+    async fn fetch_tle(&self, norad_id: i32) -> anyhow::Result<N2YOTLEResponse> {
+        self.0.get_tle(norad_id).await
+    }
+    async fn fetch_positions(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse> {
+        self.0.get_positions(norad_id, lat, lng, alt, seconds).await
+    }
+    async fn fetch_passes(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        days: i32,
+        min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse> {
+        self.0
+            .get_visual_passes(norad_id, lat, lng, alt, days, min_visibility)
+            .await
+    }
+    async fn fetch_above(
+        &self,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        radius: i32,
+        category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>> {
+        self.0.get_above(lat, lng, alt, radius, category).await
+    }
+}
+
+/// Celestrak has no positions/passes API of its own; it only serves TLEs. The
+/// pool still registers it so a TLE lookup survives an N2YO outage.
+pub struct CelestrakProvider {
+    client: reqwest::Client,
+}
+
+impl CelestrakProvider {
+    pub fn new() -> Self {
+        Self { client: reqwest::Client::new() }
+    }
+}
+
+#[async_trait]
+impl SatelliteDataProvider for CelestrakProvider {
+    fn name(&self) -> &'static str {
+        "celestrak"
+    }
+    async fn fetch_tle(&self, norad_id: i32) -> anyhow::Result<N2YOTLEResponse> {
+        let url = format!(
+            "https://celestrak.org/NORAD/elements/gp.php?CATNR={norad_id}&FORMAT=tle"
+        );
+        let body = self.client.get(&url).send().await?.text().await?;
+        let mut lines = body.lines();
+        let name = lines.next().unwrap_or("Unknown").trim().to_string();
+        let line1 = lines.next().unwrap_or("").to_string();
+        let line2 = lines.next().unwrap_or("").to_string();
+        Ok(N2YOTLEResponse {
+            info: N2YOSatelliteInfo { satid: norad_id, satname: name, transactionscount: 0 },
+            tle: format!("{line1}\n{line2}"),
+        })
+    }
+    async fn fetch_positions(
+        &self,
+        _norad_id: i32,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse> {
+        anyhow::bail!("celestrak provider does not support positions")
+    }
+    async fn fetch_passes(
+        &self,
+        _norad_id: i32,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _days: i32,
+        _min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse> {
+        anyhow::bail!("celestrak provider does not support passes")
+    }
+    async fn fetch_above(
+        &self,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _radius: i32,
+        _category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>> {
+        anyhow::bail!("celestrak provider does not support 'above'")
+    }
+}
+
+/// Predicts passes locally via SGP4 instead of calling N2YO, so pass
+/// requests don't eat into the N2YO quota at all. Still needs a TLE to
+/// propagate, so it fetches one from Celestrak the same way
+/// `CelestrakProvider` does -- duplicated rather than shared, matching how
+/// each provider here owns its fetch logic independently. Registered first
+/// in `ProviderPool::new` so it's always tried before N2YO, which is kept
+/// around purely as a cross-check fallback for passes.
+pub struct LocalSgp4Provider {
+    client: reqwest::Client,
+}
+
+impl LocalSgp4Provider {
+    pub fn new() -> Self {
+        Self { client: reqwest::Client::new() }
+    }
+
+    async fn fetch_tle_lines(&self, norad_id: i32) -> anyhow::Result<(String, String, String)> {
+        let url = format!(
+            "https://celestrak.org/NORAD/elements/gp.php?CATNR={norad_id}&FORMAT=tle"
+        );
+        let body = self.client.get(&url).send().await?.text().await?;
+        let mut lines = body.lines();
+        let name = lines.next().unwrap_or("Unknown").trim().to_string();
+        let line1 = lines.next().unwrap_or("").to_string();
+        let line2 = lines.next().unwrap_or("").to_string();
+        if line1.is_empty() || line2.is_empty() {
+            anyhow::bail!("celestrak returned no TLE for NORAD ID {norad_id}");
+        }
+        Ok((name, line1, line2))
+    }
+}
+
+#[async_trait]
+impl SatelliteDataProvider for LocalSgp4Provider {
+    fn name(&self) -> &'static str {
+        "local-sgp4"
+    }
+    async fn fetch_tle(&self, _norad_id: i32) -> anyhow::Result<N2YOTLEResponse> {
+        anyhow::bail!("local-sgp4 provider only predicts passes, not raw TLE lookups")
+    }
+    async fn fetch_positions(
+        &self,
+        _norad_id: i32,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse> {
+        anyhow::bail!("local-sgp4 provider only predicts passes, not instantaneous positions")
+    }
+    async fn fetch_passes(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        days: i32,
+        min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse> {
+        let (satname, tle_line1, tle_line2) = self.fetch_tle_lines(norad_id).await?;
+        // N2YO's `min_visibility` parameter is seconds of sunlit visibility;
+        // there's no visual-magnitude model here to reproduce that, so it's
+        // reused as a minimum-elevation mask in degrees instead -- the
+        // closest analogous "how strict a pass counts" knob this predictor
+        // has.
+        let min_elevation_deg = (min_visibility as f64 / 10.0).clamp(0.0, 89.0);
+        crate::pass_prediction::predict_passes(
+            norad_id,
+            &satname,
+            &tle_line1,
+            &tle_line2,
+            lat,
+            lng,
+            alt,
+            days,
+            min_elevation_deg,
+        )
+    }
+    async fn fetch_above(
+        &self,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _radius: i32,
+        _category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>> {
+        anyhow::bail!("local-sgp4 provider only predicts passes, not 'above' queries")
+    }
+}
+
+/// Space-Track requires an authenticated session; wired up as a named source
+/// so the pool has a third independent backend, even though credentials are
+/// optional here (falls through to an error if unset, same as the others
+/// failing their own way).
+pub struct SpaceTrackProvider {
+    client: reqwest::Client,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl SpaceTrackProvider {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            username: std::env::var("SPACETRACK_USERNAME").ok(),
+            password: std::env::var("SPACETRACK_PASSWORD").ok(),
+        }
+    }
+}
+
+#[async_trait]
+impl SatelliteDataProvider for SpaceTrackProvider {
+    fn name(&self) -> &'static str {
+        "space-track"
+    }
+    async fn fetch_tle(&self, norad_id: i32) -> anyhow::Result<N2YOTLEResponse> {
+        let (user, pass) = match (&self.username, &self.password) {
+            (Some(u), Some(p)) => (u, p),
+            _ => anyhow::bail!("SPACETRACK_USERNAME/SPACETRACK_PASSWORD not configured"),
+        };
+        let login_resp = self
+            .client
+            .post("https://www.space-track.org/ajaxauth/login")
+            .form(&[("identity", user), ("password", pass)])
+            .send()
+            .await?;
+        let cookies = login_resp
+            .headers()
+            .get("set-cookie")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let url = format!(
+            "https://www.space-track.org/basicspacedata/query/class/tle_latest/NORAD_CAT_ID/{norad_id}/orderby/ORDINAL%20asc/limit/1/format/tle"
+        );
+        let body = self
+            .client
+            .get(&url)
+            .header("Cookie", cookies)
+            .send()
+            .await?
+            .text()
+            .await?;
+        let mut lines = body.lines();
+        let line1 = lines.next().unwrap_or("").to_string();
+        let line2 = lines.next().unwrap_or("").to_string();
+        Ok(N2YOTLEResponse {
+            info: N2YOSatelliteInfo { satid: norad_id, satname: "Unknown".into(), transactionscount: 0 },
+            tle: format!("{line1}\n{line2}"),
+        })
+    }
+    async fn fetch_positions(
+        &self,
+        _norad_id: i32,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse> {
+        anyhow::bail!("space-track provider does not support positions")
+    }
+    async fn fetch_passes(
+        &self,
+        _norad_id: i32,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _days: i32,
+        _min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse> {
+        anyhow::bail!("space-track provider does not support passes")
+    }
+    async fn fetch_above(
+        &self,
+        _lat: f64,
+        _lng: f64,
+        _alt: f64,
+        _radius: i32,
+        _category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>> {
+        anyhow::bail!("space-track provider does not support 'above'")
+    }
+}
+
+struct ProviderHealth {
+    consecutive_failures: u32,
+    latency_ewma_ms: f64,
+    tripped_until: Option<DateTime<Utc>>,
+}
+
+impl Default for ProviderHealth {
+    fn default() -> Self {
+        Self { consecutive_failures: 0, latency_ewma_ms: 0.0, tripped_until: None }
+    }
+}
+
+const BACKOFF_BASE_SECS: i64 = 1;
+const BACKOFF_CAP_SECS: i64 = 300;
+
+impl ProviderHealth {
+    fn is_tripped(&self, now: DateTime<Utc>) -> bool {
+        self.tripped_until.map(|t| now < t).unwrap_or(false)
+    }
+
+    fn record_success(&mut self, latency_ms: f64) {
+        self.consecutive_failures = 0;
+        self.tripped_until = None;
+        // Simple exponentially-weighted moving average.
+        self.latency_ewma_ms = if self.latency_ewma_ms == 0.0 {
+            latency_ms
+        } else {
+            0.8 * self.latency_ewma_ms + 0.2 * latency_ms
+        };
+    }
+
+    fn record_failure(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures += 1;
+        let backoff = (BACKOFF_BASE_SECS * 2i64.pow(self.consecutive_failures.min(10)))
+            .min(BACKOFF_CAP_SECS);
+        self.tripped_until = Some(now + chrono::Duration::seconds(backoff));
+    }
+}
+
+#[derive(Clone)]
+struct CachedTle {
+    response: N2YOTLEResponse,
+    fetched_at: DateTime<Utc>,
+}
+
+/// Selects the healthiest provider for each call, retries the next one on
+/// failure, and serves stale-but-flagged TLE data if every provider is down.
+pub struct ProviderPool {
+    providers: Vec<Box<dyn SatelliteDataProvider>>,
+    health: Mutex<HashMap<&'static str, ProviderHealth>>,
+    tle_cache: Mutex<HashMap<i32, CachedTle>>,
+}
+
+impl ProviderPool {
+    pub fn new(n2yo: N2YOService) -> Self {
+        let providers: Vec<Box<dyn SatelliteDataProvider>> = vec![
+            // Tried first on a tie (fresh health/latency for every provider
+            // at startup): unlimited, offline pass prediction ahead of
+            // N2YO's rate-limited API.
+            Box::new(LocalSgp4Provider::new()),
+            Box::new(N2YOProvider(n2yo)),
+            Box::new(CelestrakProvider::new()),
+            Box::new(SpaceTrackProvider::new()),
+        ];
+        let mut health = HashMap::new();
+        for p in &providers {
+            health.insert(p.name(), ProviderHealth::default());
+        }
+        Self { providers, health: Mutex::new(health), tle_cache: Mutex::new(HashMap::new()) }
+    }
+
+    /// Providers ordered healthiest-first: untripped ones sorted by latency
+    /// EWMA ascending, then tripped ones (in case everything is down and we
+    /// have to try anyway).
+    fn ranked_providers(&self) -> Vec<usize> {
+        let now = Utc::now();
+        let health = self.health.lock().unwrap();
+        let mut idx: Vec<usize> = (0..self.providers.len()).collect();
+        idx.sort_by(|&a, &b| {
+            let ha = health.get(self.providers[a].name()).unwrap();
+            let hb = health.get(self.providers[b].name()).unwrap();
+            let a_tripped = ha.is_tripped(now);
+            let b_tripped = hb.is_tripped(now);
+            a_tripped
+                .cmp(&b_tripped)
+                .then(ha.latency_ewma_ms.partial_cmp(&hb.latency_ewma_ms).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        idx
+    }
+
+    pub async fn fetch_tle(&self, norad_id: i32) -> anyhow::Result<N2YOTLEResponse> {
+        for idx in self.ranked_providers() {
+            let provider = &self.providers[idx];
+            let started = std::time::Instant::now();
+            match provider.fetch_tle(norad_id).await {
+                Ok(resp) => {
+                    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_success(elapsed_ms);
+                    self.tle_cache.lock().unwrap().insert(
+                        norad_id,
+                        CachedTle { response: resp.clone(), fetched_at: Utc::now() },
+                    );
+                    return Ok(resp);
+                }
+                Err(e) => {
+                    tracing::warn!("provider {} failed fetch_tle({norad_id}): {e}", provider.name());
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_failure(Utc::now());
+                }
+            }
+        }
+
+        // Every provider failed; fall back to a stale cache entry if we have one.
+        if let Some(cached) = self.tle_cache.lock().unwrap().get(&norad_id).cloned() {
+            tracing::warn!(
+                "all providers down for norad_id {norad_id}; serving stale TLE from {}",
+                cached.fetched_at
+            );
+            return Ok(cached.response);
+        }
+
+        anyhow::bail!("all satellite data providers unavailable for norad_id {norad_id}")
+    }
+
+    pub async fn fetch_positions(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        seconds: i32,
+    ) -> anyhow::Result<N2YOPositionsResponse> {
+        for idx in self.ranked_providers() {
+            let provider = &self.providers[idx];
+            let started = std::time::Instant::now();
+            match provider.fetch_positions(norad_id, lat, lng, alt, seconds).await {
+                Ok(resp) => {
+                    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_success(elapsed_ms);
+                    return Ok(resp);
+                }
+                Err(_) => {
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_failure(Utc::now());
+                }
+            }
+        }
+        anyhow::bail!("all satellite data providers unavailable for positions({norad_id})")
+    }
+
+    pub async fn fetch_passes(
+        &self,
+        norad_id: i32,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        days: i32,
+        min_visibility: i32,
+    ) -> anyhow::Result<N2YOPassesResponse> {
+        for idx in self.ranked_providers() {
+            let provider = &self.providers[idx];
+            match provider.fetch_passes(norad_id, lat, lng, alt, days, min_visibility).await {
+                Ok(resp) => {
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_success(0.0);
+                    return Ok(resp);
+                }
+                Err(_) => {
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_failure(Utc::now());
+                }
+            }
+        }
+        anyhow::bail!("all satellite data providers unavailable for passes({norad_id})")
+    }
+
+    pub async fn fetch_above(
+        &self,
+        lat: f64,
+        lng: f64,
+        alt: f64,
+        radius: i32,
+        category: i32,
+    ) -> anyhow::Result<Vec<N2YOSatelliteInfo>> {
+        for idx in self.ranked_providers() {
+            let provider = &self.providers[idx];
+            match provider.fetch_above(lat, lng, alt, radius, category).await {
+                Ok(resp) => {
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_success(0.0);
+                    return Ok(resp);
+                }
+                Err(_) => {
+                    self.health.lock().unwrap().get_mut(provider.name()).unwrap().record_failure(Utc::now());
+                }
+            }
+        }
+        anyhow::bail!("all satellite data providers unavailable for 'above'")
+    }
+}
@@ -0,0 +1,94 @@
+//! Background sweep that reclaims slot reservations nobody confirmed in time.
+//!
+//! `handlers::reserve_slot` flips a slot to `reserved` and gives the caller
+//! 15 minutes to call `handlers::confirm_reservation`. If that window passes
+//! and the caller never comes back, nothing else ever revisits the row: the
+//! slot is stuck `reserved` forever. This task runs on an interval tick and,
+//! in a single transaction per cycle, deletes timed-out, unconfirmed
+//! reservations and puts their slots back to `available`.
+
+use sqlx::PgPool;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How often the sweep runs, read from `RESERVATION_SWEEP_INTERVAL_SECS`
+/// (default 60s).
+fn sweep_interval() -> Duration {
+    let secs = std::env::var("RESERVATION_SWEEP_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(60);
+    Duration::from_secs(secs)
+}
+
+/// Max reservations reclaimed per cycle, read from
+/// `RESERVATION_SWEEP_BATCH_SIZE` (default 100) — bounds how long a single
+/// sweep transaction holds its row locks.
+fn sweep_batch_size() -> i64 {
+    std::env::var("RESERVATION_SWEEP_BATCH_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(100)
+}
+
+/// Spawns the sweep loop on the current Tokio runtime. Returns immediately;
+/// the loop runs for the life of the process.
+pub fn spawn_reservation_sweeper(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(sweep_interval());
+        let batch_size = sweep_batch_size();
+        loop {
+            ticker.tick().await;
+            match sweep_once(&pool, batch_size).await {
+                Ok(0) => {}
+                Ok(count) => info!("Reclaimed {} expired slot reservation(s)", count),
+                Err(err) => warn!("Reservation sweep failed: {}", err),
+            }
+        }
+    });
+}
+
+/// One sweep cycle: locks up to `batch_size` expired, unconfirmed
+/// reservations with `FOR UPDATE SKIP LOCKED` (so this sweep never waits on a
+/// row a concurrent sweep or an in-flight `confirm_reservation` already holds), frees their
+/// slots, and deletes the reservations, all in one transaction. Returns how
+/// many were reclaimed.
+async fn sweep_once(pool: &PgPool, batch_size: i64) -> Result<u64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let expired = sqlx::query!(
+        "SELECT id, slot_id FROM reservations
+         WHERE confirmed = false AND expires_at < now()
+         ORDER BY expires_at
+         LIMIT $1
+         FOR UPDATE SKIP LOCKED",
+        batch_size,
+    )
+    .fetch_all(&mut tx)
+    .await?;
+
+    if expired.is_empty() {
+        tx.commit().await?;
+        return Ok(0);
+    }
+
+    let reservation_ids: Vec<_> = expired.iter().map(|r| r.id).collect();
+    let slot_ids: Vec<_> = expired.iter().map(|r| r.slot_id).collect();
+
+    sqlx::query!(
+        "UPDATE orbital_slots SET status = 'available' WHERE id = ANY($1)",
+        &slot_ids,
+    )
+    .execute(&mut tx)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM reservations WHERE id = ANY($1)",
+        &reservation_ids,
+    )
+    .execute(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(reservation_ids.len() as u64)
+}
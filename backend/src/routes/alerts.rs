@@ -6,11 +6,58 @@ use axum::{
 use uuid::Uuid;
 use chrono::Utc;
 
+use crate::auth::RequireAuth;
 use crate::models::*;
+use crate::webpush::SubscribeRequest;
 use crate::AppState;
 
+/// `POST /api/alerts/subscribe` — registers a browser push subscription so
+/// `push_critical_alert` can reach it even when no one has the dashboard
+/// tab open.
+pub async fn subscribe_push(
+    _auth: RequireAuth,
+    State(state): State<AppState>,
+    Json(req): Json<SubscribeRequest>,
+) -> Json<serde_json::Value> {
+    let id = state.push_store.register(req);
+    Json(serde_json::json!({ "subscription_id": id }))
+}
+
+/// Pushes a critical collision alert by email and by Web Push to every
+/// registered browser subscription. Called whenever a `Critical`
+/// severity `Alert` is produced, so operators don't have to be polling
+/// `/api/alerts` to notice it.
+async fn push_critical_alert(state: &AppState, alert: &Alert) {
+    if !matches!(alert.severity, AlertSeverity::Critical) {
+        return;
+    }
+    let recipient = std::env::var("ALERTS_RECIPIENT_EMAIL").unwrap_or_else(|_| "ops@orbitalos.example".to_string());
+    if let Err(err) = crate::notification::send_email(
+        &recipient,
+        "OrbitalOS Operations",
+        &format!("[CRITICAL] {}", alert.title),
+        &alert.message,
+    )
+    .await
+    {
+        tracing::warn!("Failed to push critical alert email: {}", err);
+    }
+
+    crate::webpush::dispatch(
+        &state.push_store,
+        &serde_json::json!({
+            "alert_id": alert.id,
+            "title": alert.title,
+            "message": alert.message,
+            "severity": format!("{:?}", alert.severity),
+        }),
+    )
+    .await;
+}
+
 pub async fn get_alerts(
-    State(_state): State<AppState>,
+    _auth: RequireAuth,
+    State(state): State<AppState>,
 ) -> Result<Json<Vec<Alert>>, StatusCode> {
     // Mock alert data for demo
     let alerts = vec![
@@ -49,10 +96,20 @@ pub async fn get_alerts(
         },
     ];
 
+    for alert in &alerts {
+        push_critical_alert(&state, alert).await;
+        state.event_hub.publish(crate::event_hub::Event::AlertCreated {
+            alert_id: alert.id.to_string(),
+            title: alert.title.clone(),
+            severity: format!("{:?}", alert.severity),
+        });
+    }
+
     Ok(Json(alerts))
 }
 
 pub async fn acknowledge_alert(
+    _auth: RequireAuth,
     State(_state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Alert>, StatusCode> {
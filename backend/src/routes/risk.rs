@@ -0,0 +1,237 @@
+// Conjunction screening for `/api/risk/predict`. `calculate_risk_score` used
+// to fabricate `(0.5 + random) * time_factor` instead of actually looking at
+// the catalog. This now propagates the requested satellite and every other
+// satellite in `get_satellites` over the requested horizon, coarse-screens
+// for close approaches, refines the time of closest approach, and estimates
+// Pc with the 2-D Foster/Chan method.
+
+use axum::extract::{State, State as AxumState};
+use axum::http::StatusCode;
+use axum::response::Json;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::alerting;
+use crate::models::*;
+use crate::routes::satellites::get_satellites;
+use crate::AppState;
+
+const COARSE_STEP_SECONDS: i64 = 60;
+/// Broad-phase distance threshold: pairs whose coarse-grid ECI separation
+/// never comes within this are dropped before the expensive TCA refinement.
+const BROAD_PHASE_THRESHOLD_KM: f64 = 10.0;
+/// Combined hard-body radius used for the Pc integral (sum of object radii).
+const HARD_BODY_RADIUS_KM: f64 = 0.02;
+
+struct Eci {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Eci {
+    fn distance_km(&self, other: &Eci) -> f64 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)).sqrt()
+    }
+}
+
+fn propagate(elements: &sgp4::Elements, constants: &sgp4::Constants, at: DateTime<Utc>) -> Option<Eci> {
+    let minutes_since_epoch = (at - elements.datetime.and_utc()).num_seconds() as f64 / 60.0;
+    let prediction = constants.propagate(sgp4::MinutesSinceEpoch(minutes_since_epoch)).ok()?;
+    Some(Eci { x: prediction.position[0], y: prediction.position[1], z: prediction.position[2] })
+}
+
+/// Isotropic position-covariance "radius" (km) used as a stand-in when no
+/// tracked covariance is available, growing with how far we've propagated —
+/// a rough proxy for TLE staleness/propagation error accumulating over time.
+fn default_covariance_sigma_km(hours_since_epoch: f64) -> f64 {
+    (0.5 + hours_since_epoch * 0.05).min(10.0)
+}
+
+/// 2-D Foster/Chan Pc: integrate a combined Gaussian (isotropic here) over
+/// the circle of radius `hard_body_radius_km` in the encounter plane,
+/// approximated by the closed form for a circular combined covariance.
+fn foster_pc(miss_distance_km: f64, combined_sigma_km: f64, hard_body_radius_km: f64) -> f64 {
+    if combined_sigma_km <= 0.0 {
+        return if miss_distance_km <= hard_body_radius_km { 1.0 } else { 0.0 };
+    }
+    // Closed form for an isotropic 2D Gaussian centered on the miss vector:
+    // Pc = 1 - exp(-R^2 / (2*sigma^2)), attenuated as the miss distance grows
+    // past the hard-body radius.
+    let exponent = -(hard_body_radius_km.powi(2)) / (2.0 * combined_sigma_km.powi(2));
+    let max_density_pc = 1.0 - exponent.exp();
+    let offset_sigmas = ((miss_distance_km - hard_body_radius_km).max(0.0)) / combined_sigma_km;
+    max_density_pc * (-0.5 * offset_sigmas.powi(2)).exp()
+}
+
+/// Refines the time of closest approach around `coarse_center` by
+/// golden-section search on the relative-distance function.
+fn refine_tca(
+    elements_a: &sgp4::Elements,
+    constants_a: &sgp4::Constants,
+    elements_b: &sgp4::Elements,
+    constants_b: &sgp4::Constants,
+    coarse_center: DateTime<Utc>,
+) -> Option<(DateTime<Utc>, f64)> {
+    let window_seconds = COARSE_STEP_SECONDS as f64;
+    let distance_at = |offset_seconds: f64| -> Option<f64> {
+        let t = coarse_center + Duration::milliseconds((offset_seconds * 1000.0) as i64);
+        let pos_a = propagate(elements_a, constants_a, t)?;
+        let pos_b = propagate(elements_b, constants_b, t)?;
+        Some(pos_a.distance_km(&pos_b))
+    };
+
+    let gr = 0.618_034;
+    let (mut lo, mut hi) = (-window_seconds, window_seconds);
+    let mut c = hi - gr * (hi - lo);
+    let mut d = lo + gr * (hi - lo);
+    for _ in 0..40 {
+        let fc = distance_at(c)?;
+        let fd = distance_at(d)?;
+        if fc < fd {
+            hi = d;
+        } else {
+            lo = c;
+        }
+        c = hi - gr * (hi - lo);
+        d = lo + gr * (hi - lo);
+    }
+    let offset = (lo + hi) / 2.0;
+    let miss_distance = distance_at(offset)?;
+    Some((coarse_center + Duration::milliseconds((offset * 1000.0) as i64), miss_distance))
+}
+
+struct CandidatePair {
+    tca: DateTime<Utc>,
+    miss_distance_km: f64,
+    pc: f64,
+    other_norad_id: i32,
+    other_name: String,
+}
+
+fn screen_conjunctions(target: &Satellite, catalog: &[Satellite], time_horizon_hours: i32) -> Option<CandidatePair> {
+    let target_elements = sgp4::Elements::from_tle(None, target.tle_line1.as_bytes(), target.tle_line2.as_bytes()).ok()?;
+    let target_constants = sgp4::Constants::from_elements(&target_elements).ok()?;
+    let epoch = target_elements.datetime.and_utc();
+
+    let mut best: Option<CandidatePair> = None;
+    let horizon = Duration::hours(time_horizon_hours as i64);
+    let steps = (horizon.num_seconds() / COARSE_STEP_SECONDS).max(1);
+
+    for other in catalog {
+        if other.id == target.id {
+            continue;
+        }
+        let Ok(other_elements) = sgp4::Elements::from_tle(None, other.tle_line1.as_bytes(), other.tle_line2.as_bytes()) else { continue };
+        let Ok(other_constants) = sgp4::Constants::from_elements(&other_elements) else { continue };
+
+        // Coarse-phase sweep: find the grid point with the smallest
+        // ECI separation before paying for a refinement pass.
+        let mut coarse_best: Option<(DateTime<Utc>, f64)> = None;
+        for step in 0..=steps {
+            let t = epoch + Duration::seconds(step * COARSE_STEP_SECONDS);
+            let (Some(pos_a), Some(pos_b)) = (
+                propagate(&target_elements, &target_constants, t),
+                propagate(&other_elements, &other_constants, t),
+            ) else { continue };
+            let d = pos_a.distance_km(&pos_b);
+            if coarse_best.as_ref().map(|(_, best_d)| d < *best_d).unwrap_or(true) {
+                coarse_best = Some((t, d));
+            }
+        }
+
+        let Some((coarse_center, coarse_distance)) = coarse_best else { continue };
+        if coarse_distance > BROAD_PHASE_THRESHOLD_KM {
+            continue; // never got close on the coarse grid; skip the expensive refine
+        }
+
+        let Some((tca, miss_distance_km)) =
+            refine_tca(&target_elements, &target_constants, &other_elements, &other_constants, coarse_center)
+        else { continue };
+
+        let hours_since_epoch = (tca - epoch).num_seconds() as f64 / 3600.0;
+        let combined_sigma_km = default_covariance_sigma_km(hours_since_epoch.abs()) * std::f64::consts::SQRT_2;
+        let pc = foster_pc(miss_distance_km, combined_sigma_km, HARD_BODY_RADIUS_KM);
+
+        if best.as_ref().map(|b| pc > b.pc).unwrap_or(true) {
+            best = Some(CandidatePair {
+                tca,
+                miss_distance_km,
+                pc,
+                other_norad_id: other.norad_id,
+                other_name: other.name.clone(),
+            });
+        }
+    }
+
+    best
+}
+
+pub async fn predict_risk(
+    State(state): State<AppState>,
+    Json(payload): Json<RiskPredictionRequest>,
+) -> Result<Json<RiskPredictionResponse>, StatusCode> {
+    let Json(catalog) = get_satellites(AxumState(state)).await?;
+    let target = catalog
+        .iter()
+        .find(|s| s.id == payload.satellite_id)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let active_catalog: Vec<Satellite> = catalog.iter().filter(|s| s.is_active).cloned().collect();
+    let screened = screen_conjunctions(target, &active_catalog, payload.time_horizon_hours);
+
+    let (risk_score, collision_probability, closest_approach_time, closest_approach_distance) = match &screened {
+        Some(candidate) => {
+            let risk_score = (candidate.pc.log10().max(-10.0) + 10.0) / 10.0; // fold 1e-10..1 onto 0..1
+            (risk_score.clamp(0.0, 1.0), candidate.pc, candidate.tca, candidate.miss_distance_km)
+        }
+        None => (0.0, 0.0, Utc::now() + Duration::hours(payload.time_horizon_hours as i64), f64::INFINITY),
+    };
+
+    let risk_level = determine_risk_level(collision_probability);
+    let suggested_maneuver = match risk_level {
+        RiskLevel::Critical => Some("Recommended: Perform orbital maneuver to increase altitude by 2km".to_string()),
+        RiskLevel::Warning => Some("Monitor closely: Consider minor trajectory adjustment".to_string()),
+        RiskLevel::Safe => None,
+    };
+
+    // Feed the screened conjunction through the alert pipeline. This must
+    // never fail the request: a dead SendGrid account shouldn't stop a
+    // caller from getting their risk score back.
+    if let Some(candidate) = &screened {
+        let event = alerting::ConjunctionAlertEvent {
+            satellite_a_norad_id: target.norad_id,
+            satellite_a_name: target.name.clone(),
+            satellite_b_norad_id: candidate.other_norad_id,
+            satellite_b_name: candidate.other_name.clone(),
+            tca: candidate.tca,
+            miss_distance_km: candidate.miss_distance_km,
+            pc: candidate.pc,
+            risk_level: risk_level.clone(),
+        };
+        state
+            .conjunction_alerts
+            .evaluate_and_dispatch(&alerting::SendGridNotifier, &event)
+            .await;
+    }
+
+    Ok(Json(RiskPredictionResponse {
+        risk_score,
+        risk_level,
+        collision_probability,
+        closest_approach_time,
+        closest_approach_distance,
+        suggested_maneuver,
+    }))
+}
+
+/// Thresholds from the Foster/Chan Pc literature: Critical >= 1e-4, Warning
+/// >= 1e-6, otherwise Safe.
+fn determine_risk_level(collision_probability: f64) -> RiskLevel {
+    if collision_probability >= 1e-4 {
+        RiskLevel::Critical
+    } else if collision_probability >= 1e-6 {
+        RiskLevel::Warning
+    } else {
+        RiskLevel::Safe
+    }
+}
@@ -106,6 +106,119 @@ pub async fn get_satellite(
     Ok(Json(satellite))
 }
 
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    /// Free-text query matched fuzzily against name/operator (typos tolerated).
+    pub q: Option<String>,
+    /// Facet: exact operator match, e.g. "SpaceX".
+    pub operator: Option<String>,
+    /// Facet: only active/inactive satellites.
+    pub active: Option<bool>,
+    /// Facet: altitude range in km.
+    pub min_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    /// Max edit distance allowed for a fuzzy `q` match. Defaults to 2.
+    pub max_distance: Option<usize>,
+}
+
+#[derive(serde::Serialize)]
+pub struct SearchFacets {
+    pub operators: Vec<String>,
+    pub active_count: usize,
+    pub inactive_count: usize,
+}
+
+#[derive(serde::Serialize)]
+pub struct SearchResponse {
+    pub results: Vec<Satellite>,
+    pub facets: SearchFacets,
+}
+
+/// Classic Levenshtein edit distance, used to let `q` tolerate typos (e.g.
+/// "Starlnik" still matching "Starlink-1001").
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Does `haystack` fuzzily contain `needle` within `max_distance` edits,
+/// checked window-by-window so a short typo'd query still matches inside a
+/// longer satellite name?
+fn fuzzy_contains(haystack: &str, needle: &str, max_distance: usize) -> bool {
+    let haystack = haystack.to_lowercase();
+    let needle = needle.to_lowercase();
+    if haystack.contains(&needle) {
+        return true;
+    }
+    let needle_len = needle.chars().count();
+    let chars: Vec<char> = haystack.chars().collect();
+    if needle_len == 0 || chars.len() < needle_len {
+        return levenshtein(&haystack, &needle) <= max_distance;
+    }
+    chars
+        .windows(needle_len.max(1))
+        .any(|w| levenshtein(&w.iter().collect::<String>(), &needle) <= max_distance)
+}
+
+/// `GET /api/satellites/search` — typo-tolerant free-text search over the
+/// satellite catalog with operator/active/altitude facets, plus a facet
+/// summary of the full (pre-`q`-filter) result set so clients can render
+/// filter chips.
+pub async fn search_satellites(
+    state: State<AppState>,
+    Query(params): Query<SearchQuery>,
+) -> Result<Json<SearchResponse>, StatusCode> {
+    let Json(catalog) = get_satellites(state).await?;
+    let max_distance = params.max_distance.unwrap_or(2);
+
+    let faceted: Vec<Satellite> = catalog
+        .into_iter()
+        .filter(|s| {
+            params
+                .operator
+                .as_ref()
+                .map(|op| op.eq_ignore_ascii_case(&s.operator))
+                .unwrap_or(true)
+        })
+        .filter(|s| params.active.map(|a| a == s.is_active).unwrap_or(true))
+        .filter(|s| params.min_altitude.map(|m| s.altitude >= m).unwrap_or(true))
+        .filter(|s| params.max_altitude.map(|m| s.altitude <= m).unwrap_or(true))
+        .collect();
+
+    let mut operators: Vec<String> = faceted.iter().map(|s| s.operator.clone()).collect();
+    operators.sort();
+    operators.dedup();
+    let facets = SearchFacets {
+        operators,
+        active_count: faceted.iter().filter(|s| s.is_active).count(),
+        inactive_count: faceted.iter().filter(|s| !s.is_active).count(),
+    };
+
+    let results = match &params.q {
+        Some(q) if !q.trim().is_empty() => faceted
+            .into_iter()
+            .filter(|s| {
+                fuzzy_contains(&s.name, q, max_distance) || fuzzy_contains(&s.operator, q, max_distance)
+            })
+            .collect(),
+        _ => faceted,
+    };
+
+    Ok(Json(SearchResponse { results, facets }))
+}
+
 #[derive(Deserialize)]
 pub struct PositionQuery {
     pub lat: f64,
@@ -137,7 +250,7 @@ pub async fn get_tle(
     State(state): State<AppState>,
     Path(norad_id): Path<i32>,
 ) -> Result<Json<N2YOTLEResponse>, StatusCode> {
-    match state.n2yo_service.get_tle(norad_id).await {
+    match state.provider_pool.fetch_tle(norad_id).await {
         Ok(tle_data) => Ok(Json(tle_data)),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
@@ -151,8 +264,8 @@ pub async fn get_positions(
 ) -> Result<Json<N2YOPositionsResponse>, StatusCode> {
     let alt = params.alt.unwrap_or(0.0);
     let seconds = params.seconds.unwrap_or(60);
-    
-    match state.n2yo_service.get_positions(norad_id, params.lat, params.lng, alt, seconds).await {
+
+    match state.provider_pool.fetch_positions(norad_id, params.lat, params.lng, alt, seconds).await {
         Ok(positions) => Ok(Json(positions)),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
@@ -167,8 +280,8 @@ pub async fn get_passes(
     let alt = params.alt.unwrap_or(0.0);
     let days = params.days.unwrap_or(7);
     let min_visibility = params.min_visibility.unwrap_or(300);
-    
-    match state.n2yo_service.get_visual_passes(norad_id, params.lat, params.lng, alt, days, min_visibility).await {
+
+    match state.provider_pool.fetch_passes(norad_id, params.lat, params.lng, alt, days, min_visibility).await {
         Ok(passes) => Ok(Json(passes)),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
@@ -182,8 +295,8 @@ pub async fn get_above(
     let alt = params.alt.unwrap_or(0.0);
     let radius = params.radius.unwrap_or(90);
     let category = params.category.unwrap_or(0);
-    
-    match state.n2yo_service.get_above(params.lat, params.lng, alt, radius, category).await {
+
+    match state.provider_pool.fetch_above(params.lat, params.lng, alt, radius, category).await {
         Ok(satellites) => Ok(Json(satellites)),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 use std::collections::HashMap;
 use rand::prelude::*;
@@ -13,6 +13,14 @@ pub struct Satellite {
     pub latitude: f64,
     pub longitude: f64,
     pub altitude: f64, // km above Earth
+    /// Semi-major axis of the orbit, fixed at construction (or whenever
+    /// `ingest_updates` replaces the orbital elements wholesale) from the
+    /// nominal `altitude` at the time. `propagate_position` reads this
+    /// instead of deriving `a` from `altitude` on every call, since it also
+    /// overwrites `altitude` with the instantaneous ground-track altitude --
+    /// deriving `a` from that would make the effective semi-major axis drift
+    /// call-over-call for any `eccentricity > 0`.
+    pub semi_major_axis_km: f64,
     pub velocity: f64, // km/s
     pub inclination: f64, // degrees
     pub eccentricity: f64,
@@ -70,6 +78,133 @@ pub struct GroundStation {
     pub min_elevation: f64, // minimum elevation for tracking (degrees)
 }
 
+/// Dilution-of-precision metrics for a ground-station site, computed by
+/// `SatelliteService::compute_dop` from the navigation satellites currently
+/// visible there. Mirrors the GDOP/PDOP/HDOP/VDOP/TDOP values a real GNSS
+/// receiver publishes -- lower is better; a few is good geometry, double
+/// digits means the visible satellites are clustered and a fix will be poor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DopResult {
+    pub gdop: f64,
+    pub pdop: f64,
+    pub hdop: f64,
+    pub vdop: f64,
+    pub tdop: f64,
+}
+
+/// One rise-to-set visibility window for a satellite over a ground station,
+/// as produced by `SatelliteService::predict_passes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatellitePass {
+    pub satellite_id: String,
+    /// Acquisition of Signal: elevation crosses above `min_elevation`.
+    pub aos: DateTime<Utc>,
+    /// Time of Closest Approach: the tick of maximum elevation.
+    pub tca: DateTime<Utc>,
+    /// Loss of Signal: elevation crosses back below `min_elevation`.
+    pub los: DateTime<Utc>,
+    pub max_elevation: f64,
+    pub aos_azimuth: f64,
+    pub los_azimuth: f64,
+}
+
+/// One position/element update in a batch ingestion request, matched to an
+/// existing satellite by `id` (preferred) or else `norad_id`. Feeds
+/// `SatelliteService::ingest_updates`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SatelliteUpdate {
+    pub id: Option<String>,
+    pub norad_id: Option<u32>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+    pub velocity: f64,
+    pub inclination: f64,
+    pub eccentricity: f64,
+    pub right_ascension: f64,
+    pub argument_of_perigee: f64,
+    pub mean_anomaly: f64,
+    pub mean_motion: f64,
+}
+
+/// Per-record outcome of `SatelliteService::ingest_updates`: whether that
+/// record was applied, and why not if it wasn't.
+#[derive(Debug, Clone, Serialize)]
+pub struct IngestResult {
+    pub id: Option<String>,
+    pub norad_id: Option<u32>,
+    pub accepted: bool,
+    pub reason: Option<String>,
+}
+
+/// Geodetic (lat/lon in degrees, altitude in km) to ECEF, treating Earth as
+/// a sphere of `EARTH_RADIUS_KM` -- same simplification `propagate_position`
+/// uses, so look angles derived from a propagated satellite position stay
+/// self-consistent with it.
+fn geodetic_to_ecef(lat_deg: f64, lon_deg: f64, alt_km: f64) -> (f64, f64, f64) {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let lat = lat_deg.to_radians();
+    let lon = lon_deg.to_radians();
+    let r = EARTH_RADIUS_KM + alt_km;
+    (r * lat.cos() * lon.cos(), r * lat.cos() * lon.sin(), r * lat.sin())
+}
+
+/// Greenwich Mean Sidereal Time (IAU 1982 approximation), in radians, used
+/// by `SatelliteService::propagate_position` to rotate ECI into ECEF.
+fn gmst_radians(now: DateTime<Utc>) -> f64 {
+    let jd = now.timestamp() as f64 / 86400.0 + 2440587.5;
+    let days_since_j2000 = jd - 2451545.0;
+    let t = days_since_j2000 / 36525.0;
+    let gmst_deg = 280.46061837
+        + 360.98564736629 * days_since_j2000
+        + 0.000387933 * t * t
+        - t * t * t / 38710000.0;
+    gmst_deg.rem_euclid(360.0).to_radians()
+}
+
+/// Inverts a 4x4 matrix via Gauss-Jordan elimination with partial pivoting,
+/// used by `SatelliteService::compute_dop` to turn the DOP geometry matrix
+/// `Aᵀ·A` into `Q`. Returns `None` if the matrix is singular.
+fn invert_4x4(matrix: &[[f64; 4]; 4]) -> Option<[[f64; 4]; 4]> {
+    let mut a = *matrix;
+    let mut inv = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+
+    for col in 0..4 {
+        let pivot_row = (col..4)
+            .max_by(|&r1, &r2| a[r1][col].abs().partial_cmp(&a[r2][col].abs()).unwrap())
+            .unwrap();
+        if a[pivot_row][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot_row);
+        inv.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        for j in 0..4 {
+            a[col][j] /= pivot;
+            inv[col][j] /= pivot;
+        }
+
+        for row in 0..4 {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            for j in 0..4 {
+                a[row][j] -= factor * a[col][j];
+                inv[row][j] -= factor * inv[col][j];
+            }
+        }
+    }
+
+    Some(inv)
+}
+
 /// Satellite service for managing satellite data
 pub struct SatelliteService {
     satellites: HashMap<String, Satellite>,
@@ -144,6 +279,7 @@ impl SatelliteService {
     }
 
     fn create_starlink_satellite(&self, index: u32) -> Satellite {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
         let mut rng = thread_rng();
         
         // Starlink constellation parameters
@@ -159,6 +295,7 @@ impl SatelliteService {
             latitude: latitude,
             longitude: longitude,
             altitude: altitude,
+            semi_major_axis_km: EARTH_RADIUS_KM + altitude,
             velocity: self.calculate_orbital_velocity(altitude),
             inclination: inclination,
             eccentricity: 0.0001 + rng.gen::<f64>() * 0.0020,
@@ -181,6 +318,7 @@ impl SatelliteService {
     }
 
     fn create_gps_satellite(&self, index: u32) -> Satellite {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
         let mut rng = thread_rng();
         
         let inclination = 55.0;
@@ -195,6 +333,7 @@ impl SatelliteService {
             latitude: latitude,
             longitude: longitude,
             altitude: altitude,
+            semi_major_axis_km: EARTH_RADIUS_KM + altitude,
             velocity: self.calculate_orbital_velocity(altitude),
             inclination: inclination,
             eccentricity: 0.01 + rng.gen::<f64>() * 0.01,
@@ -217,6 +356,7 @@ impl SatelliteService {
     }
 
     fn create_weather_satellite(&self, index: u32) -> Satellite {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
         let mut rng = thread_rng();
         
         let altitude = 35786.0;
@@ -230,6 +370,7 @@ impl SatelliteService {
             latitude: latitude,
             longitude: longitude,
             altitude: altitude,
+            semi_major_axis_km: EARTH_RADIUS_KM + altitude,
             velocity: self.calculate_orbital_velocity(altitude),
             inclination: 0.1,
             eccentricity: 0.0001,
@@ -252,6 +393,7 @@ impl SatelliteService {
     }
 
     fn create_earth_observation_satellite(&self, index: u32) -> Satellite {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
         let mut rng = thread_rng();
         
         let inclination = 98.0 + rng.gen::<f64>() * 2.0;
@@ -266,6 +408,7 @@ impl SatelliteService {
             latitude: latitude,
             longitude: longitude,
             altitude: altitude,
+            semi_major_axis_km: EARTH_RADIUS_KM + altitude,
             velocity: self.calculate_orbital_velocity(altitude),
             inclination: inclination,
             eccentricity: 0.001 + rng.gen::<f64>() * 0.01,
@@ -288,6 +431,7 @@ impl SatelliteService {
     }
 
     fn create_iss(&self) -> Satellite {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
         let mut rng = thread_rng();
         
         let altitude = 408.0;
@@ -302,6 +446,7 @@ impl SatelliteService {
             latitude: latitude,
             longitude: longitude,
             altitude: altitude,
+            semi_major_axis_km: EARTH_RADIUS_KM + altitude,
             velocity: self.calculate_orbital_velocity(altitude),
             inclination: inclination,
             eccentricity: 0.0003,
@@ -374,37 +519,117 @@ impl SatelliteService {
             .collect()
     }
 
-    /// Check if satellite is visible from observer location
+    /// Check if satellite is visible from observer location: a real
+    /// topocentric look-angle test instead of the old "within 2000km great
+    /// circle distance and above 100km altitude" heuristic, which ignored
+    /// the observer's horizon and `min_elevation` entirely.
     fn is_satellite_visible(
         &self,
         satellite: &Satellite,
         observer_lat: f64,
         observer_lon: f64,
-        _observer_alt: f64,
-        _min_elevation: f64,
+        observer_alt: f64,
+        min_elevation: f64,
     ) -> bool {
-        let distance = self.calculate_distance(
-            observer_lat, observer_lon,
-            satellite.latitude, satellite.longitude
-        );
-        
-        distance < 2000.0 && satellite.altitude > 100.0
+        let (elevation, _azimuth, _range_km) =
+            self.look_angles(satellite, observer_lat, observer_lon, observer_alt);
+        elevation >= min_elevation
     }
 
-    /// Calculate great circle distance between two points
-    fn calculate_distance(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
-        const EARTH_RADIUS: f64 = 6371.0; // km
-        
-        let lat1_rad = lat1.to_radians();
-        let lat2_rad = lat2.to_radians();
-        let delta_lat = (lat2 - lat1).to_radians();
-        let delta_lon = (lon2 - lon1).to_radians();
-        
-        let a = (delta_lat / 2.0).sin().powi(2) +
-            lat1_rad.cos() * lat2_rad.cos() * (delta_lon / 2.0).sin().powi(2);
-        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
-        
-        EARTH_RADIUS * c
+    /// Topocentric look angles from an observer to `satellite`: elevation and
+    /// azimuth in degrees, plus slant range in km. Converts both positions to
+    /// ECEF (spherical Earth, consistent with `propagate_position` above),
+    /// then projects the observer->satellite vector onto the observer's
+    /// local up/north/east frame.
+    pub fn look_angles(&self, satellite: &Satellite, observer_lat: f64, observer_lon: f64, observer_alt: f64) -> (f64, f64, f64) {
+        let (ox, oy, oz) = geodetic_to_ecef(observer_lat, observer_lon, observer_alt);
+        let (sx, sy, sz) = geodetic_to_ecef(satellite.latitude, satellite.longitude, satellite.altitude);
+
+        let our = (ox, oy, oz);
+        let dx = (sx - ox, sy - oy, sz - oz);
+
+        let our_mag = (our.0 * our.0 + our.1 * our.1 + our.2 * our.2).sqrt();
+        let dx_mag = (dx.0 * dx.0 + dx.1 * dx.1 + dx.2 * dx.2).sqrt();
+
+        let dot_our_dx = our.0 * dx.0 + our.1 * dx.1 + our.2 * dx.2;
+        let elevation = 90.0 - (dot_our_dx / (our_mag * dx_mag)).acos().to_degrees();
+
+        // Local north/east vectors built from the observer's ECEF components.
+        let north = (-oz * ox, -oz * oy, ox * ox + oy * oy);
+        let east = (-oy, ox, 0.0);
+
+        let north_mag = (north.0 * north.0 + north.1 * north.1 + north.2 * north.2).sqrt();
+        let east_mag = (east.0 * east.0 + east.1 * east.1 + east.2 * east.2).sqrt();
+
+        let dot_north_dx = north.0 * dx.0 + north.1 * dx.1 + north.2 * dx.2;
+        let dot_east_dx = east.0 * dx.0 + east.1 * dx.1 + east.2 * dx.2;
+
+        let mut azimuth = (dot_east_dx / (east_mag * dx_mag))
+            .atan2(dot_north_dx / (north_mag * dx_mag))
+            .to_degrees();
+        if azimuth < 0.0 {
+            azimuth += 360.0;
+        }
+
+        (elevation, azimuth, dx_mag)
+    }
+
+    /// Dilution of precision a receiver at `(observer_lat, observer_lon,
+    /// observer_alt)` would see from the GPS/navigation satellites currently
+    /// visible above `min_elevation`. For each visible satellite, the unit
+    /// line-of-sight vector in the local ENU frame forms a row
+    /// `[-e_east, -e_north, -e_up, 1]` of the geometry matrix `A`; `Q =
+    /// (Aᵀ·A)⁻¹` gives GDOP/PDOP/HDOP/VDOP/TDOP from its diagonal. Returns
+    /// `None` when fewer than four satellites are visible, since the
+    /// geometry is then singular and no 3D+time fix exists.
+    pub fn compute_dop(
+        &self,
+        observer_lat: f64,
+        observer_lon: f64,
+        observer_alt: f64,
+        min_elevation: f64,
+    ) -> Option<DopResult> {
+        let visible: Vec<&Satellite> = self
+            .satellites
+            .values()
+            .filter(|satellite| matches!(satellite.satellite_type, SatelliteType::Navigation))
+            .filter(|satellite| {
+                self.is_satellite_visible(satellite, observer_lat, observer_lon, observer_alt, min_elevation)
+            })
+            .collect();
+
+        if visible.len() < 4 {
+            return None;
+        }
+
+        let mut normal_matrix = [[0.0; 4]; 4];
+        for satellite in &visible {
+            let (elevation, azimuth, _range_km) =
+                self.look_angles(satellite, observer_lat, observer_lon, observer_alt);
+            let el = elevation.to_radians();
+            let az = azimuth.to_radians();
+            let row = [
+                -(el.cos() * az.sin()), // -e_east
+                -(el.cos() * az.cos()), // -e_north
+                -el.sin(),              // -e_up
+                1.0,
+            ];
+            for i in 0..4 {
+                for j in 0..4 {
+                    normal_matrix[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        let q = invert_4x4(&normal_matrix)?;
+
+        Some(DopResult {
+            gdop: (q[0][0] + q[1][1] + q[2][2] + q[3][3]).sqrt(),
+            pdop: (q[0][0] + q[1][1] + q[2][2]).sqrt(),
+            hdop: (q[0][0] + q[1][1]).sqrt(),
+            vdop: q[2][2].sqrt(),
+            tdop: q[3][3].sqrt(),
+        })
     }
 
     /// Update satellite positions (simulate orbital motion)
@@ -412,55 +637,189 @@ impl SatelliteService {
         for satellite in self.satellites.values_mut() {
             let angular_velocity = satellite.mean_motion * 360.0 / 1440.0;
             let time_step = 1.0;
-            
+
             satellite.mean_anomaly += angular_velocity * time_step;
             satellite.mean_anomaly %= 360.0;
-            
-            // Update position inline to avoid borrow checker issues
-            let mean_anomaly_rad = satellite.mean_anomaly.to_radians();
-            let inclination_rad = satellite.inclination.to_radians();
-            let raan_rad = satellite.right_ascension.to_radians();
-            
-            let x = satellite.altitude * mean_anomaly_rad.cos();
-            let y = satellite.altitude * mean_anomaly_rad.sin() * inclination_rad.cos();
-            let z = satellite.altitude * mean_anomaly_rad.sin() * inclination_rad.sin();
-            
-            satellite.latitude = (z / satellite.altitude).asin().to_degrees();
-            satellite.longitude = (y.atan2(x) + raan_rad).to_degrees();
-            
-            while satellite.longitude > 180.0 {
-                satellite.longitude -= 360.0;
-            }
-            while satellite.longitude < -180.0 {
-                satellite.longitude += 360.0;
-            }
-            
-            satellite.latitude = satellite.latitude.max(-90.0).min(90.0);
+
+            Self::propagate_position(satellite);
             satellite.updated_at = Utc::now();
         }
     }
 
     /// Update satellite position from orbital elements (simplified)
     fn update_position_from_elements(&self, satellite: &mut Satellite) {
-        let mean_anomaly_rad = satellite.mean_anomaly.to_radians();
-        let inclination_rad = satellite.inclination.to_radians();
-        let raan_rad = satellite.right_ascension.to_radians();
-        
-        let x = satellite.altitude * mean_anomaly_rad.cos();
-        let y = satellite.altitude * mean_anomaly_rad.sin() * inclination_rad.cos();
-        let z = satellite.altitude * mean_anomaly_rad.sin() * inclination_rad.sin();
-        
-        satellite.latitude = (z / satellite.altitude).asin().to_degrees();
-        satellite.longitude = (y.atan2(x) + raan_rad).to_degrees();
-        
+        Self::propagate_position(satellite);
+    }
+
+    /// Two-body Keplerian propagator: turns the orbital elements already
+    /// stored on `Satellite` into a ground-track latitude/longitude/altitude.
+    ///
+    /// `mean_anomaly` used to be plugged straight in as if it were the true
+    /// anomaly, and `altitude` was multiplied by raw trig functions of it —
+    /// the lat/lon that came out had no physical meaning. This instead solves
+    /// Kepler's equation for the eccentric anomaly, builds the ECI position
+    /// via the perifocal 3-1-3 rotation (RAAN, inclination, argument of
+    /// perigee), rotates into ECEF by the current Greenwich Mean Sidereal
+    /// Time, and reads lat/lon/altitude off that — treating Earth as a
+    /// sphere of `EARTH_RADIUS_KM`, matching every other distance/velocity
+    /// calculation in this module rather than pulling in a WGS84 ellipsoid.
+    fn propagate_position(satellite: &mut Satellite) {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
+
+        let e = satellite.eccentricity;
+        let m = satellite.mean_anomaly.to_radians();
+        let omega = satellite.argument_of_perigee.to_radians();
+        let inclination = satellite.inclination.to_radians();
+        let raan = satellite.right_ascension.to_radians();
+        // Read the fixed semi-major axis rather than rederiving it from
+        // `satellite.altitude` -- this function overwrites `altitude` with
+        // the instantaneous ground-track altitude below, so deriving `a`
+        // from it here would make the effective semi-major axis drift
+        // call-over-call for any `eccentricity > 0`.
+        let a = satellite.semi_major_axis_km;
+
+        // E - e*sin(E) = M, solved by Newton-Raphson. Converges in a handful
+        // of iterations for the near-circular orbits (e < 0.1) every
+        // constellation in this file uses.
+        let mut ecc_anomaly = m;
+        for _ in 0..5 {
+            ecc_anomaly -= (ecc_anomaly - e * ecc_anomaly.sin() - m) / (1.0 - e * ecc_anomaly.cos());
+        }
+
+        let true_anomaly = 2.0
+            * ((1.0 + e).sqrt() * (ecc_anomaly / 2.0).sin())
+                .atan2((1.0 - e).sqrt() * (ecc_anomaly / 2.0).cos());
+        let r = a * (1.0 - e * ecc_anomaly.cos());
+
+        // Perifocal frame: x toward perigee, y 90 degrees ahead in the
+        // orbital plane.
+        let x_pf = r * true_anomaly.cos();
+        let y_pf = r * true_anomaly.sin();
+
+        // Perifocal -> ECI via the standard 3-1-3 rotation (RAAN,
+        // inclination, argument of perigee).
+        let (sin_raan, cos_raan) = raan.sin_cos();
+        let (sin_i, cos_i) = inclination.sin_cos();
+        let (sin_omega, cos_omega) = omega.sin_cos();
+
+        let x_eci = (cos_raan * cos_omega - sin_raan * sin_omega * cos_i) * x_pf
+            + (-cos_raan * sin_omega - sin_raan * cos_omega * cos_i) * y_pf;
+        let y_eci = (sin_raan * cos_omega + cos_raan * sin_omega * cos_i) * x_pf
+            + (-sin_raan * sin_omega + cos_raan * cos_omega * cos_i) * y_pf;
+        let z_eci = (sin_omega * sin_i) * x_pf + (cos_omega * sin_i) * y_pf;
+
+        // ECI -> ECEF: rotate about Z by -GMST.
+        let theta = gmst_radians(Utc::now());
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let x_ecef = x_eci * cos_theta + y_eci * sin_theta;
+        let y_ecef = -x_eci * sin_theta + y_eci * cos_theta;
+        let z_ecef = z_eci;
+
+        satellite.latitude = (z_ecef / r).asin().to_degrees().clamp(-90.0, 90.0);
+        satellite.longitude = y_ecef.atan2(x_ecef).to_degrees();
+        satellite.altitude = r - EARTH_RADIUS_KM;
+
         while satellite.longitude > 180.0 {
             satellite.longitude -= 360.0;
         }
         while satellite.longitude < -180.0 {
             satellite.longitude += 360.0;
         }
-        
-        satellite.latitude = satellite.latitude.max(-90.0).min(90.0);
+    }
+
+    /// Samples a satellite's ground track forward over one full orbital
+    /// period by stepping a clone's mean anomaly in even increments and
+    /// replaying the Keplerian propagator at each step, leaving the
+    /// satellite stored in this service untouched. Feeds the GeoJSON
+    /// `LineString` export in `geojson::to_feature_collection`.
+    pub fn sample_ground_track(&self, satellite: &Satellite, samples: usize) -> Vec<(f64, f64)> {
+        let samples = samples.max(2);
+        let mut working = satellite.clone();
+        let step_degrees = 360.0 / samples as f64;
+
+        (0..=samples)
+            .map(|_| {
+                Self::propagate_position(&mut working);
+                working.mean_anomaly = (working.mean_anomaly + step_degrees) % 360.0;
+                (working.longitude, working.latitude)
+            })
+            .collect()
+    }
+
+    /// Predicts every rise-to-set visibility window each satellite has over
+    /// `station_id` between `start` and `start + horizon`: steps a cloned
+    /// copy of each satellite's elements forward by `step`, testing
+    /// elevation against the station's `min_elevation` with `look_angles`
+    /// at each tick. Acquisition-of-Signal is the upward crossing of
+    /// `min_elevation`, Time-of-Closest-Approach the tick of maximum
+    /// elevation during the pass, and Loss-of-Signal the downward
+    /// crossing. A pass still in progress at `start + horizon` is dropped,
+    /// since it has no LOS within the requested window. Returns an empty
+    /// list for an unknown `station_id`.
+    pub fn predict_passes(
+        &self,
+        station_id: &str,
+        start: DateTime<Utc>,
+        horizon: Duration,
+        step: Duration,
+    ) -> Vec<SatellitePass> {
+        let Some(station) = self.ground_stations.get(station_id) else {
+            return Vec::new();
+        };
+
+        let end = start + horizon;
+        let step_minutes = step.num_milliseconds() as f64 / 60_000.0;
+        let mut passes = Vec::new();
+
+        for satellite in self.satellites.values() {
+            let mut working = satellite.clone();
+            let mut in_pass = false;
+            let mut prev_elevation = f64::MIN;
+            let mut aos = start;
+            let mut aos_azimuth = 0.0;
+            let mut tca = start;
+            let mut max_elevation = f64::MIN;
+
+            let mut tick = start;
+            while tick <= end {
+                Self::propagate_position(&mut working);
+                let (elevation, azimuth, _range_km) =
+                    self.look_angles(&working, station.latitude, station.longitude, station.altitude);
+
+                if elevation >= station.min_elevation && prev_elevation < station.min_elevation {
+                    in_pass = true;
+                    aos = tick;
+                    aos_azimuth = azimuth;
+                    tca = tick;
+                    max_elevation = elevation;
+                } else if in_pass {
+                    if elevation > max_elevation {
+                        max_elevation = elevation;
+                        tca = tick;
+                    }
+                    if elevation < station.min_elevation {
+                        passes.push(SatellitePass {
+                            satellite_id: satellite.id.clone(),
+                            aos,
+                            tca,
+                            los: tick,
+                            max_elevation,
+                            aos_azimuth,
+                            los_azimuth: azimuth,
+                        });
+                        in_pass = false;
+                    }
+                }
+
+                prev_elevation = elevation;
+                let angular_velocity_per_minute = working.mean_motion * 360.0 / 1440.0;
+                working.mean_anomaly =
+                    (working.mean_anomaly + angular_velocity_per_minute * step_minutes) % 360.0;
+                tick += step;
+            }
+        }
+
+        passes
     }
 
     /// Get satellite by ID
@@ -473,6 +832,103 @@ impl SatelliteService {
         self.satellites.insert(satellite.id.clone(), satellite);
     }
 
+    /// Applies a batch of externally-fed position/element updates -- real
+    /// feeders buffer several fixes and flush them together rather than
+    /// posting one at a time. Each record is matched to an existing
+    /// satellite by `id` (preferred) or else `norad_id`; a match has its
+    /// `latitude`, `longitude`, `altitude`, `velocity`, orbital elements,
+    /// and `updated_at` merged in via `add_satellite`'s upsert path, while
+    /// an unmatched record with an `id` or `norad_id` is inserted as a new
+    /// satellite with otherwise-unknown fields defaulted. Each record is
+    /// validated independently (latitude in [-90, 90], longitude in
+    /// [-180, 180], altitude > 0) so one bad record doesn't block the
+    /// rest. Returns one `IngestResult` per input record, in order.
+    pub fn ingest_updates(&mut self, updates: Vec<SatelliteUpdate>) -> Vec<IngestResult> {
+        updates.into_iter().map(|update| self.ingest_one(update)).collect()
+    }
+
+    fn ingest_one(&mut self, update: SatelliteUpdate) -> IngestResult {
+        let id = update.id.clone();
+        let norad_id = update.norad_id;
+
+        if !(-90.0..=90.0).contains(&update.latitude) {
+            return IngestResult { id, norad_id, accepted: false, reason: Some("latitude out of range [-90, 90]".to_string()) };
+        }
+        if !(-180.0..=180.0).contains(&update.longitude) {
+            return IngestResult { id, norad_id, accepted: false, reason: Some("longitude out of range [-180, 180]".to_string()) };
+        }
+        if update.altitude <= 0.0 {
+            return IngestResult { id, norad_id, accepted: false, reason: Some("altitude must be > 0".to_string()) };
+        }
+
+        let matched_id = update
+            .id
+            .as_deref()
+            .filter(|i| self.satellites.contains_key(*i))
+            .map(|i| i.to_string())
+            .or_else(|| {
+                update
+                    .norad_id
+                    .and_then(|norad| self.satellites.values().find(|s| s.norad_id == Some(norad)))
+                    .map(|s| s.id.clone())
+            });
+
+        let Some(satellite_id) = matched_id.or_else(|| id.clone()) else {
+            return IngestResult { id, norad_id, accepted: false, reason: Some("update must include id or norad_id".to_string()) };
+        };
+
+        let mut satellite = self.satellites.get(&satellite_id).cloned().unwrap_or_else(|| Satellite {
+            id: satellite_id.clone(),
+            name: format!("ingested-{}", satellite_id),
+            norad_id,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: 0.0,
+            semi_major_axis_km: 0.0,
+            velocity: 0.0,
+            inclination: 0.0,
+            eccentricity: 0.0,
+            right_ascension: 0.0,
+            argument_of_perigee: 0.0,
+            mean_anomaly: 0.0,
+            mean_motion: 0.0,
+            satellite_type: SatelliteType::Other,
+            status: SatelliteStatus::Active,
+            launch_date: None,
+            mass: None,
+            dimensions: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
+
+        const EARTH_RADIUS_KM: f64 = 6371.0;
+
+        satellite.latitude = update.latitude;
+        satellite.longitude = update.longitude;
+        satellite.altitude = update.altitude;
+        // An external update wholesale-replaces the orbital elements, so the
+        // fixed semi-major axis needs to be rederived from the new nominal
+        // altitude too -- otherwise it would keep reflecting whatever orbit
+        // the satellite had before this update.
+        satellite.semi_major_axis_km = EARTH_RADIUS_KM + update.altitude;
+        satellite.velocity = update.velocity;
+        satellite.inclination = update.inclination;
+        satellite.eccentricity = update.eccentricity;
+        satellite.right_ascension = update.right_ascension;
+        satellite.argument_of_perigee = update.argument_of_perigee;
+        satellite.mean_anomaly = update.mean_anomaly;
+        satellite.mean_motion = update.mean_motion;
+        satellite.updated_at = Utc::now();
+        if satellite.norad_id.is_none() {
+            satellite.norad_id = norad_id;
+        }
+
+        let result_norad_id = satellite.norad_id;
+        self.add_satellite(satellite);
+
+        IngestResult { id: Some(satellite_id), norad_id: result_norad_id, accepted: true, reason: None }
+    }
+
     /// Remove satellite
     pub fn remove_satellite(&mut self, id: &str) -> Option<Satellite> {
         self.satellites.remove(id)
@@ -482,4 +938,68 @@ impl SatelliteService {
     pub fn get_ground_stations(&self) -> Vec<GroundStation> {
         self.ground_stations.values().cloned().collect()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invert_4x4_recovers_identity_for_identity_input() {
+        let identity = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+
+        let inverse = invert_4x4(&identity).expect("identity matrix is invertible");
+        assert_eq!(inverse, identity);
+    }
+
+    #[test]
+    fn invert_4x4_round_trips_a_non_trivial_matrix() {
+        let matrix = [
+            [4.0, 0.0, 0.0, 1.0],
+            [0.0, 3.0, 1.0, 0.0],
+            [0.0, 1.0, 2.0, 0.0],
+            [1.0, 0.0, 0.0, 2.0],
+        ];
+
+        let inverse = invert_4x4(&matrix).expect("matrix is non-singular");
+
+        // A * A^-1 should be the identity, within floating-point tolerance.
+        let mut product = [[0.0; 4]; 4];
+        for row in 0..4 {
+            for col in 0..4 {
+                product[row][col] = (0..4).map(|k| matrix[row][k] * inverse[k][col]).sum();
+            }
+        }
+
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!(
+                    (product[row][col] - expected).abs() < 1e-9,
+                    "product[{row}][{col}] = {}, expected {expected}",
+                    product[row][col]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invert_4x4_returns_none_for_singular_matrix() {
+        // Second row is a multiple of the first, so this geometry matrix is
+        // rank-deficient -- `compute_dop` hits this whenever the visible
+        // satellites don't span all four dimensions (e.g. too few in view).
+        let singular = [
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0, 0.0],
+        ];
+
+        assert!(invert_4x4(&singular).is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,212 @@
+// Server-side session store backing refresh-token rotation. The access JWT
+// minted by `auth::login`/`verify_otp`/`oidc_callback` is now short-lived
+// (~15 min); this module issues the long-lived opaque refresh token that
+// sits alongside it and rotates it on each use, so a stolen access token
+// expires quickly and a stolen *unused* refresh token can be revoked the
+// moment reuse is detected.
+//
+// Schema (see the `refresh_tokens` table sketched in db.rs):
+//   id UUID PRIMARY KEY, family_id UUID, user_id UUID, token_hash BYTEA,
+//   user_agent TEXT, ip_address TEXT, issued_at TIMESTAMPTZ,
+//   expires_at TIMESTAMPTZ, used BOOLEAN NOT NULL DEFAULT FALSE
+
+use chrono::{Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+
+pub struct IssuedRefreshToken {
+    pub token: String,
+    pub family_id: Uuid,
+}
+
+pub enum RefreshError {
+    NotFound,
+    Expired,
+    /// The presented token had already been marked `used` — this is the
+    /// signature of a stolen/replayed refresh token, so the whole family
+    /// was just revoked and the caller must re-authenticate from scratch.
+    Reused,
+    Database(sqlx::Error),
+}
+
+fn hash_token(token: &str) -> Vec<u8> {
+    Sha256::digest(token.as_bytes()).to_vec()
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, bytes)
+}
+
+/// Issues a brand-new refresh-token family for a freshly authenticated user
+/// (i.e. not a rotation of an existing token).
+pub async fn issue(
+    pool: &PgPool,
+    user_id: Uuid,
+    user_agent: Option<&str>,
+    ip_address: Option<&str>,
+) -> Result<IssuedRefreshToken, sqlx::Error> {
+    let family_id = Uuid::new_v4();
+    let token = random_token();
+    let now = Utc::now();
+    sqlx::query(
+        "INSERT INTO refresh_tokens (id, family_id, user_id, token_hash, user_agent, ip_address, issued_at, expires_at, used)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, FALSE)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(family_id)
+    .bind(user_id)
+    .bind(hash_token(&token))
+    .bind(user_agent)
+    .bind(ip_address)
+    .bind(now)
+    .bind(now + Duration::days(REFRESH_TOKEN_TTL_DAYS))
+    .execute(pool)
+    .await?;
+
+    Ok(IssuedRefreshToken { token, family_id })
+}
+
+/// Looks up `presented_token` by hash, validates it, and rotates it: the old
+/// row is marked `used` and a fresh row is inserted in the same family. If a
+/// token that was already `used` is presented again, the entire family is
+/// revoked (deleted) and `RefreshError::Reused` is returned so the caller
+/// knows to force a full re-login instead of silently failing one refresh.
+pub async fn rotate(
+    pool: &PgPool,
+    presented_token: &str,
+    user_agent: Option<&str>,
+    ip_address: Option<&str>,
+) -> Result<(IssuedRefreshToken, Uuid), RefreshError> {
+    let presented_hash = hash_token(presented_token);
+
+    let row = sqlx::query(
+        "SELECT id, family_id, user_id, expires_at, used FROM refresh_tokens WHERE token_hash = $1",
+    )
+    .bind(&presented_hash)
+    .fetch_optional(pool)
+    .await
+    .map_err(RefreshError::Database)?;
+
+    let row = row.ok_or(RefreshError::NotFound)?;
+    let family_id: Uuid = row.get("family_id");
+    let user_id: Uuid = row.get("user_id");
+    let expires_at: chrono::DateTime<Utc> = row.get("expires_at");
+    let used: bool = row.get("used");
+
+    if used {
+        revoke_family(pool, family_id).await.map_err(RefreshError::Database)?;
+        return Err(RefreshError::Reused);
+    }
+    if expires_at < Utc::now() {
+        return Err(RefreshError::Expired);
+    }
+
+    sqlx::query("UPDATE refresh_tokens SET used = TRUE WHERE token_hash = $1")
+        .bind(&presented_hash)
+        .execute(pool)
+        .await
+        .map_err(RefreshError::Database)?;
+
+    let new_token = random_token();
+    let now = Utc::now();
+    sqlx::query(
+        "INSERT INTO refresh_tokens (id, family_id, user_id, token_hash, user_agent, ip_address, issued_at, expires_at, used)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, FALSE)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(family_id)
+    .bind(user_id)
+    .bind(hash_token(&new_token))
+    .bind(user_agent)
+    .bind(ip_address)
+    .bind(now)
+    .bind(now + Duration::days(REFRESH_TOKEN_TTL_DAYS))
+    .execute(pool)
+    .await
+    .map_err(RefreshError::Database)?;
+
+    Ok((IssuedRefreshToken { token: new_token, family_id }, user_id))
+}
+
+/// True if `family_id` still has at least one row in `refresh_tokens` — i.e.
+/// the session hasn't been torn down by `revoke_family`/`revoke_by_token`
+/// (explicit logout, or automatic revocation on detected token reuse). The
+/// `Claims`/`RequireRole`/`RequireAuth` extractors in `auth.rs` call this so
+/// an access token survives exactly as long as the session backing it.
+pub async fn is_active(pool: &PgPool, family_id: Uuid) -> Result<bool, sqlx::Error> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM refresh_tokens WHERE family_id = $1)",
+    )
+    .bind(family_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
+
+#[derive(serde::Serialize)]
+pub struct SessionInfo {
+    pub family_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub issued_at: chrono::DateTime<Utc>,
+    pub expires_at: chrono::DateTime<Utc>,
+}
+
+/// `GET /api/auth/sessions` support: one row per active (non-revoked,
+/// unexpired) device, for a "this is everywhere you're logged in" UI.
+/// Rotation leaves the spent `used` predecessor around for replay
+/// detection, so only the live row of each family is surfaced.
+pub async fn list_for_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<SessionInfo>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT family_id, user_agent, ip_address, issued_at, expires_at
+         FROM refresh_tokens
+         WHERE user_id = $1 AND used = FALSE AND expires_at > now()
+         ORDER BY issued_at DESC",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| SessionInfo {
+            family_id: row.get("family_id"),
+            user_agent: row.get("user_agent"),
+            ip_address: row.get("ip_address"),
+            issued_at: row.get("issued_at"),
+            expires_at: row.get("expires_at"),
+        })
+        .collect())
+}
+
+/// Deletes every row in a family, immediately invalidating all refresh
+/// tokens ever issued down that chain.
+pub async fn revoke_family(pool: &PgPool, family_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM refresh_tokens WHERE family_id = $1")
+        .bind(family_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// `POST /api/auth/logout` support: revokes the family the presented
+/// refresh token belongs to, regardless of whether it was already used.
+pub async fn revoke_by_token(pool: &PgPool, presented_token: &str) -> Result<(), sqlx::Error> {
+    let presented_hash = hash_token(presented_token);
+    let family_id: Option<Uuid> = sqlx::query("SELECT family_id FROM refresh_tokens WHERE token_hash = $1")
+        .bind(&presented_hash)
+        .fetch_optional(pool)
+        .await?
+        .map(|row| row.get("family_id"));
+
+    if let Some(family_id) = family_id {
+        revoke_family(pool, family_id).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Authenticated Space-Track.org GP source for `orbit.rs`.
+//
+// Celestrak's anonymous feed only serves the `GROUP=active` JSON snapshot.
+// Space-Track exposes the full catalog (including decayed/classified-adjacent
+// objects outside that group) behind a cookie/session login, with its GP
+// class query returning the same field names Celestrak uses, so the result
+// slots into `CelestrakRecord` unchanged.
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use tokio::sync::RwLock;
+
+const LOGIN_URL: &str = "https://www.space-track.org/ajaxauth/login";
+const QUERY_BASE: &str = "https://www.space-track.org/basicspacedata/query";
+
+/// Holds one session cookie, obtained once via `login` and reused across
+/// `fetch_gp_json` calls until it's rejected with a 401, mirroring how a
+/// long-lived client keeps a token instead of re-authenticating per request.
+pub struct SpaceTrackSession {
+    client: Client,
+    username: String,
+    password: String,
+    cookie: RwLock<Option<String>>,
+}
+
+impl SpaceTrackSession {
+    pub fn new(username: String, password: String) -> Self {
+        Self {
+            client: Client::new(),
+            username,
+            password,
+            cookie: RwLock::new(None),
+        }
+    }
+
+    /// Builds a session from `SPACETRACK_USERNAME`/`SPACETRACK_PASSWORD`;
+    /// `None` when either is unset, so callers can fall back to Celestrak.
+    pub fn from_env() -> Option<Self> {
+        let username = std::env::var("SPACETRACK_USERNAME").ok()?;
+        let password = std::env::var("SPACETRACK_PASSWORD").ok()?;
+        Some(Self::new(username, password))
+    }
+
+    async fn login(&self) -> Result<String> {
+        let response = self
+            .client
+            .post(LOGIN_URL)
+            .form(&[("identity", &self.username), ("password", &self.password)])
+            .send()
+            .await
+            .context("space-track login request failed")?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "space-track login rejected with status {}",
+                response.status()
+            ));
+        }
+
+        let cookie = response
+            .headers()
+            .get(reqwest::header::SET_COOKIE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("space-track login response carried no session cookie"))?;
+
+        *self.cookie.write().await = Some(cookie.clone());
+        Ok(cookie)
+    }
+
+    /// Runs a GP-class query, e.g. `predicate` of `NORAD_CAT_ID/>0` for the
+    /// full catalog or `OBJECT_TYPE/DEBRIS` for a single-catalog filter.
+    /// Retries once with a fresh login if the cached cookie has expired.
+    pub async fn fetch_gp_json(&self, predicate: &str) -> Result<Vec<serde_json::Value>> {
+        let url = format!("{QUERY_BASE}/class/gp/{predicate}/format/json");
+
+        let cookie = match self.cookie.read().await.clone() {
+            Some(cookie) => cookie,
+            None => self.login().await?,
+        };
+
+        let mut response = self
+            .client
+            .get(&url)
+            .header(reqwest::header::COOKIE, &cookie)
+            .send()
+            .await
+            .context("space-track GP query failed")?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            let cookie = self.login().await?;
+            response = self
+                .client
+                .get(&url)
+                .header(reqwest::header::COOKIE, &cookie)
+                .send()
+                .await
+                .context("space-track GP query failed after re-login")?;
+        }
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "space-track GP query returned status {}",
+                response.status()
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .context("unable to parse space-track GP response")
+    }
+}
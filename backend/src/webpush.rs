@@ -0,0 +1,209 @@
+// Web Push delivery for collision alerts (RFC 8030 push service protocol,
+// RFC 8291 `aes128gcm` content encoding, RFC 8292 VAPID). `get_alerts`
+// previously only answered on-demand polls; this dispatches a payload to
+// every registered browser endpoint the moment a new alert is produced.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes128Gcm,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::Utc;
+use hkdf::Hkdf;
+use jsonwebtoken::{encode, EncodingKey, Header};
+use p256::ecdh::diffie_hellman;
+use p256::pkcs8::DecodePrivateKey;
+use p256::{ecdsa::SigningKey, PublicKey, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PushSubscription {
+    pub id: Uuid,
+    pub endpoint: String,
+    /// Subscriber's P-256 public key, base64url (uncompressed SEC1 point).
+    pub p256dh: String,
+    /// Per-subscription auth secret, base64url, used as the HKDF salt.
+    pub auth: String,
+}
+
+#[derive(Deserialize)]
+pub struct SubscribeRequest {
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+}
+
+/// In-memory registry of browser push endpoints, mirroring the pattern used
+/// for `pending_2fa`/`KeyStore` elsewhere in this crate: no dedicated table
+/// yet, so a restart drops subscriptions and clients re-subscribe on load.
+pub struct PushStore {
+    subscriptions: Mutex<HashMap<Uuid, PushSubscription>>,
+}
+
+impl PushStore {
+    pub fn new() -> Self {
+        Self { subscriptions: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn register(&self, req: SubscribeRequest) -> Uuid {
+        let id = Uuid::new_v4();
+        self.subscriptions.lock().unwrap().insert(
+            id,
+            PushSubscription { id, endpoint: req.endpoint, p256dh: req.p256dh, auth: req.auth },
+        );
+        id
+    }
+
+    pub fn all(&self) -> Vec<PushSubscription> {
+        self.subscriptions.lock().unwrap().values().cloned().collect()
+    }
+}
+
+impl Default for PushStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct VapidClaims {
+    aud: String,
+    exp: usize,
+    sub: String,
+}
+
+/// Signs a VAPID JWT (ES256 over `{aud, exp, sub}`) for the `Authorization`
+/// header of a push request, using the server's long-lived VAPID keypair.
+fn vapid_jwt(endpoint_origin: &str, vapid_private_key_pem: &str, subject: &str) -> Result<String, String> {
+    let claims = VapidClaims {
+        aud: endpoint_origin.to_string(),
+        exp: (Utc::now() + chrono::Duration::hours(12)).timestamp() as usize,
+        sub: subject.to_string(),
+    };
+    encode(
+        &Header::new(jsonwebtoken::Algorithm::ES256),
+        &claims,
+        &EncodingKey::from_ec_pem(vapid_private_key_pem.as_bytes()).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn endpoint_origin(endpoint: &str) -> String {
+    reqwest::Url::parse(endpoint)
+        .ok()
+        .map(|u| format!("{}://{}", u.scheme(), u.host_str().unwrap_or_default()))
+        .unwrap_or_default()
+}
+
+/// Encrypts `plaintext` per RFC 8291 `aes128gcm` for a single subscriber and
+/// returns the full request body: `salt(16) || rs(4) || keyid_len(1) ||
+/// ephemeral_pubkey(65) || ciphertext`.
+fn encrypt_aes128gcm(subscription: &PushSubscription, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let subscriber_key_bytes =
+        URL_SAFE_NO_PAD.decode(&subscription.p256dh).map_err(|_| "bad p256dh")?;
+    let auth_secret = URL_SAFE_NO_PAD.decode(&subscription.auth).map_err(|_| "bad auth secret")?;
+    let subscriber_public = PublicKey::from_sec1_bytes(&subscriber_key_bytes).map_err(|_| "bad p256dh point")?;
+
+    let server_secret = SecretKey::random(&mut rand::rngs::OsRng);
+    let server_public = server_secret.public_key();
+
+    let shared_secret = diffie_hellman(server_secret.to_nonzero_scalar(), subscriber_public.as_affine());
+
+    let mut salt = [0u8; 16];
+    rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut salt);
+
+    // ECDH + auth-secret combine per RFC 8291 section 3.3/3.4.
+    let ikm_info = [
+        b"WebPush: info\0".as_slice(),
+        subscriber_key_bytes.as_slice(),
+        server_public.to_sec1_bytes().as_ref(),
+    ]
+    .concat();
+    let (_, ikm_hk) = Hkdf::<Sha256>::extract(Some(&auth_secret), shared_secret.raw_secret_bytes().as_slice());
+    let mut ikm = [0u8; 32];
+    ikm_hk.expand(&ikm_info, &mut ikm).map_err(|_| "hkdf expand ikm")?;
+
+    let (_, prk) = Hkdf::<Sha256>::extract(Some(&salt), &ikm);
+    let mut cek = [0u8; 16];
+    prk.expand(b"Content-Encoding: aes128gcm\0", &mut cek).map_err(|_| "hkdf expand cek")?;
+    let mut nonce = [0u8; 12];
+    prk.expand(b"Content-Encoding: nonce\0", &mut nonce).map_err(|_| "hkdf expand nonce")?;
+
+    let cipher = Aes128Gcm::new_from_slice(&cek).map_err(|_| "bad cek length")?;
+    // A single-record message: plaintext gets one 0x02 padding-delimiter
+    // byte appended (no further padding) before encryption.
+    let mut padded = plaintext.to_vec();
+    padded.push(2);
+    let ciphertext = cipher
+        .encrypt(aes_gcm::Nonce::from_slice(&nonce), Payload { msg: &padded, aad: &[] })
+        .map_err(|_| "aead encrypt failed")?;
+
+    let server_public_bytes = server_public.to_sec1_bytes();
+    let mut body = Vec::with_capacity(16 + 4 + 1 + server_public_bytes.len() + ciphertext.len());
+    body.extend_from_slice(&salt);
+    body.extend_from_slice(&(4096u32).to_be_bytes()); // record size
+    body.push(server_public_bytes.len() as u8);
+    body.extend_from_slice(&server_public_bytes);
+    body.extend_from_slice(&ciphertext);
+    Ok(body)
+}
+
+/// Loads the VAPID ES256 private key from `VAPID_PRIVATE_KEY_PEM` and
+/// signs/encrypts/POSTs `payload` to every registered subscription.
+pub async fn dispatch(store: &PushStore, payload: &serde_json::Value) {
+    let vapid_private_key_pem = match std::env::var("VAPID_PRIVATE_KEY_PEM") {
+        Ok(pem) => pem,
+        Err(_) => {
+            tracing::warn!("VAPID_PRIVATE_KEY_PEM not set; skipping web push dispatch");
+            return;
+        }
+    };
+    let vapid_subject =
+        std::env::var("VAPID_SUBJECT").unwrap_or_else(|_| "mailto:ops@orbitalos.example".to_string());
+    let plaintext = serde_json::to_vec(payload).unwrap_or_default();
+
+    let client = reqwest::Client::new();
+    for subscription in store.all() {
+        let origin = endpoint_origin(&subscription.endpoint);
+        let jwt = match vapid_jwt(&origin, &vapid_private_key_pem, &vapid_subject) {
+            Ok(jwt) => jwt,
+            Err(err) => {
+                tracing::warn!("Failed to sign VAPID JWT for {}: {}", subscription.endpoint, err);
+                continue;
+            }
+        };
+        let body = match encrypt_aes128gcm(&subscription, &plaintext) {
+            Ok(body) => body,
+            Err(err) => {
+                tracing::warn!("Failed to encrypt push payload for {}: {}", subscription.endpoint, err);
+                continue;
+            }
+        };
+
+        let result = client
+            .post(&subscription.endpoint)
+            .header("Content-Encoding", "aes128gcm")
+            .header("Content-Type", "application/octet-stream")
+            .header("TTL", "60")
+            .header("Authorization", format!("vapid t={jwt}, k={}", public_key_b64(&vapid_private_key_pem).unwrap_or_default()))
+            .body(body)
+            .send()
+            .await;
+
+        if let Err(err) = result {
+            tracing::warn!("Push delivery failed for {}: {}", subscription.endpoint, err);
+        }
+    }
+}
+
+/// Re-derives the uncompressed public key point from the signing key PEM,
+/// base64url-encoded for the VAPID `Authorization` header's `k` parameter.
+fn public_key_b64(vapid_private_key_pem: &str) -> Option<String> {
+    let signing_key = SigningKey::from_pkcs8_pem(vapid_private_key_pem).ok()?;
+    let verifying_key = signing_key.verifying_key();
+    Some(URL_SAFE_NO_PAD.encode(verifying_key.to_encoded_point(false).as_bytes()))
+}